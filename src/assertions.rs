@@ -0,0 +1,308 @@
+use std::collections::BTreeSet;
+use std::path::{Path, PathBuf};
+use walkdir::WalkDir;
+
+/// Assert that a file exists at the specified path.
+///
+/// # Arguments
+///
+/// * `path` - The path to the file (can be a `&str`, [`String`], [`Path`], or [`PathBuf`]).
+///
+/// # Panics
+///
+/// If nothing exists at `path`, or if `path` is not a file.
+///
+/// # Example
+///
+/// ```
+/// use file_io::assert_file_exists;
+///
+/// assert_file_exists("Cargo.toml");
+/// ```
+#[track_caller]
+pub fn assert_file_exists<P: AsRef<Path>>(path: P) {
+    let path = path.as_ref();
+    assert!(path.exists(), "Path does not exist: {path:?}");
+    assert!(path.is_file(), "Path is not a file: {path:?}");
+}
+
+/// Reads a file for an assertion.
+///
+/// # Arguments
+///
+/// * `path` - The path to the file.
+///
+/// # Returns
+///
+/// The contents of the file.
+///
+/// # Panics
+///
+/// If the file does not exist or cannot be read as UTF-8 text.
+#[track_caller]
+fn read_for_assertion(path: &Path) -> String {
+    assert_file_exists(path);
+    std::fs::read_to_string(path)
+        .unwrap_or_else(|error| panic!("Failed to read file {path:?} as text: {error}"))
+}
+
+/// Assert that a file contains the specified text.
+///
+/// # Arguments
+///
+/// * `path` - The path to the file (can be a `&str`, [`String`], [`Path`], or [`PathBuf`]).
+/// * `needle` - The text that the file should contain.
+///
+/// # Panics
+///
+/// If the file does not exist, cannot be read as UTF-8 text, or does not contain `needle`.
+///
+/// # Example
+///
+/// ```
+/// use file_io::assert_file_contains;
+///
+/// assert_file_contains("Cargo.toml", "name = \"file-io\"");
+/// ```
+#[track_caller]
+pub fn assert_file_contains<P: AsRef<Path>>(path: P, needle: &str) {
+    let path = path.as_ref();
+    let content = read_for_assertion(path);
+    assert!(
+        content.contains(needle),
+        "File {path:?} does not contain {needle:?}.\nFile contents:\n{content}"
+    );
+}
+
+/// Assert that the contents of a file are equal to the specified text.
+///
+/// # Arguments
+///
+/// * `path` - The path to the file (can be a `&str`, [`String`], [`Path`], or [`PathBuf`]).
+/// * `expected` - The expected contents of the file.
+///
+/// # Panics
+///
+/// If the file does not exist, cannot be read as UTF-8 text, or its contents differ from
+/// `expected` (in which case the panic message includes a unified diff).
+///
+/// # Example
+///
+/// ```
+/// use file_io::{assert_file_eq, save_string_to_file};
+///
+/// save_string_to_file("Hello, world!", "folder/subfolder_22/file_18.txt");
+/// assert_file_eq("folder/subfolder_22/file_18.txt", "Hello, world!");
+/// ```
+#[track_caller]
+pub fn assert_file_eq<P: AsRef<Path>>(path: P, expected: &str) {
+    let path = path.as_ref();
+    let content = read_for_assertion(path);
+    if content != expected {
+        let diff = crate::diff::unified_diff(expected, &content, "expected", &format!("{path:?}"));
+        panic!("Contents of file {path:?} differ from the expected contents.\n{diff}");
+    }
+}
+
+/// Assert that a folder contains exactly the specified entries (files and folders).
+///
+/// # Arguments
+///
+/// * `path` - The path to the folder (can be a `&str`, [`String`], [`Path`], or [`PathBuf`]).
+/// * `names` - Names of the entries that the folder should (directly) contain, in any order.
+///
+/// # Panics
+///
+/// If the folder does not exist or cannot be read, or if any entry is missing or unexpected (in
+/// which case the panic message lists them).
+///
+/// # Example
+///
+/// ```
+/// use file_io::assert_folder_contains_exactly;
+///
+/// assert_folder_contains_exactly(".vscode", &["extensions.json", "settings.json"]);
+/// ```
+#[track_caller]
+pub fn assert_folder_contains_exactly<P: AsRef<Path>>(path: P, names: &[&str]) {
+    let path = path.as_ref();
+    assert!(path.is_dir(), "Path is not a directory: {path:?}");
+    let actual: BTreeSet<String> = std::fs::read_dir(path)
+        .unwrap_or_else(|error| panic!("Failed to read directory {path:?}: {error}"))
+        .map(|entry| entry.unwrap().file_name().to_string_lossy().into_owned())
+        .collect();
+    let expected: BTreeSet<String> = names.iter().map(|name| name.to_string()).collect();
+    let missing: Vec<&String> = expected.difference(&actual).collect();
+    let unexpected: Vec<&String> = actual.difference(&expected).collect();
+    assert!(
+        missing.is_empty() && unexpected.is_empty(),
+        "Folder {path:?} does not contain exactly the expected entries.\n\
+         Missing: {missing:?}\nUnexpected: {unexpected:?}"
+    );
+}
+
+/// Collects the relative paths of all entries within a folder (recursively).
+///
+/// # Arguments
+///
+/// * `path` - The path to the folder.
+///
+/// # Returns
+///
+/// The relative paths of all entries (files and folders) within the folder.
+///
+/// # Panics
+///
+/// If the folder cannot be traversed.
+#[track_caller]
+fn collect_relative_paths(path: &Path) -> BTreeSet<PathBuf> {
+    assert!(path.is_dir(), "Path is not a directory: {path:?}");
+    WalkDir::new(path)
+        .min_depth(1)
+        .into_iter()
+        .map(|entry| {
+            let entry =
+                entry.unwrap_or_else(|error| panic!("Failed to traverse {path:?}: {error}"));
+            entry.path().strip_prefix(path).unwrap().to_path_buf()
+        })
+        .collect()
+}
+
+/// Assert that two folders have the same structure and file contents.
+///
+/// # Arguments
+///
+/// * `actual` - The path to the first folder (can be a `&str`, [`String`], [`Path`], or
+///   [`PathBuf`]).
+/// * `expected` - The path to the second folder (can be a `&str`, [`String`], [`Path`], or
+///   [`PathBuf`]).
+///
+/// # Panics
+///
+/// If either folder cannot be traversed, if an entry only exists in one of the folders, if an
+/// entry is a file in one folder and a folder in the other, or if the contents of any file differ.
+///
+/// # Example
+///
+/// ```
+/// use file_io::{assert_trees_equal, copy_folder};
+///
+/// copy_folder(".vscode", "folder/vscode_copy");
+/// assert_trees_equal("folder/vscode_copy", ".vscode");
+/// ```
+#[track_caller]
+pub fn assert_trees_equal<P: AsRef<Path>, Q: AsRef<Path>>(actual: P, expected: Q) {
+    let (actual, expected) = (actual.as_ref(), expected.as_ref());
+
+    // Compare the structure.
+    let actual_paths = collect_relative_paths(actual);
+    let expected_paths = collect_relative_paths(expected);
+    let missing: Vec<&PathBuf> = expected_paths.difference(&actual_paths).collect();
+    let unexpected: Vec<&PathBuf> = actual_paths.difference(&expected_paths).collect();
+    assert!(
+        missing.is_empty() && unexpected.is_empty(),
+        "Folder {actual:?} does not have the same structure as {expected:?}.\n\
+         Missing: {missing:?}\nUnexpected: {unexpected:?}"
+    );
+
+    // Compare the entry types and file contents.
+    for relative_path in &actual_paths {
+        let (a, b) = (actual.join(relative_path), expected.join(relative_path));
+        assert_eq!(
+            a.is_dir(),
+            b.is_dir(),
+            "{relative_path:?} is a folder in only one of {actual:?} and {expected:?}."
+        );
+        if a.is_file() {
+            let (a_bytes, b_bytes) = (std::fs::read(&a).unwrap(), std::fs::read(&b).unwrap());
+            if a_bytes != b_bytes {
+                let diff = match (std::str::from_utf8(&b_bytes), std::str::from_utf8(&a_bytes)) {
+                    (Ok(b_text), Ok(a_text)) => crate::diff::unified_diff(
+                        b_text,
+                        a_text,
+                        &format!("{b:?}"),
+                        &format!("{a:?}"),
+                    ),
+                    _ => String::from("(binary files differ)\n"),
+                };
+                panic!("Contents of {a:?} differ from {b:?}.\n{diff}");
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::save::save_string_to_file;
+    use crate::test_utils::get_temp_dir_path;
+    use tempfile::tempdir;
+
+    #[test]
+    fn test_file_assertions() {
+        // Create a temporary directory to work in.
+        let temp_dir = tempdir().unwrap();
+        let file_path = get_temp_dir_path(&temp_dir).join("file.txt");
+        save_string_to_file("line 1\nline 2\n", &file_path);
+
+        // Passing assertions.
+        assert_file_exists(&file_path);
+        assert_file_contains(&file_path, "line 2");
+        assert_file_eq(&file_path, "line 1\nline 2\n");
+
+        // Failing assertions.
+        let error = std::panic::catch_unwind(|| assert_file_eq(&file_path, "line 1\nline 3\n"))
+            .unwrap_err();
+        let message = error.downcast_ref::<String>().unwrap();
+        assert!(message.contains("-line 3\n+line 2\n"));
+        assert!(std::panic::catch_unwind(|| assert_file_contains(&file_path, "line 3")).is_err());
+        assert!(
+            std::panic::catch_unwind(|| assert_file_exists(file_path.with_extension("md")))
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn test_assert_folder_contains_exactly() {
+        // Create a temporary directory to work in.
+        let temp_dir = tempdir().unwrap();
+        let temp_dir_path = get_temp_dir_path(&temp_dir);
+        save_string_to_file("", temp_dir_path.join("a.txt"));
+        save_string_to_file("", temp_dir_path.join("sub/b.txt"));
+
+        // Passing assertion.
+        assert_folder_contains_exactly(&temp_dir_path, &["sub", "a.txt"]);
+
+        // Failing assertion.
+        let error = std::panic::catch_unwind(|| {
+            assert_folder_contains_exactly(&temp_dir_path, &["a.txt", "c.txt"])
+        })
+        .unwrap_err();
+        let message = error.downcast_ref::<String>().unwrap();
+        assert!(message.contains("Missing: [\"c.txt\"]\nUnexpected: [\"sub\"]"));
+    }
+
+    #[test]
+    fn test_assert_trees_equal() {
+        // Create a temporary directory to work in.
+        let temp_dir = tempdir().unwrap();
+        let temp_dir_path = get_temp_dir_path(&temp_dir);
+        let (a, b) = (temp_dir_path.join("a"), temp_dir_path.join("b"));
+        for root in [&a, &b] {
+            save_string_to_file("x", root.join("x.txt"));
+            save_string_to_file("y", root.join("sub/y.txt"));
+        }
+
+        // Passing assertion.
+        assert_trees_equal(&a, &b);
+
+        // Failing assertions (differing contents, then differing structure).
+        save_string_to_file("z", b.join("sub/y.txt"));
+        assert!(std::panic::catch_unwind(|| assert_trees_equal(&a, &b)).is_err());
+        save_string_to_file("y", b.join("sub/y.txt"));
+        save_string_to_file("", b.join("extra.txt"));
+        let error = std::panic::catch_unwind(|| assert_trees_equal(&a, &b)).unwrap_err();
+        let message = error.downcast_ref::<String>().unwrap();
+        assert!(message.contains("Missing: [\"extra.txt\"]"));
+    }
+}
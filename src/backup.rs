@@ -0,0 +1,341 @@
+use crate::copy::{copy_file, copy_folder};
+use crate::create::create_folder;
+use crate::delete::{delete_file, delete_folder};
+use crate::timestamp::current_timestamp;
+use std::path::{Path, PathBuf};
+
+/// Options controlling where backups are made by [`backup_file_with_options`] and
+/// [`backup_folder_with_options`].
+///
+/// The default options match the behavior of [`backup_file`] and [`backup_folder`].
+///
+/// # Example
+///
+/// ```
+/// use file_io::BackupOptions;
+/// use std::path::PathBuf;
+///
+/// // Make backups in a dedicated folder.
+/// let options = BackupOptions {
+///     backup_root: Some(PathBuf::from("/path/to/backups")),
+/// };
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct BackupOptions {
+    /// The folder to make backups in. If `None`, backups are made next to the original file or
+    /// folder.
+    pub backup_root: Option<PathBuf>,
+}
+
+/// Determines a new path to back up a file or folder to.
+///
+/// # Arguments
+///
+/// * `path` - The path to the file or folder to back up.
+/// * `options` - Options controlling where the backup is made.
+///
+/// # Returns
+///
+/// The path of the backup (the name of the file or folder with a timestamp and a `.bak` suffix
+/// appended, e.g. `file.txt.20261016T093015.123456Z.bak`). Nothing exists at this path yet.
+///
+/// # Panics
+///
+/// If the path does not have a file name.
+fn new_backup_path(path: &Path, options: &BackupOptions) -> PathBuf {
+    let name = path
+        .file_name()
+        .unwrap_or_else(|| panic!("Path '{path:?}' does not have a file name."));
+    let folder = match &options.backup_root {
+        Some(backup_root) => backup_root.as_path(),
+        None => path.parent().unwrap_or(Path::new("")),
+    };
+
+    // Take a new timestamp if a backup was already made at the same time.
+    loop {
+        let mut backup = folder.join(name).into_os_string();
+        backup.push(format!(".{}.bak", current_timestamp()));
+        let backup = PathBuf::from(backup);
+        if !backup.exists() {
+            return backup;
+        }
+    }
+}
+
+/// Backs up a file by copying it to a timestamped sibling file.
+///
+/// # Arguments
+///
+/// * `path` - The path to the file to back up (can be a `&str`, [`String`], [`Path`], or
+///   [`std::path::PathBuf`]).
+///
+/// # Returns
+///
+/// The path of the backup (e.g. `file.txt.20261016T093015.123456Z.bak` next to `file.txt`).
+///
+/// # Panics
+///
+/// If the file does not exist or some error is encountered while copying it.
+///
+/// # Example
+///
+/// ```
+/// use file_io::{backup_file, load_file_as_string, save_string_to_file};
+///
+/// let path: &str = "folder/subfolder_36/file_41.txt";
+/// save_string_to_file("Hello, world!", path);
+///
+/// let backup = backup_file(path);
+/// assert_eq!(load_file_as_string(backup), "Hello, world!");
+/// ```
+pub fn backup_file<P: AsRef<Path>>(path: P) -> PathBuf {
+    backup_file_with_options(path, &BackupOptions::default())
+}
+
+/// Backs up a file by copying it to a timestamped file, with options controlling where the backup
+/// is made.
+///
+/// # Arguments
+///
+/// * `path` - The path to the file to back up (can be a `&str`, [`String`], [`Path`], or
+///   [`std::path::PathBuf`]).
+/// * `options` - Options controlling where the backup is made.
+///
+/// # Returns
+///
+/// The path of the backup.
+///
+/// # Panics
+///
+/// If the file does not exist or some error is encountered while copying it.
+///
+/// # Note
+///
+/// This function will create the backup folder if it does not already exist.
+///
+/// # Example
+///
+/// ```
+/// use file_io::{BackupOptions, backup_file_with_options, save_string_to_file};
+/// use std::path::PathBuf;
+///
+/// let path: &str = "folder/subfolder_36/file_42.txt";
+/// save_string_to_file("Hello, world!", path);
+///
+/// let options = BackupOptions {
+///     backup_root: Some(PathBuf::from("folder/subfolder_36/backups")),
+/// };
+/// let backup = backup_file_with_options(path, &options);
+/// assert!(backup.starts_with("folder/subfolder_36/backups"));
+/// ```
+pub fn backup_file_with_options<P: AsRef<Path>>(path: P, options: &BackupOptions) -> PathBuf {
+    let path = path.as_ref();
+    if !path.is_file() {
+        panic!("File '{path:?}' does not exist.");
+    }
+    let backup = new_backup_path(path, options);
+    copy_file(path, &backup);
+    backup
+}
+
+/// Backs up a folder by copying it (along with its contents) to a timestamped sibling folder.
+///
+/// # Arguments
+///
+/// * `path` - The path to the folder to back up (can be a `&str`, [`String`], [`Path`], or
+///   [`std::path::PathBuf`]).
+///
+/// # Returns
+///
+/// The path of the backup (e.g. `folder.20261016T093015.123456Z.bak` next to `folder`).
+///
+/// # Panics
+///
+/// If the folder does not exist or some error is encountered while copying it.
+///
+/// # Example
+///
+/// ```
+/// use file_io::{backup_folder, load_file_as_string, save_string_to_file};
+///
+/// let path: &str = "folder/subfolder_37";
+/// save_string_to_file("Hello, world!", "folder/subfolder_37/file_43.txt");
+///
+/// let backup = backup_folder(path);
+/// assert_eq!(load_file_as_string(backup.join("file_43.txt")), "Hello, world!");
+/// ```
+pub fn backup_folder<P: AsRef<Path>>(path: P) -> PathBuf {
+    backup_folder_with_options(path, &BackupOptions::default())
+}
+
+/// Backs up a folder by copying it (along with its contents) to a timestamped folder, with options
+/// controlling where the backup is made.
+///
+/// # Arguments
+///
+/// * `path` - The path to the folder to back up (can be a `&str`, [`String`], [`Path`], or
+///   [`std::path::PathBuf`]).
+/// * `options` - Options controlling where the backup is made.
+///
+/// # Returns
+///
+/// The path of the backup.
+///
+/// # Panics
+///
+/// If the folder does not exist or some error is encountered while copying it.
+///
+/// # Note
+///
+/// This function will create the backup folder if it does not already exist.
+///
+/// # Example
+///
+/// ```
+/// use file_io::{BackupOptions, backup_folder_with_options, save_string_to_file};
+/// use std::path::PathBuf;
+///
+/// let path: &str = "folder/subfolder_38";
+/// save_string_to_file("Hello, world!", "folder/subfolder_38/file_44.txt");
+///
+/// let options = BackupOptions {
+///     backup_root: Some(PathBuf::from("folder/subfolder_38_backups")),
+/// };
+/// let backup = backup_folder_with_options(path, &options);
+/// assert!(backup.join("file_44.txt").is_file());
+/// ```
+pub fn backup_folder_with_options<P: AsRef<Path>>(path: P, options: &BackupOptions) -> PathBuf {
+    let path = path.as_ref();
+    if !path.is_dir() {
+        panic!("Folder '{path:?}' does not exist.");
+    }
+    let backup = new_backup_path(path, options);
+    create_folder(&backup);
+    copy_folder(path, &backup);
+    backup
+}
+
+/// Restores a file or folder from a backup made by [`backup_file`] or [`backup_folder`].
+///
+/// # Arguments
+///
+/// * `backup` - The path to the backup (can be a `&str`, [`String`], [`Path`], or
+///   [`std::path::PathBuf`]).
+/// * `path` - The path to restore the file or folder to (can be a `&str`, [`String`], [`Path`],
+///   or [`std::path::PathBuf`]).
+///
+/// # Panics
+///
+/// If the backup does not exist or some error is encountered while restoring it.
+///
+/// # Note
+///
+/// * Whatever currently exists at `path` is replaced by the backup (for folders, files that were
+///   created since the backup was made are deleted).
+/// * The backup itself is kept, so it can be restored again.
+///
+/// # Example
+///
+/// ```
+/// use file_io::{backup_file, load_file_as_string, restore_backup, save_string_to_file};
+///
+/// let path: &str = "folder/subfolder_36/file_45.txt";
+/// save_string_to_file("good", path);
+/// let backup = backup_file(path);
+///
+/// // Roll back a bad change.
+/// save_string_to_file("bad", path);
+/// restore_backup(&backup, path);
+/// assert_eq!(load_file_as_string(path), "good");
+/// ```
+pub fn restore_backup<P: AsRef<Path>, Q: AsRef<Path>>(backup: P, path: Q) {
+    let backup = backup.as_ref();
+    let path = path.as_ref();
+    if backup.is_file() {
+        if path.is_dir() {
+            delete_folder(path);
+        }
+        copy_file(backup, path);
+    } else if backup.is_dir() {
+        if path.is_dir() {
+            delete_folder(path);
+        } else {
+            delete_file(path);
+        }
+        create_folder(path);
+        copy_folder(backup, path);
+    } else {
+        panic!("Backup '{backup:?}' does not exist.");
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::load::load_file_as_string;
+    use crate::save::save_string_to_file;
+    use crate::test_utils::get_temp_dir_path;
+    use tempfile::tempdir;
+
+    #[test]
+    fn test_backup_restore_file() {
+        // Create a temporary directory to work in.
+        let temp_dir = tempdir().unwrap();
+
+        // Path to the file.
+        let file_path = get_temp_dir_path(&temp_dir).join("config.toml");
+        save_string_to_file("good", &file_path);
+
+        // Back up the file next to itself, twice.
+        let first = backup_file(&file_path);
+        let second = backup_file(&file_path);
+        assert_ne!(first, second);
+        assert_eq!(first.parent(), file_path.parent());
+        let name = first.file_name().unwrap().to_str().unwrap();
+        assert!(name.starts_with("config.toml.") && name.ends_with("Z.bak"));
+
+        // Restore the file after changing it.
+        save_string_to_file("bad", &file_path);
+        restore_backup(&first, &file_path);
+        assert_eq!(load_file_as_string(&file_path), "good");
+        assert!(first.is_file());
+    }
+
+    #[test]
+    fn test_backup_restore_folder() {
+        // Create a temporary directory to work in.
+        let temp_dir = tempdir().unwrap();
+        let temp_dir_path = get_temp_dir_path(&temp_dir);
+
+        // Folder to back up.
+        let folder_path = temp_dir_path.join("data");
+        save_string_to_file("a", folder_path.join("a.txt"));
+        save_string_to_file("b", folder_path.join("sub/b.txt"));
+
+        // Back up the folder into a backup root.
+        let options = BackupOptions {
+            backup_root: Some(temp_dir_path.join("backups")),
+        };
+        let backup = backup_folder_with_options(&folder_path, &options);
+        assert!(backup.starts_with(temp_dir_path.join("backups")));
+        assert_eq!(load_file_as_string(backup.join("sub/b.txt")), "b");
+
+        // Restoring the folder undoes any changes, including new files.
+        save_string_to_file("changed", folder_path.join("a.txt"));
+        save_string_to_file("new", folder_path.join("new.txt"));
+        restore_backup(&backup, &folder_path);
+        assert_eq!(load_file_as_string(folder_path.join("a.txt")), "a");
+        assert_eq!(load_file_as_string(folder_path.join("sub/b.txt")), "b");
+        assert!(!folder_path.join("new.txt").exists());
+    }
+
+    #[test]
+    #[should_panic(expected = "does not exist")]
+    fn test_backup_file_missing() {
+        // Create a temporary directory to work in.
+        let temp_dir = tempdir().unwrap();
+
+        // Try to back up a file that does not exist.
+        backup_file(get_temp_dir_path(&temp_dir).join("missing.txt"));
+    }
+}
@@ -1,5 +1,67 @@
-use crate::path::get_cwd;
+use crate::path::{canonicalize_path_for_display, get_cwd};
+use crate::temp::TempFolderGuard;
 use std::path::{Path, PathBuf};
+use std::sync::{Condvar, Mutex, PoisonError};
+use std::thread::ThreadId;
+
+/// Owner (and number of nested acquisitions) of the global current working directory lock.
+static CWD_LOCK_OWNER: Mutex<Option<(ThreadId, usize)>> = Mutex::new(None);
+
+/// Signalled whenever the global current working directory lock is released.
+static CWD_LOCK_RELEASED: Condvar = Condvar::new();
+
+/// Guard holding the global current working directory lock (released when dropped).
+///
+/// The lock is reentrant, so a thread that already holds it can acquire it again (e.g. for nested
+/// calls to [`cd_locked`]).
+struct CwdLock;
+
+impl CwdLock {
+    /// Acquires the global current working directory lock, blocking until it is available.
+    ///
+    /// # Returns
+    ///
+    /// A guard that releases the lock when dropped.
+    fn acquire() -> Self {
+        let current = std::thread::current().id();
+        let mut owner = CWD_LOCK_OWNER
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner);
+        loop {
+            match owner.as_mut() {
+                None => {
+                    *owner = Some((current, 1));
+                    return Self;
+                }
+                Some((thread, count)) if *thread == current => {
+                    *count += 1;
+                    return Self;
+                }
+                Some(_) => {
+                    owner = CWD_LOCK_RELEASED
+                        .wait(owner)
+                        .unwrap_or_else(PoisonError::into_inner);
+                }
+            }
+        }
+    }
+}
+
+// Release the lock when the guard goes out of scope.
+impl Drop for CwdLock {
+    fn drop(&mut self) {
+        let mut owner = CWD_LOCK_OWNER
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner);
+        if let Some((_, count)) = owner.as_mut() {
+            *count -= 1;
+            if *count == 0 {
+                *owner = None;
+                CWD_LOCK_RELEASED.notify_all();
+            }
+        }
+    }
+}
 
 /// A struct that changes the current working directory to a specified path.
 ///
@@ -9,6 +71,15 @@ use std::path::{Path, PathBuf};
 pub struct CdGuard {
     /// Path to the original current working directory.
     original_cwd: PathBuf,
+
+    /// Path to the current working directory that was changed to.
+    target_cwd: PathBuf,
+
+    /// Whether the original current working directory should be restored when dropped.
+    active: bool,
+
+    /// Global current working directory lock (only held by guards created in locking mode).
+    _lock: Option<CwdLock>,
 }
 
 impl CdGuard {
@@ -23,23 +94,304 @@ impl CdGuard {
     ///
     /// An instance of [`CdGuard`] that will restore the original directory when dropped.
     pub fn new<P: AsRef<Path>>(path: P) -> Self {
-        let path = path.as_ref();
+        Self::with_lock(path.as_ref(), None)
+    }
+
+    /// Constructor (locking mode).
+    ///
+    /// The returned guard holds a global lock for its entire lifetime, so that no other thread can
+    /// change the current working directory through a locking [`CdGuard`] until it is dropped.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - The path to change the current working directory to (can be a `&str`, [`String`],
+    ///   [`Path`], or [`PathBuf`]).
+    ///
+    /// # Returns
+    ///
+    /// An instance of [`CdGuard`] that will restore the original directory and release the lock
+    /// when dropped.
+    pub fn new_locked<P: AsRef<Path>>(path: P) -> Self {
+        Self::with_lock(path.as_ref(), Some(CwdLock::acquire()))
+    }
+
+    /// Changes the current working directory while (optionally) holding the global lock.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - The path to change the current working directory to.
+    /// * `lock` - The global lock (if in locking mode).
+    ///
+    /// # Returns
+    ///
+    /// An instance of [`CdGuard`] that will restore the original directory when dropped.
+    fn with_lock(path: &Path, lock: Option<CwdLock>) -> Self {
         let original_cwd = get_cwd();
         std::env::set_current_dir(path)
             .unwrap_or_else(|_| panic!("Failed to change directory to '{path:?}'."));
-        Self { original_cwd }
+        Self {
+            original_cwd,
+            target_cwd: get_cwd(),
+            active: true,
+            _lock: lock,
+        }
+    }
+
+    /// Get the original current working directory (i.e. the directory that is restored).
+    ///
+    /// # Returns
+    ///
+    /// Path to the original current working directory.
+    pub fn original_dir(&self) -> &Path {
+        &self.original_cwd
+    }
+
+    /// Get the directory that the current working directory was changed to.
+    ///
+    /// # Returns
+    ///
+    /// Absolute path to the directory that was changed to.
+    pub fn target_dir(&self) -> &Path {
+        &self.target_cwd
+    }
+
+    /// Restores the original current working directory now (instead of when the guard is
+    /// dropped).
+    ///
+    /// # Returns
+    ///
+    /// An error if the original current working directory could not be restored (unlike when the
+    /// guard is dropped, this does not panic).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use file_io::{cd, get_cwd};
+    ///
+    /// let original_cwd_path = get_cwd();
+    /// let cd_guard = cd("src");
+    /// cd_guard.restore().expect("Failed to restore the original directory.");
+    /// assert_eq!(get_cwd(), original_cwd_path);
+    /// ```
+    pub fn restore(mut self) -> std::io::Result<()> {
+        self.active = false;
+        std::env::set_current_dir(&self.original_cwd)
+    }
+
+    /// Keeps the current working directory (i.e. the original current working directory is not
+    /// restored).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use file_io::{cd, get_cwd};
+    ///
+    /// let original_cwd_path = get_cwd();
+    /// cd("src").keep();
+    /// assert_eq!(get_cwd(), original_cwd_path.join("src"));
+    /// # cd(original_cwd_path).keep();
+    /// ```
+    pub fn keep(mut self) {
+        self.active = false;
     }
 }
 
-// Restore the original directory when `cd` goes out of scope.
+// Restore the original directory when `cd` goes out of scope (the lock, if any, is released
+// afterwards when the fields are dropped).
 impl Drop for CdGuard {
     fn drop(&mut self) {
+        if !self.active {
+            return;
+        }
         let original_cwd = self.original_cwd.clone();
         std::env::set_current_dir(&original_cwd)
             .unwrap_or_else(|_| panic!("Failed to change directory to '{original_cwd:?}'."))
     }
 }
 
+/// A stack of working directories, supporting `pushd`/`popd`-style navigation.
+///
+/// Each [`DirStack::push`] changes the current working directory and remembers the previous one,
+/// and each [`DirStack::pop`] returns to the most recently remembered directory. When the stack is
+/// dropped, the current working directory is restored to what it was before the first push that
+/// has not been popped yet.
+///
+/// # Example
+///
+/// ```
+/// use file_io::{DirStack, get_cwd};
+///
+/// // Get the path to the original current working directory.
+/// let original_cwd_path = get_cwd();
+///
+/// // Hop between directories.
+/// let mut dirs = DirStack::new();
+/// dirs.push("src");
+/// dirs.push("..");
+/// assert_eq!(get_cwd(), original_cwd_path);
+/// dirs.pop();
+/// assert_eq!(get_cwd(), original_cwd_path.join("src"));
+///
+/// // Dropping the stack returns to the original directory.
+/// drop(dirs);
+/// assert_eq!(get_cwd(), original_cwd_path);
+/// ```
+#[derive(Debug, Default)]
+#[must_use]
+pub struct DirStack {
+    /// Paths to the directories to return to (the most recent one is last).
+    previous: Vec<PathBuf>,
+}
+
+impl DirStack {
+    /// Constructor.
+    ///
+    /// # Returns
+    ///
+    /// An empty directory stack (the current working directory is not changed).
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Changes the current working directory, remembering the previous one (like `pushd`).
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - The path to change the current working directory to (can be a `&str`,
+    ///   [`String`], [`Path`], or [`PathBuf`]).
+    ///
+    /// # Panics
+    ///
+    /// If `path` does not exist or cannot be accessed.
+    pub fn push<P: AsRef<Path>>(&mut self, path: P) {
+        let path = path.as_ref();
+        let previous = get_cwd();
+        std::env::set_current_dir(path)
+            .unwrap_or_else(|_| panic!("Failed to change directory to '{path:?}'."));
+        self.previous.push(previous);
+    }
+
+    /// Returns to the most recently remembered working directory (like `popd`).
+    ///
+    /// # Returns
+    ///
+    /// The path to the directory that was returned to, or `None` if the stack is empty (in which
+    /// case the current working directory is not changed).
+    ///
+    /// # Panics
+    ///
+    /// If the remembered directory can no longer be accessed.
+    pub fn pop(&mut self) -> Option<PathBuf> {
+        let previous = self.previous.pop()?;
+        std::env::set_current_dir(&previous)
+            .unwrap_or_else(|_| panic!("Failed to change directory to '{previous:?}'."));
+        Some(previous)
+    }
+
+    /// Get the number of remembered directories (i.e. the number of pushes not yet popped).
+    ///
+    /// # Returns
+    ///
+    /// The number of remembered directories.
+    pub fn len(&self) -> usize {
+        self.previous.len()
+    }
+
+    /// Check whether there are no remembered directories.
+    ///
+    /// # Returns
+    ///
+    /// `true` if every push has been popped, `false` otherwise.
+    pub fn is_empty(&self) -> bool {
+        self.previous.is_empty()
+    }
+}
+
+// Restore the original directory when the stack goes out of scope.
+impl Drop for DirStack {
+    fn drop(&mut self) {
+        if let Some(original_cwd) = self.previous.first() {
+            std::env::set_current_dir(original_cwd)
+                .unwrap_or_else(|_| panic!("Failed to change directory to '{original_cwd:?}'."))
+        }
+    }
+}
+
+/// A struct that creates a fresh temporary directory and changes the current working directory to
+/// it.
+///
+/// When an instance of this struct goes out of scope (i.e. it is dropped), it automatically
+/// restores the original current working directory and then deletes the temporary directory
+/// (along with all of its contents).
+#[must_use]
+pub struct TempDirCdGuard {
+    /// Guard restoring the original current working directory (declared first so that it is
+    /// dropped before the temporary directory is deleted).
+    _cd: CdGuard,
+
+    /// The temporary directory.
+    _temp_dir: TempFolderGuard,
+
+    /// Path to the temporary directory (with symbolic links resolved).
+    path: PathBuf,
+}
+
+impl TempDirCdGuard {
+    /// Get the path to the temporary directory.
+    ///
+    /// # Returns
+    ///
+    /// Path to the temporary directory (with any symbolic links resolved, so that it matches
+    /// [`get_cwd`] while the guard is alive).
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+}
+
+/// Create a fresh temporary directory and change the current working directory to it.
+///
+/// # Returns
+///
+/// A [`TempDirCdGuard`] instance that will automatically restore the original current working
+/// directory and delete the temporary directory when it goes out of scope (i.e. when it is
+/// dropped).
+///
+/// # Panics
+///
+/// If the temporary directory cannot be created or changed into.
+///
+/// # Example
+///
+/// ```
+/// use file_io::{cd_into_temp_dir, get_cwd, save_string_to_file};
+///
+/// // Get the path to the original current working directory.
+/// let original_cwd_path = get_cwd();
+///
+/// // Enter a new scope.
+/// let temp_dir_path = {
+///     // Change into a fresh temporary directory and create a file there.
+///     let temp_dir = cd_into_temp_dir();
+///     assert_eq!(get_cwd(), temp_dir.path());
+///     save_string_to_file("Hello, world!", "file.txt");
+///     temp_dir.path().to_path_buf()
+/// };
+///
+/// // Verify that we are back in the original directory and the temporary directory is gone.
+/// assert_eq!(get_cwd(), original_cwd_path);
+/// assert!(!temp_dir_path.exists());
+/// ```
+pub fn cd_into_temp_dir() -> TempDirCdGuard {
+    let temp_dir = TempFolderGuard::new();
+    let path = canonicalize_path_for_display(temp_dir.path());
+    let cd = CdGuard::new(&path);
+    TempDirCdGuard {
+        _cd: cd,
+        _temp_dir: temp_dir,
+        path,
+    }
+}
+
 /// Change the current working directory.
 ///
 /// This function works by creating a [`CdGuard`] instance. When the [`CdGuard`] instance goes out
@@ -90,6 +442,95 @@ pub fn cd<P: AsRef<Path>>(path: P) -> CdGuard {
     CdGuard::new(path)
 }
 
+/// Change the current working directory while holding a global lock.
+///
+/// This works like [`cd`], except that the returned [`CdGuard`] holds a global lock until it is
+/// dropped. Any other thread calling [`cd_locked`] in the meantime blocks until the lock is
+/// released, so threads (e.g. tests running in parallel) that only change the current working
+/// directory through this function cannot interfere with each other.
+///
+/// # Arguments
+///
+/// * `path` - The path to change the current working directory to (can be a `&str`, [`String`],
+///   [`Path`], or [`PathBuf`]).
+///
+/// # Returns
+///
+/// A [`CdGuard`] instance that will automatically restore the original current working directory
+/// and release the lock when it goes out of scope (i.e. when it is dropped).
+///
+/// # Panics
+///
+/// If `path` does not exist or cannot be accessed.
+///
+/// # Note
+///
+/// * The lock is reentrant, so nested calls on the same thread do not deadlock.
+/// * The lock only coordinates callers of this function (and [`CdGuard::new_locked`]). Calls to
+///   [`cd`] or [`std::env::set_current_dir`] on other threads are not blocked.
+///
+/// # Example
+///
+/// ```
+/// use file_io::{cd_locked, get_cwd};
+///
+/// // Get the path to the original current working directory.
+/// let original_cwd_path = get_cwd();
+///
+/// // Enter a new scope.
+/// {
+///     // Change to the `src` directory (no other thread can use `cd_locked` within this scope).
+///     let _cd = cd_locked("src");
+///     assert_eq!(get_cwd(), original_cwd_path.join("src"));
+/// }
+///
+/// // Verify that outside the scope, we are back in the original directory.
+/// assert_eq!(get_cwd(), original_cwd_path);
+/// ```
+pub fn cd_locked<P: AsRef<Path>>(path: P) -> CdGuard {
+    CdGuard::new_locked(path)
+}
+
+/// Run a closure with the current working directory temporarily changed.
+///
+/// The original current working directory is restored once the closure returns, even if the
+/// closure panics.
+///
+/// # Arguments
+///
+/// * `path` - The path to change the current working directory to (can be a `&str`, [`String`],
+///   [`Path`], or [`PathBuf`]).
+/// * `f` - The closure to run.
+///
+/// # Returns
+///
+/// The value returned by the closure.
+///
+/// # Panics
+///
+/// If `path` does not exist or cannot be accessed, or if the closure panics (after the original
+/// current working directory has been restored).
+///
+/// # Example
+///
+/// ```
+/// use file_io::{get_cwd, in_dir};
+///
+/// // Get the path to the original current working directory.
+/// let original_cwd_path = get_cwd();
+///
+/// // Run a closure inside the `src` directory.
+/// let cwd_in_closure = in_dir("src", get_cwd);
+/// assert_eq!(cwd_in_closure, original_cwd_path.join("src"));
+///
+/// // Verify that we are back in the original directory.
+/// assert_eq!(get_cwd(), original_cwd_path);
+/// ```
+pub fn in_dir<P: AsRef<Path>, F: FnOnce() -> R, R>(path: P, f: F) -> R {
+    let _cd = cd(path);
+    f()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -209,4 +650,192 @@ mod tests {
         // Ensure we are back in the original directory.
         assert_eq!(get_cwd(), original_cwd_path);
     }
+
+    #[test]
+    #[serial]
+    fn test_in_dir() {
+        // Create a temporary directory to work in and get its path.
+        let temp_dir = tempdir().unwrap();
+        let temp_dir_path = get_temp_dir_path(&temp_dir);
+
+        // Get the path to the original current working directory.
+        let original_cwd_path = get_cwd();
+
+        // The closure runs in the new directory and its result is returned.
+        let cwd_in_closure = in_dir(&temp_dir_path, get_cwd);
+        assert_eq!(cwd_in_closure, temp_dir_path);
+        assert_eq!(get_cwd(), original_cwd_path);
+
+        // The original directory is restored even if the closure panics.
+        let result = std::panic::catch_unwind(|| {
+            in_dir(&temp_dir_path, || panic!("Simulated failure."));
+        });
+        assert!(result.is_err());
+        assert_eq!(get_cwd(), original_cwd_path);
+    }
+
+    #[test]
+    #[serial]
+    fn test_cd_locked_threads() {
+        // Create a temporary directory to work in and get its path.
+        let temp_dir = tempdir().unwrap();
+        let temp_dir_path = get_temp_dir_path(&temp_dir);
+
+        // Get the path to the original current working directory.
+        let original_cwd_path = get_cwd();
+
+        // Create a folder for each thread.
+        let folders: Vec<PathBuf> = (0..4)
+            .map(|i| temp_dir_path.join(format!("folder_{i}")))
+            .collect();
+        for folder in &folders {
+            create_folder(folder);
+        }
+
+        // Each thread changes into its own folder and checks that no other thread changed the
+        // current working directory while it held the lock.
+        std::thread::scope(|scope| {
+            for folder in &folders {
+                scope.spawn(move || {
+                    for _ in 0..10 {
+                        let _cd = cd_locked(folder);
+                        assert_eq!(&get_cwd(), folder);
+                        std::thread::sleep(std::time::Duration::from_millis(1));
+                        assert_eq!(&get_cwd(), folder);
+                    }
+                });
+            }
+        });
+
+        // Verify that we are back in the original directory.
+        assert_eq!(get_cwd(), original_cwd_path);
+    }
+
+    #[test]
+    #[serial]
+    fn test_cd_locked_nested() {
+        // Get the path to the original current working directory.
+        let original_cwd_path = get_cwd();
+
+        // Nested locking guards on the same thread do not deadlock.
+        {
+            let _outer = cd_locked("src");
+            {
+                let _inner = cd_locked(&original_cwd_path);
+                assert_eq!(get_cwd(), original_cwd_path);
+            }
+            assert_eq!(get_cwd(), original_cwd_path.join("src"));
+        }
+        assert_eq!(get_cwd(), original_cwd_path);
+
+        // The lock is released even if a panic occurs while it is held.
+        let result = std::panic::catch_unwind(|| {
+            let _cd = cd_locked("src");
+            panic!("Simulated failure.");
+        });
+        assert!(result.is_err());
+        assert_eq!(get_cwd(), original_cwd_path);
+        std::thread::spawn(|| drop(cd_locked("src")))
+            .join()
+            .unwrap();
+    }
+
+    #[test]
+    #[serial]
+    fn test_dir_stack() {
+        // Create a temporary directory to work in and get its path.
+        let temp_dir = tempdir().unwrap();
+        let temp_dir_path = get_temp_dir_path(&temp_dir);
+
+        // Get the path to the original current working directory.
+        let original_cwd_path = get_cwd();
+
+        // Create some folders to hop between.
+        let folder_a = temp_dir_path.join("a");
+        let folder_b = temp_dir_path.join("a/b");
+        create_folder(&folder_b);
+
+        // Push and pop directories.
+        let mut dirs = DirStack::new();
+        assert!(dirs.is_empty());
+        assert_eq!(dirs.pop(), None);
+        dirs.push(&folder_a);
+        dirs.push("b");
+        assert_eq!(dirs.len(), 2);
+        assert_eq!(get_cwd(), folder_b);
+        assert_eq!(dirs.pop(), Some(folder_a.clone()));
+        assert_eq!(get_cwd(), folder_a);
+        assert_eq!(dirs.pop(), Some(original_cwd_path.clone()));
+        assert_eq!(get_cwd(), original_cwd_path);
+
+        // Dropping a stack with remembered directories restores the original directory.
+        let mut dirs = DirStack::new();
+        dirs.push(&folder_a);
+        dirs.push(&folder_b);
+        drop(dirs);
+        assert_eq!(get_cwd(), original_cwd_path);
+    }
+
+    #[test]
+    #[serial]
+    fn test_cd_into_temp_dir() {
+        // Get the path to the original current working directory.
+        let original_cwd_path = get_cwd();
+
+        // Change into a temporary directory and create some content in it.
+        let temp_dir = cd_into_temp_dir();
+        let temp_dir_path = temp_dir.path().to_path_buf();
+        assert_eq!(get_cwd(), temp_dir_path);
+        create_folder("subfolder");
+        assert!(temp_dir_path.join("subfolder").is_dir());
+
+        // The original directory is restored and the temporary directory is deleted on drop.
+        drop(temp_dir);
+        assert_eq!(get_cwd(), original_cwd_path);
+        assert!(!temp_dir_path.exists());
+
+        // The same happens if a panic occurs.
+        let result = std::panic::catch_unwind(|| {
+            let _temp_dir = cd_into_temp_dir();
+            panic!("Simulated failure.");
+        });
+        assert!(result.is_err());
+        assert_eq!(get_cwd(), original_cwd_path);
+    }
+
+    #[test]
+    #[serial]
+    fn test_cd_guard_restore_and_keep() {
+        // Create a temporary directory to work in and get its path.
+        let temp_dir = tempdir().unwrap();
+        let temp_dir_path = get_temp_dir_path(&temp_dir);
+
+        // Get the path to the original current working directory.
+        let original_cwd_path = get_cwd();
+
+        // Check the accessors.
+        let cd_guard = cd(&temp_dir_path);
+        assert_eq!(cd_guard.original_dir(), original_cwd_path);
+        assert_eq!(cd_guard.target_dir(), temp_dir_path);
+
+        // Restore early.
+        cd_guard.restore().unwrap();
+        assert_eq!(get_cwd(), original_cwd_path);
+
+        // Keep the new directory.
+        cd(&temp_dir_path).keep();
+        assert_eq!(get_cwd(), temp_dir_path);
+
+        // Restoring a directory that no longer exists returns an error instead of panicking.
+        let removed_path = temp_dir_path.join("removed");
+        create_folder(&removed_path);
+        let outer = cd(&removed_path);
+        let inner = cd(&original_cwd_path);
+        std::fs::remove_dir(&removed_path).unwrap();
+        assert!(inner.restore().is_err());
+
+        // Return to the original directory.
+        outer.keep();
+        std::env::set_current_dir(&original_cwd_path).unwrap();
+    }
 }
@@ -0,0 +1,207 @@
+use crate::hash::hash_file;
+use std::path::Path;
+use std::time::{Duration, SystemTime};
+
+/// Margin by which a file's modification time must predate the last check for its modification
+/// time to be trusted (this covers filesystems that only store modification times with a
+/// granularity of up to 2 seconds, e.g. FAT).
+const MODIFIED_TIME_GRANULARITY: Duration = Duration::from_secs(2);
+
+/// State of a file as last seen by [`has_file_changed`].
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+enum FileState {
+    /// The file has not been checked yet.
+    #[default]
+    Unknown,
+
+    /// The file did not exist.
+    Missing,
+
+    /// The file existed.
+    Present {
+        /// The last modification time of the file.
+        modified: SystemTime,
+
+        /// The size of the file (in bytes).
+        size: u64,
+
+        /// The SHA-256 hash of the file's contents.
+        hash: String,
+
+        /// The time at which the file was checked.
+        checked_at: SystemTime,
+    },
+}
+
+/// Token recording the state of a file, used by [`has_file_changed`] to detect changes to it.
+///
+/// A new token (created with [`ChangeToken::default`]) has not seen the file yet.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct ChangeToken {
+    /// State of the file as last seen.
+    state: FileState,
+}
+
+/// Determines whether the contents of a file have changed since it was last checked with a token.
+///
+/// # Arguments
+///
+/// * `path` - The path to the file (can be a `&str`, [`String`], [`Path`], or
+///   [`std::path::PathBuf`]).
+/// * `token` - The token recording the state of the file as of the last check (updated to the
+///   current state of the file).
+///
+/// # Returns
+///
+/// `true` if the file has changed since the last check (i.e. its contents changed, or it was
+/// created or deleted), `false` otherwise. The first check with a new token always returns `true`.
+///
+/// # Panics
+///
+/// If the file exists but its metadata cannot be read or it cannot be hashed.
+///
+/// # Note
+///
+/// * The token records the file's modification time, size, and the hash of its contents (see
+///   [`crate::hash_file`]). The file is only hashed again if its modification time or size changed,
+///   or if its modification time is too close to the last check to be trusted (as modification
+///   times may have a coarse granularity). Changes to the modification time alone (e.g. from
+///   [`crate::touch_file`]) are therefore not reported as changes.
+/// * A file whose contents are changed and then restored between two checks is not reported as
+///   changed.
+///
+/// # Example
+///
+/// ```
+/// use file_io::{ChangeToken, has_file_changed, save_string_to_file, touch_file};
+///
+/// let path: &str = "folder/subfolder_61/file_65.txt";
+/// save_string_to_file("Hello, world!", path);
+///
+/// // The first check with a new token always reports a change.
+/// let mut token = ChangeToken::default();
+/// assert!(has_file_changed(path, &mut token));
+/// assert!(!has_file_changed(path, &mut token));
+///
+/// // Only changes to the contents are reported.
+/// touch_file(path);
+/// assert!(!has_file_changed(path, &mut token));
+/// save_string_to_file("Goodbye, world!", path);
+/// assert!(has_file_changed(path, &mut token));
+/// ```
+pub fn has_file_changed<P: AsRef<Path>>(path: P, token: &mut ChangeToken) -> bool {
+    let path = path.as_ref();
+    let checked_at = SystemTime::now();
+
+    // Get the current modification time and size of the file.
+    let metadata = match std::fs::metadata(path) {
+        Ok(metadata) => metadata,
+        Err(error) if error.kind() == std::io::ErrorKind::NotFound => {
+            let changed = token.state != FileState::Missing;
+            token.state = FileState::Missing;
+            return changed;
+        }
+        Err(_) => panic!("Failed to get metadata for '{path:?}'."),
+    };
+    let modified = metadata
+        .modified()
+        .unwrap_or_else(|_| panic!("Failed to get modification time for '{path:?}'."));
+    let size = metadata.len();
+
+    // Compare the file with its previous state, only hashing it if necessary.
+    let (changed, hash) = match std::mem::take(&mut token.state) {
+        FileState::Present {
+            modified: previous_modified,
+            size: previous_size,
+            hash: previous_hash,
+            checked_at: previous_checked_at,
+        } => {
+            let modified_time_trusted = modified
+                .checked_add(MODIFIED_TIME_GRANULARITY)
+                .is_some_and(|trusted_until| trusted_until < previous_checked_at);
+            if modified == previous_modified && size == previous_size && modified_time_trusted {
+                (false, previous_hash)
+            } else {
+                let hash = hash_file(path);
+                (hash != previous_hash, hash)
+            }
+        }
+        FileState::Unknown | FileState::Missing => (true, hash_file(path)),
+    };
+
+    // Record the current state of the file.
+    token.state = FileState::Present {
+        modified,
+        size,
+        hash,
+        checked_at,
+    };
+    changed
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::delete::delete_file;
+    use crate::metadata::set_modified_time;
+    use crate::save::save_string_to_file;
+    use crate::test_utils::get_temp_dir_path;
+    use tempfile::tempdir;
+
+    #[test]
+    fn test_has_file_changed() {
+        // Create a temporary directory to work in.
+        let temp_dir = tempdir().unwrap();
+        let file_path = get_temp_dir_path(&temp_dir).join("file.txt");
+        let mut token = ChangeToken::default();
+
+        // A missing file is reported as changed on the first check only.
+        assert!(has_file_changed(&file_path, &mut token));
+        assert!(!has_file_changed(&file_path, &mut token));
+
+        // Creating the file is a change.
+        save_string_to_file("aaa", &file_path);
+        assert!(has_file_changed(&file_path, &mut token));
+        assert!(!has_file_changed(&file_path, &mut token));
+
+        // Changing only the modification time is not a change.
+        set_modified_time(
+            &file_path,
+            SystemTime::UNIX_EPOCH + Duration::from_secs(1_000),
+        );
+        assert!(!has_file_changed(&file_path, &mut token));
+
+        // Changing the contents without changing the size or modification time (as may happen on
+        // filesystems with a coarse granularity) is a change, provided the modification time is
+        // close to the last check.
+        let now = SystemTime::now();
+        set_modified_time(&file_path, now);
+        assert!(!has_file_changed(&file_path, &mut token));
+        save_string_to_file("bbb", &file_path);
+        set_modified_time(&file_path, now);
+        assert!(has_file_changed(&file_path, &mut token));
+
+        // Deleting the file is a change.
+        delete_file(&file_path);
+        assert!(has_file_changed(&file_path, &mut token));
+        assert!(!has_file_changed(&file_path, &mut token));
+    }
+
+    #[test]
+    fn test_has_file_changed_trusts_old_modified_times() {
+        // Create a temporary directory to work in.
+        let temp_dir = tempdir().unwrap();
+        let file_path = get_temp_dir_path(&temp_dir).join("file.txt");
+        save_string_to_file("aaa", &file_path);
+        let old = SystemTime::UNIX_EPOCH + Duration::from_secs(1_000);
+        set_modified_time(&file_path, old);
+        let mut token = ChangeToken::default();
+        assert!(has_file_changed(&file_path, &mut token));
+
+        // If the modification time and size are unchanged and the modification time well
+        // predates the last check, the file is not hashed again (so this change goes unnoticed).
+        save_string_to_file("bbb", &file_path);
+        set_modified_time(&file_path, old);
+        assert!(!has_file_changed(&file_path, &mut token));
+    }
+}
@@ -0,0 +1,302 @@
+use crate::load::load_file_as_string;
+use serde_json::{Map, Value};
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+
+/// Configuration merged from multiple files by [`load_layered_config`].
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct LayeredConfig {
+    /// The merged configuration (always a JSON object).
+    pub value: Value,
+
+    /// The file each value in the merged configuration came from, keyed by the dotted path of the
+    /// value (e.g. `server.port` for the `port` key within the `server` table). Only values that
+    /// are not tables themselves (and empty tables) are included.
+    pub sources: BTreeMap<String, PathBuf>,
+}
+
+/// Parses a configuration file, using its extension to determine the format.
+///
+/// # Arguments
+///
+/// * `path` - The path to the configuration file.
+///
+/// # Returns
+///
+/// The top-level table of the configuration file.
+///
+/// # Panics
+///
+/// If the file cannot be read or parsed, if its extension is not `json`, `toml`, `yaml`, or `yml`,
+/// or if it does not contain a table at the top level.
+fn parse_config_file(path: &Path) -> Map<String, Value> {
+    let content = load_file_as_string(path);
+    let extension = path
+        .extension()
+        .map(|extension| extension.to_string_lossy().to_lowercase());
+    let value = match extension.as_deref() {
+        Some("json") => serde_json::from_str(&content)
+            .unwrap_or_else(|error| panic!("Failed to parse config file '{path:?}': {error}.")),
+        Some("toml") => toml::from_str::<toml::Table>(&content)
+            .map(|table| toml_to_json(toml::Value::Table(table)))
+            .unwrap_or_else(|error| panic!("Failed to parse config file '{path:?}': {error}.")),
+        Some("yaml" | "yml") => serde_norway::from_str(&content)
+            .unwrap_or_else(|error| panic!("Failed to parse config file '{path:?}': {error}.")),
+        _ => panic!("Unsupported config file format for '{path:?}'."),
+    };
+    match value {
+        Value::Object(table) => table,
+        // An empty YAML file is parsed as null.
+        Value::Null => Map::new(),
+        _ => panic!("Config file '{path:?}' does not contain a table at the top level."),
+    }
+}
+
+/// Converts a TOML value to a JSON value.
+///
+/// # Arguments
+///
+/// * `value` - The TOML value.
+///
+/// # Returns
+///
+/// The equivalent JSON value (dates and times are converted to strings in RFC 3339 format, and
+/// non-finite floats to null).
+fn toml_to_json(value: toml::Value) -> Value {
+    match value {
+        toml::Value::String(string) => Value::String(string),
+        toml::Value::Integer(integer) => Value::from(integer),
+        toml::Value::Float(float) => Value::from(float),
+        toml::Value::Boolean(boolean) => Value::Bool(boolean),
+        toml::Value::Datetime(datetime) => Value::String(datetime.to_string()),
+        toml::Value::Array(array) => Value::Array(array.into_iter().map(toml_to_json).collect()),
+        toml::Value::Table(table) => Value::Object(
+            table
+                .into_iter()
+                .map(|(key, value)| (key, toml_to_json(value)))
+                .collect(),
+        ),
+    }
+}
+
+/// Records the source of a value (and of all values nested within it).
+///
+/// # Arguments
+///
+/// * `value` - The value.
+/// * `key` - The dotted path of the value.
+/// * `source` - The file the value came from.
+/// * `sources` - The sources recorded so far.
+fn record_sources(
+    value: &Value,
+    key: &str,
+    source: &Path,
+    sources: &mut BTreeMap<String, PathBuf>,
+) {
+    match value {
+        Value::Object(table) if !table.is_empty() => {
+            for (child_key, child_value) in table {
+                record_sources(child_value, &format!("{key}.{child_key}"), source, sources);
+            }
+        }
+        _ => {
+            sources.insert(key.to_string(), source.to_path_buf());
+        }
+    }
+}
+
+/// Deep-merges a table into another table.
+///
+/// # Arguments
+///
+/// * `base` - The table to merge into.
+/// * `overlay` - The table to merge (its values take precedence over those in `base`).
+/// * `prefix` - The dotted path of the tables (empty for the top-level tables).
+/// * `source` - The file `overlay` came from.
+/// * `sources` - The sources of the values in `base` (updated with the sources of the merged
+///   values).
+fn merge_tables(
+    base: &mut Map<String, Value>,
+    overlay: Map<String, Value>,
+    prefix: &str,
+    source: &Path,
+    sources: &mut BTreeMap<String, PathBuf>,
+) {
+    for (key, value) in overlay {
+        let path = if prefix.is_empty() {
+            key.clone()
+        } else {
+            format!("{prefix}.{key}")
+        };
+        match (base.get_mut(&key), value) {
+            // Tables are merged recursively (an empty table is recorded as a value itself, so its
+            // source is forgotten once it has contents).
+            (Some(Value::Object(base_table)), Value::Object(overlay_table)) => {
+                if base_table.is_empty() && !overlay_table.is_empty() {
+                    sources.remove(&path);
+                }
+                merge_tables(base_table, overlay_table, &path, source, sources);
+            }
+            // All other values replace the existing value (including everything nested in it).
+            (_, value) => {
+                let nested_prefix = format!("{path}.");
+                sources.retain(|key, _| *key != path && !key.starts_with(&nested_prefix));
+                record_sources(&value, &path, source, sources);
+                base.insert(key, value);
+            }
+        }
+    }
+}
+
+/// Loads multiple configuration files and deep-merges them, with later files taking precedence.
+///
+/// # Arguments
+///
+/// * `paths` - The paths to the configuration files, from lowest to highest precedence (e.g.
+///   system, then user, then project configuration). Each path can be a `&str`, [`String`],
+///   [`Path`], or [`PathBuf`].
+///
+/// # Returns
+///
+/// The merged configuration, along with the file each value came from.
+///
+/// # Panics
+///
+/// If any existing file cannot be read or parsed, if its format is not supported, or if it does not
+/// contain a table at the top level.
+///
+/// # Note
+///
+/// * The format of each file is determined by its extension: `json` (JSON), `toml` (TOML), or
+///   `yaml`/`yml` (YAML). Files in different formats can be merged with each other.
+/// * Files that do not exist are skipped.
+/// * Tables are merged recursively. All other values (including arrays) from later files replace
+///   the values from earlier files.
+/// * Keys containing `.` are not escaped in [`LayeredConfig::sources`], so their dotted paths may
+///   be ambiguous.
+///
+/// # Example
+///
+/// ```
+/// use file_io::{load_layered_config, save_string_to_file};
+/// use std::path::PathBuf;
+///
+/// save_string_to_file(
+///     "[server]\nhost = \"localhost\"\nport = 8080\n",
+///     "folder/subfolder_52/system.toml",
+/// );
+/// save_string_to_file("server:\n  port: 9090\n", "folder/subfolder_52/project.yaml");
+///
+/// let config = load_layered_config(&[
+///     "folder/subfolder_52/system.toml",
+///     "folder/subfolder_52/missing.json",
+///     "folder/subfolder_52/project.yaml",
+/// ]);
+///
+/// assert_eq!(config.value["server"]["host"], "localhost");
+/// assert_eq!(config.value["server"]["port"], 9090);
+/// assert_eq!(
+///     config.sources["server.port"],
+///     PathBuf::from("folder/subfolder_52/project.yaml")
+/// );
+/// ```
+pub fn load_layered_config<P: AsRef<Path>>(paths: &[P]) -> LayeredConfig {
+    let mut merged = Map::new();
+    let mut sources = BTreeMap::new();
+    for path in paths {
+        let path = path.as_ref();
+        if !path.exists() {
+            continue;
+        }
+        merge_tables(&mut merged, parse_config_file(path), "", path, &mut sources);
+    }
+    LayeredConfig {
+        value: Value::Object(merged),
+        sources,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::save::save_string_to_file;
+    use crate::test_utils::get_temp_dir_path;
+    use serde_json::json;
+    use tempfile::tempdir;
+
+    #[test]
+    fn test_load_layered_config() {
+        // Create a temporary directory to work in.
+        let temp_dir = tempdir().unwrap();
+        let temp_dir_path = get_temp_dir_path(&temp_dir);
+        let system = temp_dir_path.join("system.json");
+        let user = temp_dir_path.join("user.toml");
+        let project = temp_dir_path.join("project.yml");
+        save_string_to_file(
+            r#"{"log": {"level": "warn", "file": "/var/log/app.log"}, "plugins": ["a"]}"#,
+            &system,
+        );
+        save_string_to_file(
+            "plugins = [\"b\", \"c\"]\n[log]\nlevel = \"info\"\n[cache]\nsince = 2026-10-16\n",
+            &user,
+        );
+        save_string_to_file("log: stderr\ncache:\n  size: 64\n", &project);
+
+        // Merge the files (missing files are skipped).
+        let config = load_layered_config(&[
+            system.clone(),
+            temp_dir_path.join("missing.json"),
+            user.clone(),
+            project.clone(),
+        ]);
+        assert_eq!(
+            config.value,
+            json!({
+                "log": "stderr",
+                "plugins": ["b", "c"],
+                "cache": {"since": "2026-10-16", "size": 64},
+            })
+        );
+        assert_eq!(
+            config.sources,
+            BTreeMap::from([
+                (String::from("cache.since"), user.clone()),
+                (String::from("cache.size"), project.clone()),
+                (String::from("log"), project.clone()),
+                (String::from("plugins"), user.clone()),
+            ])
+        );
+    }
+
+    #[test]
+    fn test_load_layered_config_tables_replacing_values() {
+        // Create a temporary directory to work in.
+        let temp_dir = tempdir().unwrap();
+        let temp_dir_path = get_temp_dir_path(&temp_dir);
+        let first = temp_dir_path.join("first.json");
+        let second = temp_dir_path.join("second.json");
+        save_string_to_file(r#"{"a": 1, "b": {}}"#, &first);
+        save_string_to_file(r#"{"a": {"x": true}, "b": {"y": null}}"#, &second);
+
+        // Tables replace plain values, and empty tables are merged with later tables.
+        let config = load_layered_config(&[&first, &second]);
+        assert_eq!(config.value, json!({"a": {"x": true}, "b": {"y": null}}));
+        assert_eq!(
+            config.sources,
+            BTreeMap::from([
+                (String::from("a.x"), second.clone()),
+                (String::from("b.y"), second.clone()),
+            ])
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "Unsupported config file format")]
+    fn test_load_layered_config_unsupported_format() {
+        // Create a temporary directory to work in.
+        let temp_dir = tempdir().unwrap();
+        let file_path = get_temp_dir_path(&temp_dir).join("config.ini");
+        save_string_to_file("[section]\nkey = value\n", &file_path);
+        load_layered_config(&[file_path]);
+    }
+}
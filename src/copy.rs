@@ -1,7 +1,242 @@
-use crate::create::create_folder_for_file;
-use std::path::Path;
+use crate::create::{create_folder_for_file, create_unique_file};
+use crate::instrument::Mutation;
+use crate::path::unique_path;
+use crate::progress::{NoProgress, ProgressSink, file_size};
+use glob::MatchOptions;
+use std::collections::HashSet;
+use std::fs::File;
+use std::io::{ErrorKind, Read, Write};
+use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant};
 use walkdir::WalkDir;
 
+/// Options controlling how files are copied by [`copy_file_with_options`] and
+/// [`copy_folder_with_options`].
+///
+/// # Example
+///
+/// ```
+/// use file_io::CopyOptions;
+///
+/// // Copy at most 10 MiB per second.
+/// let options = CopyOptions {
+///     max_bytes_per_sec: Some(10 * 1024 * 1024),
+///     ..Default::default()
+/// };
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct CopyOptions {
+    /// The maximum number of bytes to copy per second (averaged over the whole copy operation). If
+    /// `None`, files are copied as fast as possible.
+    pub max_bytes_per_sec: Option<u64>,
+
+    /// Controls how copying a file is retried if it fails. If `None`, copying is not retried.
+    pub retry: Option<RetryOptions>,
+}
+
+/// Options controlling how failed operations are retried (see [`CopyOptions::retry`]).
+///
+/// Only the errors listed in `retryable_error_kinds` or `retryable_os_errors` are retried, so the
+/// default options do not retry anything. Any other error (e.g. a missing source file or a
+/// permission error) fails immediately.
+///
+/// # Example
+///
+/// ```
+/// use file_io::RetryOptions;
+/// use std::io::ErrorKind;
+/// use std::time::Duration;
+///
+/// // Make up to 5 attempts, waiting 1 s, 2 s, 4 s, and 8 s between them, retrying only if the
+/// // resource is busy or an I/O error (`EIO` on Linux) occurred.
+/// let options = RetryOptions {
+///     max_attempts: 5,
+///     backoff: Duration::from_secs(1),
+///     retryable_error_kinds: vec![ErrorKind::ResourceBusy],
+///     retryable_os_errors: vec![5],
+/// };
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct RetryOptions {
+    /// The maximum number of attempts (including the first one). Values below 2 disable retrying.
+    pub max_attempts: u32,
+
+    /// The time to wait before the first retry. The time to wait is doubled before each further
+    /// retry.
+    pub backoff: Duration,
+
+    /// Errors of these kinds are retried (in addition to those listed in `retryable_os_errors`).
+    pub retryable_error_kinds: Vec<ErrorKind>,
+
+    /// Errors with these raw OS error codes (e.g. `libc::EIO`) are retried. This allows retrying
+    /// errors that do not have a dedicated [`ErrorKind`].
+    pub retryable_os_errors: Vec<i32>,
+}
+
+impl RetryOptions {
+    /// Determines whether an error should be retried.
+    ///
+    /// # Arguments
+    ///
+    /// * `error` - The error.
+    ///
+    /// # Returns
+    ///
+    /// `true` if the error is listed in `retryable_error_kinds` or `retryable_os_errors`, `false`
+    /// otherwise.
+    fn is_retryable(&self, error: &std::io::Error) -> bool {
+        self.retryable_error_kinds.contains(&error.kind())
+            || error
+                .raw_os_error()
+                .is_some_and(|code| self.retryable_os_errors.contains(&code))
+    }
+}
+
+/// Limits the rate at which bytes are copied.
+struct Throttle {
+    /// The maximum number of bytes to copy per second.
+    max_bytes_per_sec: u64,
+
+    /// The time the copy operation started.
+    start: Instant,
+
+    /// The number of bytes copied so far.
+    bytes_copied: u64,
+}
+
+impl Throttle {
+    /// Creates a new throttle, starting now.
+    ///
+    /// # Arguments
+    ///
+    /// * `max_bytes_per_sec` - The maximum number of bytes to copy per second.
+    ///
+    /// # Returns
+    ///
+    /// The throttle.
+    ///
+    /// # Panics
+    ///
+    /// If `max_bytes_per_sec` is zero.
+    fn new(max_bytes_per_sec: u64) -> Self {
+        assert!(
+            max_bytes_per_sec > 0,
+            "The maximum number of bytes per second must be greater than zero."
+        );
+        Throttle {
+            max_bytes_per_sec,
+            start: Instant::now(),
+            bytes_copied: 0,
+        }
+    }
+
+    /// Records that bytes were copied, sleeping as long as necessary to stay within the limit.
+    ///
+    /// # Arguments
+    ///
+    /// * `bytes` - The number of bytes copied.
+    fn consume(&mut self, bytes: u64) {
+        self.bytes_copied += bytes;
+        let target =
+            Duration::from_secs_f64(self.bytes_copied as f64 / self.max_bytes_per_sec as f64);
+        if let Some(remaining) = target.checked_sub(self.start.elapsed()) {
+            std::thread::sleep(remaining);
+        }
+    }
+}
+
+/// Copies the contents of a file through a throttle.
+///
+/// # Arguments
+///
+/// * `from` - The source file path.
+/// * `to` - The destination file path (overwritten if it already exists).
+/// * `throttle` - The throttle limiting the rate at which bytes are copied.
+///
+/// # Returns
+///
+/// The number of bytes copied.
+///
+/// # Errors
+///
+/// If the source file cannot be read or the destination file cannot be written.
+///
+/// # Note
+///
+/// Like [`std::fs::copy`], the permissions of the source file are copied to the destination file.
+fn copy_throttled(from: &Path, to: &Path, throttle: &mut Throttle) -> std::io::Result<u64> {
+    let mut reader = File::open(from)?;
+    let permissions = reader.metadata()?.permissions();
+    let mut writer = File::create(to)?;
+    let chunk_size = DEFAULT_BUFFER_SIZE.min(throttle.max_bytes_per_sec as usize);
+    let mut buffer = vec![0; chunk_size];
+    let mut bytes_copied: u64 = 0;
+    loop {
+        let bytes_read = match reader.read(&mut buffer) {
+            Ok(0) => break,
+            Ok(bytes_read) => bytes_read,
+            Err(error) if error.kind() == ErrorKind::Interrupted => continue,
+            Err(error) => return Err(error),
+        };
+        writer.write_all(&buffer[..bytes_read])?;
+        bytes_copied += bytes_read as u64;
+        throttle.consume(bytes_read as u64);
+    }
+    writer.set_permissions(permissions)?;
+    Ok(bytes_copied)
+}
+
+/// Copies a file from one location to another, optionally through a throttle and retrying
+/// failures.
+///
+/// # Arguments
+///
+/// * `from` - The source file path.
+/// * `to` - The destination file path.
+/// * `throttle` - The throttle limiting the rate at which bytes are copied (`None` to copy as fast
+///   as possible).
+/// * `retry` - Controls how copying is retried if it fails (`None` to not retry).
+///
+/// # Panics
+///
+/// If the source file does not exist or cannot be accessed, or if the destination cannot be
+/// created (once all attempts have failed).
+fn copy_file_using(
+    from: &Path,
+    to: &Path,
+    mut throttle: Option<&mut Throttle>,
+    retry: Option<&RetryOptions>,
+) {
+    let mut mutation = Mutation::start("copy_file", from).to(to);
+    if mutation.is_dry_run() {
+        return;
+    }
+    create_folder_for_file(to);
+    let mut attempt = 1;
+    let mut backoff = retry.map_or(Duration::ZERO, |retry| retry.backoff);
+    let bytes = loop {
+        let result = match throttle.as_deref_mut() {
+            Some(throttle) => copy_throttled(from, to, throttle),
+            None => std::fs::copy(from, to),
+        };
+        match result {
+            Ok(bytes) => break bytes,
+            Err(error)
+                if retry.is_some_and(|retry| {
+                    attempt < retry.max_attempts && retry.is_retryable(&error)
+                }) =>
+            {
+                std::thread::sleep(backoff);
+                backoff = backoff.saturating_mul(2);
+                attempt += 1;
+            }
+            Err(error) => panic!("Failed to copy file from '{from:?}' to '{to:?}': {error}."),
+        }
+    };
+    mutation.bytes(bytes);
+    mutation.succeeded();
+}
+
 /// Copies a file from one location to another.
 ///
 /// # Arguments
@@ -46,11 +281,349 @@ use walkdir::WalkDir;
 /// copy_file(from, to);
 /// ```
 pub fn copy_file<P: AsRef<Path>, Q: AsRef<Path>>(from: P, to: Q) {
+    copy_file_using(from.as_ref(), to.as_ref(), None, None);
+}
+
+/// Copies a file from one location to another, with options.
+///
+/// # Arguments
+///
+/// * `from` - The source file path (can be a `&str`, [`String`], [`Path`], or
+///   [`std::path::PathBuf`]).
+/// * `to` - The destination file path (can be a `&str`, [`String`], [`Path`], or
+///   [`std::path::PathBuf`]).
+/// * `options` - Options controlling how the file is copied.
+///
+/// # Panics
+///
+/// If the source file does not exist or cannot be accessed, if the destination cannot be created,
+/// or if `options.max_bytes_per_sec` is `Some(0)`.
+///
+/// # Note
+///
+/// * The parent folder for the destination file will be created if it does not already exist.
+/// * If the destination file already exists, it will be overwritten.
+/// * If `options.max_bytes_per_sec` is set, the file is copied in chunks, sleeping between them as
+///   necessary to stay within the limit.
+/// * If `options.retry` is set, copying the file is retried (from the start) if it fails with a
+///   retryable error, waiting longer before each retry.
+///
+/// # Example
+///
+/// ```
+/// use file_io::{CopyOptions, copy_file_with_options};
+///
+/// // Copy 'Cargo.toml' to 'folder/Cargo_new_4.toml', at most 1 MiB per second.
+/// let options = CopyOptions {
+///     max_bytes_per_sec: Some(1024 * 1024),
+///     ..Default::default()
+/// };
+/// copy_file_with_options("Cargo.toml", "folder/Cargo_new_4.toml", &options);
+/// ```
+pub fn copy_file_with_options<P: AsRef<Path>, Q: AsRef<Path>>(
+    from: P,
+    to: Q,
+    options: &CopyOptions,
+) {
+    let mut throttle = options.max_bytes_per_sec.map(Throttle::new);
+    copy_file_using(
+        from.as_ref(),
+        to.as_ref(),
+        throttle.as_mut(),
+        options.retry.as_ref(),
+    );
+}
+
+/// Copies a file to a new file, never overwriting an existing file.
+///
+/// # Arguments
+///
+/// * `from` - The source file path (can be a `&str`, [`String`], [`Path`], or
+///   [`std::path::PathBuf`]).
+/// * `to` - The desired destination file path (can be a `&str`, [`String`], [`Path`], or
+///   [`std::path::PathBuf`]).
+///
+/// # Returns
+///
+/// The path the file was actually copied to. This is `to` itself if nothing exists there yet, or
+/// otherwise a numbered variant of it (see [`crate::unique_path`]).
+///
+/// # Panics
+///
+/// If the source file does not exist or cannot be accessed, or if the destination cannot be
+/// created.
+///
+/// # Note
+///
+/// The parent folder for the destination file will be created if it does not already exist.
+///
+/// # Example
+///
+/// ```
+/// use file_io::copy_file_to_unique;
+///
+/// // Copying twice to the same destination creates two separate files.
+/// let first = copy_file_to_unique("Cargo.toml", "folder/Cargo_new_3.toml");
+/// let second = copy_file_to_unique("Cargo.toml", "folder/Cargo_new_3.toml");
+/// assert_ne!(first, second);
+/// ```
+pub fn copy_file_to_unique<P: AsRef<Path>, Q: AsRef<Path>>(from: P, to: Q) -> PathBuf {
     let from = from.as_ref();
+
     let to = to.as_ref();
-    create_folder_for_file(to);
-    std::fs::copy(from, to)
+    let mut mutation = Mutation::start("copy_file_to_unique", from).to(to);
+    if mutation.is_dry_run() {
+        return unique_path(to);
+    }
+
+    // Reserve the destination path by creating an empty file there, then copy over it.
+    let (_, to) = create_unique_file(to);
+    let bytes = std::fs::copy(from, &to)
         .unwrap_or_else(|_| panic!("Failed to copy file from '{from:?}' to '{to:?}'."));
+    mutation.bytes(bytes);
+    mutation.succeeded();
+    to
+}
+
+/// Default size of the buffer used by [`copy_file_to_writer`] (in bytes).
+const DEFAULT_BUFFER_SIZE: usize = 64 * 1024;
+
+/// Copies the contents of a file to a writer.
+///
+/// # Arguments
+///
+/// * `path` - The path to the file (can be a `&str`, [`String`], [`Path`], or
+///   [`std::path::PathBuf`]).
+/// * `writer` - The writer to copy the contents to (e.g. a socket, an encoder, or a hasher).
+///
+/// # Returns
+///
+/// The number of bytes written to the writer.
+///
+/// # Panics
+///
+/// If some error is encountered while reading the file or writing to the writer.
+///
+/// # Note
+///
+/// The contents are streamed through a 64 KiB buffer (see [`copy_file_to_writer_with_buffer_size`]
+/// to use a different size), so the file never has to be held in memory in its entirety. The writer
+/// is flushed once all contents have been written.
+///
+/// # Example
+///
+/// ```
+/// use file_io::{copy_file_to_writer, save_string_to_file};
+///
+/// let path: &str = "folder/subfolder_58/file_61.txt";
+/// save_string_to_file("Hello, world!", path);
+///
+/// let mut buffer: Vec<u8> = Vec::new();
+/// let bytes_written = copy_file_to_writer(path, &mut buffer);
+///
+/// assert_eq!(bytes_written, 13);
+/// assert_eq!(buffer, b"Hello, world!");
+/// ```
+pub fn copy_file_to_writer<P: AsRef<Path>, W: Write>(path: P, writer: W) -> u64 {
+    copy_file_to_writer_with_buffer_size(path, writer, DEFAULT_BUFFER_SIZE)
+}
+
+/// Copies the contents of a file to a writer, using a buffer of the specified size.
+///
+/// # Arguments
+///
+/// * `path` - The path to the file (can be a `&str`, [`String`], [`Path`], or
+///   [`std::path::PathBuf`]).
+/// * `writer` - The writer to copy the contents to (e.g. a socket, an encoder, or a hasher).
+/// * `buffer_size` - The size of the buffer the contents are streamed through (in bytes).
+///
+/// # Returns
+///
+/// The number of bytes written to the writer.
+///
+/// # Panics
+///
+/// If `buffer_size` is zero, or if some error is encountered while reading the file or writing to
+/// the writer.
+///
+/// # Note
+///
+/// Each chunk read from the file (of at most `buffer_size` bytes) is written to the writer in its
+/// entirety before the next one is read. The writer is flushed once all contents have been
+/// written.
+///
+/// # Example
+///
+/// ```
+/// use file_io::{copy_file_to_writer_with_buffer_size, save_string_to_file};
+///
+/// let path: &str = "folder/subfolder_58/file_62.txt";
+/// save_string_to_file("Hello, world!", path);
+///
+/// let mut buffer: Vec<u8> = Vec::new();
+/// copy_file_to_writer_with_buffer_size(path, &mut buffer, 4);
+///
+/// assert_eq!(buffer, b"Hello, world!");
+/// ```
+pub fn copy_file_to_writer_with_buffer_size<P: AsRef<Path>, W: Write>(
+    path: P,
+    mut writer: W,
+    buffer_size: usize,
+) -> u64 {
+    let path = path.as_ref();
+    assert!(
+        buffer_size > 0,
+        "The buffer size must be greater than zero."
+    );
+    let mut file = File::open(path).unwrap_or_else(|_| panic!("Failed to open file '{path:?}'."));
+    let mut buffer = vec![0; buffer_size];
+    let mut bytes_written: u64 = 0;
+    loop {
+        let bytes_read = match file.read(&mut buffer) {
+            Ok(0) => break,
+            Ok(bytes_read) => bytes_read,
+            Err(error) if error.kind() == ErrorKind::Interrupted => continue,
+            Err(_) => panic!("Failed to read file '{path:?}'."),
+        };
+        writer
+            .write_all(&buffer[..bytes_read])
+            .unwrap_or_else(|_| panic!("Failed to write contents of file '{path:?}' to writer."));
+        bytes_written += bytes_read as u64;
+    }
+    writer
+        .flush()
+        .unwrap_or_else(|_| panic!("Failed to write contents of file '{path:?}' to writer."));
+    bytes_written
+}
+
+/// Copies multiple files into a folder, keeping their file names.
+///
+/// # Arguments
+///
+/// * `files` - The paths to the files to copy (each can be a `&str`, [`String`], [`Path`], or
+///   [`std::path::PathBuf`]).
+/// * `folder` - The path to the destination folder (can be a `&str`, [`String`], [`Path`], or
+///   [`std::path::PathBuf`]).
+///
+/// # Returns
+///
+/// The paths of the copies, in the same order as `files`.
+///
+/// # Panics
+///
+/// * If a path does not have a file name, or if two of the files have the same file name. In this
+///   case, no files are copied.
+/// * If some error is encountered while copying a file.
+///
+/// # Note
+///
+/// * The destination folder will be created if it does not already exist.
+/// * Existing files in the destination folder will be overwritten.
+///
+/// # Example
+///
+/// ```
+/// use file_io::{copy_files_to_folder, save_string_to_file};
+/// use std::path::PathBuf;
+///
+/// save_string_to_file("a", "folder/subfolder_65/a.txt");
+/// save_string_to_file("b", "folder/subfolder_65/nested/b.txt");
+///
+/// let copies = copy_files_to_folder(
+///     &["folder/subfolder_65/a.txt", "folder/subfolder_65/nested/b.txt"],
+///     "folder/subfolder_65/copies",
+/// );
+///
+/// assert_eq!(
+///     copies,
+///     [
+///         PathBuf::from("folder/subfolder_65/copies/a.txt"),
+///         PathBuf::from("folder/subfolder_65/copies/b.txt"),
+///     ]
+/// );
+/// ```
+pub fn copy_files_to_folder<P: AsRef<Path>, Q: AsRef<Path>>(
+    files: &[P],
+    folder: Q,
+) -> Vec<PathBuf> {
+    let folder = folder.as_ref();
+
+    // Determine the destination path of each file (checking for collisions before copying
+    // anything).
+    let mut names = HashSet::new();
+    let copies: Vec<(&Path, PathBuf)> = files
+        .iter()
+        .map(|file| {
+            let file = file.as_ref();
+            let name = file
+                .file_name()
+                .unwrap_or_else(|| panic!("Path '{file:?}' does not have a file name."));
+            if !names.insert(name) {
+                panic!("Multiple files named '{name:?}' would be copied to '{folder:?}'.");
+            }
+            (file, folder.join(name))
+        })
+        .collect();
+
+    // Copy the files.
+    copies
+        .into_iter()
+        .map(|(file, copy)| {
+            copy_file(file, &copy);
+            copy
+        })
+        .collect()
+}
+
+/// Copies all files matching a glob pattern into a folder, keeping their file names.
+///
+/// # Arguments
+///
+/// * `pattern` - The glob pattern selecting the files to copy (e.g. `logs/*.txt`, or
+///   `logs/**/*.txt` to also select files in subfolders).
+/// * `folder` - The path to the destination folder (can be a `&str`, [`String`], [`Path`], or
+///   [`std::path::PathBuf`]).
+///
+/// # Returns
+///
+/// The paths of the copies, in alphabetical order of the paths of the copied files.
+///
+/// # Panics
+///
+/// * If the glob pattern is invalid.
+/// * If two of the matching files have the same file name. In this case, no files are copied.
+/// * If some error is encountered while reading a folder or copying a file.
+///
+/// # Note
+///
+/// * Folders matching the pattern are not copied. `*` and `?` do not match `/`.
+/// * See also [`copy_files_to_folder`].
+///
+/// # Example
+///
+/// ```
+/// use file_io::{copy_glob_to_folder, save_string_to_file};
+/// use std::path::PathBuf;
+///
+/// save_string_to_file("", "folder/subfolder_66/file_68.log");
+/// save_string_to_file("", "folder/subfolder_66/file_69.txt");
+///
+/// let copies = copy_glob_to_folder("folder/subfolder_66/*.log", "folder/subfolder_66/logs");
+///
+/// assert_eq!(copies, [PathBuf::from("folder/subfolder_66/logs/file_68.log")]);
+/// ```
+pub fn copy_glob_to_folder<Q: AsRef<Path>>(pattern: &str, folder: Q) -> Vec<PathBuf> {
+    let match_options = MatchOptions {
+        require_literal_separator: true,
+        ..MatchOptions::new()
+    };
+    let mut files: Vec<PathBuf> = glob::glob_with(pattern, match_options)
+        .unwrap_or_else(|_| panic!("Invalid glob pattern '{pattern}'."))
+        .map(|entry| entry.unwrap_or_else(|error| panic!("Failed to read '{:?}'.", error.path())))
+        .filter(|file| file.is_file())
+        .collect();
+    files.sort();
+    copy_files_to_folder(&files, folder)
 }
 
 /// Copies a folder and its contents from one location to another.
@@ -97,24 +670,143 @@ pub fn copy_file<P: AsRef<Path>, Q: AsRef<Path>>(from: P, to: Q) {
 /// copy_folder(from, to);
 /// ```
 pub fn copy_folder<P: AsRef<Path>, Q: AsRef<Path>>(from: P, to: Q) {
-    // Convert the input paths to `Path` references.
-    let from = from.as_ref();
-    let to = to.as_ref();
+    copy_folder_with_progress(from, to, &NoProgress);
+}
+
+/// Copies a folder (along with its contents) from one location to another, reporting progress
+/// along the way.
+///
+/// # Arguments
+///
+/// * `from` - The source folder path (can be a `&str`, [`String`], [`Path`], or
+///   [`std::path::PathBuf`]).
+/// * `to` - The destination folder path (can be a `&str`, [`String`], [`Path`], or
+///   [`std::path::PathBuf`]).
+/// * `progress` - Receives progress updates (one item per file copied).
+///
+/// # Panics
+///
+/// If some error is encountered while copying a file.
+///
+/// # Example
+///
+/// ```
+/// use file_io::{NoProgress, copy_folder_with_progress};
+///
+/// // Copy 'src/' to 'folder/src/'.
+/// copy_folder_with_progress("src", "folder/src", &NoProgress);
+/// ```
+pub fn copy_folder_with_progress<P: AsRef<Path>, Q: AsRef<Path>>(
+    from: P,
+    to: Q,
+    progress: &dyn ProgressSink,
+) {
+    copy_folder_using(from.as_ref(), to.as_ref(), None, None, progress);
+}
+
+/// Copies a folder (along with its contents) from one location to another, with options.
+///
+/// # Arguments
+///
+/// * `from` - The source folder path (can be a `&str`, [`String`], [`Path`], or
+///   [`std::path::PathBuf`]).
+/// * `to` - The destination folder path (can be a `&str`, [`String`], [`Path`], or
+///   [`std::path::PathBuf`]).
+/// * `options` - Options controlling how the files are copied.
+///
+/// # Panics
+///
+/// If some error is encountered while copying a file, or if `options.max_bytes_per_sec` is
+/// `Some(0)`.
+///
+/// # Note
+///
+/// * If `options.max_bytes_per_sec` is set, the limit applies to the copy operation as a whole
+///   (not to each file separately).
+/// * If `options.retry` is set, each file is retried separately, so a transient error only causes
+///   the affected file to be copied again.
+///
+/// # Example
+///
+/// ```
+/// use file_io::{CopyOptions, copy_folder_with_options};
+///
+/// // Copy '.vscode/' to 'folder/vscode_throttled/', at most 1 MiB per second.
+/// let options = CopyOptions {
+///     max_bytes_per_sec: Some(1024 * 1024),
+///     ..Default::default()
+/// };
+/// copy_folder_with_options(".vscode", "folder/vscode_throttled", &options);
+/// ```
+pub fn copy_folder_with_options<P: AsRef<Path>, Q: AsRef<Path>>(
+    from: P,
+    to: Q,
+    options: &CopyOptions,
+) {
+    let mut throttle = options.max_bytes_per_sec.map(Throttle::new);
+    copy_folder_using(
+        from.as_ref(),
+        to.as_ref(),
+        throttle.as_mut(),
+        options.retry.as_ref(),
+        &NoProgress,
+    );
+}
 
-    // Traverse over all entries (files and folders) in the directory and its subdirectories.
-    for entry in WalkDir::new(from).into_iter().filter_map(Result::ok) {
-        // Get the path of the current entry.
-        let entry_path = entry.path();
+/// Copies a folder (along with its contents) from one location to another, optionally through a
+/// throttle and retrying failures, reporting progress along the way.
+///
+/// # Arguments
+///
+/// * `from` - The source folder path.
+/// * `to` - The destination folder path.
+/// * `throttle` - The throttle limiting the rate at which bytes are copied (`None` to copy as fast
+///   as possible).
+/// * `retry` - Controls how copying each file is retried if it fails (`None` to not retry).
+/// * `progress` - Receives progress updates (one item per file copied).
+///
+/// # Panics
+///
+/// If some error is encountered while copying a file (once all attempts have failed).
+fn copy_folder_using(
+    from: &Path,
+    to: &Path,
+    mut throttle: Option<&mut Throttle>,
+    retry: Option<&RetryOptions>,
+    progress: &dyn ProgressSink,
+) {
+    let mut mutation = Mutation::start_composite("copy_folder", from).to(to);
 
-        // Construct the destination path.
-        let destination_path = to.join(entry_path.strip_prefix(from).unwrap());
+    // Collect all files in the directory and its subdirectories (note that `WalkDir` will also
+    // traverse subdirectories, and we don't need to manually create subdirectories since
+    // `copy_file` will handle that for us).
+    let file_paths: Vec<PathBuf> = WalkDir::new(from)
+        .into_iter()
+        .filter_map(Result::ok)
+        .map(|entry| entry.into_path())
+        .filter(|entry_path| entry_path.is_file())
+        .collect();
+    progress.on_start(
+        file_paths.len(),
+        file_paths
+            .iter()
+            .map(|file_path| file_size(file_path))
+            .sum(),
+    );
 
-        // Copy any files (note that `WalkDir` will also traverse subdirectories, and we don't need
-        // to manually create subdirectories since `copy_file` will handle that for us).
-        if entry_path.is_file() {
-            copy_file(entry_path, &destination_path);
-        }
+    // Copy each file to its destination path.
+    for file_path in &file_paths {
+        copy_file_using(
+            file_path,
+            &to.join(file_path.strip_prefix(from).unwrap()),
+            throttle.as_deref_mut(),
+            retry,
+        );
+        progress.on_item(file_path);
+        progress.on_bytes(file_size(file_path));
     }
+    progress.on_finish();
+    mutation.succeeded();
 }
 
 #[cfg(test)]
@@ -349,4 +1041,226 @@ mod tests {
             "Hello from subfolder!"
         );
     }
+
+    #[test]
+    fn test_copy_file_to_unique() {
+        // Create a temporary directory to work in.
+        let temp_dir = tempdir().unwrap();
+        let temp_dir_path = get_temp_dir_path(&temp_dir);
+
+        // Create the source file and an existing destination file.
+        let from = temp_dir_path.join("from.txt");
+        let to = temp_dir_path.join("to.txt");
+        save_string_to_file("source", &from);
+        save_string_to_file("existing", &to);
+
+        // Copy the file without overwriting the existing destination.
+        let copied = copy_file_to_unique(&from, &to);
+        assert_eq!(copied, temp_dir_path.join("to (1).txt"));
+        assert_eq!(load_file_as_string(&to), "existing");
+        assert_eq!(load_file_as_string(&copied), "source");
+    }
+
+    #[test]
+    fn test_copy_file_to_writer() {
+        // Create a temporary directory to work in.
+        let temp_dir = tempdir().unwrap();
+        let file_path = get_temp_dir_path(&temp_dir).join("file.bin");
+        let content: Vec<u8> = (0..200_000).map(|i| (i % 251) as u8).collect();
+        std::fs::write(&file_path, &content).unwrap();
+
+        // Copy the file with the default buffer size and with a buffer size that does not evenly
+        // divide the file size.
+        for buffer_size in [DEFAULT_BUFFER_SIZE, 7] {
+            let mut buffer = Vec::new();
+            let bytes_written =
+                copy_file_to_writer_with_buffer_size(&file_path, &mut buffer, buffer_size);
+            assert_eq!(bytes_written, content.len() as u64);
+            assert_eq!(buffer, content);
+        }
+
+        // Empty files write nothing.
+        let empty_path = get_temp_dir_path(&temp_dir).join("empty");
+        save_string_to_file("", &empty_path);
+        let mut buffer = Vec::new();
+        assert_eq!(copy_file_to_writer(&empty_path, &mut buffer), 0);
+        assert!(buffer.is_empty());
+    }
+
+    #[test]
+    fn test_copy_with_bandwidth_limit() {
+        // Create a temporary directory to work in.
+        let temp_dir = tempdir().unwrap();
+        let temp_dir_path = get_temp_dir_path(&temp_dir);
+        let content: Vec<u8> = (0..100 * 1024).map(|i| (i % 251) as u8).collect();
+        std::fs::create_dir(temp_dir_path.join("src")).unwrap();
+        std::fs::write(temp_dir_path.join("src/a.bin"), &content).unwrap();
+        std::fs::write(temp_dir_path.join("src/b.bin"), &content).unwrap();
+        let options = CopyOptions {
+            max_bytes_per_sec: Some(800 * 1024),
+            ..Default::default()
+        };
+
+        // Copying 100 KiB at 800 KiB/s takes at least 125 ms.
+        let start = Instant::now();
+        copy_file_with_options(
+            temp_dir_path.join("src/a.bin"),
+            temp_dir_path.join("file_copy/a.bin"),
+            &options,
+        );
+        assert!(start.elapsed() >= Duration::from_millis(125));
+        assert_eq!(
+            std::fs::read(temp_dir_path.join("file_copy/a.bin")).unwrap(),
+            content
+        );
+
+        // The limit applies to a folder copy as a whole, so copying 200 KiB takes at least 250 ms.
+        let start = Instant::now();
+        copy_folder_with_options(
+            temp_dir_path.join("src"),
+            temp_dir_path.join("folder_copy"),
+            &options,
+        );
+        assert!(start.elapsed() >= Duration::from_millis(250));
+        assert_eq!(
+            std::fs::read(temp_dir_path.join("folder_copy/b.bin")).unwrap(),
+            content
+        );
+    }
+
+    #[test]
+    fn test_copy_with_retry() {
+        // Create a temporary directory to work in.
+        let temp_dir = tempdir().unwrap();
+        let temp_dir_path = get_temp_dir_path(&temp_dir);
+        let from = temp_dir_path.join("late.txt");
+        let to = temp_dir_path.join("copy/late.txt");
+        let options = CopyOptions {
+            retry: Some(RetryOptions {
+                max_attempts: 8,
+                backoff: Duration::from_millis(20),
+                retryable_error_kinds: vec![ErrorKind::NotFound],
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+
+        // The source file only appears after the first attempts have failed, and is copied once
+        // it does.
+        let writer = {
+            let from = from.clone();
+            std::thread::spawn(move || {
+                std::thread::sleep(Duration::from_millis(100));
+                save_string_to_file("finally", &from);
+            })
+        };
+        copy_file_with_options(&from, &to, &options);
+        writer.join().unwrap();
+        assert_eq!(load_file_as_string(&to), "finally");
+
+        // Errors that are not retryable fail immediately.
+        let options = CopyOptions {
+            retry: Some(RetryOptions {
+                max_attempts: 8,
+                backoff: Duration::from_secs(60),
+                retryable_error_kinds: vec![ErrorKind::ResourceBusy],
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+        let start = Instant::now();
+        let result = std::panic::catch_unwind(|| {
+            copy_file_with_options(temp_dir_path.join("missing.txt"), &to, &options);
+        });
+        assert!(start.elapsed() < Duration::from_secs(60));
+
+        // The panic message includes the error that made the copy fail.
+        let message = *result.unwrap_err().downcast::<String>().unwrap();
+        let error = std::fs::File::open(temp_dir_path.join("missing.txt")).unwrap_err();
+        assert!(message.ends_with(&format!(": {error}.")), "{message}");
+    }
+
+    #[test]
+    fn test_retry_options_is_retryable() {
+        let busy = std::io::Error::from(ErrorKind::ResourceBusy);
+        let eio = std::io::Error::from_raw_os_error(5);
+
+        // Without any retryable errors specified, no errors are retried.
+        assert!(!RetryOptions::default().is_retryable(&busy));
+        assert!(!RetryOptions::default().is_retryable(&eio));
+
+        // Otherwise, only the specified kinds and OS error codes are retried.
+        let options = RetryOptions {
+            retryable_error_kinds: vec![ErrorKind::ResourceBusy],
+            ..Default::default()
+        };
+        assert!(options.is_retryable(&busy));
+        assert!(!options.is_retryable(&eio));
+        let options = RetryOptions {
+            retryable_os_errors: vec![5],
+            ..Default::default()
+        };
+        assert!(!options.is_retryable(&busy));
+        assert!(options.is_retryable(&eio));
+    }
+
+    #[test]
+    fn test_copy_files_to_folder() {
+        // Create a temporary directory to work in.
+        let temp_dir = tempdir().unwrap();
+        let temp_dir_path = get_temp_dir_path(&temp_dir);
+        save_string_to_file("a", temp_dir_path.join("src/a.txt"));
+        save_string_to_file("b", temp_dir_path.join("src/nested/b.md"));
+        save_string_to_file("c", temp_dir_path.join("src/nested/c.txt"));
+
+        // Copy a list of files.
+        let copies = copy_files_to_folder(
+            &[
+                temp_dir_path.join("src/nested/b.md"),
+                temp_dir_path.join("src/a.txt"),
+            ],
+            temp_dir_path.join("list"),
+        );
+        assert_eq!(
+            copies,
+            [
+                temp_dir_path.join("list/b.md"),
+                temp_dir_path.join("list/a.txt")
+            ]
+        );
+        assert_eq!(load_file_as_string(temp_dir_path.join("list/b.md")), "b");
+
+        // Copy the files matching a glob pattern.
+        let pattern = format!("{}/src/**/*.txt", temp_dir_path.display());
+        let copies = copy_glob_to_folder(&pattern, temp_dir_path.join("glob"));
+        assert_eq!(
+            copies,
+            [
+                temp_dir_path.join("glob/a.txt"),
+                temp_dir_path.join("glob/c.txt")
+            ]
+        );
+    }
+
+    #[test]
+    fn test_copy_files_to_folder_name_collision() {
+        // Create a temporary directory to work in.
+        let temp_dir = tempdir().unwrap();
+        let temp_dir_path = get_temp_dir_path(&temp_dir);
+        save_string_to_file("1", temp_dir_path.join("one/same.txt"));
+        save_string_to_file("2", temp_dir_path.join("two/same.txt"));
+
+        // Files with the same name cannot be copied into the same folder, and nothing is copied.
+        let result = std::panic::catch_unwind(|| {
+            copy_files_to_folder(
+                &[
+                    temp_dir_path.join("one/same.txt"),
+                    temp_dir_path.join("two/same.txt"),
+                ],
+                temp_dir_path.join("copies"),
+            )
+        });
+        assert!(result.is_err());
+        assert!(!temp_dir_path.join("copies").exists());
+    }
 }
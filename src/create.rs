@@ -1,4 +1,9 @@
-use std::path::Path;
+use crate::instrument::Mutation;
+use crate::path::{numbered_path, safe_join};
+use std::fs::{File, FileTimes};
+use std::io::ErrorKind;
+use std::path::{Path, PathBuf};
+use std::time::SystemTime;
 
 /// Creates a new folder at the specified path if it does not already exist.
 ///
@@ -34,8 +39,13 @@ use std::path::Path;
 pub fn create_folder<P: AsRef<Path>>(path: P) {
     let path = path.as_ref();
     if !path.exists() {
+        let mut mutation = Mutation::start("create_folder", path);
+        if mutation.is_dry_run() {
+            return;
+        }
         std::fs::create_dir_all(path)
             .unwrap_or_else(|_| panic!("Failed to create folder at '{path:?}'."));
+        mutation.succeeded();
     }
 }
 
@@ -81,11 +91,328 @@ pub fn create_folder_for_file<P: AsRef<Path>>(path: P) {
     }
 }
 
+/// Creates an empty file at the specified path if it does not already exist, or updates its
+/// access and modification times to the current time if it does.
+///
+/// # Arguments
+///
+/// * `path` - The path to the file (can be a `&str`, [`String`], [`Path`], or
+///   [`std::path::PathBuf`]).
+///
+/// # Returns
+///
+/// `true` if the file was created, `false` if it already existed.
+///
+/// # Panics
+///
+/// If some error is encountered while creating the file or updating its timestamps.
+///
+/// # Note
+///
+/// * This function will create the parent folder for the file if it does not already exist.
+/// * The contents of an existing file are never modified.
+///
+/// # Example
+///
+/// ```
+/// use file_io::touch_file;
+///
+/// let path: &str = "folder/subfolder_15/marker";
+///
+/// // The first call creates the (empty) file.
+/// let created: bool = touch_file(path);
+///
+/// // The second call only updates the file's timestamps.
+/// assert!(!touch_file(path));
+/// ```
+pub fn touch_file<P: AsRef<Path>>(path: P) -> bool {
+    let path = path.as_ref();
+    let existed = path.exists();
+    let mut mutation = Mutation::start("touch_file", path);
+    if mutation.is_dry_run() {
+        return !existed;
+    }
+    create_folder_for_file(path);
+    let now = SystemTime::now();
+    File::options()
+        .create(true)
+        .append(true)
+        .open(path)
+        .and_then(|file| file.set_times(FileTimes::new().set_accessed(now).set_modified(now)))
+        .unwrap_or_else(|_| panic!("Failed to touch file at '{path:?}'."));
+    mutation.succeeded();
+    !existed
+}
+
+/// Options for creating an empty file.
+///
+/// # Example
+///
+/// ```
+/// use file_io::CreateFileOptions;
+///
+/// // Fail if the file already exists.
+/// let options = CreateFileOptions { exclusive: true };
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct CreateFileOptions {
+    /// If `true`, creating the file fails if something already exists at its path (the file is
+    /// created atomically, so a file created concurrently by another process is never
+    /// overwritten). If `false`, an existing file is truncated to be empty.
+    pub exclusive: bool,
+}
+
+/// Creates an empty file at the specified path, truncating it if it already exists.
+///
+/// # Arguments
+///
+/// * `path` - The path to the file (can be a `&str`, [`String`], [`Path`], or
+///   [`std::path::PathBuf`]).
+///
+/// # Panics
+///
+/// If some error is encountered while creating the file.
+///
+/// # Note
+///
+/// * This function will create the parent folder for the file if it does not already exist.
+/// * To leave the contents of an existing file unchanged, use [`touch_file`] instead. To fail if
+///   the file already exists, use [`create_empty_file_with_options`].
+///
+/// # Example
+///
+/// ```
+/// use file_io::{create_empty_file, load_file_as_string, save_string_to_file};
+///
+/// let path: &str = "folder/subfolder_50/file_51.txt";
+/// save_string_to_file("Hello, world!", path);
+///
+/// // The existing file is truncated.
+/// create_empty_file(path);
+/// assert_eq!(load_file_as_string(path), "");
+/// ```
+pub fn create_empty_file<P: AsRef<Path>>(path: P) {
+    create_empty_file_with_options(path, &CreateFileOptions::default());
+}
+
+/// Creates an empty file at the specified path, with options.
+///
+/// # Arguments
+///
+/// * `path` - The path to the file (can be a `&str`, [`String`], [`Path`], or
+///   [`std::path::PathBuf`]).
+/// * `options` - Options controlling how the file is created.
+///
+/// # Panics
+///
+/// * If some error is encountered while creating the file.
+/// * If `options.exclusive` is `true` and something already exists at `path`.
+///
+/// # Note
+///
+/// This function will create the parent folder for the file if it does not already exist.
+///
+/// # Example
+///
+/// ```
+/// use file_io::{CreateFileOptions, create_empty_file_with_options, delete_file};
+/// use std::path::Path;
+///
+/// let path: &str = "folder/subfolder_50/file_52.txt";
+/// delete_file(path);
+///
+/// // Create the file, failing if it already exists.
+/// create_empty_file_with_options(path, &CreateFileOptions { exclusive: true });
+/// assert!(Path::new(path).is_file());
+/// ```
+pub fn create_empty_file_with_options<P: AsRef<Path>>(path: P, options: &CreateFileOptions) {
+    let path = path.as_ref();
+    let mut mutation = Mutation::start("create_empty_file", path);
+    if mutation.is_dry_run() {
+        return;
+    }
+    create_folder_for_file(path);
+    let mut open_options = File::options();
+    open_options.write(true);
+    if options.exclusive {
+        open_options.create_new(true);
+    } else {
+        open_options.create(true).truncate(true);
+    }
+    if let Err(error) = open_options.open(path) {
+        if error.kind() == ErrorKind::AlreadyExists {
+            panic!("Failed to create file at '{path:?}' because it already exists.");
+        }
+        panic!("Failed to create file at '{path:?}'.");
+    }
+    mutation.succeeded();
+}
+
+/// Creates a structure of empty files and folders from a textual tree.
+///
+/// # Arguments
+///
+/// * `root` - The folder to create the structure in (can be a `&str`, [`String`], [`Path`], or
+///   [`std::path::PathBuf`]). It is created if it does not already exist.
+/// * `tree` - The textual tree. This can either be in the format printed by
+///   [`crate::print_folder_tree`] (using `├── `/`└── ` connectors), or simply use indentation to
+///   nest entries.
+///
+/// # Panics
+///
+/// If any file or folder cannot be created, or if an entry would be created outside of `root`
+/// (e.g. because its name contains `..`).
+///
+/// # Note
+///
+/// * Entries with nested entries, or whose names end in `/`, are created as folders. All other
+///   entries are created as empty files (existing files are left unchanged). Note that this means
+///   empty folders in the output of [`crate::print_folder_tree`] are created as files.
+/// * For trees in the format printed by [`crate::print_folder_tree`], the first line (i.e. the
+///   path of the top-level folder) is skipped.
+///
+/// # Examples
+///
+/// ## Tree format
+///
+/// ```
+/// use file_io::create_folder_tree_from_string;
+/// use std::path::Path;
+///
+/// create_folder_tree_from_string(
+///     "folder/scaffold_1",
+///     "project
+/// ├── Cargo.toml
+/// └── src
+///     └── main.rs",
+/// );
+///
+/// assert!(Path::new("folder/scaffold_1/Cargo.toml").is_file());
+/// assert!(Path::new("folder/scaffold_1/src/main.rs").is_file());
+/// ```
+///
+/// ## Indented format
+///
+/// ```
+/// use file_io::create_folder_tree_from_string;
+/// use std::path::Path;
+///
+/// create_folder_tree_from_string(
+///     "folder/scaffold_2",
+///     "
+///     docs/
+///     src
+///       lib.rs
+///     README.md
+///     ",
+/// );
+///
+/// assert!(Path::new("folder/scaffold_2/docs").is_dir());
+/// assert!(Path::new("folder/scaffold_2/src/lib.rs").is_file());
+/// assert!(Path::new("folder/scaffold_2/README.md").is_file());
+/// ```
+pub fn create_folder_tree_from_string<P: AsRef<Path>>(root: P, tree: &str) {
+    let root = root.as_ref();
+    create_folder(root);
+
+    // Skip blank lines, as well as the top-level path if the tree is in the printed format.
+    let mut lines: Vec<&str> = tree
+        .lines()
+        .filter(|line| !line.trim().is_empty())
+        .collect();
+    let is_printed_format = lines.iter().any(|line| line.contains("── "));
+    if is_printed_format && lines.first().is_some_and(|line| !line.contains("── ")) {
+        lines.remove(0);
+    }
+
+    // Split each line into the column at which the name starts and the name itself.
+    let entries: Vec<(usize, &str)> = lines
+        .iter()
+        .map(|line| {
+            let name = line.trim_start_matches(|c: char| {
+                c.is_whitespace() || matches!(c, '│' | '├' | '└' | '─')
+            });
+            (line.chars().count() - name.chars().count(), name.trim_end())
+        })
+        .collect();
+
+    // Create the entries, keeping track of the chain of folders containing the current entry.
+    let mut parents: Vec<(usize, PathBuf)> = Vec::new();
+    for (i, &(column, name)) in entries.iter().enumerate() {
+        while parents
+            .last()
+            .is_some_and(|(parent_column, _)| *parent_column >= column)
+        {
+            parents.pop();
+        }
+        let relative_path = match parents.last() {
+            Some((_, parent)) => parent.join(name.trim_end_matches('/')),
+            None => PathBuf::from(name.trim_end_matches('/')),
+        };
+        let path = safe_join(root, &relative_path)
+            .unwrap_or_else(|| panic!("Entry '{relative_path:?}' is outside of '{root:?}'."));
+        let has_children = entries
+            .get(i + 1)
+            .is_some_and(|(next_column, _)| *next_column > column);
+        if has_children || name.ends_with('/') {
+            create_folder(&path);
+            parents.push((column, relative_path));
+        } else {
+            touch_file(&path);
+        }
+    }
+}
+
+/// Creates a new, empty file at a path that does not already exist (see
+/// [`crate::unique_path`]).
+///
+/// # Arguments
+///
+/// * `path` - The desired path of the file.
+///
+/// # Returns
+///
+/// The created file (opened for writing) and the path it was created at.
+///
+/// # Panics
+///
+/// If the parent folder cannot be created or the file cannot be created for any reason other than
+/// a file already existing at a candidate path.
+///
+/// # Note
+///
+/// Each candidate path is created exclusively, so a file created concurrently by another process
+/// is never overwritten.
+pub(crate) fn create_unique_file(path: &Path) -> (File, PathBuf) {
+    create_folder_for_file(path);
+    for n in 0.. {
+        let candidate = if n == 0 {
+            path.to_path_buf()
+        } else {
+            numbered_path(path, n)
+        };
+        match File::options()
+            .write(true)
+            .create_new(true)
+            .open(&candidate)
+        {
+            Ok(file) => {
+                Mutation::start("create_unique_file", &candidate).succeeded();
+                return (file, candidate);
+            }
+            Err(error) if error.kind() == ErrorKind::AlreadyExists => continue,
+            Err(_) => panic!("Failed to create file '{candidate:?}'."),
+        }
+    }
+    unreachable!()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::delete::delete_folder;
     use crate::path::to_path_buf;
+    use crate::print::write_folder_tree;
     use crate::test_utils::{assert_folder_exists, get_temp_dir_path};
     use tempfile::tempdir;
 
@@ -191,4 +518,144 @@ mod tests {
             delete_folder(file_path_buf.parent().unwrap());
         }
     }
+
+    #[test]
+    fn test_touch_file() {
+        // Create a temporary directory to work in.
+        let temp_dir = tempdir().unwrap();
+
+        // Define a file path that requires a parent directory.
+        let file_path = get_temp_dir_path(&temp_dir).join("a/b/marker");
+
+        // Touching a file that does not exist creates it (along with its parent folder).
+        assert!(touch_file(&file_path));
+        assert!(file_path.is_file());
+        assert_eq!(std::fs::read(&file_path).unwrap(), b"");
+
+        // Write some content to the file and set its modification time to the past.
+        std::fs::write(&file_path, "content").unwrap();
+        let past = SystemTime::UNIX_EPOCH + std::time::Duration::from_secs(1_000_000_000);
+        File::options()
+            .write(true)
+            .open(&file_path)
+            .unwrap()
+            .set_modified(past)
+            .unwrap();
+
+        // Touching an existing file updates its modification time without changing its content.
+        assert!(!touch_file(&file_path));
+        assert!(std::fs::metadata(&file_path).unwrap().modified().unwrap() > past);
+        assert_eq!(std::fs::read_to_string(&file_path).unwrap(), "content");
+    }
+
+    #[test]
+    fn test_create_unique_file() {
+        // Create a temporary directory to work in.
+        let temp_dir = tempdir().unwrap();
+        let file_path = get_temp_dir_path(&temp_dir).join("folder/file.txt");
+
+        // The first file is created at the desired path, the following ones are numbered.
+        let (_, first) = create_unique_file(&file_path);
+        let (_, second) = create_unique_file(&file_path);
+        let (_, third) = create_unique_file(&file_path);
+        assert_eq!(first, file_path);
+        assert_eq!(second, file_path.with_file_name("file (1).txt"));
+        assert_eq!(third, file_path.with_file_name("file (2).txt"));
+        assert!(third.is_file());
+    }
+
+    #[test]
+    fn test_create_folder_tree_from_string_round_trip() {
+        // Create a temporary directory to work in.
+        let temp_dir = tempdir().unwrap();
+        let temp_dir_path = get_temp_dir_path(&temp_dir);
+
+        // Create the original structure and print it.
+        let original = temp_dir_path.join("original");
+        for path in ["a.txt", "b/c.txt", "b/d/e.txt", "b/f.txt", "g/h.txt"] {
+            touch_file(original.join(path));
+        }
+        let mut tree = Vec::new();
+        write_folder_tree(&original, &mut tree);
+        let tree = String::from_utf8(tree).unwrap();
+
+        // Recreate the structure from the printed tree and check that it prints the same way.
+        let copy = temp_dir_path.join("copy");
+        create_folder_tree_from_string(&copy, &tree);
+        let mut copy_tree = Vec::new();
+        write_folder_tree(&copy, &mut copy_tree);
+        assert_eq!(
+            String::from_utf8(copy_tree).unwrap(),
+            tree.replacen(
+                &original.display().to_string(),
+                &copy.display().to_string(),
+                1
+            )
+        );
+    }
+
+    #[test]
+    fn test_create_folder_tree_from_string_indented() {
+        // Create a temporary directory to work in.
+        let temp_dir = tempdir().unwrap();
+        let root = get_temp_dir_path(&temp_dir);
+
+        // Create the structure.
+        create_folder_tree_from_string(&root, "a\n  b\n    c.txt\n  d.txt\ne/\nf.txt\n");
+
+        // Check the structure.
+        assert!(root.join("a/b/c.txt").is_file());
+        assert!(root.join("a/d.txt").is_file());
+        assert!(root.join("e").is_dir());
+        assert!(root.join("f.txt").is_file());
+    }
+
+    #[test]
+    #[should_panic(expected = "is outside of")]
+    fn test_create_folder_tree_from_string_escape() {
+        // Create a temporary directory to work in.
+        let temp_dir = tempdir().unwrap();
+        let root = get_temp_dir_path(&temp_dir).join("root");
+
+        // Try to create an entry outside of the root folder.
+        create_folder_tree_from_string(&root, "../escaped.txt");
+    }
+
+    #[test]
+    fn test_create_empty_file() {
+        // Create a temporary directory to work in.
+        let temp_dir = tempdir().unwrap();
+
+        // Define a file path that requires a parent directory.
+        let file_path = get_temp_dir_path(&temp_dir).join("a/b/file.txt");
+
+        // Creating a file that does not exist creates it (along with its parent folder).
+        create_empty_file(&file_path);
+        assert_eq!(std::fs::read(&file_path).unwrap(), b"");
+
+        // Creating a file that already exists truncates it.
+        std::fs::write(&file_path, "content").unwrap();
+        create_empty_file(&file_path);
+        assert_eq!(std::fs::read(&file_path).unwrap(), b"");
+    }
+
+    #[test]
+    fn test_create_empty_file_exclusive() {
+        // Create a temporary directory to work in.
+        let temp_dir = tempdir().unwrap();
+        let file_path = get_temp_dir_path(&temp_dir).join("a/file.txt");
+        let options = CreateFileOptions { exclusive: true };
+
+        // Creating a file that does not exist succeeds.
+        create_empty_file_with_options(&file_path, &options);
+        assert!(file_path.is_file());
+
+        // Creating it again fails, and the existing file is left unchanged.
+        std::fs::write(&file_path, "content").unwrap();
+        let result = std::panic::catch_unwind(|| {
+            create_empty_file_with_options(&file_path, &options);
+        });
+        assert!(result.is_err());
+        assert_eq!(std::fs::read_to_string(&file_path).unwrap(), "content");
+    }
 }
@@ -1,4 +1,7 @@
-use std::path::Path;
+use crate::instrument::Mutation;
+use crate::progress::{ProgressSink, file_size};
+use std::path::{Path, PathBuf};
+use walkdir::WalkDir;
 
 /// Deletes a folder at the specified path if it exists.
 ///
@@ -55,11 +58,84 @@ use std::path::Path;
 pub fn delete_folder<P: AsRef<Path>>(path: P) {
     let path = path.as_ref();
     if path.exists() {
+        let mut mutation = Mutation::start("delete_folder", path);
+        if mutation.is_dry_run() {
+            return;
+        }
         std::fs::remove_dir_all(path)
             .unwrap_or_else(|_| panic!("Failed to delete folder at '{path:?}'."));
+        mutation.succeeded();
     }
 }
 
+/// Deletes a folder at the specified path if it exists, reporting progress along the way.
+///
+/// # Arguments
+///
+/// * `path` - The path to the folder to delete (can be a `&str`, [`String`], [`Path`], or
+///   [`std::path::PathBuf`]).
+/// * `progress` - Receives progress updates (one item per file deleted).
+///
+/// # Panics
+///
+/// If some error is encountered while deleting a file or the folder.
+///
+/// # Note
+///
+/// Symbolic links within the folder are deleted (rather than the files they point to).
+///
+/// # Example
+///
+/// ```
+/// use file_io::{NoProgress, delete_folder_with_progress, save_string_to_file};
+/// use std::path::Path;
+///
+/// // Create a folder to delete later.
+/// save_string_to_file("Hello, world!", "folder/subfolder_39/file_46.txt");
+///
+/// // Now delete the folder.
+/// delete_folder_with_progress("folder/subfolder_39", &NoProgress);
+/// assert!(!Path::new("folder/subfolder_39").exists());
+/// ```
+pub fn delete_folder_with_progress<P: AsRef<Path>>(path: P, progress: &dyn ProgressSink) {
+    let path = path.as_ref();
+    if !path.exists() {
+        return;
+    }
+    let mut mutation = Mutation::start("delete_folder", path);
+    if mutation.is_dry_run() {
+        return;
+    }
+
+    // Collect all files (and symbolic links) in the folder and its subfolders.
+    let file_paths: Vec<PathBuf> = WalkDir::new(path)
+        .into_iter()
+        .filter_map(Result::ok)
+        .filter(|entry| !entry.file_type().is_dir())
+        .map(|entry| entry.into_path())
+        .collect();
+    progress.on_start(
+        file_paths.len(),
+        file_paths
+            .iter()
+            .map(|file_path| file_size(file_path))
+            .sum(),
+    );
+
+    // Delete the files one at a time, then the (now empty) folders.
+    for file_path in &file_paths {
+        let bytes = file_size(file_path);
+        std::fs::remove_file(file_path)
+            .unwrap_or_else(|_| panic!("Failed to delete file at '{file_path:?}'."));
+        progress.on_item(file_path);
+        progress.on_bytes(bytes);
+    }
+    std::fs::remove_dir_all(path)
+        .unwrap_or_else(|_| panic!("Failed to delete folder at '{path:?}'."));
+    progress.on_finish();
+    mutation.succeeded();
+}
+
 /// Deletes a file at the specified path if it exists.
 ///
 /// # Arguments
@@ -115,8 +191,13 @@ pub fn delete_folder<P: AsRef<Path>>(path: P) {
 pub fn delete_file<P: AsRef<Path>>(path: P) {
     let path = path.as_ref();
     if path.exists() {
+        let mut mutation = Mutation::start("delete_file", path);
+        if mutation.is_dry_run() {
+            return;
+        }
         std::fs::remove_file(path)
             .unwrap_or_else(|_| panic!("Failed to delete file at '{path:?}'."));
+        mutation.succeeded();
     }
 }
 
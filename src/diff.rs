@@ -0,0 +1,296 @@
+/// Number of unchanged lines shown before and after each change in a unified diff.
+const CONTEXT_LINES: usize = 3;
+
+/// Maximum edit distance explored by the diff algorithm before falling back to replacing the
+/// entire changed region (this bounds the memory used for very different inputs).
+const MAX_EDIT_DISTANCE: usize = 4096;
+
+/// Kind of a single line-level edit.
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum EditKind {
+    /// The line is present in both the old and the new text.
+    Equal,
+
+    /// The line is only present in the old text.
+    Delete,
+
+    /// The line is only present in the new text.
+    Insert,
+}
+
+/// A single line-level edit.
+#[derive(Clone, Copy, Debug, PartialEq)]
+struct Edit {
+    /// Kind of edit.
+    kind: EditKind,
+
+    /// Index of the line in the old text (for insertions, the index of the next old line).
+    old_index: usize,
+
+    /// Index of the line in the new text (for deletions, the index of the next new line).
+    new_index: usize,
+}
+
+/// Computes the shortest sequence of line edits transforming `a` into `b` using Myers' algorithm.
+///
+/// # Arguments
+///
+/// * `a` - Lines of the old text.
+/// * `b` - Lines of the new text.
+///
+/// # Returns
+///
+/// The edits (in order).
+fn diff_lines(a: &[&str], b: &[&str]) -> Vec<Edit> {
+    // Lines shared at the start and end of both texts do not need to go through the (expensive)
+    // search below.
+    let prefix = a.iter().zip(b).take_while(|(x, y)| x == y).count();
+    let suffix = a[prefix..]
+        .iter()
+        .rev()
+        .zip(b[prefix..].iter().rev())
+        .take_while(|(x, y)| x == y)
+        .count();
+    let (a_mid, b_mid) = (&a[prefix..a.len() - suffix], &b[prefix..b.len() - suffix]);
+
+    // Edits for the common prefix.
+    let mut edits: Vec<Edit> = (0..prefix)
+        .map(|i| Edit {
+            kind: EditKind::Equal,
+            old_index: i,
+            new_index: i,
+        })
+        .collect();
+
+    // Edits for the middle section (with indices offset by the length of the common prefix).
+    edits.extend(diff_middle(a_mid, b_mid).into_iter().map(|edit| Edit {
+        old_index: edit.old_index + prefix,
+        new_index: edit.new_index + prefix,
+        ..edit
+    }));
+
+    // Edits for the common suffix.
+    edits.extend((0..suffix).map(|i| Edit {
+        kind: EditKind::Equal,
+        old_index: a.len() - suffix + i,
+        new_index: b.len() - suffix + i,
+    }));
+
+    edits
+}
+
+/// Computes the line edits transforming `a` into `b` (see [`diff_lines`]), without any
+/// prefix/suffix trimming.
+///
+/// # Arguments
+///
+/// * `a` - Lines of the old text.
+/// * `b` - Lines of the new text.
+///
+/// # Returns
+///
+/// The edits (in order).
+fn diff_middle(a: &[&str], b: &[&str]) -> Vec<Edit> {
+    let (n, m) = (a.len() as isize, b.len() as isize);
+    let max = (a.len() + b.len()).min(MAX_EDIT_DISTANCE) as isize;
+
+    // `v[k + offset]` is the furthest x reached on diagonal k. `trace[d]` stores the portion of `v`
+    // for diagonals -d..=d at the start of round d (used to backtrack once the end is reached).
+    let offset = max + 1;
+    let mut v = vec![0isize; 2 * offset as usize + 1];
+    let mut trace: Vec<Vec<isize>> = Vec::new();
+    let mut found = false;
+    'search: for d in 0..=max {
+        trace.push(v[(offset - d) as usize..=(offset + d) as usize].to_vec());
+        for k in (-d..=d).step_by(2) {
+            let idx = (k + offset) as usize;
+            let mut x = if k == -d || (k != d && v[idx - 1] < v[idx + 1]) {
+                v[idx + 1]
+            } else {
+                v[idx - 1] + 1
+            };
+            let mut y = x - k;
+            while x < n && y < m && a[x as usize] == b[y as usize] {
+                x += 1;
+                y += 1;
+            }
+            v[idx] = x;
+            if x >= n && y >= m {
+                found = true;
+                break 'search;
+            }
+        }
+    }
+
+    // If the texts are too different, simply delete all old lines and insert all new lines.
+    if !found {
+        let deletions = (0..a.len()).map(|i| Edit {
+            kind: EditKind::Delete,
+            old_index: i,
+            new_index: 0,
+        });
+        let insertions = (0..b.len()).map(|j| Edit {
+            kind: EditKind::Insert,
+            old_index: a.len(),
+            new_index: j,
+        });
+        return deletions.chain(insertions).collect();
+    }
+
+    // Backtrack through the trace to recover the edits (in reverse order).
+    let mut edits = Vec::new();
+    let (mut x, mut y) = (n, m);
+    for (d, v) in trace.iter().enumerate().rev() {
+        let d = d as isize;
+        let get = |k: isize| v[(k + d) as usize];
+        let k = x - y;
+        let prev_k = if k == -d || (k != d && get(k - 1) < get(k + 1)) {
+            k + 1
+        } else {
+            k - 1
+        };
+        let prev_x = if d == 0 { 0 } else { get(prev_k) };
+        let prev_y = if d == 0 { 0 } else { prev_x - prev_k };
+        while x > prev_x && y > prev_y {
+            x -= 1;
+            y -= 1;
+            edits.push(Edit {
+                kind: EditKind::Equal,
+                old_index: x as usize,
+                new_index: y as usize,
+            });
+        }
+        if d > 0 {
+            if x == prev_x {
+                edits.push(Edit {
+                    kind: EditKind::Insert,
+                    old_index: x as usize,
+                    new_index: prev_y as usize,
+                });
+            } else {
+                edits.push(Edit {
+                    kind: EditKind::Delete,
+                    old_index: prev_x as usize,
+                    new_index: y as usize,
+                });
+            }
+        }
+        x = prev_x;
+        y = prev_y;
+    }
+    edits.reverse();
+    edits
+}
+
+/// Produces a unified diff (in the format used by `diff -u` and `git diff`) between two texts.
+///
+/// # Arguments
+///
+/// * `old` - The old text.
+/// * `new` - The new text.
+/// * `old_label` - Label for the old text (printed on the `---` line).
+/// * `new_label` - Label for the new text (printed on the `+++` line).
+///
+/// # Returns
+///
+/// The unified diff. If the texts are identical, this is an empty string.
+pub(crate) fn unified_diff(old: &str, new: &str, old_label: &str, new_label: &str) -> String {
+    // Split the texts into lines and compute the edits.
+    let a: Vec<&str> = old.lines().collect();
+    let b: Vec<&str> = new.lines().collect();
+    let edits = diff_lines(&a, &b);
+
+    // Indices (into `edits`) of all the changes.
+    let changes: Vec<usize> = (0..edits.len())
+        .filter(|&i| edits[i].kind != EditKind::Equal)
+        .collect();
+    if changes.is_empty() {
+        return String::new();
+    }
+
+    // Group the changes into hunks (changes separated by little enough context share a hunk).
+    let mut hunks: Vec<(usize, usize)> = Vec::new();
+    for &i in &changes {
+        let start = i.saturating_sub(CONTEXT_LINES);
+        let end = (i + CONTEXT_LINES + 1).min(edits.len());
+        match hunks.last_mut() {
+            Some(last) if start <= last.1 => last.1 = end,
+            _ => hunks.push((start, end)),
+        }
+    }
+
+    // Write the header and each hunk.
+    let mut output = format!("--- {old_label}\n+++ {new_label}\n");
+    for (start, end) in hunks {
+        let hunk = &edits[start..end];
+        let old_count = hunk.iter().filter(|e| e.kind != EditKind::Insert).count();
+        let new_count = hunk.iter().filter(|e| e.kind != EditKind::Delete).count();
+        let old_start = hunk[0].old_index + usize::from(old_count > 0);
+        let new_start = hunk[0].new_index + usize::from(new_count > 0);
+        output.push_str(&format!(
+            "@@ -{old_start},{old_count} +{new_start},{new_count} @@\n"
+        ));
+        for edit in hunk {
+            match edit.kind {
+                EditKind::Equal => output.push_str(&format!(" {}\n", a[edit.old_index])),
+                EditKind::Delete => output.push_str(&format!("-{}\n", a[edit.old_index])),
+                EditKind::Insert => output.push_str(&format!("+{}\n", b[edit.new_index])),
+            }
+        }
+    }
+
+    output
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_unified_diff_identical() {
+        assert_eq!(unified_diff("a\nb\n", "a\nb\n", "old", "new"), "");
+    }
+
+    #[test]
+    fn test_unified_diff_single_change() {
+        let old = "1\n2\n3\n4\n5\n6\n7\n8\n9\n";
+        let new = "1\n2\n3\n4\nfive\n6\n7\n8\n9\n";
+        assert_eq!(
+            unified_diff(old, new, "a/file.txt", "b/file.txt"),
+            "--- a/file.txt\n+++ b/file.txt\n@@ -2,7 +2,7 @@\n 2\n 3\n 4\n-5\n+five\n 6\n 7\n 8\n"
+        );
+    }
+
+    #[test]
+    fn test_unified_diff_multiple_hunks() {
+        let old: String = (1..=20).map(|i| format!("{i}\n")).collect();
+        let new: String = (1..=20)
+            .filter(|&i| i != 19)
+            .map(|i| {
+                if i == 2 {
+                    "two\n".to_string()
+                } else {
+                    format!("{i}\n")
+                }
+            })
+            .collect();
+        assert_eq!(
+            unified_diff(&old, &new, "old", "new"),
+            "--- old\n+++ new\n\
+             @@ -1,5 +1,5 @@\n 1\n-2\n+two\n 3\n 4\n 5\n\
+             @@ -16,5 +16,4 @@\n 16\n 17\n 18\n-19\n 20\n"
+        );
+    }
+
+    #[test]
+    fn test_unified_diff_insertion_and_deletion() {
+        assert_eq!(
+            unified_diff("", "a\nb\n", "old", "new"),
+            "--- old\n+++ new\n@@ -0,0 +1,2 @@\n+a\n+b\n"
+        );
+        assert_eq!(
+            unified_diff("a\nb\n", "", "old", "new"),
+            "--- old\n+++ new\n@@ -1,2 +0,0 @@\n-a\n-b\n"
+        );
+    }
+}
@@ -0,0 +1,312 @@
+use crate::path::get_home_path;
+use std::path::PathBuf;
+
+/// Kind of per-user application folder.
+#[derive(Clone, Copy)]
+enum AppDirKind {
+    /// Configuration files.
+    Config,
+
+    /// Non-essential cached data.
+    Cache,
+
+    /// Persistent application data.
+    Data,
+}
+
+/// Gets the base folder (i.e. the folder in which each application gets its own subfolder) for a
+/// kind of per-user application folder.
+///
+/// # Arguments
+///
+/// * `kind` - Kind of application folder.
+///
+/// # Returns
+///
+/// The base folder.
+///
+/// # Panics
+///
+/// If the home directory cannot be determined.
+fn base_dir(kind: AppDirKind) -> PathBuf {
+    if cfg!(windows) {
+        let (var, fallback) = match kind {
+            AppDirKind::Config | AppDirKind::Data => ("APPDATA", "AppData/Roaming"),
+            AppDirKind::Cache => ("LOCALAPPDATA", "AppData/Local"),
+        };
+        std::env::var_os(var)
+            .filter(|value| !value.is_empty())
+            .map(PathBuf::from)
+            .unwrap_or_else(|| get_home_path().join(fallback))
+    } else if cfg!(target_os = "macos") {
+        get_home_path().join(match kind {
+            AppDirKind::Config | AppDirKind::Data => "Library/Application Support",
+            AppDirKind::Cache => "Library/Caches",
+        })
+    } else {
+        let (var, fallback) = match kind {
+            AppDirKind::Config => ("XDG_CONFIG_HOME", ".config"),
+            AppDirKind::Cache => ("XDG_CACHE_HOME", ".cache"),
+            AppDirKind::Data => ("XDG_DATA_HOME", ".local/share"),
+        };
+        xdg_var(var).unwrap_or_else(|| get_home_path().join(fallback))
+    }
+}
+
+/// Reads an XDG Base Directory environment variable.
+///
+/// # Arguments
+///
+/// * `var` - Name of the environment variable.
+///
+/// # Returns
+///
+/// The path stored in the environment variable, or `None` if it is unset, empty, or not an
+/// absolute path (the XDG Base Directory Specification requires relative paths to be ignored).
+fn xdg_var(var: &str) -> Option<PathBuf> {
+    std::env::var_os(var)
+        .map(PathBuf::from)
+        .filter(|path| path.is_absolute())
+}
+
+/// Get the folder in which an application should store its configuration files.
+///
+/// # Arguments
+///
+/// * `app` - Name of the application.
+///
+/// # Returns
+///
+/// Path to the configuration folder of the application:
+///
+/// * Linux (and other Unix platforms): `$XDG_CONFIG_HOME/<app>`, falling back to `~/.config/<app>`
+///   if `$XDG_CONFIG_HOME` is unset, empty, or relative
+/// * macOS: `~/Library/Application Support/<app>`
+/// * Windows: `%APPDATA%\<app>`
+///
+/// # Panics
+///
+/// If the home directory cannot be determined.
+///
+/// # Note
+///
+/// The folder is not created.
+///
+/// # Example
+///
+/// ```
+/// use file_io::get_config_dir;
+///
+/// let config_dir = get_config_dir("my_app");
+/// assert!(config_dir.ends_with("my_app"));
+/// ```
+pub fn get_config_dir(app: &str) -> PathBuf {
+    base_dir(AppDirKind::Config).join(app)
+}
+
+/// Get the folder in which an application should store non-essential cached data.
+///
+/// # Arguments
+///
+/// * `app` - Name of the application.
+///
+/// # Returns
+///
+/// Path to the cache folder of the application:
+///
+/// * Linux (and other Unix platforms): `$XDG_CACHE_HOME/<app>`, falling back to `~/.cache/<app>`
+///   if `$XDG_CACHE_HOME` is unset, empty, or relative
+/// * macOS: `~/Library/Caches/<app>`
+/// * Windows: `%LOCALAPPDATA%\<app>`
+///
+/// # Panics
+///
+/// If the home directory cannot be determined.
+///
+/// # Note
+///
+/// The folder is not created.
+///
+/// # Example
+///
+/// ```
+/// use file_io::get_cache_dir;
+///
+/// let cache_dir = get_cache_dir("my_app");
+/// assert!(cache_dir.ends_with("my_app"));
+/// ```
+pub fn get_cache_dir(app: &str) -> PathBuf {
+    base_dir(AppDirKind::Cache).join(app)
+}
+
+/// Get the folder in which an application should store persistent data.
+///
+/// # Arguments
+///
+/// * `app` - Name of the application.
+///
+/// # Returns
+///
+/// Path to the data folder of the application:
+///
+/// * Linux (and other Unix platforms): `$XDG_DATA_HOME/<app>`, falling back to `~/.local/share/<app>`
+///   if `$XDG_DATA_HOME` is unset, empty, or relative
+/// * macOS: `~/Library/Application Support/<app>`
+/// * Windows: `%APPDATA%\<app>`
+///
+/// # Panics
+///
+/// If the home directory cannot be determined.
+///
+/// # Note
+///
+/// The folder is not created.
+///
+/// # Example
+///
+/// ```
+/// use file_io::get_data_dir;
+///
+/// let data_dir = get_data_dir("my_app");
+/// assert!(data_dir.ends_with("my_app"));
+/// ```
+pub fn get_data_dir(app: &str) -> PathBuf {
+    base_dir(AppDirKind::Data).join(app)
+}
+
+/// Get the user's runtime folder (for sockets, named pipes, and other runtime files).
+///
+/// # Returns
+///
+/// The path stored in the `$XDG_RUNTIME_DIR` environment variable, or `None` if it is unset,
+/// empty, or not an absolute path.
+///
+/// # Note
+///
+/// The XDG Base Directory Specification does not define a fallback for this folder, so none is
+/// provided. It is usually only set on Linux.
+///
+/// # Example
+///
+/// ```
+/// use file_io::xdg_runtime_dir;
+///
+/// if let Some(runtime_dir) = xdg_runtime_dir() {
+///     println!("Runtime folder: {runtime_dir:?}");
+/// }
+/// ```
+pub fn xdg_runtime_dir() -> Option<PathBuf> {
+    xdg_var("XDG_RUNTIME_DIR")
+}
+
+/// Get the user's downloads folder.
+///
+/// # Returns
+///
+/// Path to the user's downloads folder (`~/Downloads` on all platforms).
+///
+/// # Panics
+///
+/// If the home directory cannot be determined.
+///
+/// # Example
+///
+/// ```
+/// use file_io::get_downloads_dir;
+///
+/// let downloads_dir = get_downloads_dir();
+/// assert!(downloads_dir.ends_with("Downloads"));
+/// ```
+pub fn get_downloads_dir() -> PathBuf {
+    get_home_path().join("Downloads")
+}
+
+#[cfg(all(test, unix))]
+mod tests {
+    use super::*;
+    use temp_env::with_var;
+
+    #[cfg(not(target_os = "macos"))]
+    #[test]
+    fn test_app_dirs() {
+        temp_env::with_vars(
+            [
+                ("HOME", Some("/home/test")),
+                ("XDG_CONFIG_HOME", None),
+                ("XDG_CACHE_HOME", None),
+                ("XDG_DATA_HOME", None),
+            ],
+            || {
+                assert_eq!(
+                    get_config_dir("my_app"),
+                    PathBuf::from("/home/test/.config/my_app")
+                );
+                assert_eq!(
+                    get_cache_dir("my_app"),
+                    PathBuf::from("/home/test/.cache/my_app")
+                );
+                assert_eq!(
+                    get_data_dir("my_app"),
+                    PathBuf::from("/home/test/.local/share/my_app")
+                );
+                assert_eq!(get_downloads_dir(), PathBuf::from("/home/test/Downloads"));
+            },
+        );
+    }
+
+    #[cfg(target_os = "macos")]
+    #[test]
+    fn test_app_dirs() {
+        with_var("HOME", Some("/Users/test"), || {
+            assert_eq!(
+                get_config_dir("my_app"),
+                PathBuf::from("/Users/test/Library/Application Support/my_app")
+            );
+            assert_eq!(
+                get_cache_dir("my_app"),
+                PathBuf::from("/Users/test/Library/Caches/my_app")
+            );
+            assert_eq!(get_data_dir("my_app"), get_config_dir("my_app"));
+        });
+    }
+
+    #[cfg(not(target_os = "macos"))]
+    #[test]
+    fn test_app_dirs_xdg() {
+        temp_env::with_vars(
+            [
+                ("HOME", Some("/home/test")),
+                ("XDG_CONFIG_HOME", Some("/xdg/config")),
+                ("XDG_CACHE_HOME", Some("relative/cache")),
+                ("XDG_DATA_HOME", Some("")),
+            ],
+            || {
+                assert_eq!(
+                    get_config_dir("my_app"),
+                    PathBuf::from("/xdg/config/my_app")
+                );
+                assert_eq!(
+                    get_cache_dir("my_app"),
+                    PathBuf::from("/home/test/.cache/my_app")
+                );
+                assert_eq!(
+                    get_data_dir("my_app"),
+                    PathBuf::from("/home/test/.local/share/my_app")
+                );
+            },
+        );
+    }
+
+    #[test]
+    fn test_xdg_runtime_dir() {
+        with_var("XDG_RUNTIME_DIR", Some("/run/user/1000"), || {
+            assert_eq!(xdg_runtime_dir(), Some(PathBuf::from("/run/user/1000")));
+        });
+        with_var("XDG_RUNTIME_DIR", Some("run"), || {
+            assert_eq!(xdg_runtime_dir(), None)
+        });
+        with_var("XDG_RUNTIME_DIR", None::<&str>, || {
+            assert_eq!(xdg_runtime_dir(), None)
+        });
+    }
+}
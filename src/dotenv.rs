@@ -0,0 +1,370 @@
+use crate::load::load_file_as_string;
+use crate::save::save_string_to_file;
+use std::collections::HashMap;
+use std::path::Path;
+
+/// Parses a double-quoted value, resolving escape sequences.
+///
+/// # Arguments
+///
+/// * `chars` - The characters following the opening quote (consumed up to and including the
+///   closing quote).
+///
+/// # Returns
+///
+/// The unescaped value, or `None` if there is no closing quote.
+fn parse_double_quoted(chars: &mut std::str::Chars) -> Option<String> {
+    let mut value = String::new();
+    while let Some(c) = chars.next() {
+        match c {
+            '"' => return Some(value),
+            '\\' => match chars.next()? {
+                'n' => value.push('\n'),
+                'r' => value.push('\r'),
+                't' => value.push('\t'),
+                other @ ('"' | '\\' | '$') => value.push(other),
+                other => {
+                    value.push('\\');
+                    value.push(other);
+                }
+            },
+            _ => value.push(c),
+        }
+    }
+    None
+}
+
+/// Loads the variables defined in a `.env` file.
+///
+/// # Arguments
+///
+/// * `path` - The path to the `.env` file (can be a `&str`, [`String`], [`Path`], or
+///   [`std::path::PathBuf`]).
+///
+/// # Returns
+///
+/// A map from the name of each variable to its value.
+///
+/// # Panics
+///
+/// If the file cannot be read, or if it contains a line that is not a valid variable definition
+/// (e.g. because it has no `=` or a quoted value is not terminated).
+///
+/// # Note
+///
+/// * Each variable is defined as `KEY=VALUE` on its own line, optionally preceded by `export `.
+///   Whitespace around keys and unquoted values is ignored.
+/// * Blank lines and lines starting with `#` are ignored. In unquoted values, a `#` preceded by
+///   whitespace starts a comment.
+/// * Values can be enclosed in single quotes (taken literally) or double quotes (supporting the
+///   escape sequences `\n`, `\r`, `\t`, `\"`, `\\`, and `\$`). Quoted values may span multiple
+///   lines.
+/// * If a variable is defined more than once, the last definition is used.
+/// * Variable references (e.g. `${HOME}`) are not expanded.
+///
+/// # Example
+///
+/// ```
+/// use file_io::{load_dotenv_file, save_string_to_file};
+///
+/// let path: &str = "folder/subfolder_53/.env";
+/// save_string_to_file(
+///     "# Database settings\nexport DB_HOST=localhost # local only\nDB_PASSWORD=\"p@ss word\"\n",
+///     path,
+/// );
+///
+/// let variables = load_dotenv_file(path);
+/// assert_eq!(variables["DB_HOST"], "localhost");
+/// assert_eq!(variables["DB_PASSWORD"], "p@ss word");
+/// ```
+pub fn load_dotenv_file<P: AsRef<Path>>(path: P) -> HashMap<String, String> {
+    let path = path.as_ref();
+    let content = load_file_as_string(path);
+    let mut variables = HashMap::new();
+    let mut lines = content.lines().enumerate();
+    while let Some((index, line)) = lines.next() {
+        let invalid_line = || panic!("Invalid line {} in '{path:?}'.", index + 1);
+
+        // Skip blank lines and comments.
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+
+        // Split the line into the key and the (raw) value.
+        let line = line.strip_prefix("export ").unwrap_or(line);
+        let Some((key, raw_value)) = line.split_once('=') else {
+            invalid_line()
+        };
+        let key = key.trim();
+        if key.is_empty() || key.contains(char::is_whitespace) {
+            invalid_line();
+        }
+        let raw_value = raw_value.trim_start();
+
+        // Parse the value, continuing onto the following lines for unterminated quoted values.
+        let value = if let Some(quoted) = raw_value.strip_prefix(['"', '\'']) {
+            let quote = raw_value.chars().next().unwrap();
+            let mut quoted = quoted.to_string();
+            let (value, rest) = loop {
+                let parsed = if quote == '"' {
+                    let mut chars = quoted.chars();
+                    parse_double_quoted(&mut chars).map(|value| (value, chars.as_str().to_string()))
+                } else {
+                    quoted
+                        .split_once('\'')
+                        .map(|(value, rest)| (value.to_string(), rest.to_string()))
+                };
+                if let Some(parsed) = parsed {
+                    break parsed;
+                }
+                match lines.next() {
+                    Some((_, next_line)) => {
+                        quoted.push('\n');
+                        quoted.push_str(next_line);
+                    }
+                    None => invalid_line(),
+                }
+            };
+            let rest = rest.trim();
+            if !rest.is_empty() && !rest.starts_with('#') {
+                invalid_line();
+            }
+            value
+        } else {
+            let end = raw_value
+                .char_indices()
+                .find(|&(i, c)| c == '#' && raw_value[..i].ends_with(char::is_whitespace))
+                .map_or(raw_value.len(), |(i, _)| i);
+            raw_value[..end].trim_end().to_string()
+        };
+        variables.insert(key.to_string(), value);
+    }
+    variables
+}
+
+/// Saves variables to a `.env` file.
+///
+/// # Arguments
+///
+/// * `variables` - A map from the name of each variable to its value.
+/// * `path` - The path to the `.env` file (can be a `&str`, [`String`], [`Path`], or
+///   [`std::path::PathBuf`]).
+///
+/// # Panics
+///
+/// If a variable name is empty or contains whitespace, `=`, or `#`, or if the file cannot be
+/// written.
+///
+/// # Note
+///
+/// * The variables are written in alphabetical order, one `KEY=VALUE` definition per line.
+/// * Values consisting only of letters, digits, and the characters `_-.,:/@+` are written
+///   unquoted. All other values are written in double quotes (with escape sequences), so that they
+///   are loaded back unchanged by [`load_dotenv_file`].
+/// * This function will create the parent folder for the file if it does not already exist.
+///
+/// # Example
+///
+/// ```
+/// use file_io::{load_file_as_string, save_dotenv_file};
+/// use std::collections::HashMap;
+///
+/// let path: &str = "folder/subfolder_53/file_54.env";
+/// let variables = HashMap::from([
+///     (String::from("DB_HOST"), String::from("localhost")),
+///     (String::from("DB_PASSWORD"), String::from("p@ss word")),
+/// ]);
+/// save_dotenv_file(&variables, path);
+///
+/// assert_eq!(
+///     load_file_as_string(path),
+///     "DB_HOST=localhost\nDB_PASSWORD=\"p@ss word\"\n"
+/// );
+/// ```
+pub fn save_dotenv_file<P: AsRef<Path>>(variables: &HashMap<String, String>, path: P) {
+    let mut keys: Vec<&String> = variables.keys().collect();
+    keys.sort();
+    let mut content = String::new();
+    for key in keys {
+        if key.is_empty() || key.contains(|c: char| c.is_whitespace() || c == '=' || c == '#') {
+            panic!("Invalid variable name '{key}'.");
+        }
+        let value = &variables[key];
+        content.push_str(key);
+        content.push('=');
+        if !value.is_empty()
+            && value
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || "_-.,:/@+".contains(c))
+        {
+            content.push_str(value);
+        } else {
+            content.push('"');
+            for c in value.chars() {
+                match c {
+                    '\n' => content.push_str("\\n"),
+                    '\r' => content.push_str("\\r"),
+                    '\t' => content.push_str("\\t"),
+                    '"' | '\\' | '$' => {
+                        content.push('\\');
+                        content.push(c);
+                    }
+                    _ => content.push(c),
+                }
+            }
+            content.push('"');
+        }
+        content.push('\n');
+    }
+    save_string_to_file(&content, path);
+}
+
+/// Sets the variables defined in a `.env` file in the environment of the current process.
+///
+/// # Arguments
+///
+/// * `path` - The path to the `.env` file (can be a `&str`, [`String`], [`Path`], or
+///   [`std::path::PathBuf`]).
+///
+/// # Panics
+///
+/// If the file cannot be loaded (see [`load_dotenv_file`]), or if a variable name or value cannot
+/// be set in the environment (e.g. because it contains a NUL character).
+///
+/// # Safety
+///
+/// Modifying the environment is not thread-safe on most platforms (see [`std::env::set_var`]).
+/// The caller must ensure that no other thread reads or writes the environment (including
+/// indirectly, e.g. through [`std::env::var`] or by spawning a process) during the call to this
+/// function.
+///
+/// # Note
+///
+/// Variables that are already set in the environment are not overridden, so the environment of
+/// the process takes precedence over the `.env` file.
+///
+/// # Example
+///
+/// ```
+/// use file_io::{apply_dotenv, save_string_to_file};
+///
+/// let path: &str = "folder/subfolder_53/file_55.env";
+/// save_string_to_file("FILE_IO_DOTENV_EXAMPLE=value\n", path);
+///
+/// // SAFETY: No other threads access the environment.
+/// unsafe { apply_dotenv(path) };
+/// assert_eq!(std::env::var("FILE_IO_DOTENV_EXAMPLE").unwrap(), "value");
+/// ```
+pub unsafe fn apply_dotenv<P: AsRef<Path>>(path: P) {
+    for (key, value) in load_dotenv_file(path) {
+        if std::env::var_os(&key).is_none() {
+            // SAFETY: Upheld by the caller.
+            unsafe { std::env::set_var(&key, &value) };
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::env::set_env_var_scoped;
+    use crate::test_utils::get_temp_dir_path;
+    use serial_test::serial;
+    use tempfile::tempdir;
+
+    #[test]
+    fn test_load_dotenv_file() {
+        // Create a temporary directory to work in.
+        let temp_dir = tempdir().unwrap();
+        let file_path = get_temp_dir_path(&temp_dir).join(".env");
+        save_string_to_file(
+            "# Comment\n\
+             \n\
+             PLAIN=value\n\
+             export EXPORTED = spaced value  # comment\n\
+             HASH=a#b\n\
+             EMPTY=\n\
+             SINGLE='literal \\n # not a comment'\n\
+             DOUBLE=\"tab\\there \\\"quoted\\\" \\$HOME\" # comment\n\
+             MULTILINE=\"first\n\
+             second\"\n\
+             PLAIN=overridden\n",
+            &file_path,
+        );
+
+        let variables = load_dotenv_file(&file_path);
+        let expected = HashMap::from(
+            [
+                ("PLAIN", "overridden"),
+                ("EXPORTED", "spaced value"),
+                ("HASH", "a#b"),
+                ("EMPTY", ""),
+                ("SINGLE", "literal \\n # not a comment"),
+                ("DOUBLE", "tab\there \"quoted\" $HOME"),
+                ("MULTILINE", "first\nsecond"),
+            ]
+            .map(|(key, value)| (key.to_string(), value.to_string())),
+        );
+        assert_eq!(variables, expected);
+    }
+
+    #[test]
+    #[should_panic(expected = "Invalid line 2")]
+    fn test_load_dotenv_file_unterminated_quote() {
+        // Create a temporary directory to work in.
+        let temp_dir = tempdir().unwrap();
+        let file_path = get_temp_dir_path(&temp_dir).join(".env");
+        save_string_to_file("A=1\nB=\"unterminated\nC=3\n", &file_path);
+        load_dotenv_file(&file_path);
+    }
+
+    #[test]
+    fn test_save_dotenv_file_round_trip() {
+        // Create a temporary directory to work in.
+        let temp_dir = tempdir().unwrap();
+        let file_path = get_temp_dir_path(&temp_dir).join("config/.env");
+        let variables = HashMap::from(
+            [
+                ("URL", "https://example.com:8080/path"),
+                ("EMPTY", ""),
+                ("SPECIAL", "quote \" backslash \\ dollar $ hash #"),
+                ("MULTILINE", "line 1\nline 2\r\n\tindented"),
+                ("UNICODE", "héllo wörld"),
+            ]
+            .map(|(key, value)| (key.to_string(), value.to_string())),
+        );
+
+        // Save the variables and load them back.
+        save_dotenv_file(&variables, &file_path);
+        assert_eq!(load_dotenv_file(&file_path), variables);
+    }
+
+    #[test]
+    #[serial]
+    fn test_apply_dotenv() {
+        // Create a temporary directory to work in.
+        let temp_dir = tempdir().unwrap();
+        let file_path = get_temp_dir_path(&temp_dir).join(".env");
+        save_string_to_file(
+            "FILE_IO_TEST_DOTENV_NEW=from file\nFILE_IO_TEST_DOTENV_SET=from file\n",
+            &file_path,
+        );
+
+        // SAFETY: Tests accessing the environment are serialized.
+        unsafe {
+            // New variables are set, but existing variables are not overridden.
+            let _set = set_env_var_scoped("FILE_IO_TEST_DOTENV_SET", "from environment");
+            apply_dotenv(&file_path);
+            assert_eq!(
+                std::env::var("FILE_IO_TEST_DOTENV_NEW").unwrap(),
+                "from file"
+            );
+            assert_eq!(
+                std::env::var("FILE_IO_TEST_DOTENV_SET").unwrap(),
+                "from environment"
+            );
+            std::env::remove_var("FILE_IO_TEST_DOTENV_NEW");
+        }
+    }
+}
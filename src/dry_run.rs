@@ -0,0 +1,242 @@
+use std::cell::RefCell;
+use std::path::PathBuf;
+use std::sync::{Arc, Mutex};
+
+/// The operations planned under a dry run.
+pub(crate) type Plan = Arc<Mutex<Vec<PlannedOperation>>>;
+
+thread_local! {
+    /// The plan of the dry run active on this thread (if any).
+    static CURRENT_PLAN: RefCell<Option<Plan>> = const { RefCell::new(None) };
+}
+
+/// A mutating operation that would have been performed if it were not for a dry run (see
+/// [`DryRun::enter`]).
+#[derive(Clone, Debug, PartialEq)]
+pub struct PlannedOperation {
+    /// The name of the operation (e.g. `"copy_file"`).
+    pub operation: &'static str,
+
+    /// The path that would be mutated (or, for operations with a destination, the source path).
+    pub path: PathBuf,
+
+    /// The destination path (for operations with a destination, e.g. copies).
+    pub to: Option<PathBuf>,
+
+    /// The number of bytes that would be written (if known).
+    pub bytes: Option<u64>,
+}
+
+impl std::fmt::Display for PlannedOperation {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} {}", self.operation, self.path.display())?;
+        if let Some(to) = &self.to {
+            write!(f, " -> {}", to.display())?;
+        }
+        if let Some(bytes) = self.bytes {
+            write!(f, " ({bytes} bytes)")?;
+        }
+        Ok(())
+    }
+}
+
+/// Entry point for dry runs, under which mutating operations are planned rather than performed.
+///
+/// # Example
+///
+/// ```
+/// use file_io::{DryRun, save_string_to_file};
+/// use std::path::Path;
+///
+/// let dry_run = DryRun::enter();
+/// save_string_to_file("Hello, world!", "folder/subfolder_41/file_49.txt");
+/// let plan = dry_run.finish();
+///
+/// // Nothing was written, but the intended action was recorded.
+/// assert!(!Path::new("folder/subfolder_41/file_49.txt").exists());
+/// assert_eq!(
+///     plan[0].to_string(),
+///     "save_string_to_file folder/subfolder_41/file_49.txt (13 bytes)"
+/// );
+/// ```
+pub struct DryRun;
+
+impl DryRun {
+    /// Starts a dry run on the current thread.
+    ///
+    /// # Returns
+    ///
+    /// A guard under which all mutating functions of this crate (e.g. saving, copying, or deleting
+    /// files) record the operations they would perform instead of performing them. The dry run
+    /// ends when the guard is dropped (or [`DryRunGuard::finish`] is called).
+    ///
+    /// # Note
+    ///
+    /// * The dry run only applies to the current thread (and to worker threads spawned by this
+    ///   crate on its behalf, e.g. by [`crate::replace_str_in_files`]).
+    /// * Functions that return a value return what they would have returned (e.g.
+    ///   [`crate::touch_file`] returns whether it would have created the file), based on the
+    ///   current state of the filesystem. Since nothing is actually modified, later operations see
+    ///   the filesystem as it was before the dry run (e.g. saving the same file twice with
+    ///   [`crate::save_string_to_unique_file`] plans to save it to the same path twice).
+    /// * Operations made up of several smaller ones (e.g. [`crate::copy_folder`]) are planned as
+    ///   their individual steps (e.g. copying each file).
+    /// * Dry runs can be nested, in which case the operations are only recorded to the innermost
+    ///   one.
+    pub fn enter() -> DryRunGuard {
+        DryRunGuard::new(Arc::new(Mutex::new(Vec::new())))
+    }
+}
+
+/// Guard under which mutating operations are planned rather than performed (see
+/// [`DryRun::enter`]).
+#[must_use = "the dry run ends when the guard is dropped"]
+pub struct DryRunGuard {
+    /// The operations planned so far.
+    plan: Plan,
+
+    /// The plan of the dry run that was active before this one (restored when the guard is
+    /// dropped).
+    previous: Option<Plan>,
+}
+
+impl DryRunGuard {
+    /// Makes a plan the active dry run on the current thread.
+    ///
+    /// # Arguments
+    ///
+    /// * `plan` - The plan to record operations to.
+    ///
+    /// # Returns
+    ///
+    /// A guard that restores the previously active dry run (if any) when dropped.
+    pub(crate) fn new(plan: Plan) -> DryRunGuard {
+        let previous = CURRENT_PLAN.with(|current| current.replace(Some(plan.clone())));
+        DryRunGuard { plan, previous }
+    }
+
+    /// Returns the operations planned so far.
+    ///
+    /// # Returns
+    ///
+    /// The planned operations, in the order they were planned.
+    pub fn plan(&self) -> Vec<PlannedOperation> {
+        self.plan
+            .lock()
+            .unwrap_or_else(|error| error.into_inner())
+            .clone()
+    }
+
+    /// Ends the dry run.
+    ///
+    /// # Returns
+    ///
+    /// The planned operations, in the order they were planned.
+    pub fn finish(self) -> Vec<PlannedOperation> {
+        self.plan()
+    }
+}
+
+impl Drop for DryRunGuard {
+    fn drop(&mut self) {
+        CURRENT_PLAN.with(|current| current.replace(self.previous.take()));
+    }
+}
+
+/// Returns the plan of the dry run active on the current thread.
+///
+/// # Returns
+///
+/// The plan, or `None` if no dry run is active.
+pub(crate) fn current_plan() -> Option<Plan> {
+    CURRENT_PLAN.with(|current| current.borrow().clone())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::copy::copy_folder;
+    use crate::create::touch_file;
+    use crate::delete::delete_file;
+    use crate::load::load_file_as_string;
+    use crate::modify::replace_str_in_files;
+    use crate::save::save_string_to_file;
+    use crate::test_utils::get_temp_dir_path;
+    use tempfile::tempdir;
+
+    #[test]
+    fn test_dry_run() {
+        // Create a temporary directory to work in.
+        let temp_dir = tempdir().unwrap();
+        let temp_dir_path = get_temp_dir_path(&temp_dir);
+        let source_path = temp_dir_path.join("source");
+        let copy_path = temp_dir_path.join("copy");
+        save_string_to_file("foo", source_path.join("a.txt"));
+        save_string_to_file("foo", source_path.join("b.txt"));
+
+        // Perform several operations during a dry run.
+        let dry_run = DryRun::enter();
+        copy_folder(&source_path, &copy_path);
+        replace_str_in_files(&source_path, "foo", "bar");
+        delete_file(source_path.join("a.txt"));
+        assert!(touch_file(temp_dir_path.join("new.txt")));
+        let plan = dry_run.finish();
+
+        // Nothing was modified.
+        assert!(!copy_path.exists());
+        assert_eq!(load_file_as_string(source_path.join("a.txt")), "foo");
+        assert_eq!(load_file_as_string(source_path.join("b.txt")), "foo");
+        assert!(!temp_dir_path.join("new.txt").exists());
+
+        // The individual steps were planned (the copies and replacements in any order, since the
+        // folders are not traversed in any particular order and the replacements are planned by
+        // worker threads).
+        let mut plan: Vec<String> = plan
+            .iter()
+            .map(|operation| {
+                operation
+                    .to_string()
+                    .replace(&*temp_dir_path.to_string_lossy(), "<tmp>")
+            })
+            .collect();
+        plan[0..2].sort();
+        plan[2..4].sort();
+        assert_eq!(
+            plan,
+            [
+                "copy_file <tmp>/source/a.txt -> <tmp>/copy/a.txt",
+                "copy_file <tmp>/source/b.txt -> <tmp>/copy/b.txt",
+                "replace_str_in_file <tmp>/source/a.txt (3 bytes)",
+                "replace_str_in_file <tmp>/source/b.txt (3 bytes)",
+                "delete_file <tmp>/source/a.txt",
+                "touch_file <tmp>/new.txt",
+            ]
+        );
+
+        // Operations are performed again once the dry run has ended.
+        delete_file(source_path.join("a.txt"));
+        assert!(!source_path.join("a.txt").exists());
+    }
+
+    #[test]
+    fn test_nested_dry_run() {
+        // Create a temporary directory to work in.
+        let temp_dir = tempdir().unwrap();
+        let temp_dir_path = get_temp_dir_path(&temp_dir);
+
+        // Operations are only recorded to the innermost dry run.
+        let outer = DryRun::enter();
+        save_string_to_file("1", temp_dir_path.join("1.txt"));
+        {
+            let inner = DryRun::enter();
+            save_string_to_file("2", temp_dir_path.join("2.txt"));
+            assert_eq!(inner.plan().len(), 1);
+        }
+        save_string_to_file("3", temp_dir_path.join("3.txt"));
+        let plan = outer.finish();
+        assert_eq!(plan.len(), 2);
+        assert_eq!(plan[0].path, temp_dir_path.join("1.txt"));
+        assert_eq!(plan[1].path, temp_dir_path.join("3.txt"));
+        assert!(!temp_dir_path.join("1.txt").exists());
+    }
+}
@@ -0,0 +1,204 @@
+use crate::create::create_folder_for_file;
+use crate::instrument::Mutation;
+use argon2::Argon2;
+use chacha20poly1305::aead::rand_core::RngCore;
+use chacha20poly1305::aead::{Aead, AeadCore, KeyInit, OsRng};
+use chacha20poly1305::{Key, XChaCha20Poly1305, XNonce};
+use std::path::Path;
+
+/// Magic bytes at the start of every encrypted file (identifying the format and its version).
+const MAGIC: &[u8] = b"file-io-encrypted-v1\n";
+
+/// Length of the random salt used to derive the key from the passphrase (in bytes).
+const SALT_LENGTH: usize = 16;
+
+/// Length of the XChaCha20-Poly1305 nonce (in bytes).
+const NONCE_LENGTH: usize = 24;
+
+/// Derives an encryption key from a passphrase.
+///
+/// # Arguments
+///
+/// * `passphrase` - The passphrase.
+/// * `salt` - The salt.
+///
+/// # Returns
+///
+/// The key (derived using Argon2id with its default parameters).
+fn derive_key(passphrase: &str, salt: &[u8]) -> Key {
+    let mut key = Key::default();
+    Argon2::default()
+        .hash_password_into(passphrase.as_bytes(), salt, &mut key)
+        .expect("Failed to derive key from passphrase.");
+    key
+}
+
+/// Encrypts a string with a passphrase and saves it to a file at the specified path.
+///
+/// # Arguments
+///
+/// * `content` - The string content to encrypt and save to the file.
+/// * `path` - The path where the file should be saved (can be a `&str`, [`String`], [`Path`], or
+///   [`std::path::PathBuf`]).
+/// * `passphrase` - The passphrase to encrypt the content with.
+///
+/// # Panics
+///
+/// If some error is encountered while creating the file or writing to it.
+///
+/// # Note
+///
+/// * This function will create the parent folder for the file if it does not already exist.
+/// * The content is encrypted and authenticated with XChaCha20-Poly1305, using a key derived from
+///   the passphrase with Argon2id and a random salt. A new salt and nonce are generated every time
+///   the file is saved.
+/// * The file can be decrypted with [`load_file_as_string_encrypted`].
+///
+/// # Example
+///
+/// ```
+/// use file_io::{load_file_as_string_encrypted, save_string_to_file_encrypted};
+///
+/// let path: &str = "folder/subfolder_33/file_36.enc";
+/// save_string_to_file_encrypted("API_KEY=secret", path, "correct horse battery staple");
+///
+/// assert_eq!(
+///     load_file_as_string_encrypted(path, "correct horse battery staple"),
+///     "API_KEY=secret"
+/// );
+/// ```
+pub fn save_string_to_file_encrypted<P: AsRef<Path>>(content: &str, path: P, passphrase: &str) {
+    let path = path.as_ref();
+
+    // Derive the key from the passphrase and a random salt.
+    let mut salt = [0; SALT_LENGTH];
+    OsRng.fill_bytes(&mut salt);
+    let cipher = XChaCha20Poly1305::new(&derive_key(passphrase, &salt));
+
+    // Encrypt the content with a random nonce.
+    let nonce = XChaCha20Poly1305::generate_nonce(&mut OsRng);
+    let ciphertext = cipher
+        .encrypt(&nonce, content.as_bytes())
+        .unwrap_or_else(|_| panic!("Failed to encrypt content for file '{path:?}'."));
+
+    // Write the magic bytes, salt, nonce, and ciphertext.
+    let mut bytes = Vec::with_capacity(MAGIC.len() + SALT_LENGTH + NONCE_LENGTH + ciphertext.len());
+    bytes.extend_from_slice(MAGIC);
+    bytes.extend_from_slice(&salt);
+    bytes.extend_from_slice(&nonce);
+    bytes.extend_from_slice(&ciphertext);
+    let mut mutation = Mutation::start("save_string_to_file_encrypted", path);
+    mutation.bytes(bytes.len() as u64);
+    if mutation.is_dry_run() {
+        return;
+    }
+    create_folder_for_file(path);
+    std::fs::write(path, bytes).unwrap_or_else(|_| panic!("Failed to write to file '{path:?}'."));
+    mutation.succeeded();
+}
+
+/// Loads and decrypts the content of a file saved with [`save_string_to_file_encrypted`].
+///
+/// # Arguments
+///
+/// * `path` - The path to the file to load (can be a `&str`, [`String`], [`Path`], or
+///   [`std::path::PathBuf`]).
+/// * `passphrase` - The passphrase the content was encrypted with.
+///
+/// # Returns
+///
+/// The decrypted contents of the file.
+///
+/// # Panics
+///
+/// If the file cannot be read, is not an encrypted file, was modified, or the passphrase is wrong.
+///
+/// # Example
+///
+/// ```
+/// use file_io::{load_file_as_string_encrypted, save_string_to_file_encrypted};
+///
+/// let path: &str = "folder/subfolder_33/file_37.enc";
+/// save_string_to_file_encrypted("Hello, world!", path, "passphrase");
+///
+/// assert_eq!(load_file_as_string_encrypted(path, "passphrase"), "Hello, world!");
+/// ```
+pub fn load_file_as_string_encrypted<P: AsRef<Path>>(path: P, passphrase: &str) -> String {
+    let path = path.as_ref();
+    let bytes =
+        std::fs::read(path).unwrap_or_else(|_| panic!("Failed to read file at '{path:?}'."));
+
+    // Split the file into its salt, nonce, and ciphertext.
+    let rest = bytes
+        .strip_prefix(MAGIC)
+        .filter(|rest| rest.len() >= SALT_LENGTH + NONCE_LENGTH)
+        .unwrap_or_else(|| panic!("File at '{path:?}' is not an encrypted file."));
+    let (salt, rest) = rest.split_at(SALT_LENGTH);
+    let (nonce, ciphertext) = rest.split_at(NONCE_LENGTH);
+
+    // Decrypt and authenticate the content.
+    let cipher = XChaCha20Poly1305::new(&derive_key(passphrase, salt));
+    let content = cipher
+        .decrypt(XNonce::from_slice(nonce), ciphertext)
+        .unwrap_or_else(|_| {
+            panic!("Failed to decrypt file at '{path:?}' (wrong passphrase or modified file).")
+        });
+    String::from_utf8(content)
+        .unwrap_or_else(|_| panic!("Decrypted content of file at '{path:?}' is not valid UTF-8."))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::get_temp_dir_path;
+    use tempfile::tempdir;
+
+    #[test]
+    fn test_save_load_file_encrypted() {
+        // Create a temporary directory to work in.
+        let temp_dir = tempdir().unwrap();
+
+        // Path to the file.
+        let file_path = get_temp_dir_path(&temp_dir).join("secret.enc");
+
+        // Encrypt and decrypt the content.
+        save_string_to_file_encrypted("Top secret", &file_path, "passphrase");
+        assert_eq!(
+            load_file_as_string_encrypted(&file_path, "passphrase"),
+            "Top secret"
+        );
+
+        // The content is not stored in plain text.
+        let bytes = std::fs::read(&file_path).unwrap();
+        assert!(bytes.starts_with(MAGIC));
+        assert!(!bytes.windows(10).any(|window| window == b"Top secret"));
+    }
+
+    #[test]
+    #[should_panic(expected = "wrong passphrase or modified file")]
+    fn test_load_file_encrypted_wrong_passphrase() {
+        // Create a temporary directory to work in.
+        let temp_dir = tempdir().unwrap();
+
+        // Path to the file.
+        let file_path = get_temp_dir_path(&temp_dir).join("secret.enc");
+
+        // Try to decrypt the content with the wrong passphrase.
+        save_string_to_file_encrypted("Top secret", &file_path, "passphrase");
+        load_file_as_string_encrypted(&file_path, "wrong");
+    }
+
+    #[test]
+    #[should_panic(expected = "is not an encrypted file")]
+    fn test_load_file_encrypted_not_encrypted() {
+        // Create a temporary directory to work in.
+        let temp_dir = tempdir().unwrap();
+
+        // Path to the file.
+        let file_path = get_temp_dir_path(&temp_dir).join("plain.txt");
+
+        // Try to decrypt a plain text file.
+        std::fs::write(&file_path, "Hello").unwrap();
+        load_file_as_string_encrypted(&file_path, "passphrase");
+    }
+}
@@ -0,0 +1,106 @@
+use std::ffi::{OsStr, OsString};
+
+/// A struct that sets an environment variable.
+///
+/// When an instance of this struct goes out of scope (i.e. it is dropped), it automatically
+/// restores the previous value of the environment variable (or removes the variable if it was not
+/// set before).
+#[must_use]
+pub struct EnvGuard {
+    /// Name of the environment variable.
+    key: OsString,
+
+    /// Previous value of the environment variable (`None` if it was not set).
+    previous: Option<OsString>,
+}
+
+// Restore the previous value when the guard goes out of scope.
+impl Drop for EnvGuard {
+    fn drop(&mut self) {
+        // SAFETY: The caller of `set_env_var_scoped` guaranteed that no other thread accesses the
+        // environment for the lifetime of this guard.
+        unsafe {
+            match &self.previous {
+                Some(previous) => std::env::set_var(&self.key, previous),
+                None => std::env::remove_var(&self.key),
+            }
+        }
+    }
+}
+
+/// Set an environment variable until the returned guard is dropped.
+///
+/// # Arguments
+///
+/// * `key` - Name of the environment variable.
+/// * `value` - Value to set the environment variable to.
+///
+/// # Returns
+///
+/// An [`EnvGuard`] instance that will automatically restore the previous value of the
+/// environment variable (or remove it, if it was not set before) when it goes out of scope (i.e.
+/// when it is dropped).
+///
+/// # Panics
+///
+/// If `key` is empty, contains `=` or a NUL character, or if `value` contains a NUL character.
+///
+/// # Safety
+///
+/// Modifying the environment is not thread-safe on most platforms (see [`std::env::set_var`]).
+/// The caller must ensure that no other thread reads or writes the environment (including
+/// indirectly, e.g. through [`std::env::var`] or by spawning a process) from the call to this
+/// function until the returned guard is dropped. In tests, this usually means marking the test
+/// with `#[serial]`.
+///
+/// # Example
+///
+/// ```
+/// use file_io::set_env_var_scoped;
+///
+/// // Enter a new scope.
+/// {
+///     // Set an environment variable within this limited scope.
+///     // SAFETY: No other threads access the environment.
+///     let _env = unsafe { set_env_var_scoped("FILE_IO_EXAMPLE", "value") };
+///     assert_eq!(std::env::var("FILE_IO_EXAMPLE").unwrap(), "value");
+/// }
+///
+/// // Verify that outside the scope, the variable has been removed again.
+/// assert!(std::env::var("FILE_IO_EXAMPLE").is_err());
+/// ```
+pub unsafe fn set_env_var_scoped<K: AsRef<OsStr>, V: AsRef<OsStr>>(key: K, value: V) -> EnvGuard {
+    let key = key.as_ref().to_os_string();
+    let previous = std::env::var_os(&key);
+    // SAFETY: Upheld by the caller.
+    unsafe { std::env::set_var(&key, value) };
+    EnvGuard { key, previous }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serial_test::serial;
+
+    #[test]
+    #[serial]
+    fn test_set_env_var_scoped() {
+        // SAFETY: Tests accessing the environment are serialized.
+        unsafe {
+            // A variable that was not set before is removed again.
+            {
+                let _env = set_env_var_scoped("FILE_IO_TEST_VAR", "new");
+                assert_eq!(std::env::var("FILE_IO_TEST_VAR").unwrap(), "new");
+            }
+            assert!(std::env::var_os("FILE_IO_TEST_VAR").is_none());
+
+            // A variable that was set before is restored, including across nested guards.
+            let _outer = set_env_var_scoped("FILE_IO_TEST_VAR", "outer");
+            {
+                let _inner = set_env_var_scoped("FILE_IO_TEST_VAR", "inner");
+                assert_eq!(std::env::var("FILE_IO_TEST_VAR").unwrap(), "inner");
+            }
+            assert_eq!(std::env::var("FILE_IO_TEST_VAR").unwrap(), "outer");
+        }
+    }
+}
@@ -0,0 +1,808 @@
+use crate::path::normalize_path;
+use std::collections::BTreeMap;
+use std::path::{Component, Path, PathBuf};
+use std::sync::Mutex;
+
+/// Abstraction over the filesystem operations provided by this crate.
+///
+/// Code that performs its file i/o through a `&dyn FileSystem` (or a generic `F: FileSystem`) can
+/// use [`OsFileSystem`] in production and [`MemoryFileSystem`] in unit tests, so that the tests
+/// never touch the disk.
+///
+/// Each method behaves like the free function of the same name (e.g.
+/// [`FileSystem::save_string_to_file`] creates any missing parent folders, just like
+/// [`crate::save_string_to_file`]), including panicking on errors.
+///
+/// # Example
+///
+/// ```
+/// use file_io::{FileSystem, MemoryFileSystem};
+/// use std::path::Path;
+///
+/// // Code under test.
+/// fn write_greeting(fs: &dyn FileSystem, name: &str) {
+///     fs.save_string_to_file(&format!("Hello, {name}!"), Path::new("out/greeting.txt"));
+/// }
+///
+/// // Test it without touching the disk.
+/// let fs = MemoryFileSystem::new();
+/// write_greeting(&fs, "world");
+/// assert_eq!(fs.load_file_as_string(Path::new("out/greeting.txt")), "Hello, world!");
+/// ```
+pub trait FileSystem {
+    /// Loads the contents of a file as a string (see [`crate::load_file_as_string`]).
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - The path to the file.
+    ///
+    /// # Returns
+    ///
+    /// The contents of the file.
+    ///
+    /// # Panics
+    ///
+    /// If the file does not exist or cannot be read.
+    fn load_file_as_string(&self, path: &Path) -> String;
+
+    /// Saves a string to a file, creating any missing parent folders (see
+    /// [`crate::save_string_to_file`]).
+    ///
+    /// # Arguments
+    ///
+    /// * `content` - The string content to save to the file.
+    /// * `path` - The path to the file.
+    ///
+    /// # Panics
+    ///
+    /// If the file cannot be written.
+    fn save_string_to_file(&self, content: &str, path: &Path);
+
+    /// Creates a folder along with any missing parent folders (see [`crate::create_folder`]).
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - The path to the folder.
+    ///
+    /// # Panics
+    ///
+    /// If the folder cannot be created.
+    fn create_folder(&self, path: &Path);
+
+    /// Deletes a file if it exists (see [`crate::delete_file`]).
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - The path to the file.
+    ///
+    /// # Panics
+    ///
+    /// If the file exists but cannot be deleted (e.g. because it is a folder).
+    fn delete_file(&self, path: &Path);
+
+    /// Deletes a folder along with all of its contents if it exists (see
+    /// [`crate::delete_folder`]).
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - The path to the folder.
+    ///
+    /// # Panics
+    ///
+    /// If the folder exists but cannot be deleted (e.g. because it is a file).
+    fn delete_folder(&self, path: &Path);
+
+    /// Copies a file, creating any missing parent folders of the destination (see
+    /// [`crate::copy_file`]).
+    ///
+    /// # Arguments
+    ///
+    /// * `from` - The source file path.
+    /// * `to` - The destination file path.
+    ///
+    /// # Panics
+    ///
+    /// If the source file does not exist or the destination cannot be written.
+    fn copy_file(&self, from: &Path, to: &Path);
+
+    /// Lists the contents of a folder (see [`crate::list_folder_contents`]).
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - The path to the folder.
+    ///
+    /// # Returns
+    ///
+    /// Paths of the files and folders in the folder (in alphabetical order).
+    ///
+    /// # Panics
+    ///
+    /// If `path` is not a folder.
+    fn list_folder_contents(&self, path: &Path) -> Vec<PathBuf>;
+
+    /// Check whether a file or folder exists.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - The path to check.
+    ///
+    /// # Returns
+    ///
+    /// `true` if a file or folder exists at `path`, `false` otherwise.
+    fn exists(&self, path: &Path) -> bool;
+
+    /// Check whether a file exists.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - The path to check.
+    ///
+    /// # Returns
+    ///
+    /// `true` if a file exists at `path`, `false` otherwise.
+    fn is_file(&self, path: &Path) -> bool;
+
+    /// Check whether a folder exists.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - The path to check.
+    ///
+    /// # Returns
+    ///
+    /// `true` if a folder exists at `path`, `false` otherwise.
+    fn is_folder(&self, path: &Path) -> bool;
+}
+
+/// The real (operating system) filesystem.
+///
+/// Each method simply calls the free function of the same name.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct OsFileSystem;
+
+impl FileSystem for OsFileSystem {
+    fn load_file_as_string(&self, path: &Path) -> String {
+        crate::load::load_file_as_string(path)
+    }
+
+    fn save_string_to_file(&self, content: &str, path: &Path) {
+        crate::save::save_string_to_file(content, path);
+    }
+
+    fn create_folder(&self, path: &Path) {
+        crate::create::create_folder(path);
+    }
+
+    fn delete_file(&self, path: &Path) {
+        crate::delete::delete_file(path);
+    }
+
+    fn delete_folder(&self, path: &Path) {
+        crate::delete::delete_folder(path);
+    }
+
+    fn copy_file(&self, from: &Path, to: &Path) {
+        crate::copy::copy_file(from, to);
+    }
+
+    fn list_folder_contents(&self, path: &Path) -> Vec<PathBuf> {
+        crate::list::list_folder_contents(path)
+    }
+
+    fn exists(&self, path: &Path) -> bool {
+        path.exists()
+    }
+
+    fn is_file(&self, path: &Path) -> bool {
+        path.is_file()
+    }
+
+    fn is_folder(&self, path: &Path) -> bool {
+        path.is_dir()
+    }
+}
+
+/// An entry of a [`MemoryFileSystem`].
+#[derive(Clone, Debug, PartialEq)]
+enum MemoryEntry {
+    /// A folder.
+    Folder,
+
+    /// A file with the given contents.
+    File(String),
+}
+
+/// An in-memory filesystem (e.g. for unit tests).
+///
+/// Paths are normalized lexically (see [`crate::normalize_path`]) before they are used, and
+/// relative paths are not resolved against the current working directory (i.e. `file.txt` and
+/// `/file.txt` are different files). The root folders (e.g. `.` and `/`) always exist.
+///
+/// # Example
+///
+/// ```
+/// use file_io::{FileSystem, MemoryFileSystem};
+/// use std::path::{Path, PathBuf};
+///
+/// let fs = MemoryFileSystem::new();
+/// fs.save_string_to_file("a", Path::new("folder/a.txt"));
+/// fs.copy_file(Path::new("folder/a.txt"), Path::new("folder/b.txt"));
+///
+/// assert_eq!(
+///     fs.list_folder_contents(Path::new("folder")),
+///     vec![PathBuf::from("folder/a.txt"), PathBuf::from("folder/b.txt")]
+/// );
+/// ```
+#[derive(Debug, Default)]
+pub struct MemoryFileSystem {
+    /// All files and folders (keyed by their normalized path).
+    entries: Mutex<BTreeMap<PathBuf, MemoryEntry>>,
+}
+
+/// Check whether a normalized path is a root folder (i.e. it has no named components).
+///
+/// # Arguments
+///
+/// * `path` - The normalized path.
+///
+/// # Returns
+///
+/// `true` if the path is a root folder, `false` otherwise.
+fn is_root(path: &Path) -> bool {
+    path.components()
+        .all(|comp| !matches!(comp, Component::Normal(_) | Component::ParentDir))
+}
+
+impl MemoryFileSystem {
+    /// Constructor.
+    ///
+    /// # Returns
+    ///
+    /// An empty in-memory filesystem.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Get the type of the entry at a path.
+    ///
+    /// # Arguments
+    ///
+    /// * `entries` - All files and folders.
+    /// * `path` - The normalized path.
+    ///
+    /// # Returns
+    ///
+    /// The entry at `path`, or `None` if nothing exists there.
+    fn get(entries: &BTreeMap<PathBuf, MemoryEntry>, path: &Path) -> Option<MemoryEntry> {
+        if is_root(path) {
+            Some(MemoryEntry::Folder)
+        } else {
+            entries.get(path).cloned()
+        }
+    }
+
+    /// Creates a folder along with any missing parent folders.
+    ///
+    /// # Arguments
+    ///
+    /// * `entries` - All files and folders.
+    /// * `path` - The normalized path to the folder.
+    ///
+    /// # Panics
+    ///
+    /// If a file exists at `path` or at any of its parents.
+    fn create_folder_all(entries: &mut BTreeMap<PathBuf, MemoryEntry>, path: &Path) {
+        for ancestor in path.ancestors().collect::<Vec<_>>().into_iter().rev() {
+            if is_root(ancestor) {
+                continue;
+            }
+            match entries.get(ancestor) {
+                Some(MemoryEntry::File(_)) => {
+                    panic!("Failed to create folder at '{path:?}'.")
+                }
+                Some(MemoryEntry::Folder) => {}
+                None => {
+                    entries.insert(ancestor.to_path_buf(), MemoryEntry::Folder);
+                }
+            }
+        }
+    }
+
+    /// Writes a file, creating any missing parent folders.
+    ///
+    /// # Arguments
+    ///
+    /// * `entries` - All files and folders.
+    /// * `path` - The normalized path to the file.
+    /// * `content` - The contents of the file.
+    ///
+    /// # Panics
+    ///
+    /// If a folder exists at `path`, or a file exists at any of its parents.
+    fn write(entries: &mut BTreeMap<PathBuf, MemoryEntry>, path: &Path, content: String) {
+        if Self::get(entries, path) == Some(MemoryEntry::Folder) {
+            panic!("Failed to write to file '{path:?}'.");
+        }
+        if let Some(parent) = path.parent() {
+            Self::create_folder_all(entries, parent);
+        }
+        entries.insert(path.to_path_buf(), MemoryEntry::File(content));
+    }
+}
+
+impl FileSystem for MemoryFileSystem {
+    fn load_file_as_string(&self, path: &Path) -> String {
+        let entries = self.entries.lock().unwrap();
+        match Self::get(&entries, &normalize_path(path)) {
+            Some(MemoryEntry::File(content)) => content,
+            _ => panic!("Failed to read file at '{path:?}'."),
+        }
+    }
+
+    fn save_string_to_file(&self, content: &str, path: &Path) {
+        let mut entries = self.entries.lock().unwrap();
+        Self::write(&mut entries, &normalize_path(path), content.to_string());
+    }
+
+    fn create_folder(&self, path: &Path) {
+        let mut entries = self.entries.lock().unwrap();
+        Self::create_folder_all(&mut entries, &normalize_path(path));
+    }
+
+    fn delete_file(&self, path: &Path) {
+        let mut entries = self.entries.lock().unwrap();
+        let normalized = normalize_path(path);
+        match Self::get(&entries, &normalized) {
+            Some(MemoryEntry::File(_)) => {
+                entries.remove(&normalized);
+            }
+            Some(MemoryEntry::Folder) => panic!("Failed to delete file at '{path:?}'."),
+            None => {}
+        }
+    }
+
+    fn delete_folder(&self, path: &Path) {
+        let mut entries = self.entries.lock().unwrap();
+        let normalized = normalize_path(path);
+        match Self::get(&entries, &normalized) {
+            Some(MemoryEntry::Folder) => {
+                entries.retain(|entry_path, _| !entry_path.starts_with(&normalized));
+            }
+            Some(MemoryEntry::File(_)) => panic!("Failed to delete folder at '{path:?}'."),
+            None => {}
+        }
+    }
+
+    fn copy_file(&self, from: &Path, to: &Path) {
+        let mut entries = self.entries.lock().unwrap();
+        match Self::get(&entries, &normalize_path(from)) {
+            Some(MemoryEntry::File(content)) => {
+                Self::write(&mut entries, &normalize_path(to), content);
+            }
+            _ => panic!("Failed to copy file from '{from:?}' to '{to:?}'."),
+        }
+    }
+
+    fn list_folder_contents(&self, path: &Path) -> Vec<PathBuf> {
+        let entries = self.entries.lock().unwrap();
+        let normalized = normalize_path(path);
+        if Self::get(&entries, &normalized) != Some(MemoryEntry::Folder) {
+            panic!("The provided path is not a folder: {path:?}");
+        }
+        // `BTreeMap` keys are already sorted.
+        entries
+            .keys()
+            .filter(|entry_path| {
+                entry_path.parent().map(normalize_path).as_deref() == Some(normalized.as_path())
+            })
+            .map(|entry_path| path.join(entry_path.file_name().unwrap()))
+            .collect()
+    }
+
+    fn exists(&self, path: &Path) -> bool {
+        let entries = self.entries.lock().unwrap();
+        Self::get(&entries, &normalize_path(path)).is_some()
+    }
+
+    fn is_file(&self, path: &Path) -> bool {
+        let entries = self.entries.lock().unwrap();
+        matches!(
+            Self::get(&entries, &normalize_path(path)),
+            Some(MemoryEntry::File(_))
+        )
+    }
+
+    fn is_folder(&self, path: &Path) -> bool {
+        let entries = self.entries.lock().unwrap();
+        Self::get(&entries, &normalize_path(path)) == Some(MemoryEntry::Folder)
+    }
+}
+
+/// A single operation performed through a [`RecordingFileSystem`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum FsOperation {
+    /// A file was loaded ([`FileSystem::load_file_as_string`]).
+    Load {
+        /// The path to the file.
+        path: PathBuf,
+        /// The number of bytes read.
+        bytes: usize,
+    },
+
+    /// A file was saved ([`FileSystem::save_string_to_file`]).
+    Save {
+        /// The path to the file.
+        path: PathBuf,
+        /// The number of bytes written.
+        bytes: usize,
+    },
+
+    /// A folder was created ([`FileSystem::create_folder`]).
+    CreateFolder {
+        /// The path to the folder.
+        path: PathBuf,
+    },
+
+    /// A file was deleted ([`FileSystem::delete_file`]).
+    DeleteFile {
+        /// The path to the file.
+        path: PathBuf,
+    },
+
+    /// A folder was deleted ([`FileSystem::delete_folder`]).
+    DeleteFolder {
+        /// The path to the folder.
+        path: PathBuf,
+    },
+
+    /// A file was copied ([`FileSystem::copy_file`]).
+    Copy {
+        /// The source file path.
+        from: PathBuf,
+        /// The destination file path.
+        to: PathBuf,
+    },
+
+    /// A folder was listed ([`FileSystem::list_folder_contents`]).
+    List {
+        /// The path to the folder.
+        path: PathBuf,
+    },
+
+    /// A path was checked ([`FileSystem::exists`], [`FileSystem::is_file`], or
+    /// [`FileSystem::is_folder`]).
+    Check {
+        /// The path that was checked.
+        path: PathBuf,
+    },
+}
+
+impl std::fmt::Display for FsOperation {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            FsOperation::Load { path, bytes } => {
+                write!(f, "load {} ({bytes} bytes)", path.display())
+            }
+            FsOperation::Save { path, bytes } => {
+                write!(f, "save {} ({bytes} bytes)", path.display())
+            }
+            FsOperation::CreateFolder { path } => write!(f, "create folder {}", path.display()),
+            FsOperation::DeleteFile { path } => write!(f, "delete file {}", path.display()),
+            FsOperation::DeleteFolder { path } => write!(f, "delete folder {}", path.display()),
+            FsOperation::Copy { from, to } => {
+                write!(f, "copy {} -> {}", from.display(), to.display())
+            }
+            FsOperation::List { path } => write!(f, "list {}", path.display()),
+            FsOperation::Check { path } => write!(f, "check {}", path.display()),
+        }
+    }
+}
+
+/// A [`FileSystem`] wrapper that records every operation performed through it.
+///
+/// Operations are forwarded to the wrapped filesystem and recorded in the order they were
+/// performed.
+///
+/// # Example
+///
+/// ```
+/// use file_io::{FileSystem, FsOperation, MemoryFileSystem, RecordingFileSystem};
+/// use std::path::{Path, PathBuf};
+///
+/// let fs = RecordingFileSystem::new(MemoryFileSystem::new());
+/// fs.save_string_to_file("Hello", Path::new("out/a.txt"));
+/// fs.copy_file(Path::new("out/a.txt"), Path::new("out/b.txt"));
+///
+/// // Assert exactly which files were written.
+/// assert_eq!(
+///     fs.written_files(),
+///     vec![PathBuf::from("out/a.txt"), PathBuf::from("out/b.txt")]
+/// );
+///
+/// // Print an audit of the operations.
+/// for operation in fs.operations() {
+///     println!("{operation}");
+/// }
+/// assert_eq!(
+///     fs.operations()[0],
+///     FsOperation::Save { path: PathBuf::from("out/a.txt"), bytes: 5 }
+/// );
+/// ```
+#[derive(Debug, Default)]
+pub struct RecordingFileSystem<F: FileSystem> {
+    /// The wrapped filesystem.
+    inner: F,
+
+    /// The operations performed so far.
+    operations: Mutex<Vec<FsOperation>>,
+}
+
+impl<F: FileSystem> RecordingFileSystem<F> {
+    /// Constructor.
+    ///
+    /// # Arguments
+    ///
+    /// * `inner` - The filesystem to forward operations to.
+    ///
+    /// # Returns
+    ///
+    /// A recording filesystem wrapping `inner` (with no operations recorded yet).
+    pub fn new(inner: F) -> Self {
+        Self {
+            inner,
+            operations: Mutex::new(Vec::new()),
+        }
+    }
+
+    /// Get the wrapped filesystem.
+    ///
+    /// # Returns
+    ///
+    /// A reference to the wrapped filesystem.
+    pub fn inner(&self) -> &F {
+        &self.inner
+    }
+
+    /// Get the wrapped filesystem, discarding the recorded operations.
+    ///
+    /// # Returns
+    ///
+    /// The wrapped filesystem.
+    pub fn into_inner(self) -> F {
+        self.inner
+    }
+
+    /// Get the operations performed so far.
+    ///
+    /// # Returns
+    ///
+    /// The recorded operations (in the order they were performed).
+    pub fn operations(&self) -> Vec<FsOperation> {
+        self.operations.lock().unwrap().clone()
+    }
+
+    /// Get the files written so far (i.e. saved or copied to).
+    ///
+    /// # Returns
+    ///
+    /// Paths of the written files (in the order they were first written, without duplicates).
+    pub fn written_files(&self) -> Vec<PathBuf> {
+        let mut written: Vec<PathBuf> = Vec::new();
+        for operation in self.operations.lock().unwrap().iter() {
+            let path = match operation {
+                FsOperation::Save { path, .. } => path,
+                FsOperation::Copy { to, .. } => to,
+                _ => continue,
+            };
+            if !written.contains(path) {
+                written.push(path.clone());
+            }
+        }
+        written
+    }
+
+    /// Clears the recorded operations.
+    pub fn clear(&self) {
+        self.operations.lock().unwrap().clear();
+    }
+
+    /// Records an operation.
+    ///
+    /// # Arguments
+    ///
+    /// * `operation` - The operation to record.
+    fn record(&self, operation: FsOperation) {
+        self.operations.lock().unwrap().push(operation);
+    }
+}
+
+impl<F: FileSystem> FileSystem for RecordingFileSystem<F> {
+    fn load_file_as_string(&self, path: &Path) -> String {
+        let content = self.inner.load_file_as_string(path);
+        self.record(FsOperation::Load {
+            path: path.to_path_buf(),
+            bytes: content.len(),
+        });
+        content
+    }
+
+    fn save_string_to_file(&self, content: &str, path: &Path) {
+        self.record(FsOperation::Save {
+            path: path.to_path_buf(),
+            bytes: content.len(),
+        });
+        self.inner.save_string_to_file(content, path);
+    }
+
+    fn create_folder(&self, path: &Path) {
+        self.record(FsOperation::CreateFolder {
+            path: path.to_path_buf(),
+        });
+        self.inner.create_folder(path);
+    }
+
+    fn delete_file(&self, path: &Path) {
+        self.record(FsOperation::DeleteFile {
+            path: path.to_path_buf(),
+        });
+        self.inner.delete_file(path);
+    }
+
+    fn delete_folder(&self, path: &Path) {
+        self.record(FsOperation::DeleteFolder {
+            path: path.to_path_buf(),
+        });
+        self.inner.delete_folder(path);
+    }
+
+    fn copy_file(&self, from: &Path, to: &Path) {
+        self.record(FsOperation::Copy {
+            from: from.to_path_buf(),
+            to: to.to_path_buf(),
+        });
+        self.inner.copy_file(from, to);
+    }
+
+    fn list_folder_contents(&self, path: &Path) -> Vec<PathBuf> {
+        self.record(FsOperation::List {
+            path: path.to_path_buf(),
+        });
+        self.inner.list_folder_contents(path)
+    }
+
+    fn exists(&self, path: &Path) -> bool {
+        self.record(FsOperation::Check {
+            path: path.to_path_buf(),
+        });
+        self.inner.exists(path)
+    }
+
+    fn is_file(&self, path: &Path) -> bool {
+        self.record(FsOperation::Check {
+            path: path.to_path_buf(),
+        });
+        self.inner.is_file(path)
+    }
+
+    fn is_folder(&self, path: &Path) -> bool {
+        self.record(FsOperation::Check {
+            path: path.to_path_buf(),
+        });
+        self.inner.is_folder(path)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::get_temp_dir_path;
+    use tempfile::tempdir;
+
+    /// Runs the same sequence of operations against a filesystem implementation.
+    ///
+    /// # Arguments
+    ///
+    /// * `fs` - The filesystem.
+    /// * `root` - The folder to work in.
+    fn exercise(fs: &dyn FileSystem, root: &Path) {
+        // Save and load files (parent folders are created).
+        fs.save_string_to_file("a", &root.join("a.txt"));
+        fs.save_string_to_file("b", &root.join("sub/deeper/b.txt"));
+        assert_eq!(fs.load_file_as_string(&root.join("a.txt")), "a");
+        assert!(fs.is_folder(&root.join("sub/deeper")));
+        assert!(fs.is_file(&root.join("sub/deeper/b.txt")));
+        assert!(!fs.exists(&root.join("missing")));
+
+        // Copy a file and list the folder contents.
+        fs.copy_file(&root.join("a.txt"), &root.join("copy/a.txt"));
+        fs.create_folder(&root.join("empty"));
+        assert_eq!(
+            fs.list_folder_contents(root),
+            vec![
+                root.join("a.txt"),
+                root.join("copy"),
+                root.join("empty"),
+                root.join("sub")
+            ]
+        );
+
+        // Delete a file and a folder (deleting missing entries is a no-op).
+        fs.delete_file(&root.join("a.txt"));
+        fs.delete_file(&root.join("a.txt"));
+        fs.delete_folder(&root.join("sub"));
+        fs.delete_folder(&root.join("sub"));
+        assert!(!fs.exists(&root.join("a.txt")));
+        assert!(!fs.exists(&root.join("sub/deeper/b.txt")));
+        assert_eq!(
+            fs.list_folder_contents(root),
+            vec![root.join("copy"), root.join("empty")]
+        );
+    }
+
+    #[test]
+    fn test_os_file_system() {
+        // Create a temporary directory to work in.
+        let temp_dir = tempdir().unwrap();
+        exercise(&OsFileSystem, &get_temp_dir_path(&temp_dir));
+    }
+
+    #[test]
+    fn test_memory_file_system() {
+        // Absolute and relative roots.
+        exercise(&MemoryFileSystem::new(), Path::new("/root"));
+        exercise(&MemoryFileSystem::new(), Path::new("root"));
+
+        // Paths are normalized lexically.
+        let fs = MemoryFileSystem::new();
+        fs.save_string_to_file("x", Path::new("a/./b/../x.txt"));
+        assert_eq!(fs.load_file_as_string(Path::new("a/x.txt")), "x");
+        assert_eq!(
+            fs.list_folder_contents(Path::new(".")),
+            vec![PathBuf::from("./a")]
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "Failed to read file")]
+    fn test_memory_file_system_missing_file() {
+        MemoryFileSystem::new().load_file_as_string(Path::new("missing.txt"));
+    }
+
+    #[test]
+    fn test_recording_file_system() {
+        // Record operations performed on an in-memory filesystem.
+        let fs = RecordingFileSystem::new(MemoryFileSystem::new());
+        exercise(&fs, Path::new("root"));
+
+        // Check the recorded operations.
+        let operations = fs.operations();
+        assert_eq!(
+            operations[0],
+            FsOperation::Save {
+                path: PathBuf::from("root/a.txt"),
+                bytes: 1
+            }
+        );
+        assert_eq!(
+            operations[2],
+            FsOperation::Load {
+                path: PathBuf::from("root/a.txt"),
+                bytes: 1
+            }
+        );
+        assert_eq!(operations[0].to_string(), "save root/a.txt (1 bytes)");
+        assert_eq!(
+            fs.written_files(),
+            vec![
+                PathBuf::from("root/a.txt"),
+                PathBuf::from("root/sub/deeper/b.txt"),
+                PathBuf::from("root/copy/a.txt")
+            ]
+        );
+
+        // Clear the recorded operations.
+        fs.clear();
+        assert!(fs.operations().is_empty());
+        assert!(fs.inner().is_file(Path::new("root/copy/a.txt")));
+    }
+}
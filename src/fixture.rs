@@ -0,0 +1,227 @@
+use crate::create::create_folder;
+use crate::save::save_string_to_file;
+use std::path::{Path, PathBuf};
+
+/// A single entry of a [`FixtureBuilder`].
+#[derive(Clone, Debug)]
+enum FixtureEntry {
+    /// A file with the given contents.
+    File(PathBuf, String),
+
+    /// An (empty) folder.
+    Folder(PathBuf),
+}
+
+/// A builder for declaratively creating a tree of folders and files (e.g. for test setups).
+///
+/// # Example
+///
+/// ```
+/// use file_io::{FixtureBuilder, load_file_as_string};
+///
+/// FixtureBuilder::new()
+///     .file("Cargo.toml", "[package]")
+///     .file("src/main.rs", "fn main() {}")
+///     .folder("target")
+///     .folder_with("tests", |tests| tests.file("it.rs", "#[test] fn it() {}"))
+///     .build("folder/fixture_1");
+///
+/// assert_eq!(load_file_as_string("folder/fixture_1/src/main.rs"), "fn main() {}");
+/// assert!(std::path::Path::new("folder/fixture_1/target").is_dir());
+/// assert!(std::path::Path::new("folder/fixture_1/tests/it.rs").is_file());
+/// ```
+#[derive(Clone, Debug, Default)]
+#[must_use]
+pub struct FixtureBuilder {
+    /// Entries to create (with paths relative to the root folder).
+    entries: Vec<FixtureEntry>,
+}
+
+impl FixtureBuilder {
+    /// Constructor.
+    ///
+    /// # Returns
+    ///
+    /// An empty fixture builder.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a file.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - Path to the file, relative to the root folder (can be a `&str`, [`String`],
+    ///   [`Path`], or [`PathBuf`]). Any missing parent folders are created.
+    /// * `content` - Contents of the file.
+    ///
+    /// # Returns
+    ///
+    /// The updated builder.
+    pub fn file<P: AsRef<Path>>(mut self, path: P, content: &str) -> Self {
+        self.entries.push(FixtureEntry::File(
+            path.as_ref().to_path_buf(),
+            content.to_string(),
+        ));
+        self
+    }
+
+    /// Adds an empty folder.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - Path to the folder, relative to the root folder (can be a `&str`, [`String`],
+    ///   [`Path`], or [`PathBuf`]). Any missing parent folders are created.
+    ///
+    /// # Returns
+    ///
+    /// The updated builder.
+    pub fn folder<P: AsRef<Path>>(mut self, path: P) -> Self {
+        self.entries
+            .push(FixtureEntry::Folder(path.as_ref().to_path_buf()));
+        self
+    }
+
+    /// Adds a folder along with its contents, which are declared using a nested builder.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - Path to the folder, relative to the root folder (can be a `&str`, [`String`],
+    ///   [`Path`], or [`PathBuf`]).
+    /// * `contents` - Function declaring the contents of the folder (with paths relative to the
+    ///   folder).
+    ///
+    /// # Returns
+    ///
+    /// The updated builder.
+    pub fn folder_with<P: AsRef<Path>, F: FnOnce(FixtureBuilder) -> FixtureBuilder>(
+        self,
+        path: P,
+        contents: F,
+    ) -> Self {
+        let path = path.as_ref();
+        let mut builder = self.folder(path);
+        for entry in contents(FixtureBuilder::new()).entries {
+            builder.entries.push(match entry {
+                FixtureEntry::File(file, content) => FixtureEntry::File(path.join(file), content),
+                FixtureEntry::Folder(folder) => FixtureEntry::Folder(path.join(folder)),
+            });
+        }
+        builder
+    }
+
+    /// Creates all declared folders and files.
+    ///
+    /// # Arguments
+    ///
+    /// * `root` - The folder to create the entries in (can be a `&str`, [`String`], [`Path`], or
+    ///   [`PathBuf`]). It is created if it does not already exist.
+    ///
+    /// # Returns
+    ///
+    /// Path to the root folder.
+    ///
+    /// # Panics
+    ///
+    /// If any folder or file cannot be created. Existing files are overwritten.
+    pub fn build<P: AsRef<Path>>(&self, root: P) -> PathBuf {
+        let root = root.as_ref();
+        create_folder(root);
+        for entry in &self.entries {
+            match entry {
+                FixtureEntry::File(path, content) => save_string_to_file(content, root.join(path)),
+                FixtureEntry::Folder(path) => create_folder(root.join(path)),
+            }
+        }
+        root.to_path_buf()
+    }
+}
+
+/// Creates a tree of folders and files from a list of paths and contents.
+///
+/// # Arguments
+///
+/// * `root` - The folder to create the tree in (can be a `&str`, [`String`], [`Path`], or
+///   [`PathBuf`]). It is created if it does not already exist.
+/// * `spec` - Paths (relative to `root`) along with their contents. Paths ending in `/` declare
+///   (empty) folders, and their contents are ignored.
+///
+/// # Panics
+///
+/// If any folder or file cannot be created. Existing files are overwritten.
+///
+/// # Example
+///
+/// ```
+/// use file_io::{create_tree, load_file_as_string};
+///
+/// create_tree(
+///     "folder/fixture_2",
+///     &[
+///         ("README.md", "# Title"),
+///         ("src/lib.rs", "pub fn f() {}"),
+///         ("empty/", ""),
+///     ],
+/// );
+///
+/// assert_eq!(load_file_as_string("folder/fixture_2/README.md"), "# Title");
+/// assert!(std::path::Path::new("folder/fixture_2/empty").is_dir());
+/// ```
+pub fn create_tree<P: AsRef<Path>>(root: P, spec: &[(&str, &str)]) {
+    spec.iter()
+        .fold(FixtureBuilder::new(), |builder, (path, content)| {
+            if path.ends_with('/') {
+                builder.folder(path)
+            } else {
+                builder.file(path, content)
+            }
+        })
+        .build(root);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::load::load_file_as_string;
+    use crate::test_utils::get_temp_dir_path;
+    use tempfile::tempdir;
+
+    #[test]
+    fn test_fixture_builder() {
+        // Create a temporary directory to work in.
+        let temp_dir = tempdir().unwrap();
+        let root = get_temp_dir_path(&temp_dir).join("root");
+
+        // Build a nested fixture.
+        let built = FixtureBuilder::new()
+            .file("a.txt", "A")
+            .folder("empty/nested")
+            .folder_with("sub", |sub| {
+                sub.file("b.txt", "B")
+                    .folder_with("deeper", |deeper| deeper.file("c.txt", "C"))
+            })
+            .build(&root);
+
+        // Check the created entries.
+        assert_eq!(built, root);
+        assert_eq!(load_file_as_string(root.join("a.txt")), "A");
+        assert_eq!(load_file_as_string(root.join("sub/b.txt")), "B");
+        assert_eq!(load_file_as_string(root.join("sub/deeper/c.txt")), "C");
+        assert!(root.join("empty/nested").is_dir());
+    }
+
+    #[test]
+    fn test_create_tree() {
+        // Create a temporary directory to work in.
+        let temp_dir = tempdir().unwrap();
+        let root = get_temp_dir_path(&temp_dir);
+
+        // Create the tree.
+        create_tree(&root, &[("x/y.txt", "Y"), ("z/", "ignored"), ("w.txt", "")]);
+
+        // Check the created entries.
+        assert_eq!(load_file_as_string(root.join("x/y.txt")), "Y");
+        assert!(root.join("z").is_dir());
+        assert_eq!(load_file_as_string(root.join("w.txt")), "");
+    }
+}
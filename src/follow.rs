@@ -0,0 +1,528 @@
+use same_file::Handle;
+use std::fs::File;
+use std::io::{Read, Seek, SeekFrom};
+use std::path::Path;
+use std::time::{Duration, Instant, SystemTime};
+
+/// Default time to wait between checks for new data in [`follow_file`].
+const DEFAULT_POLL_INTERVAL: Duration = Duration::from_millis(100);
+
+/// Options controlling how a file is followed by [`follow_file_with_options`].
+///
+/// The default options match the behavior of [`follow_file`].
+///
+/// # Example
+///
+/// ```
+/// use file_io::FollowOptions;
+/// use std::time::Duration;
+///
+/// // Start with the existing contents and check for new data once per second.
+/// let options = FollowOptions {
+///     from_start: true,
+///     poll_interval: Some(Duration::from_secs(1)),
+/// };
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct FollowOptions {
+    /// If `true`, the existing lines of the file are passed to the callback first. Otherwise, only
+    /// lines appended after following starts are (like `tail -f`).
+    pub from_start: bool,
+
+    /// The time to wait between checks for new data. If `None`, 100 ms is used.
+    pub poll_interval: Option<Duration>,
+}
+
+/// The file currently being followed.
+struct FollowedFile {
+    /// The open file.
+    file: File,
+
+    /// The identity of the open file (used to detect rotation).
+    handle: Handle,
+
+    /// The position up to which the file has been read.
+    position: u64,
+}
+
+impl FollowedFile {
+    /// Opens a file for following.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - The path to the file.
+    /// * `from_start` - Whether to start reading at the beginning (rather than the end) of the
+    ///   file.
+    ///
+    /// # Returns
+    ///
+    /// The followed file, or `None` if it cannot be opened (e.g. because it does not exist yet).
+    fn open(path: &Path, from_start: bool) -> Option<FollowedFile> {
+        let file = File::open(path).ok()?;
+        let handle = Handle::from_file(file.try_clone().ok()?).ok()?;
+        let position = if from_start {
+            0
+        } else {
+            file.metadata().ok()?.len()
+        };
+        Some(FollowedFile {
+            file,
+            handle,
+            position,
+        })
+    }
+
+    /// Reads all data appended since the last read.
+    ///
+    /// # Arguments
+    ///
+    /// * `buffer` - The buffer to append the data to.
+    ///
+    /// # Returns
+    ///
+    /// `true` if any data was read, `false` otherwise.
+    fn read_new(&mut self, buffer: &mut Vec<u8>) -> bool {
+        // If the file was truncated, start over from its beginning.
+        let length = self.file.metadata().map_or(0, |metadata| metadata.len());
+        if length < self.position {
+            self.position = 0;
+        }
+        if self.file.seek(SeekFrom::Start(self.position)).is_err() {
+            return false;
+        }
+        let read = self.file.read_to_end(buffer).unwrap_or(0);
+        self.position += read as u64;
+        read > 0
+    }
+}
+
+/// Passes the complete lines in a buffer to a callback, keeping any incomplete last line in the
+/// buffer.
+///
+/// # Arguments
+///
+/// * `buffer` - The buffer.
+/// * `callback` - The callback.
+///
+/// # Returns
+///
+/// `false` if the callback asked to stop, `true` otherwise.
+fn emit_lines<F: FnMut(&str) -> bool>(buffer: &mut Vec<u8>, callback: &mut F) -> bool {
+    while let Some(end) = buffer.iter().position(|&byte| byte == b'\n') {
+        let line: Vec<u8> = buffer.drain(..=end).collect();
+        let line = String::from_utf8_lossy(&line);
+        if !callback(line.trim_end_matches('\n').trim_end_matches('\r')) {
+            return false;
+        }
+    }
+    true
+}
+
+/// Follows a file (like `tail -F`), passing each line appended to it to a callback.
+///
+/// # Arguments
+///
+/// * `path` - The path to the file (can be a `&str`, [`String`], [`Path`], or
+///   [`std::path::PathBuf`]).
+/// * `callback` - Function called with each new line (without its line ending). Following stops
+///   as soon as it returns `false`.
+///
+/// # Note
+///
+/// * This function blocks until the callback returns `false`.
+/// * Only lines appended after following starts are passed to the callback.
+/// * If the file does not exist (yet), this function waits for it to be created.
+/// * If the file is truncated, it is read again from its beginning.
+/// * If the file is rotated (i.e. renamed or deleted and replaced by a new file at the same path),
+///   the remaining lines of the old file are read before switching to the new file.
+/// * An incomplete last line is only passed to the callback once its line break is written.
+/// * Invalid UTF-8 is replaced with `U+FFFD`.
+///
+/// # Example
+///
+/// ```no_run
+/// use file_io::follow_file;
+///
+/// // Print new log lines until one contains "shutdown".
+/// follow_file("app.log", |line| {
+///     println!("{line}");
+///     !line.contains("shutdown")
+/// });
+/// ```
+pub fn follow_file<P: AsRef<Path>, F: FnMut(&str) -> bool>(path: P, callback: F) {
+    follow_file_with_options(path, &FollowOptions::default(), callback);
+}
+
+/// Follows a file (like `tail -F`), passing each line appended to it to a callback, with options
+/// controlling how the file is followed.
+///
+/// # Arguments
+///
+/// * `path` - The path to the file (can be a `&str`, [`String`], [`Path`], or
+///   [`std::path::PathBuf`]).
+/// * `options` - Options controlling how the file is followed.
+/// * `callback` - Function called with each new line (without its line ending). Following stops
+///   as soon as it returns `false`.
+///
+/// # Note
+///
+/// See [`follow_file`].
+///
+/// # Example
+///
+/// ```
+/// use file_io::{FollowOptions, follow_file_with_options, save_string_to_file};
+///
+/// // Create a file.
+/// let path: &str = "folder/subfolder_27/file_26.log";
+/// save_string_to_file("first\nsecond\n", path);
+///
+/// // Read the existing lines.
+/// let options = FollowOptions {
+///     from_start: true,
+///     ..Default::default()
+/// };
+/// let mut lines = Vec::new();
+/// follow_file_with_options(path, &options, |line| {
+///     lines.push(line.to_string());
+///     lines.len() < 2
+/// });
+/// assert_eq!(lines, vec!["first", "second"]);
+/// ```
+pub fn follow_file_with_options<P: AsRef<Path>, F: FnMut(&str) -> bool>(
+    path: P,
+    options: &FollowOptions,
+    mut callback: F,
+) {
+    let path = path.as_ref();
+    let poll_interval = options.poll_interval.unwrap_or(DEFAULT_POLL_INTERVAL);
+    let mut buffer = Vec::new();
+
+    // A file that already exists is read from the end (unless requested otherwise), but files
+    // created later on are always read from their beginning.
+    let mut followed = FollowedFile::open(path, options.from_start);
+    loop {
+        let mut read_any = false;
+        if let Some(current) = followed.as_mut() {
+            read_any = current.read_new(&mut buffer);
+            if !emit_lines(&mut buffer, &mut callback) {
+                return;
+            }
+
+            // Switch to a new file at the same path once the old one has been read completely.
+            let rotated = Handle::from_path(path).is_ok_and(|handle| handle != current.handle);
+            if !read_any && rotated {
+                buffer.clear();
+                followed = FollowedFile::open(path, true);
+                continue;
+            }
+        } else {
+            followed = FollowedFile::open(path, true);
+            if followed.is_some() {
+                continue;
+            }
+        }
+        if !read_any {
+            std::thread::sleep(poll_interval);
+        }
+    }
+}
+
+/// Waits until something exists at the specified path.
+///
+/// # Arguments
+///
+/// * `path` - The path to wait for (can be a `&str`, [`String`], [`Path`], or
+///   [`std::path::PathBuf`]).
+/// * `timeout` - The maximum time to wait.
+///
+/// # Returns
+///
+/// `true` if something exists at the path (possibly before waiting at all), `false` if the timeout
+/// elapsed first.
+///
+/// # Note
+///
+/// * The path is checked every 100 ms (or more often, to avoid waiting past the timeout), blocking
+///   the current thread in between.
+/// * Only the existence of the path is checked, so a file may still be in the process of being
+///   written when this function returns. Processes communicating via files typically write the
+///   file under a temporary name and rename it once complete, or create a separate marker file
+///   (see [`crate::touch_file`]) once it is complete.
+///
+/// # Example
+///
+/// ```
+/// use file_io::{save_string_to_file, wait_for_file};
+/// use std::time::Duration;
+///
+/// let path: &str = "folder/subfolder_62/ready";
+///
+/// // Create the file from another thread after a short delay.
+/// let writer = std::thread::spawn(move || {
+///     std::thread::sleep(Duration::from_millis(200));
+///     save_string_to_file("", path);
+/// });
+///
+/// assert!(wait_for_file(path, Duration::from_secs(10)));
+/// writer.join().unwrap();
+/// ```
+pub fn wait_for_file<P: AsRef<Path>>(path: P, timeout: Duration) -> bool {
+    let path = path.as_ref();
+    let start = Instant::now();
+    loop {
+        if path.exists() {
+            return true;
+        }
+        let Some(remaining) = timeout.checked_sub(start.elapsed()) else {
+            return false;
+        };
+        if remaining.is_zero() {
+            return false;
+        }
+        std::thread::sleep(remaining.min(DEFAULT_POLL_INTERVAL));
+    }
+}
+
+/// Waits until a file exists and has not changed for a quiet period.
+///
+/// # Arguments
+///
+/// * `path` - The path to the file (can be a `&str`, [`String`], [`Path`], or
+///   [`std::path::PathBuf`]).
+/// * `quiet_period` - How long the file must remain unchanged.
+/// * `timeout` - The maximum time to wait.
+///
+/// # Returns
+///
+/// `true` if the file exists and its size and modification time did not change for
+/// `quiet_period`, `false` if the timeout elapsed first.
+///
+/// # Note
+///
+/// * The file is checked every 100 ms (or more often, for quiet periods shorter than that),
+///   blocking the current thread in between. A file that does not exist yet is waited for (see
+///   [`wait_for_file`]), and the quiet period starts over whenever the file changes (or
+///   disappears).
+/// * This is useful to avoid reading a file that another process is still writing. However, a
+///   writer that pauses for longer than the quiet period cannot be distinguished from a writer
+///   that has finished.
+///
+/// # Example
+///
+/// ```
+/// use file_io::{save_string_to_file, wait_for_file_stable};
+/// use std::time::Duration;
+///
+/// let path: &str = "folder/subfolder_63/file_66.txt";
+/// save_string_to_file("Hello, world!", path);
+///
+/// assert!(wait_for_file_stable(
+///     path,
+///     Duration::from_millis(200),
+///     Duration::from_secs(10)
+/// ));
+/// ```
+pub fn wait_for_file_stable<P: AsRef<Path>>(
+    path: P,
+    quiet_period: Duration,
+    timeout: Duration,
+) -> bool {
+    let path = path.as_ref();
+    let start = Instant::now();
+
+    // Size and modification time of the file as of the last check, and when they were first seen.
+    let mut last_state: Option<(u64, Option<SystemTime>)> = None;
+    let mut unchanged_since = start;
+
+    loop {
+        // Check whether the file changed since the last check.
+        let state = std::fs::metadata(path)
+            .ok()
+            .filter(|metadata| metadata.is_file())
+            .map(|metadata| (metadata.len(), metadata.modified().ok()));
+        let now = Instant::now();
+        if state.is_none() || state != last_state {
+            last_state = state;
+            unchanged_since = now;
+        } else if now.duration_since(unchanged_since) >= quiet_period {
+            return true;
+        }
+
+        // Wait until the next check (or give up once the timeout elapsed).
+        let Some(remaining) = timeout.checked_sub(start.elapsed()) else {
+            return false;
+        };
+        if remaining.is_zero() {
+            return false;
+        }
+        std::thread::sleep(
+            remaining
+                .min(DEFAULT_POLL_INTERVAL)
+                .min(quiet_period.max(Duration::from_millis(1))),
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::get_temp_dir_path;
+    use std::io::Write;
+    use tempfile::tempdir;
+
+    /// Appends a string to a file.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - The path to the file.
+    /// * `content` - The string to append.
+    fn append(path: &Path, content: &str) {
+        std::fs::OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(path)
+            .unwrap()
+            .write_all(content.as_bytes())
+            .unwrap();
+    }
+
+    /// Follows a file in a background thread until a given number of lines has been read.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - The path to the file.
+    /// * `count` - The number of lines to read.
+    ///
+    /// # Returns
+    ///
+    /// Handle of the background thread, returning the lines that were read.
+    fn follow_in_background(path: &Path, count: usize) -> std::thread::JoinHandle<Vec<String>> {
+        let path = path.to_path_buf();
+        std::thread::spawn(move || {
+            let options = FollowOptions {
+                poll_interval: Some(Duration::from_millis(10)),
+                ..Default::default()
+            };
+            let mut lines = Vec::new();
+            follow_file_with_options(&path, &options, |line| {
+                lines.push(line.to_string());
+                lines.len() < count
+            });
+            lines
+        })
+    }
+
+    #[test]
+    fn test_follow_file() {
+        // Create a temporary directory to work in.
+        let temp_dir = tempdir().unwrap();
+
+        // Path to the file.
+        let file_path = get_temp_dir_path(&temp_dir).join("app.log");
+        append(&file_path, "old line\n");
+
+        // Follow the file while appending lines (including one written in two parts).
+        let follower = follow_in_background(&file_path, 3);
+        std::thread::sleep(Duration::from_millis(100));
+        append(&file_path, "one\r\ntw");
+        std::thread::sleep(Duration::from_millis(50));
+        append(&file_path, "o\n");
+
+        // Truncate the file and write to it again.
+        std::thread::sleep(Duration::from_millis(50));
+        std::fs::write(&file_path, "").unwrap();
+        std::thread::sleep(Duration::from_millis(50));
+        append(&file_path, "three\n");
+
+        assert_eq!(follower.join().unwrap(), vec!["one", "two", "three"]);
+    }
+
+    #[test]
+    fn test_follow_file_rotation() {
+        // Create a temporary directory to work in.
+        let temp_dir = tempdir().unwrap();
+
+        // Paths to the files.
+        let temp_dir_path = get_temp_dir_path(&temp_dir);
+        let file_path = temp_dir_path.join("app.log");
+        let rotated_path = temp_dir_path.join("app.log.1");
+
+        // Follow a file that does not exist yet.
+        let follower = follow_in_background(&file_path, 3);
+        std::thread::sleep(Duration::from_millis(50));
+        append(&file_path, "one\n");
+
+        // Rotate the file (with a line that has not been read yet).
+        std::thread::sleep(Duration::from_millis(50));
+        append(&file_path, "two\n");
+        std::fs::rename(&file_path, &rotated_path).unwrap();
+        append(&file_path, "three\n");
+
+        assert_eq!(follower.join().unwrap(), vec!["one", "two", "three"]);
+    }
+
+    #[test]
+    fn test_wait_for_file() {
+        // Create a temporary directory to work in.
+        let temp_dir = tempdir().unwrap();
+        let file_path = get_temp_dir_path(&temp_dir).join("ready");
+
+        // Waiting for a path that never appears times out.
+        let start = Instant::now();
+        assert!(!wait_for_file(&file_path, Duration::from_millis(250)));
+        assert!(start.elapsed() >= Duration::from_millis(250));
+
+        // Waiting for a path that appears later succeeds.
+        let writer = {
+            let file_path = file_path.clone();
+            std::thread::spawn(move || {
+                std::thread::sleep(Duration::from_millis(150));
+                append(&file_path, "");
+            })
+        };
+        assert!(wait_for_file(&file_path, Duration::from_secs(10)));
+        writer.join().unwrap();
+
+        // Waiting for a path that already exists returns immediately, even with a zero timeout.
+        assert!(wait_for_file(&file_path, Duration::ZERO));
+    }
+
+    #[test]
+    fn test_wait_for_file_stable() {
+        // Create a temporary directory to work in.
+        let temp_dir = tempdir().unwrap();
+        let file_path = get_temp_dir_path(&temp_dir).join("upload.bin");
+
+        // Write to the file in several chunks, pausing (for less than the quiet period) between
+        // them.
+        let writer = {
+            let file_path = file_path.clone();
+            std::thread::spawn(move || {
+                for _ in 0..5 {
+                    append(&file_path, "chunk\n");
+                    std::thread::sleep(Duration::from_millis(50));
+                }
+            })
+        };
+
+        // The file is only considered stable once the writer is done.
+        assert!(wait_for_file_stable(
+            &file_path,
+            Duration::from_millis(300),
+            Duration::from_secs(10)
+        ));
+        writer.join().unwrap();
+        assert_eq!(
+            std::fs::read_to_string(&file_path).unwrap(),
+            "chunk\n".repeat(5)
+        );
+
+        // Waiting for a file that never appears times out.
+        assert!(!wait_for_file_stable(
+            get_temp_dir_path(&temp_dir).join("missing"),
+            Duration::from_millis(50),
+            Duration::from_millis(200)
+        ));
+    }
+}
@@ -0,0 +1,171 @@
+use glob::{MatchOptions, Pattern};
+use std::path::{Path, PathBuf};
+
+/// A single rule of a `.gitignore` file.
+#[derive(Clone, Debug)]
+struct Rule {
+    /// The folder containing the `.gitignore` file (anchored patterns are relative to it).
+    base: PathBuf,
+
+    /// The glob pattern.
+    pattern: Pattern,
+
+    /// Whether the rule re-includes paths (i.e. the pattern started with `!`).
+    negated: bool,
+
+    /// Whether the rule only applies to folders (i.e. the pattern ended with `/`).
+    folder_only: bool,
+
+    /// Whether the pattern is matched against the path relative to `base` (i.e. it contained a
+    /// `/`) rather than against the name of the file or folder.
+    anchored: bool,
+}
+
+/// The `.gitignore` rules that apply within a folder.
+///
+/// This supports the commonly used subset of the `.gitignore` syntax (comments, negation with `!`,
+/// folder-only patterns ending with `/`, anchored patterns containing `/`, and `*`, `?`, `[...]`,
+/// and `**` wildcards). Only `.gitignore` files that are explicitly added are used (i.e. global
+/// excludes and `.git/info/exclude` are not).
+#[derive(Clone, Debug, Default)]
+pub(crate) struct GitIgnore {
+    /// The rules, in order of increasing precedence.
+    rules: Vec<Rule>,
+}
+
+impl GitIgnore {
+    /// Get the rules that apply within a subfolder, i.e. these rules followed by the rules of the
+    /// subfolder's own `.gitignore` file (if it has one).
+    ///
+    /// # Arguments
+    ///
+    /// * `folder` - The path to the subfolder.
+    ///
+    /// # Returns
+    ///
+    /// The rules that apply within `folder`.
+    pub(crate) fn child(&self, folder: &Path) -> GitIgnore {
+        let mut child = self.clone();
+        if let Ok(content) = std::fs::read_to_string(folder.join(".gitignore")) {
+            child
+                .rules
+                .extend(content.lines().filter_map(|line| parse_rule(folder, line)));
+        }
+        child
+    }
+
+    /// Check whether a path is ignored.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - The path to check.
+    /// * `is_folder` - Whether `path` is a folder.
+    ///
+    /// # Returns
+    ///
+    /// `true` if the last rule matching `path` ignores it, `false` otherwise.
+    pub(crate) fn is_ignored(&self, path: &Path, is_folder: bool) -> bool {
+        let match_options = MatchOptions {
+            require_literal_separator: true,
+            ..MatchOptions::new()
+        };
+        let mut ignored = false;
+        for rule in &self.rules {
+            if rule.folder_only && !is_folder {
+                continue;
+            }
+            let Ok(relative_path) = path.strip_prefix(&rule.base) else {
+                continue;
+            };
+            let matched = if rule.anchored {
+                rule.pattern.matches_path_with(relative_path, match_options)
+            } else {
+                path.file_name().is_some_and(|name| {
+                    rule.pattern
+                        .matches_with(&name.to_string_lossy(), match_options)
+                })
+            };
+            if matched {
+                ignored = !rule.negated;
+            }
+        }
+        ignored
+    }
+}
+
+/// Parses a line of a `.gitignore` file.
+///
+/// # Arguments
+///
+/// * `base` - The folder containing the `.gitignore` file.
+/// * `line` - The line to parse.
+///
+/// # Returns
+///
+/// The parsed rule, or `None` if the line is blank, a comment, or an invalid pattern.
+fn parse_rule(base: &Path, line: &str) -> Option<Rule> {
+    let mut pattern = line.trim_end();
+    if pattern.is_empty() || pattern.starts_with('#') {
+        return None;
+    }
+    let negated = pattern.starts_with('!');
+    if negated {
+        pattern = &pattern[1..];
+    }
+    let folder_only = pattern.ends_with('/');
+    pattern = pattern.trim_end_matches('/');
+    let anchored = pattern.contains('/');
+    pattern = pattern.trim_start_matches('/');
+    if pattern.is_empty() {
+        return None;
+    }
+    Some(Rule {
+        base: base.to_path_buf(),
+        pattern: Pattern::new(pattern).ok()?,
+        negated,
+        folder_only,
+        anchored,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::save::save_string_to_file;
+    use crate::test_utils::get_temp_dir_path;
+    use tempfile::tempdir;
+
+    #[test]
+    fn test_git_ignore() {
+        // Create a temporary directory to work in.
+        let temp_dir = tempdir().unwrap();
+
+        // Get the path to the temporary directory.
+        let root = get_temp_dir_path(&temp_dir);
+
+        // Create the `.gitignore` files.
+        save_string_to_file(
+            "# Comment\n\n*.log\n!keep.log\ntarget/\n/build\ndocs/*.html\n",
+            root.join(".gitignore"),
+        );
+        save_string_to_file("*.tmp\n", root.join("sub/.gitignore"));
+
+        // Check the rules.
+        let ignore = GitIgnore::default().child(&root);
+        assert!(ignore.is_ignored(&root.join("debug.log"), false));
+        assert!(ignore.is_ignored(&root.join("sub/deeper/debug.log"), false));
+        assert!(!ignore.is_ignored(&root.join("keep.log"), false));
+        assert!(ignore.is_ignored(&root.join("sub/target"), true));
+        assert!(!ignore.is_ignored(&root.join("target"), false));
+        assert!(ignore.is_ignored(&root.join("build"), true));
+        assert!(!ignore.is_ignored(&root.join("sub/build"), true));
+        assert!(ignore.is_ignored(&root.join("docs/index.html"), false));
+        assert!(!ignore.is_ignored(&root.join("docs/api/index.html"), false));
+        assert!(!ignore.is_ignored(&root.join("file.tmp"), false));
+
+        // Rules of a subfolder's `.gitignore` file only apply within that subfolder.
+        let sub_ignore = ignore.child(&root.join("sub"));
+        assert!(sub_ignore.is_ignored(&root.join("sub/file.tmp"), false));
+        assert!(sub_ignore.is_ignored(&root.join("sub/debug.log"), false));
+    }
+}
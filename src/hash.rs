@@ -0,0 +1,428 @@
+use crate::progress::{NoProgress, ProgressSink, file_size};
+use sha2::{Digest, Sha256};
+use std::collections::BTreeMap;
+use std::fs::File;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use walkdir::WalkDir;
+
+/// Compute the SHA-256 hash of a file's contents.
+///
+/// # Arguments
+///
+/// * `path` - The path to the file (can be a `&str`, [`String`], [`Path`], or [`PathBuf`]).
+///
+/// # Returns
+///
+/// The SHA-256 hash of the file's contents, as a lowercase hexadecimal string.
+///
+/// # Panics
+///
+/// If the file cannot be opened or read.
+///
+/// # Note
+///
+/// The file is read in chunks, so arbitrarily large files can be hashed.
+///
+/// # Example
+///
+/// ```
+/// use file_io::{hash_file, save_string_to_file};
+///
+/// save_string_to_file("Hello, world!", "folder/subfolder_23/file_19.txt");
+///
+/// assert_eq!(
+///     hash_file("folder/subfolder_23/file_19.txt"),
+///     "315f5bdb76d078c43b8ac0064e4a0164612b1fce77c869345bfc94c75894edd3"
+/// );
+/// ```
+pub fn hash_file<P: AsRef<Path>>(path: P) -> String {
+    let path = path.as_ref();
+    let mut hasher = Sha256::new();
+    File::open(path)
+        .and_then(|mut file| std::io::copy(&mut file, &mut hasher))
+        .unwrap_or_else(|_| panic!("Failed to hash file '{path:?}'."));
+    format!("{:x}", hasher.finalize())
+}
+
+/// Collects the paths of all files within a folder (recursively).
+///
+/// # Arguments
+///
+/// * `path` - The path to the folder.
+///
+/// # Returns
+///
+/// The paths of all files within the folder (symbolic links are not followed or included).
+///
+/// # Panics
+///
+/// If the folder cannot be traversed.
+fn collect_files(path: &Path) -> Vec<PathBuf> {
+    WalkDir::new(path)
+        .into_iter()
+        .map(|entry| entry.unwrap_or_else(|_| panic!("Failed to traverse '{path:?}'.")))
+        .filter(|entry| entry.file_type().is_file())
+        .map(|entry| entry.into_path())
+        .collect()
+}
+
+/// Compute the SHA-256 hashes of all files within a folder (recursively).
+///
+/// # Arguments
+///
+/// * `path` - The path to the folder (can be a `&str`, [`String`], [`Path`], or [`PathBuf`]).
+///
+/// # Returns
+///
+/// A map from the path of each file (relative to `path`) to the SHA-256 hash of its contents (see
+/// [`hash_file`]).
+///
+/// # Panics
+///
+/// If the folder cannot be traversed or any file cannot be hashed.
+///
+/// # Note
+///
+/// Symbolic links are not followed (and are not included in the map).
+///
+/// # Example
+///
+/// ```
+/// use file_io::hash_folder;
+/// use std::path::Path;
+///
+/// let hashes = hash_folder(".vscode");
+/// assert!(hashes.contains_key(Path::new("settings.json")));
+/// ```
+pub fn hash_folder<P: AsRef<Path>>(path: P) -> BTreeMap<PathBuf, String> {
+    hash_folder_with_progress(path, &NoProgress)
+}
+
+/// Compute the SHA-256 hashes of all files within a folder (recursively), reporting progress
+/// along the way.
+///
+/// # Arguments
+///
+/// * `path` - The path to the folder (can be a `&str`, [`String`], [`Path`], or [`PathBuf`]).
+/// * `progress` - Receives progress updates (one item per file hashed).
+///
+/// # Returns
+///
+/// A map from the path of each file (relative to `path`) to the SHA-256 hash of its contents (see
+/// [`hash_file`]).
+///
+/// # Panics
+///
+/// If the folder cannot be traversed or any file cannot be hashed.
+///
+/// # Example
+///
+/// ```
+/// use file_io::{NoProgress, hash_folder_with_progress};
+/// use std::path::Path;
+///
+/// let hashes = hash_folder_with_progress(".vscode", &NoProgress);
+/// assert!(hashes.contains_key(Path::new("settings.json")));
+/// ```
+pub fn hash_folder_with_progress<P: AsRef<Path>>(
+    path: P,
+    progress: &dyn ProgressSink,
+) -> BTreeMap<PathBuf, String> {
+    let path = path.as_ref();
+    let file_paths = collect_files(path);
+    progress.on_start(
+        file_paths.len(),
+        file_paths
+            .iter()
+            .map(|file_path| file_size(file_path))
+            .sum(),
+    );
+    let hashes = file_paths
+        .iter()
+        .map(|file_path| {
+            let hash = hash_file(file_path);
+            progress.on_item(file_path);
+            progress.on_bytes(file_size(file_path));
+            (file_path.strip_prefix(path).unwrap().to_path_buf(), hash)
+        })
+        .collect();
+    progress.on_finish();
+    hashes
+}
+
+/// Compute the SHA-256 hashes of all files within a folder (recursively), hashing several files
+/// concurrently.
+///
+/// # Arguments
+///
+/// * `path` - The path to the folder (can be a `&str`, [`String`], [`Path`], or [`PathBuf`]).
+///
+/// # Returns
+///
+/// A map from the path of each file (relative to `path`) to the SHA-256 hash of its contents (see
+/// [`hash_file`]). This is the same map that [`hash_folder`] returns.
+///
+/// # Panics
+///
+/// If the folder cannot be traversed or any file cannot be hashed.
+///
+/// # Note
+///
+/// * The files are hashed on as many threads as there is available parallelism (see
+///   [`std::thread::available_parallelism`]), which is typically much faster than [`hash_folder`]
+///   for large folders on fast storage.
+/// * Symbolic links are not followed (and are not included in the map).
+///
+/// # Example
+///
+/// ```
+/// use file_io::{hash_folder, hash_folder_parallel};
+///
+/// assert_eq!(hash_folder_parallel("src"), hash_folder("src"));
+/// ```
+pub fn hash_folder_parallel<P: AsRef<Path>>(path: P) -> BTreeMap<PathBuf, String> {
+    let path = path.as_ref();
+    let file_paths = collect_files(path);
+
+    // Number of worker threads to use.
+    let num_threads = std::thread::available_parallelism()
+        .map(|n| n.get())
+        .unwrap_or(1)
+        .min(file_paths.len().max(1));
+
+    // Index of the next file to hash (shared between the worker threads).
+    let next_index = AtomicUsize::new(0);
+
+    // Hashes shared between the worker threads.
+    let hashes = Mutex::new(BTreeMap::new());
+
+    // Each worker thread repeatedly claims the next unhashed file until none are left.
+    std::thread::scope(|scope| {
+        for _ in 0..num_threads {
+            scope.spawn(|| {
+                while let Some(file_path) =
+                    file_paths.get(next_index.fetch_add(1, Ordering::Relaxed))
+                {
+                    let hash = hash_file(file_path);
+                    hashes
+                        .lock()
+                        .unwrap()
+                        .insert(file_path.strip_prefix(path).unwrap().to_path_buf(), hash);
+                }
+            });
+        }
+    });
+
+    hashes.into_inner().unwrap()
+}
+
+/// Compute a digest of a folder's structure and contents.
+///
+/// # Arguments
+///
+/// * `path` - The path to the folder (can be a `&str`, [`String`], [`Path`], or [`PathBuf`]).
+///
+/// # Returns
+///
+/// The SHA-256 digest of the folder, as a lowercase hexadecimal string.
+///
+/// # Panics
+///
+/// If the folder (or any folder within it) cannot be read, or any file cannot be hashed.
+///
+/// # Note
+///
+/// * The digest is computed like a Merkle tree: the digest of a folder is the SHA-256 hash of the
+///   sorted names, kinds (file, folder, or symbolic link), and digests of its entries. The digest of
+///   a file is the hash of its contents (see [`hash_file`]), and the digest of a symbolic link is
+///   the hash of its target (symbolic links are not followed).
+/// * The digest therefore changes if any file is added, removed, renamed, or modified, or if any
+///   (possibly empty) folder is added, removed, or renamed. It does not depend on the name or
+///   location of the folder itself, or on metadata such as modification times and permissions.
+///
+/// # Example
+///
+/// ```
+/// use file_io::{folder_digest, save_string_to_file};
+///
+/// save_string_to_file("Hello, world!", "folder/subfolder_60/file_64.txt");
+/// let before = folder_digest("folder/subfolder_60");
+///
+/// // The digest only changes if something within the folder changes.
+/// assert_eq!(folder_digest("folder/subfolder_60"), before);
+/// save_string_to_file("Goodbye, world!", "folder/subfolder_60/file_64.txt");
+/// assert_ne!(folder_digest("folder/subfolder_60"), before);
+/// ```
+pub fn folder_digest<P: AsRef<Path>>(path: P) -> String {
+    let path = path.as_ref();
+
+    // Collect the entries of the folder, sorted by name so the digest is deterministic.
+    let mut entries: Vec<_> = std::fs::read_dir(path)
+        .and_then(|entries| entries.collect::<Result<Vec<_>, _>>())
+        .unwrap_or_else(|_| panic!("Failed to read folder '{path:?}'."));
+    entries.sort_by_key(|entry| entry.file_name());
+
+    // Hash the name, kind, and digest of each entry.
+    let mut hasher = Sha256::new();
+    for entry in entries {
+        let entry_path = entry.path();
+        let file_type = entry
+            .file_type()
+            .unwrap_or_else(|_| panic!("Failed to get file type of '{entry_path:?}'."));
+        let (kind, digest) = if file_type.is_symlink() {
+            let target = std::fs::read_link(&entry_path)
+                .unwrap_or_else(|_| panic!("Failed to read symbolic link '{entry_path:?}'."));
+            let digest = format!(
+                "{:x}",
+                Sha256::digest(target.as_os_str().as_encoded_bytes())
+            );
+            ('l', digest)
+        } else if file_type.is_dir() {
+            ('d', folder_digest(&entry_path))
+        } else {
+            ('f', hash_file(&entry_path))
+        };
+
+        // Names cannot contain NUL characters, so terminating each entry with one keeps the input
+        // to the hasher unambiguous.
+        hasher.update(format!("{kind} {digest} ").as_bytes());
+        hasher.update(entry.file_name().as_encoded_bytes());
+        hasher.update(b"\0");
+    }
+    format!("{:x}", hasher.finalize())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::save::save_string_to_file;
+    use crate::test_utils::get_temp_dir_path;
+    use tempfile::tempdir;
+
+    #[test]
+    fn test_hash_file() {
+        // Create a temporary directory to work in.
+        let temp_dir = tempdir().unwrap();
+
+        // Path to the file.
+        let file_path = get_temp_dir_path(&temp_dir).join("file.txt");
+
+        // File path in different formats.
+        let file_paths: Vec<Box<dyn AsRef<Path>>> = vec![
+            Box::new(file_path.to_str().unwrap()),             // &str
+            Box::new(file_path.to_str().unwrap().to_string()), // String
+            Box::new(file_path.as_path()),                     // Path
+            Box::new(file_path.clone()),                       // PathBuf
+        ];
+
+        // Create the file.
+        save_string_to_file("abc", &file_path);
+
+        // Test with all different path formats.
+        for file_path in file_paths {
+            // Get a reference to this path representation (i.e. "unbox").
+            let file_path = file_path.as_ref();
+
+            // Check the hash.
+            assert_eq!(
+                hash_file(file_path),
+                "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad"
+            );
+        }
+    }
+
+    #[test]
+    fn test_hash_folder() {
+        // Create a temporary directory to work in.
+        let temp_dir = tempdir().unwrap();
+        let temp_dir_path = get_temp_dir_path(&temp_dir);
+
+        // Create some files.
+        save_string_to_file("abc", temp_dir_path.join("a.txt"));
+        save_string_to_file("", temp_dir_path.join("sub/b.txt"));
+
+        // Check the hashes.
+        let hashes = hash_folder(&temp_dir_path);
+        assert_eq!(hashes.len(), 2);
+        assert_eq!(
+            hashes[Path::new("a.txt")],
+            "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad"
+        );
+        assert_eq!(
+            hashes[Path::new("sub/b.txt")],
+            "e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855"
+        );
+    }
+
+    #[test]
+    fn test_hash_folder_parallel() {
+        // Create a temporary directory to work in.
+        let temp_dir = tempdir().unwrap();
+        let temp_dir_path = get_temp_dir_path(&temp_dir);
+        for i in 0..50 {
+            save_string_to_file(
+                &i.to_string(),
+                temp_dir_path.join(format!("{}/{i}.txt", i % 5)),
+            );
+        }
+
+        // The parallel hashes match the serial hashes.
+        let hashes = hash_folder_parallel(&temp_dir_path);
+        assert_eq!(hashes.len(), 50);
+        assert_eq!(hashes, hash_folder(&temp_dir_path));
+        assert_eq!(
+            hashes[Path::new("3/13.txt")],
+            hash_file(temp_dir_path.join("3/13.txt"))
+        );
+
+        // An empty folder has no hashes.
+        let empty_path = temp_dir_path.join("empty");
+        std::fs::create_dir(&empty_path).unwrap();
+        assert!(hash_folder_parallel(&empty_path).is_empty());
+    }
+
+    #[test]
+    fn test_folder_digest() {
+        // Create a temporary directory to work in.
+        let temp_dir = tempdir().unwrap();
+        let temp_dir_path = get_temp_dir_path(&temp_dir);
+        let a = temp_dir_path.join("a");
+        save_string_to_file("1", a.join("one.txt"));
+        save_string_to_file("2", a.join("nested/two.txt"));
+        let digest = folder_digest(&a);
+
+        // The digest does not depend on the folder's name or location.
+        crate::copy::copy_folder(&a, temp_dir_path.join("b/c"));
+        assert_eq!(folder_digest(temp_dir_path.join("b/c")), digest);
+
+        // The digest does not depend on modification times.
+        std::fs::File::options()
+            .write(true)
+            .open(a.join("one.txt"))
+            .unwrap()
+            .set_modified(std::time::UNIX_EPOCH)
+            .unwrap();
+        assert_eq!(folder_digest(&a), digest);
+
+        // Modifying, renaming, or adding anything changes the digest.
+        save_string_to_file("changed", a.join("nested/two.txt"));
+        let modified = folder_digest(&a);
+        assert_ne!(modified, digest);
+        std::fs::rename(a.join("nested"), a.join("renamed")).unwrap();
+        let renamed = folder_digest(&a);
+        assert_ne!(renamed, modified);
+        std::fs::create_dir(a.join("empty")).unwrap();
+        assert_ne!(folder_digest(&a), renamed);
+
+        // Moving a file between folders changes the digest, even if the contents are unchanged.
+        let d = temp_dir_path.join("d");
+        let e = temp_dir_path.join("e");
+        save_string_to_file("1", d.join("x/f"));
+        std::fs::create_dir_all(d.join("y")).unwrap();
+        std::fs::create_dir_all(e.join("x")).unwrap();
+        save_string_to_file("1", e.join("y/f"));
+        assert_ne!(folder_digest(&d), folder_digest(&e));
+    }
+}
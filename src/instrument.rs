@@ -0,0 +1,331 @@
+use crate::dry_run::{Plan, PlannedOperation, current_plan};
+use crate::journal::{is_journal_active, record_operation};
+use std::path::{Path, PathBuf};
+#[cfg(feature = "tracing")]
+use std::time::Instant;
+
+/// Instrumentation of a single filesystem mutation (e.g. writing, copying, or deleting a file).
+///
+/// When the `tracing` feature is enabled, creating a mutation enters a `tracing` span (with target
+/// `file_io`) describing the operation and the paths involved, and dropping it emits an event with
+/// the number of bytes written (if known) and the duration of the operation. Mutations that
+/// fail are reported with a warning instead.
+///
+/// If a journal is active (see [`crate::start_journal`]), dropping a mutation also records it to
+/// the journal.
+///
+/// If a dry run is active (see [`crate::DryRun::enter`]), the operation should not be performed
+/// (see [`Mutation::is_dry_run`]); dropping the mutation then records it to the dry run's plan
+/// instead (and nothing is emitted or journaled).
+///
+/// A mutation is considered to have failed unless [`Mutation::succeeded`] is called before it is
+/// dropped, so that operations that panic or return early with an error are reported (and
+/// journaled) as failed.
+///
+/// # Note
+///
+/// Since the span stays entered until the mutation is dropped, mutations performed as part of a
+/// larger one (e.g. each file copied by [`crate::copy_folder`]) are nested within its span.
+pub(crate) struct Mutation {
+    /// The span of the operation.
+    #[cfg(feature = "tracing")]
+    span: tracing::span::EnteredSpan,
+
+    /// The time at which the operation started.
+    #[cfg(feature = "tracing")]
+    start: Instant,
+
+    /// The name of the operation.
+    operation: &'static str,
+
+    /// The number of bytes written by the operation (if known).
+    bytes: Option<u64>,
+
+    /// The path being mutated and the destination path (if any), captured only if a journal or a
+    /// dry run is active when the operation starts.
+    paths: Option<(PathBuf, Option<PathBuf>)>,
+
+    /// The plan of the active dry run (if any, and only for operations that are not composite).
+    plan: Option<Plan>,
+
+    /// Whether a dry run is active.
+    dry_run: bool,
+
+    /// Whether the operation completed successfully.
+    succeeded: bool,
+}
+
+impl Mutation {
+    /// Starts instrumenting a filesystem mutation that modifies the filesystem directly.
+    ///
+    /// # Arguments
+    ///
+    /// * `operation` - The name of the operation (e.g. `"copy_file"`).
+    /// * `path` - The path being mutated (or, for operations with a source and a destination, the
+    ///   source path).
+    ///
+    /// # Returns
+    ///
+    /// The instrumented mutation (which should be kept alive until the operation is complete).
+    pub(crate) fn start(operation: &'static str, path: &Path) -> Mutation {
+        Mutation::new(operation, path, current_plan())
+    }
+
+    /// Starts instrumenting a filesystem mutation that is made up of smaller mutations (e.g.
+    /// copying a folder, which copies each of its files).
+    ///
+    /// # Arguments
+    ///
+    /// * `operation` - The name of the operation (e.g. `"copy_folder"`).
+    /// * `path` - The path being mutated (or, for operations with a source and a destination, the
+    ///   source path).
+    ///
+    /// # Returns
+    ///
+    /// The instrumented mutation (which should be kept alive until the operation is complete).
+    ///
+    /// # Note
+    ///
+    /// Composite mutations are never skipped by dry runs (their smaller mutations are instead), and
+    /// are not recorded to dry run plans.
+    pub(crate) fn start_composite(operation: &'static str, path: &Path) -> Mutation {
+        let mut mutation = Mutation::new(operation, path, None);
+        mutation.dry_run = current_plan().is_some();
+        mutation
+    }
+
+    /// Starts instrumenting a filesystem mutation.
+    ///
+    /// # Arguments
+    ///
+    /// * `operation` - The name of the operation.
+    /// * `path` - The path being mutated.
+    /// * `plan` - The plan of the active dry run that the mutation should be recorded to (if any).
+    ///
+    /// # Returns
+    ///
+    /// The instrumented mutation.
+    fn new(operation: &'static str, path: &Path, plan: Option<Plan>) -> Mutation {
+        Mutation {
+            #[cfg(feature = "tracing")]
+            span: tracing::debug_span!(
+                target: "file_io",
+                "fs_mutation",
+                operation,
+                path = %path.display(),
+                to = tracing::field::Empty,
+            )
+            .entered(),
+            #[cfg(feature = "tracing")]
+            start: Instant::now(),
+            operation,
+            bytes: None,
+            paths: (plan.is_some() || is_journal_active()).then(|| (path.to_path_buf(), None)),
+            dry_run: plan.is_some(),
+            plan,
+            succeeded: false,
+        }
+    }
+
+    /// Determines whether the operation should be skipped because a dry run is active.
+    ///
+    /// # Returns
+    ///
+    /// `true` if the operation should not be performed (in which case it is recorded to the dry
+    /// run's plan when the mutation is dropped), `false` otherwise.
+    pub(crate) fn is_dry_run(&self) -> bool {
+        self.plan.is_some()
+    }
+
+    /// Records the destination path of the operation.
+    ///
+    /// # Arguments
+    ///
+    /// * `to` - The destination path.
+    ///
+    /// # Returns
+    ///
+    /// The instrumented mutation.
+    pub(crate) fn to(mut self, to: &Path) -> Mutation {
+        #[cfg(feature = "tracing")]
+        self.span
+            .record("to", tracing::field::display(to.display()));
+        if let Some((_, destination)) = &mut self.paths {
+            *destination = Some(to.to_path_buf());
+        }
+        self
+    }
+
+    /// Records the number of bytes written by the operation.
+    ///
+    /// # Arguments
+    ///
+    /// * `bytes` - The number of bytes written.
+    pub(crate) fn bytes(&mut self, bytes: u64) {
+        self.bytes = Some(bytes);
+    }
+
+    /// Marks the operation as having completed successfully.
+    ///
+    /// # Note
+    ///
+    /// This should be called once the operation is complete (and not for operations skipped by a
+    /// dry run). Mutations dropped without calling it are reported as failed.
+    pub(crate) fn succeeded(&mut self) {
+        self.succeeded = true;
+    }
+}
+
+impl Drop for Mutation {
+    fn drop(&mut self) {
+        // Operations skipped by a dry run are only recorded to its plan.
+        if self.dry_run {
+            if let (Some(plan), Some((path, to))) = (&self.plan, self.paths.take()) {
+                plan.lock()
+                    .unwrap_or_else(|error| error.into_inner())
+                    .push(PlannedOperation {
+                        operation: self.operation,
+                        path,
+                        to,
+                        bytes: self.bytes,
+                    });
+            }
+            return;
+        }
+        let succeeded = self.succeeded;
+
+        #[cfg(feature = "tracing")]
+        {
+            let duration_us = self.start.elapsed().as_micros() as u64;
+            if succeeded {
+                tracing::debug!(
+                    target: "file_io",
+                    operation = self.operation,
+                    bytes = self.bytes,
+                    duration_us,
+                    "{} finished",
+                    self.operation
+                );
+            } else {
+                tracing::warn!(
+                    target: "file_io",
+                    operation = self.operation,
+                    duration_us,
+                    "{} failed",
+                    self.operation
+                );
+            }
+        }
+
+        if let Some((path, to)) = &self.paths {
+            record_operation(self.operation, path, to.as_deref(), self.bytes, succeeded);
+        }
+    }
+}
+
+#[cfg(all(test, feature = "tracing"))]
+mod tests {
+    use crate::copy::copy_folder;
+    use crate::save::save_string_to_file;
+    use crate::test_utils::get_temp_dir_path;
+    use std::sync::{Arc, Mutex};
+    use tempfile::tempdir;
+    use tracing::field::{Field, Visit};
+    use tracing::span::{Attributes, Id, Record};
+    use tracing::{Event, Metadata, Subscriber};
+
+    /// Fields of an event emitted when a mutation finishes.
+    #[derive(Debug, Default, PartialEq)]
+    struct FinishedEvent {
+        /// The name of the operation.
+        operation: String,
+
+        /// The number of bytes written.
+        bytes: Option<u64>,
+    }
+
+    impl Visit for FinishedEvent {
+        fn record_str(&mut self, field: &Field, value: &str) {
+            if field.name() == "operation" {
+                self.operation = value.to_string();
+            }
+        }
+
+        fn record_u64(&mut self, field: &Field, value: u64) {
+            if field.name() == "bytes" {
+                self.bytes = Some(value);
+            }
+        }
+
+        fn record_debug(&mut self, _field: &Field, _value: &dyn std::fmt::Debug) {}
+    }
+
+    /// Subscriber that records the events emitted when mutations finish.
+    #[derive(Clone, Default)]
+    struct RecordingSubscriber {
+        /// The recorded events.
+        events: Arc<Mutex<Vec<FinishedEvent>>>,
+
+        /// Number of spans created so far (used to assign span IDs).
+        spans: Arc<Mutex<u64>>,
+    }
+
+    impl Subscriber for RecordingSubscriber {
+        fn enabled(&self, metadata: &Metadata<'_>) -> bool {
+            metadata.target() == "file_io"
+        }
+
+        fn new_span(&self, _span: &Attributes<'_>) -> Id {
+            let mut spans = self.spans.lock().unwrap();
+            *spans += 1;
+            Id::from_u64(*spans)
+        }
+
+        fn record(&self, _span: &Id, _values: &Record<'_>) {}
+
+        fn record_follows_from(&self, _span: &Id, _follows: &Id) {}
+
+        fn event(&self, event: &Event<'_>) {
+            let mut finished = FinishedEvent::default();
+            event.record(&mut finished);
+            self.events.lock().unwrap().push(finished);
+        }
+
+        fn enter(&self, _span: &Id) {}
+
+        fn exit(&self, _span: &Id) {}
+    }
+
+    #[test]
+    fn test_mutation_events() {
+        // Create a temporary directory to work in.
+        let temp_dir = tempdir().unwrap();
+        let temp_dir_path = get_temp_dir_path(&temp_dir);
+
+        // Save and copy a file while recording events.
+        let subscriber = RecordingSubscriber::default();
+        tracing::subscriber::with_default(subscriber.clone(), || {
+            save_string_to_file("Hello", temp_dir_path.join("source/file.txt"));
+            copy_folder(temp_dir_path.join("source"), temp_dir_path.join("copy"));
+        });
+
+        // Nested mutations finish before the ones they are part of.
+        let operations: Vec<(String, Option<u64>)> = subscriber
+            .events
+            .lock()
+            .unwrap()
+            .drain(..)
+            .map(|event| (event.operation, event.bytes))
+            .collect();
+        assert_eq!(
+            operations,
+            [
+                (String::from("create_folder"), None),
+                (String::from("save_string_to_file"), Some(5)),
+                (String::from("create_folder"), None),
+                (String::from("copy_file"), Some(5)),
+                (String::from("copy_folder"), None),
+            ]
+        );
+    }
+}
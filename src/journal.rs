@@ -0,0 +1,406 @@
+use crate::print::write_json_string;
+use crate::timestamp::current_timestamp;
+use std::fs::File;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+use std::sync::atomic::{AtomicBool, Ordering};
+
+/// Whether a journal is currently active (checked before locking [`JOURNAL`], so that mutations
+/// are cheap when no journal is active).
+static ACTIVE: AtomicBool = AtomicBool::new(false);
+
+/// The active journal (if any).
+static JOURNAL: Mutex<Option<Journal>> = Mutex::new(None);
+
+/// A journal file that mutating operations are recorded to.
+struct Journal {
+    /// The path to the journal file.
+    path: PathBuf,
+
+    /// The journal file (opened for appending).
+    file: File,
+}
+
+impl Journal {
+    /// Opens a journal file for appending, creating it (along with its parent folder) if it does
+    /// not already exist.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - The path to the journal file.
+    ///
+    /// # Returns
+    ///
+    /// The journal.
+    ///
+    /// # Panics
+    ///
+    /// If the journal file cannot be opened.
+    fn open(path: &Path) -> Journal {
+        // The journal is opened directly (rather than through the crate's own functions) so that
+        // opening it is not itself recorded.
+        if let Some(parent) = path
+            .parent()
+            .filter(|parent| !parent.as_os_str().is_empty())
+        {
+            std::fs::create_dir_all(parent)
+                .unwrap_or_else(|_| panic!("Failed to create folder at '{parent:?}'."));
+        }
+        let file = File::options()
+            .create(true)
+            .append(true)
+            .open(path)
+            .unwrap_or_else(|_| panic!("Failed to open journal '{path:?}'."));
+        Journal {
+            path: path.to_path_buf(),
+            file,
+        }
+    }
+}
+
+/// Replaces the active journal.
+///
+/// # Arguments
+///
+/// * `journal` - The new journal (or `None` to stop recording).
+///
+/// # Returns
+///
+/// The previously active journal (if any).
+fn replace_journal(journal: Option<Journal>) -> Option<Journal> {
+    let mut active = JOURNAL.lock().unwrap_or_else(|error| error.into_inner());
+    ACTIVE.store(journal.is_some(), Ordering::Release);
+    std::mem::replace(&mut *active, journal)
+}
+
+/// Starts recording every mutating operation performed through this crate (e.g. saving, copying,
+/// or deleting files) to a journal file.
+///
+/// # Arguments
+///
+/// * `path` - The path to the journal file (can be a `&str`, [`String`], [`Path`], or
+///   [`std::path::PathBuf`]).
+///
+/// # Panics
+///
+/// If the journal file cannot be opened.
+///
+/// # Note
+///
+/// * The journal is global: operations performed on any thread are recorded, until
+///   [`stop_journal`] is called. Use [`start_scoped_journal`] to only record operations for a
+///   limited time.
+/// * Starting a journal replaces any journal that is already active.
+/// * The journal file is appended to (and created, along with its parent folder, if it does not
+///   already exist). Each operation is recorded on its own line as a JSON object with the fields
+///   `timestamp` (UTC, e.g. `20261016T093015.123456Z`), `operation` (e.g. `copy_file`), `path`,
+///   `to` (for operations with a destination, e.g. copies), `bytes` (the number of bytes written,
+///   if known), and `outcome` (`ok` or `failed`).
+/// * Operations are recorded when they finish, so an operation that is part of a larger one (e.g.
+///   each file copied by [`crate::copy_folder`]) is recorded before it.
+///
+/// # Example
+///
+/// ```no_run
+/// use file_io::{load_file_as_string, save_string_to_file, start_journal, stop_journal};
+///
+/// start_journal("folder/subfolder_40/journal.jsonl");
+/// save_string_to_file("Hello, world!", "folder/subfolder_40/file_47.txt");
+/// stop_journal();
+///
+/// let journal = load_file_as_string("folder/subfolder_40/journal.jsonl");
+/// assert!(journal.contains(r#""operation":"save_string_to_file""#));
+/// ```
+pub fn start_journal<P: AsRef<Path>>(path: P) {
+    replace_journal(Some(Journal::open(path.as_ref())));
+}
+
+/// Stops recording mutating operations to the active journal (see [`start_journal`]).
+///
+/// # Note
+///
+/// This does nothing if no journal is active.
+pub fn stop_journal() {
+    replace_journal(None);
+}
+
+/// Guard that records mutating operations to a journal until it is dropped (see
+/// [`start_scoped_journal`]).
+#[must_use = "operations are only recorded until the guard is dropped"]
+pub struct JournalGuard {
+    /// The journal that was active before this one was started (restored when the guard is
+    /// dropped).
+    previous: Option<Journal>,
+}
+
+impl Drop for JournalGuard {
+    fn drop(&mut self) {
+        replace_journal(self.previous.take());
+    }
+}
+
+/// Starts recording every mutating operation performed through this crate to a journal file,
+/// until the returned guard is dropped.
+///
+/// # Arguments
+///
+/// * `path` - The path to the journal file (can be a `&str`, [`String`], [`Path`], or
+///   [`std::path::PathBuf`]).
+///
+/// # Returns
+///
+/// A guard that stops recording to the journal when dropped (restoring any journal that was
+/// active before).
+///
+/// # Panics
+///
+/// If the journal file cannot be opened.
+///
+/// # Note
+///
+/// See [`start_journal`] for the format of the journal. Like [`start_journal`], operations
+/// performed on any thread are recorded while the guard is alive.
+///
+/// # Example
+///
+/// ```
+/// use file_io::{load_file_as_string, save_string_to_file, start_scoped_journal};
+///
+/// {
+///     let _journal = start_scoped_journal("folder/subfolder_40/scoped_journal.jsonl");
+///     save_string_to_file("Hello, world!", "folder/subfolder_40/file_48.txt");
+/// }
+///
+/// let journal = load_file_as_string("folder/subfolder_40/scoped_journal.jsonl");
+/// assert!(journal.contains(r#""operation":"save_string_to_file""#));
+/// ```
+pub fn start_scoped_journal<P: AsRef<Path>>(path: P) -> JournalGuard {
+    JournalGuard {
+        previous: replace_journal(Some(Journal::open(path.as_ref()))),
+    }
+}
+
+/// Determines whether a journal is currently active.
+///
+/// # Returns
+///
+/// `true` if mutating operations are currently being recorded to a journal, `false` otherwise.
+pub(crate) fn is_journal_active() -> bool {
+    ACTIVE.load(Ordering::Acquire)
+}
+
+/// Records a mutating operation to the active journal (if any).
+///
+/// # Arguments
+///
+/// * `operation` - The name of the operation.
+/// * `path` - The path that was mutated (or, for operations with a destination, the source path).
+/// * `to` - The destination path (if any).
+/// * `bytes` - The number of bytes written (if known).
+/// * `succeeded` - Whether the operation succeeded.
+///
+/// # Panics
+///
+/// If the entry cannot be written to the journal (unless the thread is already panicking).
+pub(crate) fn record_operation(
+    operation: &str,
+    path: &Path,
+    to: Option<&Path>,
+    bytes: Option<u64>,
+    succeeded: bool,
+) {
+    // Format the entry.
+    let mut entry = String::from("{\"timestamp\":");
+    write_json_string(&current_timestamp(), &mut entry);
+    entry.push_str(",\"operation\":");
+    write_json_string(operation, &mut entry);
+    entry.push_str(",\"path\":");
+    write_json_string(&path.to_string_lossy(), &mut entry);
+    if let Some(to) = to {
+        entry.push_str(",\"to\":");
+        write_json_string(&to.to_string_lossy(), &mut entry);
+    }
+    if let Some(bytes) = bytes {
+        entry.push_str(&format!(",\"bytes\":{bytes}"));
+    }
+    entry.push_str(",\"outcome\":");
+    write_json_string(if succeeded { "ok" } else { "failed" }, &mut entry);
+    entry.push_str("}\n");
+
+    // Write the entry in a single call, so that entries from different threads are not
+    // interleaved.
+    let mut journal = JOURNAL.lock().unwrap_or_else(|error| error.into_inner());
+    if let Some(Journal { path, file }) = journal.as_mut()
+        && file.write_all(entry.as_bytes()).is_err()
+        && !std::thread::panicking()
+    {
+        panic!("Failed to write to journal '{path:?}'.");
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::copy::copy_file;
+    use crate::delete::delete_file;
+    use crate::load::load_file_as_string;
+    use crate::modify::{
+        BackupMode, ModifyOptions, replace_str_in_files, replace_str_in_files_with_options,
+    };
+    use crate::permissions::{Permissions, set_permissions};
+    use crate::save::save_string_to_file;
+    use crate::test_utils::get_temp_dir_path;
+    use serial_test::serial;
+    use tempfile::tempdir;
+
+    #[test]
+    #[serial]
+    fn test_journal() {
+        // Create a temporary directory to work in.
+        let temp_dir = tempdir().unwrap();
+        let temp_dir_path = get_temp_dir_path(&temp_dir);
+        let file_path = temp_dir_path.join("file.txt");
+        let copy_path = temp_dir_path.join("copy.txt");
+        let journal_path = temp_dir_path.join("journal.jsonl");
+
+        // Only operations performed while the journal is active are recorded.
+        save_string_to_file("Hello", &file_path);
+        start_journal(&journal_path);
+        copy_file(&file_path, &copy_path);
+        let result =
+            std::panic::catch_unwind(|| copy_file(temp_dir_path.join("missing"), &copy_path));
+        stop_journal();
+        delete_file(&copy_path);
+        assert!(result.is_err());
+
+        // Check the recorded operations (other tests running at the same time may record
+        // operations of their own).
+        let journal = load_file_as_string(&journal_path);
+        let temp_dir_str = temp_dir_path.to_string_lossy();
+        let lines: Vec<&str> = journal
+            .lines()
+            .filter(|line| line.contains(&*temp_dir_str))
+            .collect();
+        assert_eq!(lines.len(), 2);
+        assert!(lines[0].starts_with("{\"timestamp\":\""));
+        assert!(lines[0].ends_with(&format!(
+            "\"operation\":\"copy_file\",\"path\":\"{}\",\"to\":\"{}\",\"bytes\":5,\
+             \"outcome\":\"ok\"}}",
+            file_path.display(),
+            copy_path.display()
+        )));
+        assert!(lines[1].contains("\"operation\":\"copy_file\""));
+        assert!(lines[1].ends_with("\"outcome\":\"failed\"}"));
+    }
+
+    #[test]
+    #[serial]
+    fn test_scoped_journal() {
+        // Create a temporary directory to work in.
+        let temp_dir = tempdir().unwrap();
+        let temp_dir_path = get_temp_dir_path(&temp_dir);
+        let outer_path = temp_dir_path.join("outer.jsonl");
+        let inner_path = temp_dir_path.join("inner.jsonl");
+
+        // A scoped journal temporarily replaces the active one.
+        start_journal(&outer_path);
+        save_string_to_file("1", temp_dir_path.join("1.txt"));
+        {
+            let _journal = start_scoped_journal(&inner_path);
+            save_string_to_file("2", temp_dir_path.join("2.txt"));
+        }
+        save_string_to_file("3", temp_dir_path.join("3.txt"));
+        stop_journal();
+        assert!(!is_journal_active());
+
+        // Check which journal each operation was recorded to.
+        let outer = load_file_as_string(&outer_path);
+        let inner = load_file_as_string(&inner_path);
+        let recorded = |journal: &str, name: &str| {
+            journal.contains(&*temp_dir_path.join(name).to_string_lossy())
+        };
+        assert!(recorded(&outer, "1.txt") && !recorded(&outer, "2.txt"));
+        assert!(recorded(&outer, "3.txt"));
+        assert!(!recorded(&inner, "1.txt") && recorded(&inner, "2.txt"));
+        assert!(!recorded(&inner, "3.txt"));
+    }
+
+    /// Loads the entries of a journal that concern a given path.
+    fn journal_entries(journal_path: &Path, path: &Path) -> Vec<String> {
+        let path = path.to_string_lossy();
+        load_file_as_string(journal_path)
+            .lines()
+            .filter(|line| line.contains(&format!("\"path\":\"{path}\"")))
+            .map(String::from)
+            .collect()
+    }
+
+    #[test]
+    #[serial]
+    fn test_journal_records_failed_replacement_of_read_only_file() {
+        // Create a temporary directory to work in.
+        let temp_dir = tempdir().unwrap();
+        let temp_dir_path = get_temp_dir_path(&temp_dir);
+        let folder_path = temp_dir_path.join("folder");
+        let file_path = folder_path.join("file.txt");
+        let journal_path = temp_dir_path.join("journal.jsonl");
+        save_string_to_file("Hello", &file_path);
+        set_permissions(
+            &file_path,
+            Permissions {
+                readonly: true,
+                mode: None,
+            },
+        );
+
+        // Privileged users (e.g. root) can write to read-only files, in which case there is no
+        // failure to check.
+        if File::options().write(true).open(&file_path).is_ok() {
+            return;
+        }
+
+        // The file cannot be written, so the replacement is journaled as failed.
+        start_journal(&journal_path);
+        let report = replace_str_in_files(&folder_path, "Hello", "Goodbye");
+        stop_journal();
+        assert_eq!(report.failures.len(), 1);
+        let entries = journal_entries(&journal_path, &file_path);
+        assert_eq!(entries.len(), 1);
+        assert!(entries[0].contains("\"operation\":\"replace_str_in_file\""));
+        assert!(entries[0].ends_with("\"outcome\":\"failed\"}"));
+        let entries = journal_entries(&journal_path, &folder_path);
+        assert!(entries[0].contains("\"operation\":\"replace_str_in_files\""));
+        assert!(entries[0].ends_with("\"outcome\":\"failed\"}"));
+    }
+
+    #[test]
+    #[serial]
+    fn test_journal_records_failed_replacement_backup() {
+        // Create a temporary directory to work in.
+        let temp_dir = tempdir().unwrap();
+        let temp_dir_path = get_temp_dir_path(&temp_dir);
+        let folder_path = temp_dir_path.join("folder");
+        let file_path = folder_path.join("file.txt");
+        let journal_path = temp_dir_path.join("journal.jsonl");
+        save_string_to_file("Hello", &file_path);
+
+        // The backup folder cannot be created since a file is in the way, so the replacement
+        // fails (without panicking) and is journaled as failed.
+        let blocker_path = temp_dir_path.join("blocker");
+        save_string_to_file("", &blocker_path);
+        let options = ModifyOptions {
+            backup: BackupMode::Folder(blocker_path),
+            ..Default::default()
+        };
+        start_journal(&journal_path);
+        let report = replace_str_in_files_with_options(&folder_path, "Hello", "Goodbye", &options);
+        stop_journal();
+        assert_eq!(report.failures.len(), 1);
+        assert_eq!(load_file_as_string(&file_path), "Hello");
+        let entries = journal_entries(&journal_path, &file_path);
+        assert_eq!(entries.len(), 1);
+        assert!(entries[0].contains("\"operation\":\"replace_str_in_file\""));
+        assert!(entries[0].ends_with("\"outcome\":\"failed\"}"));
+    }
+}
@@ -10,31 +10,158 @@
 #![warn(missing_docs)]
 
 // Module declarations.
+#[cfg(feature = "assertions")]
+pub(crate) mod assertions;
+pub(crate) mod backup;
 pub(crate) mod cd;
+pub(crate) mod change;
+#[cfg(feature = "config")]
+pub(crate) mod config;
 pub(crate) mod copy;
 pub(crate) mod create;
 pub(crate) mod delete;
+pub(crate) mod diff;
+pub(crate) mod dirs;
+pub(crate) mod dotenv;
+pub(crate) mod dry_run;
+#[cfg(feature = "encryption")]
+pub(crate) mod encryption;
+pub(crate) mod env;
+pub(crate) mod filesystem;
+pub(crate) mod fixture;
+pub(crate) mod follow;
+pub(crate) mod gitignore;
+pub(crate) mod hash;
+pub(crate) mod instrument;
+pub(crate) mod journal;
+pub(crate) mod link;
 pub(crate) mod list;
 pub(crate) mod load;
+pub(crate) mod metadata;
 pub(crate) mod modify;
+pub(crate) mod organize;
+#[cfg(all(unix, feature = "ownership"))]
+pub(crate) mod ownership;
 pub(crate) mod path;
+pub(crate) mod permissions;
 pub(crate) mod print;
+pub(crate) mod progress;
+pub(crate) mod properties;
+pub(crate) mod rename;
+pub(crate) mod sandbox;
 pub(crate) mod save;
+pub(crate) mod snapshot;
+pub(crate) mod temp;
+pub(crate) mod timestamp;
+pub(crate) mod update;
+pub(crate) mod versions;
+#[cfg(feature = "xml")]
+pub(crate) mod xml;
 
 // Re-exports.
-pub use cd::{CdGuard, cd};
-pub use copy::{copy_file, copy_folder};
-pub use create::{create_folder, create_folder_for_file};
-pub use delete::{delete_file, delete_folder};
-pub use list::list_folder_contents;
-pub use load::load_file_as_string;
-pub use modify::{replace_str_in_file, replace_str_in_files};
+#[cfg(feature = "assertions")]
+pub use assertions::{
+    assert_file_contains, assert_file_eq, assert_file_exists, assert_folder_contains_exactly,
+    assert_trees_equal,
+};
+pub use backup::{
+    BackupOptions, backup_file, backup_file_with_options, backup_folder,
+    backup_folder_with_options, restore_backup,
+};
+pub use cd::{CdGuard, DirStack, TempDirCdGuard, cd, cd_into_temp_dir, cd_locked, in_dir};
+pub use change::{ChangeToken, has_file_changed};
+#[cfg(feature = "config")]
+pub use config::{LayeredConfig, load_layered_config};
+pub use copy::{
+    CopyOptions, RetryOptions, copy_file, copy_file_to_unique, copy_file_to_writer,
+    copy_file_to_writer_with_buffer_size, copy_file_with_options, copy_files_to_folder,
+    copy_folder, copy_folder_with_options, copy_folder_with_progress, copy_glob_to_folder,
+};
+pub use create::{
+    CreateFileOptions, create_empty_file, create_empty_file_with_options, create_folder,
+    create_folder_for_file, create_folder_tree_from_string, touch_file,
+};
+pub use delete::{delete_file, delete_folder, delete_folder_with_progress};
+pub use dirs::{get_cache_dir, get_config_dir, get_data_dir, get_downloads_dir, xdg_runtime_dir};
+pub use dotenv::{apply_dotenv, load_dotenv_file, save_dotenv_file};
+pub use dry_run::{DryRun, DryRunGuard, PlannedOperation};
+#[cfg(feature = "encryption")]
+pub use encryption::{load_file_as_string_encrypted, save_string_to_file_encrypted};
+pub use env::{EnvGuard, set_env_var_scoped};
+pub use filesystem::{
+    FileSystem, FsOperation, MemoryFileSystem, OsFileSystem, RecordingFileSystem,
+};
+pub use fixture::{FixtureBuilder, create_tree};
+pub use follow::{
+    FollowOptions, follow_file, follow_file_with_options, wait_for_file, wait_for_file_stable,
+};
+pub use hash::{
+    folder_digest, hash_file, hash_folder, hash_folder_parallel, hash_folder_with_progress,
+};
+pub use journal::{JournalGuard, start_journal, start_scoped_journal, stop_journal};
+pub use link::{
+    DeduplicationReport, are_hardlinked, create_hardlink, create_symlink, hardlink_duplicates,
+    is_symlink, read_symlink,
+};
+pub use list::{ListOptions, list_folder_contents, list_folder_contents_with_options};
+pub use load::{
+    ReverseLines, count_chars, count_lines, count_words, head_bytes_of_file, head_of_file,
+    is_binary_file, load_file_as_base64, load_file_as_string, load_file_or_default,
+    load_file_or_stdin, load_or_create, read_file_range, read_lines_reverse, tail_bytes_of_file,
+    tail_of_file,
+};
+pub use metadata::{
+    EntryType, get_accessed_time, get_created_time, get_entry_type, get_file_size,
+    get_modified_time, is_hidden, set_accessed_time, set_modified_time,
+};
+pub use modify::{
+    BackupMode, FileBackup, FileDiff, ModifyOptions, ReplaceFailure, ReplaceReport,
+    replace_str_in_file, replace_str_in_file_with_backup, replace_str_in_files,
+    replace_str_in_files_with_options, replace_str_in_files_with_progress, restore_backups,
+};
+pub use organize::{OrganizeBy, organize_folder};
+#[cfg(all(unix, feature = "ownership"))]
+pub use ownership::{
+    chown_file, chown_file_by_name, chown_folder_recursive, chown_folder_recursive_by_name,
+    get_gid_by_name, get_uid_by_name,
+};
 pub use path::{
-    get_cwd, get_file_extension, get_file_name, get_file_stem, get_home, get_last_path_component,
-    to_path_buf,
+    Tilde, absolute_path, absolute_path_from, add_prefix_to_stem, add_suffix_to_stem,
+    canonicalize_path, canonicalize_path_for_display, common_ancestor, expand_tilde, get_cwd,
+    get_file_extension, get_file_name, get_file_stem, get_home, get_home_path,
+    get_last_path_component, has_extension, has_extension_ignore_case, is_inside, normalize_path,
+    path_depth, paths_equal_ignore_case, relative_path_between, remove_extension, safe_join,
+    safe_join_clamped, split_path, to_forward_slashes, to_long_path, to_native_separators,
+    to_path_buf, try_get_home, unique_path, with_extension,
+};
+pub use permissions::{Permissions, get_permissions, make_executable, set_permissions};
+pub use print::{
+    ColorMode, LineNumberOptions, TreeNode, TreeOptions, TreeSort, folder_tree_to_json,
+    folder_tree_to_json_with_options, folder_tree_to_string, folder_tree_to_string_with_options,
+    get_folder_tree, get_folder_tree_with_options, hex_dump_to_string,
+    print_file_with_line_numbers, print_file_with_line_numbers_with_options, print_folder_tree,
+    print_folder_tree_with_options, print_hex_dump, write_file_with_line_numbers,
+    write_folder_tree, write_folder_tree_with_options,
+};
+pub use progress::{NoProgress, ProgressSink};
+pub use properties::{load_properties_file, save_properties_file};
+pub use rename::{
+    CaseStyle, FileRename, RenameOptions, RenameReport, RenameRule, change_extension_in_folder,
+    rename_files, rename_files_with_options, rename_to_case, rename_to_lowercase,
+    slugify_filenames,
+};
+pub use sandbox::{Sandbox, SandboxMode};
+pub use save::{
+    SaveOptions, save_base64_to_file, save_lines_streaming, save_reader_to_file,
+    save_string_to_file, save_string_to_file_or_stdout, save_string_to_file_with_options,
+    save_string_to_unique_file, truncate_file, write_bytes_at,
 };
-pub use print::print_folder_tree;
-pub use save::save_string_to_file;
+pub use snapshot::{FolderSnapshot, SnapshotDiff, SnapshotEntry, snapshot_folder};
+pub use temp::{ScratchDir, TempFileGuard, TempFolderGuard};
+pub use update::update_file_locked;
+pub use versions::{list_versions, restore_version, save_string_to_file_versioned};
+#[cfg(feature = "xml")]
+pub use xml::{load_xml, save_xml};
 
 // Helper functions for unit testing.
 #[cfg(test)]
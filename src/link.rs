@@ -0,0 +1,461 @@
+use crate::create::create_folder_for_file;
+use crate::hash::hash_folder;
+use crate::instrument::Mutation;
+use crate::path::{add_suffix_to_stem, unique_path};
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+
+/// Creates a symbolic link.
+///
+/// # Arguments
+///
+/// * `target` - The path the link should point to (can be a `&str`, [`String`], [`Path`], or
+///   [`PathBuf`]). A relative target is interpreted relative to the folder containing the link.
+/// * `link` - The path of the link to create (can be a `&str`, [`String`], [`Path`], or
+///   [`PathBuf`]).
+///
+/// # Panics
+///
+/// If the link cannot be created (e.g. because something already exists at `link`).
+///
+/// # Note
+///
+/// * The parent folder for the link will be created if it does not already exist.
+/// * On Windows, file and folder symbolic links are different kinds of links. A folder symbolic
+///   link is created if `target` is an existing folder, and a file symbolic link is created
+///   otherwise.
+///
+/// # Example
+///
+/// ```
+/// use file_io::{create_symlink, delete_file, is_symlink, read_symlink, save_string_to_file};
+/// use std::path::Path;
+///
+/// // Create a file.
+/// save_string_to_file("Hello, world!", "folder/subfolder_19/file_14.txt");
+///
+/// // Create a (relative) symbolic link to the file.
+/// let link: &str = "folder/subfolder_19/link_1";
+/// delete_file(link);
+/// create_symlink("file_14.txt", link);
+///
+/// // Check the link.
+/// assert!(is_symlink(link));
+/// assert_eq!(read_symlink(link), Path::new("file_14.txt"));
+/// ```
+pub fn create_symlink<P: AsRef<Path>, Q: AsRef<Path>>(target: P, link: Q) {
+    let target = target.as_ref();
+    let link = link.as_ref();
+    let mut mutation = Mutation::start("create_symlink", link).to(target);
+    if mutation.is_dry_run() {
+        return;
+    }
+    create_folder_for_file(link);
+
+    #[cfg(unix)]
+    let result = std::os::unix::fs::symlink(target, link);
+
+    #[cfg(windows)]
+    let result = {
+        let resolved_target = link.parent().unwrap_or(Path::new("")).join(target);
+        if resolved_target.is_dir() {
+            std::os::windows::fs::symlink_dir(target, link)
+        } else {
+            std::os::windows::fs::symlink_file(target, link)
+        }
+    };
+
+    result.unwrap_or_else(|_| {
+        panic!("Failed to create symbolic link at '{link:?}' pointing to '{target:?}'.")
+    });
+    mutation.succeeded();
+}
+
+/// Reads the target of a symbolic link.
+///
+/// # Arguments
+///
+/// * `link` - The path to the symbolic link (can be a `&str`, [`String`], [`Path`], or
+///   [`PathBuf`]).
+///
+/// # Returns
+///
+/// The path the link points to (exactly as stored in the link, i.e. it may be relative).
+///
+/// # Panics
+///
+/// If `link` is not a symbolic link or cannot be read.
+///
+/// # Example
+///
+/// See [`create_symlink`].
+pub fn read_symlink<P: AsRef<Path>>(link: P) -> PathBuf {
+    let link = link.as_ref();
+    std::fs::read_link(link)
+        .unwrap_or_else(|_| panic!("Failed to read symbolic link at '{link:?}'."))
+}
+
+/// Determines whether a path is a symbolic link.
+///
+/// # Arguments
+///
+/// * `path` - The path to check (can be a `&str`, [`String`], [`Path`], or [`PathBuf`]).
+///
+/// # Returns
+///
+/// `true` if `path` is a symbolic link (even if its target does not exist), `false` otherwise.
+///
+/// # Example
+///
+/// ```
+/// use file_io::is_symlink;
+///
+/// assert!(!is_symlink("Cargo.toml"));
+/// ```
+pub fn is_symlink<P: AsRef<Path>>(path: P) -> bool {
+    path.as_ref().is_symlink()
+}
+
+/// Creates a hard link (i.e. a new name for an existing file).
+///
+/// # Arguments
+///
+/// * `src` - The path to the existing file (can be a `&str`, [`String`], [`Path`], or
+///   [`PathBuf`]).
+/// * `link` - The path of the hard link to create (can be a `&str`, [`String`], [`Path`], or
+///   [`PathBuf`]).
+///
+/// # Panics
+///
+/// If the hard link cannot be created (e.g. because `src` does not exist, something already exists
+/// at `link`, or `src` and `link` are on different filesystems).
+///
+/// # Note
+///
+/// The parent folder for the link will be created if it does not already exist.
+///
+/// # Example
+///
+/// ```
+/// use file_io::{are_hardlinked, create_hardlink, delete_file, save_string_to_file};
+///
+/// // Create a file.
+/// let src: &str = "folder/subfolder_20/file_15.txt";
+/// save_string_to_file("Hello, world!", src);
+///
+/// // Create a hard link to the file.
+/// let link: &str = "folder/subfolder_20/link_2.txt";
+/// delete_file(link);
+/// create_hardlink(src, link);
+///
+/// // Both paths refer to the same file.
+/// assert!(are_hardlinked(src, link));
+/// ```
+pub fn create_hardlink<P: AsRef<Path>, Q: AsRef<Path>>(src: P, link: Q) {
+    let src = src.as_ref();
+    let link = link.as_ref();
+    let mut mutation = Mutation::start("create_hardlink", link).to(src);
+    if mutation.is_dry_run() {
+        return;
+    }
+    create_folder_for_file(link);
+    std::fs::hard_link(src, link).unwrap_or_else(|_| {
+        panic!("Failed to create hard link at '{link:?}' pointing to '{src:?}'.")
+    });
+    mutation.succeeded();
+}
+
+/// Determines whether two paths refer to the same underlying file (i.e. they are hard links to
+/// each other).
+///
+/// # Arguments
+///
+/// * `a` - The first path (can be a `&str`, [`String`], [`Path`], or [`PathBuf`]).
+/// * `b` - The second path (can be a `&str`, [`String`], [`Path`], or [`PathBuf`]).
+///
+/// # Returns
+///
+/// `true` if both paths refer to the same file, `false` otherwise.
+///
+/// # Panics
+///
+/// If either path does not exist or cannot be accessed.
+///
+/// # Note
+///
+/// * The comparison is based on the device and inode numbers (or the volume serial number and file
+///   index on Windows).
+/// * Symbolic links are followed, so a symbolic link is considered to refer to the same file as its
+///   target. Similarly, a path always refers to the same file as itself.
+///
+/// # Example
+///
+/// See [`create_hardlink`].
+pub fn are_hardlinked<P: AsRef<Path>, Q: AsRef<Path>>(a: P, b: Q) -> bool {
+    let a = a.as_ref();
+    let b = b.as_ref();
+    same_file::is_same_file(a, b)
+        .unwrap_or_else(|_| panic!("Failed to compare files '{a:?}' and '{b:?}'."))
+}
+
+/// Report of the duplicate files replaced by [`hardlink_duplicates`].
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct DeduplicationReport {
+    /// Duplicate files that were replaced by hard links, each paired with the file it is now
+    /// hard-linked to (in alphabetical order of the duplicates).
+    pub linked: Vec<(PathBuf, PathBuf)>,
+
+    /// Number of bytes saved by replacing the duplicates with hard links.
+    pub bytes_saved: u64,
+}
+
+/// Replaces duplicate files within a folder (recursively) by hard links to a single copy.
+///
+/// # Arguments
+///
+/// * `path` - The path to the folder (can be a `&str`, [`String`], [`Path`], or [`PathBuf`]).
+///
+/// # Returns
+///
+/// A report of the duplicates that were replaced by hard links, and the number of bytes saved.
+///
+/// # Panics
+///
+/// If the folder cannot be traversed, any file cannot be hashed, or a duplicate cannot be replaced
+/// by a hard link (e.g. because the folder spans multiple filesystems).
+///
+/// # Note
+///
+/// * Files are considered duplicates if their contents have the same SHA-256 hash (see
+///   [`crate::hash_folder`]). Within each group of duplicates, the file that comes first in
+///   alphabetical order is kept, and all others are replaced by hard links to it.
+/// * Empty files and files that are already hard-linked to the kept file are left untouched.
+/// * Hard links share their metadata (e.g. permissions and modification times), so the duplicates
+///   take on the metadata of the kept file.
+/// * Each duplicate is replaced by first creating the hard link under a temporary name and then
+///   renaming it over the duplicate, so the duplicate's path always refers to a complete file.
+/// * Symbolic links are not followed.
+///
+/// # Example
+///
+/// ```
+/// use file_io::{are_hardlinked, hardlink_duplicates, save_string_to_file};
+///
+/// save_string_to_file("Hello, world!", "folder/subfolder_49/file_50.txt");
+/// save_string_to_file("Hello, world!", "folder/subfolder_49/copy/file_50.txt");
+///
+/// hardlink_duplicates("folder/subfolder_49");
+///
+/// assert!(are_hardlinked(
+///     "folder/subfolder_49/file_50.txt",
+///     "folder/subfolder_49/copy/file_50.txt"
+/// ));
+/// ```
+pub fn hardlink_duplicates<P: AsRef<Path>>(path: P) -> DeduplicationReport {
+    let path = path.as_ref();
+    let mut mutation = Mutation::start_composite("hardlink_duplicates", path);
+
+    // Group the files by the hashes of their contents (the paths within each group are in
+    // alphabetical order since the map of hashes is sorted by path).
+    let mut groups: BTreeMap<String, Vec<PathBuf>> = BTreeMap::new();
+    for (relative_path, hash) in hash_folder(path) {
+        groups
+            .entry(hash)
+            .or_default()
+            .push(path.join(relative_path));
+    }
+
+    // Replace the duplicates within each group by hard links to the first file.
+    let mut report = DeduplicationReport::default();
+    for files in groups.values() {
+        let (original, duplicates) = files.split_first().unwrap();
+        let size = std::fs::metadata(original)
+            .unwrap_or_else(|_| panic!("Failed to get metadata for '{original:?}'."))
+            .len();
+        if size == 0 {
+            continue;
+        }
+        for duplicate in duplicates {
+            if are_hardlinked(original, duplicate) {
+                continue;
+            }
+            let mut mutation = Mutation::start("replace_with_hardlink", duplicate).to(original);
+            mutation.bytes(size);
+            if !mutation.is_dry_run() {
+                let temporary_path = unique_path(add_suffix_to_stem(duplicate, ".linking"));
+                std::fs::hard_link(original, &temporary_path)
+                    .and_then(|_| std::fs::rename(&temporary_path, duplicate))
+                    .unwrap_or_else(|_| {
+                        let _ = std::fs::remove_file(&temporary_path);
+                        panic!(
+                            "Failed to replace '{duplicate:?}' by a hard link to '{original:?}'."
+                        )
+                    });
+                mutation.succeeded();
+            }
+            report.linked.push((duplicate.clone(), original.clone()));
+            report.bytes_saved += size;
+        }
+    }
+    report.linked.sort();
+    mutation.succeeded();
+    report
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::load::load_file_as_string;
+    use crate::save::save_string_to_file;
+    use crate::test_utils::get_temp_dir_path;
+    use tempfile::tempdir;
+
+    #[test]
+    fn test_create_read_symlink_file() {
+        // Create a temporary directory to work in.
+        let temp_dir = tempdir().unwrap();
+        let temp_dir_path = get_temp_dir_path(&temp_dir);
+
+        // Create a file.
+        let file_path = temp_dir_path.join("file.txt");
+        save_string_to_file("Hello, world!", &file_path);
+
+        // Link path.
+        let link_path = temp_dir_path.join("links/link");
+
+        // Link path in different formats.
+        let link_paths: Vec<Box<dyn AsRef<Path>>> = vec![
+            Box::new(link_path.to_str().unwrap()),             // &str
+            Box::new(link_path.to_str().unwrap().to_string()), // String
+            Box::new(link_path.as_path()),                     // Path
+            Box::new(link_path.clone()),                       // PathBuf
+        ];
+
+        // Test with all different path formats.
+        for link_path in link_paths {
+            // Get a reference to this path representation (i.e. "unbox").
+            let link_path = link_path.as_ref();
+
+            // Create a symbolic link to the file (the parent folder should be created).
+            create_symlink(&file_path, link_path);
+
+            // Check the link.
+            assert!(is_symlink(link_path));
+            assert_eq!(read_symlink(link_path), file_path);
+            assert_eq!(load_file_as_string(link_path), "Hello, world!");
+
+            // Delete the link (but not the file).
+            std::fs::remove_file(link_path).unwrap();
+            assert!(file_path.exists());
+        }
+
+        // The file itself is not a symbolic link.
+        assert!(!is_symlink(&file_path));
+    }
+
+    #[test]
+    fn test_create_symlink_folder_and_dangling() {
+        // Create a temporary directory to work in.
+        let temp_dir = tempdir().unwrap();
+        let temp_dir_path = get_temp_dir_path(&temp_dir);
+
+        // Create a file in a folder.
+        save_string_to_file("Hello, world!", temp_dir_path.join("folder/file.txt"));
+
+        // Create a relative symbolic link to the folder.
+        let link_path = temp_dir_path.join("folder_link");
+        create_symlink("folder", &link_path);
+        assert!(is_symlink(&link_path));
+        assert_eq!(read_symlink(&link_path), PathBuf::from("folder"));
+        assert_eq!(
+            load_file_as_string(link_path.join("file.txt")),
+            "Hello, world!"
+        );
+
+        // A dangling link is still a symbolic link.
+        let dangling_path = temp_dir_path.join("dangling");
+        create_symlink("missing.txt", &dangling_path);
+        assert!(is_symlink(&dangling_path));
+        assert!(!dangling_path.exists());
+    }
+
+    #[test]
+    fn test_create_hardlink() {
+        // Create a temporary directory to work in.
+        let temp_dir = tempdir().unwrap();
+        let temp_dir_path = get_temp_dir_path(&temp_dir);
+
+        // Create two files with identical content.
+        let file_path = temp_dir_path.join("file.txt");
+        let copy_path = temp_dir_path.join("copy.txt");
+        save_string_to_file("Hello, world!", &file_path);
+        save_string_to_file("Hello, world!", &copy_path);
+
+        // Create a hard link to the first file (the parent folder should be created).
+        let link_path = temp_dir_path.join("links/link.txt");
+        create_hardlink(&file_path, &link_path);
+
+        // The link and the file are the same file, but the copy is a different file.
+        assert!(are_hardlinked(&file_path, &link_path));
+        assert!(!are_hardlinked(&file_path, &copy_path));
+
+        // Modifying the file through the link modifies the original file.
+        save_string_to_file("Goodbye, world!", &link_path);
+        assert_eq!(load_file_as_string(&file_path), "Goodbye, world!");
+    }
+
+    #[test]
+    fn test_hardlink_duplicates() {
+        // Create a temporary directory to work in.
+        let temp_dir = tempdir().unwrap();
+        let temp_dir_path = get_temp_dir_path(&temp_dir);
+        save_string_to_file("same", temp_dir_path.join("a.txt"));
+        save_string_to_file("same", temp_dir_path.join("nested/b.txt"));
+        save_string_to_file("same", temp_dir_path.join("nested/c.txt"));
+        save_string_to_file("different", temp_dir_path.join("d.txt"));
+        save_string_to_file("", temp_dir_path.join("empty_1.txt"));
+        save_string_to_file("", temp_dir_path.join("empty_2.txt"));
+
+        // Replace the duplicates by hard links.
+        let report = hardlink_duplicates(&temp_dir_path);
+        assert_eq!(
+            report,
+            DeduplicationReport {
+                linked: vec![
+                    (
+                        temp_dir_path.join("nested/b.txt"),
+                        temp_dir_path.join("a.txt")
+                    ),
+                    (
+                        temp_dir_path.join("nested/c.txt"),
+                        temp_dir_path.join("a.txt")
+                    ),
+                ],
+                bytes_saved: 8,
+            }
+        );
+        assert!(are_hardlinked(
+            temp_dir_path.join("a.txt"),
+            temp_dir_path.join("nested/b.txt")
+        ));
+        assert!(are_hardlinked(
+            temp_dir_path.join("a.txt"),
+            temp_dir_path.join("nested/c.txt")
+        ));
+        assert!(!are_hardlinked(
+            temp_dir_path.join("empty_1.txt"),
+            temp_dir_path.join("empty_2.txt")
+        ));
+        assert_eq!(
+            load_file_as_string(temp_dir_path.join("nested/c.txt")),
+            "same"
+        );
+        assert!(!temp_dir_path.join("nested/b.linking.txt").exists());
+
+        // Running again does nothing, since the duplicates are already hard-linked.
+        assert_eq!(
+            hardlink_duplicates(&temp_dir_path),
+            DeduplicationReport::default()
+        );
+    }
+}
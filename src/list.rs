@@ -1,5 +1,28 @@
+use crate::metadata::is_hidden;
 use std::path::{Path, PathBuf};
 
+/// Options controlling which entries are returned by [`list_folder_contents_with_options`].
+///
+/// The default options return every entry, which matches the behavior of
+/// [`list_folder_contents`].
+///
+/// # Example
+///
+/// ```
+/// use file_io::ListOptions;
+///
+/// // Skip hidden files and folders.
+/// let options = ListOptions {
+///     skip_hidden: true,
+///     ..Default::default()
+/// };
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct ListOptions {
+    /// If `true`, hidden files and folders (see [`crate::is_hidden`]) are not listed.
+    pub skip_hidden: bool,
+}
+
 /// Lists the contents of a folder at the specified path.
 ///
 /// # Arguments
@@ -45,6 +68,43 @@ use std::path::{Path, PathBuf};
 /// );
 /// ```
 pub fn list_folder_contents<P: AsRef<Path>>(path: P) -> Vec<PathBuf> {
+    list_folder_contents_with_options(path, &ListOptions::default())
+}
+
+/// Lists the contents of a folder at the specified path, with options controlling which entries
+/// are listed.
+///
+/// # Arguments
+///
+/// * `path` - The path to the folder (can be a `&str`, [`String`], [`Path`], or [`PathBuf`]).
+/// * `options` - Options controlling which entries are listed.
+///
+/// # Returns
+///
+/// Paths of the files and folders in the specified directory (in alphabetical order), excluding
+/// any entries filtered out by `options`.
+///
+/// # Panics
+///
+/// If the provided path is not a folder or if an error occurs while reading the folder.
+///
+/// # Example
+///
+/// ```
+/// use file_io::{ListOptions, list_folder_contents_with_options};
+///
+/// let options = ListOptions {
+///     skip_hidden: true,
+/// };
+/// let contents = list_folder_contents_with_options(".", &options);
+///
+/// # #[cfg(unix)]
+/// assert!(!contents.iter().any(|path| path.ends_with(".vscode")));
+/// ```
+pub fn list_folder_contents_with_options<P: AsRef<Path>>(
+    path: P,
+    options: &ListOptions,
+) -> Vec<PathBuf> {
     // Convert the input path to a Path reference.
     let path = path.as_ref();
 
@@ -58,6 +118,7 @@ pub fn list_folder_contents<P: AsRef<Path>>(path: P) -> Vec<PathBuf> {
         Ok(entries) => entries
             .filter_map(Result::ok)
             .map(|e| e.path())
+            .filter(|path| !(options.skip_hidden && is_hidden(path)))
             .collect::<Vec<PathBuf>>(),
         Err(_) => panic!("Failed to read directory: {path:?}"),
     };
@@ -101,4 +162,29 @@ mod tests {
             ]
         );
     }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_list_folder_contents_skip_hidden() {
+        // Create a temporary directory to work in.
+        let temp_dir = tempdir().unwrap();
+
+        // Get the path to the temporary directory.
+        let temp_dir_path = get_temp_dir_path(&temp_dir);
+
+        // Create visible and hidden files and folders.
+        save_string_to_file("Content 1", temp_dir_path.join("file.txt"));
+        save_string_to_file("Content 2", temp_dir_path.join(".hidden.txt"));
+        save_string_to_file("Content 3", temp_dir_path.join(".git/config"));
+
+        // Hidden entries are listed by default.
+        assert_eq!(list_folder_contents(&temp_dir_path).len(), 3);
+
+        // Hidden entries are skipped on request.
+        let options = ListOptions { skip_hidden: true };
+        assert_eq!(
+            list_folder_contents_with_options(&temp_dir_path, &options),
+            vec![temp_dir_path.join("file.txt")]
+        );
+    }
 }
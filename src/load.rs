@@ -1,4 +1,29 @@
-use std::path::Path;
+use crate::save::save_string_to_file;
+use base64::Engine;
+use base64::engine::general_purpose::STANDARD;
+use std::collections::VecDeque;
+use std::io::{BufRead, ErrorKind, Read, Seek, SeekFrom};
+use std::path::{Path, PathBuf};
+
+/// Number of bytes inspected at the start of a file when determining whether it is binary.
+const BINARY_SNIFF_LENGTH: usize = 8192;
+
+/// Determines whether a chunk of bytes (taken from the start of a file) looks like binary data.
+///
+/// # Arguments
+///
+/// * `bytes` - The bytes to inspect.
+///
+/// # Returns
+///
+/// `true` if the bytes contain a NUL byte or are not valid UTF-8, `false` otherwise.
+///
+/// # Note
+///
+/// A multi-byte UTF-8 character that is cut off at the end of `bytes` is not considered invalid.
+pub(crate) fn is_binary(bytes: &[u8]) -> bool {
+    bytes.contains(&0) || std::str::from_utf8(bytes).is_err_and(|error| error.error_len().is_some())
+}
 
 /// Loads the content of a file as a string.
 ///
@@ -60,10 +85,792 @@ pub fn load_file_as_string<P: AsRef<Path>>(path: P) -> String {
     std::fs::read_to_string(path).unwrap_or_else(|_| panic!("Failed to read file at '{path:?}'."))
 }
 
+/// Loads the content of a file as a string, falling back to default content if the file does not
+/// exist.
+///
+/// # Arguments
+///
+/// * `path` - The path to the file to load (can be a `&str`, [`String`], [`Path`], or
+///   [`std::path::PathBuf`]).
+/// * `default` - The content to return if the file does not exist.
+///
+/// # Returns
+///
+/// The contents of the file as a string, or `default` if the file does not exist.
+///
+/// # Panics
+///
+/// If the file exists but cannot be read.
+///
+/// # Note
+///
+/// The file is not created if it does not exist (see [`load_or_create`]).
+///
+/// # Example
+///
+/// ```
+/// use file_io::load_file_or_default;
+///
+/// let config = load_file_or_default("folder/subfolder_51/missing.toml", "verbose = false\n");
+/// assert_eq!(config, "verbose = false\n");
+/// ```
+pub fn load_file_or_default<P: AsRef<Path>>(path: P, default: &str) -> String {
+    let path = path.as_ref();
+    match std::fs::read_to_string(path) {
+        Ok(content) => content,
+        Err(error) if error.kind() == ErrorKind::NotFound => default.to_string(),
+        Err(_) => panic!("Failed to read file at '{path:?}'."),
+    }
+}
+
+/// Loads the content of a file as a string, creating the file with default content if it does not
+/// exist.
+///
+/// # Arguments
+///
+/// * `path` - The path to the file to load (can be a `&str`, [`String`], [`Path`], or
+///   [`std::path::PathBuf`]).
+/// * `default` - The content to create the file with if it does not exist.
+///
+/// # Returns
+///
+/// The contents of the file as a string (i.e. `default` if the file was created).
+///
+/// # Panics
+///
+/// If the file exists but cannot be read, or if it does not exist and cannot be created.
+///
+/// # Note
+///
+/// This function will create the parent folder for the file if it does not already exist.
+///
+/// # Example
+///
+/// ```
+/// use file_io::{delete_file, load_file_as_string, load_or_create};
+///
+/// let path: &str = "folder/subfolder_51/file_53.toml";
+/// delete_file(path);
+///
+/// // The file is created with the default content.
+/// assert_eq!(load_or_create(path, "verbose = false\n"), "verbose = false\n");
+/// assert_eq!(load_file_as_string(path), "verbose = false\n");
+///
+/// // Once the file exists, its content is loaded instead.
+/// assert_eq!(load_or_create(path, "verbose = true\n"), "verbose = false\n");
+/// ```
+pub fn load_or_create<P: AsRef<Path>>(path: P, default: &str) -> String {
+    let path = path.as_ref();
+    match std::fs::read_to_string(path) {
+        Ok(content) => content,
+        Err(error) if error.kind() == ErrorKind::NotFound => {
+            save_string_to_file(default, path);
+            default.to_string()
+        }
+        Err(_) => panic!("Failed to read file at '{path:?}'."),
+    }
+}
+
+/// Loads the content of a file as a string, reading from standard input if the path is `-`.
+///
+/// # Arguments
+///
+/// * `path` - The path to the file to load (can be a `&str`, [`String`], [`Path`], or
+///   [`std::path::PathBuf`]), or `-` to read from standard input.
+///
+/// # Returns
+///
+/// The contents of the file (or of standard input) as a string.
+///
+/// # Panics
+///
+/// If the file (or standard input) cannot be read.
+///
+/// # Note
+///
+/// This follows the common convention of command-line tools, so that they can be composed with
+/// pipes. Standard input is read until its end.
+///
+/// # Example
+///
+/// ```
+/// use file_io::{load_file_or_stdin, save_string_to_file};
+///
+/// let path: &str = "folder/subfolder_56/file_58.txt";
+/// save_string_to_file("Hello, world!", path);
+///
+/// assert_eq!(load_file_or_stdin(path), "Hello, world!");
+/// ```
+pub fn load_file_or_stdin<P: AsRef<Path>>(path: P) -> String {
+    let path = path.as_ref();
+    if path != Path::new("-") {
+        return load_file_as_string(path);
+    }
+    let mut content = String::new();
+    std::io::stdin()
+        .read_to_string(&mut content)
+        .expect("Failed to read from standard input.");
+    content
+}
+
+/// Determines whether a file is binary (i.e. not a UTF-8 text file).
+///
+/// # Arguments
+///
+/// * `path` - The path to the file (can be a `&str`, [`String`], [`Path`], or
+///   [`std::path::PathBuf`]).
+///
+/// # Returns
+///
+/// `true` if the file is binary, `false` if it is a text file.
+///
+/// # Panics
+///
+/// If the file cannot be read.
+///
+/// # Note
+///
+/// Only the first 8 KiB of the file are inspected. The file is considered binary if this portion
+/// contains a NUL byte or is not valid UTF-8.
+///
+/// # Example
+///
+/// ```
+/// use file_io::{is_binary_file, save_string_to_file};
+///
+/// // Create a text file.
+/// let path: &str = "folder/subfolder_14/file_9.txt";
+/// save_string_to_file("Hello, world!", path);
+///
+/// // The file is not binary.
+/// assert!(!is_binary_file(path));
+/// ```
+pub fn is_binary_file<P: AsRef<Path>>(path: P) -> bool {
+    let path = path.as_ref();
+    let mut bytes = Vec::with_capacity(BINARY_SNIFF_LENGTH);
+    std::fs::File::open(path)
+        .and_then(|file| {
+            file.take(BINARY_SNIFF_LENGTH as u64)
+                .read_to_end(&mut bytes)
+        })
+        .unwrap_or_else(|_| panic!("Failed to read file at '{path:?}'."));
+    is_binary(&bytes)
+}
+
+/// Number of bytes read at a time when reading a file backwards from its end.
+const REVERSE_CHUNK_LENGTH: u64 = 8192;
+
+/// Splits bytes into lines.
+///
+/// # Arguments
+///
+/// * `bytes` - The bytes to split.
+///
+/// # Returns
+///
+/// The lines (without their `\n` or `\r\n` line endings). Invalid UTF-8 is replaced with `U+FFFD`.
+fn split_lines(bytes: &[u8]) -> Vec<String> {
+    String::from_utf8_lossy(bytes)
+        .lines()
+        .map(String::from)
+        .collect()
+}
+
+/// Iterator over the lines of a file in reverse order (see [`read_lines_reverse`]).
+#[derive(Debug)]
+pub struct ReverseLines {
+    /// The path to the file (used in panic messages).
+    path: PathBuf,
+
+    /// The open file.
+    file: std::fs::File,
+
+    /// The offset up to which the file has not been read yet (reading proceeds backwards).
+    position: u64,
+
+    /// Chunks of bytes that have been read but not returned yet, in the order they appear in the
+    /// file. Only the first chunk may contain line breaks, so the other chunks (which hold the
+    /// start of a long line) are never scanned or copied again until the line is returned.
+    chunks: VecDeque<Vec<u8>>,
+
+    /// Whether all lines have been returned.
+    finished: bool,
+}
+
+impl ReverseLines {
+    /// Reads the chunk of the file preceding the current position into the pending chunks.
+    ///
+    /// # Panics
+    ///
+    /// If the file cannot be read.
+    fn read_chunk(&mut self) {
+        let chunk_length = REVERSE_CHUNK_LENGTH.min(self.position);
+        self.position -= chunk_length;
+        let mut chunk = vec![0; chunk_length as usize];
+        self.file
+            .seek(SeekFrom::Start(self.position))
+            .and_then(|_| self.file.read_exact(&mut chunk))
+            .unwrap_or_else(|_| panic!("Failed to read file at '{:?}'.", self.path));
+        self.chunks.push_front(chunk);
+    }
+}
+
+impl Iterator for ReverseLines {
+    type Item = String;
+
+    fn next(&mut self) -> Option<String> {
+        if self.finished {
+            return None;
+        }
+        loop {
+            // Return the line following the last line break in the first chunk (along with the
+            // rest of the line in the following chunks).
+            if let Some(first_chunk) = self.chunks.front_mut()
+                && let Some(index) = first_chunk.iter().rposition(|&byte| byte == b'\n')
+            {
+                let mut line = first_chunk.split_off(index + 1);
+                first_chunk.truncate(index);
+                for chunk in self.chunks.drain(1..) {
+                    line.extend_from_slice(&chunk);
+                }
+                return Some(line_to_string(&line));
+            }
+
+            // The start of the file has been reached, so the chunks hold the first line.
+            if self.position == 0 {
+                self.finished = true;
+                let line: Vec<u8> = self.chunks.drain(..).flatten().collect();
+                return Some(line_to_string(&line));
+            }
+
+            self.read_chunk();
+        }
+    }
+}
+
+/// Converts the bytes of a line to a string.
+///
+/// # Arguments
+///
+/// * `line` - The bytes of the line (without the `\n` line ending).
+///
+/// # Returns
+///
+/// The line without a trailing `\r`. Invalid UTF-8 is replaced with `U+FFFD`.
+fn line_to_string(line: &[u8]) -> String {
+    let line = line.strip_suffix(b"\r").unwrap_or(line);
+    String::from_utf8_lossy(line).into_owned()
+}
+
+/// Reads the lines of a file in reverse order (i.e. starting with the last line).
+///
+/// # Arguments
+///
+/// * `path` - The path to the file (can be a `&str`, [`String`], [`Path`], or
+///   [`std::path::PathBuf`]).
+///
+/// # Returns
+///
+/// An iterator over the lines of the file (without their line endings) from last to first.
+/// Invalid UTF-8 is replaced with `U+FFFD`.
+///
+/// # Panics
+///
+/// If the file cannot be opened. The iterator panics if the file cannot be read.
+///
+/// # Note
+///
+/// The file is read backwards from its end in chunks, so finding something near the end of a huge
+/// file does not require reading the whole file.
+///
+/// # Example
+///
+/// ```
+/// use file_io::{read_lines_reverse, save_string_to_file};
+///
+/// // Create a log file.
+/// let path: &str = "folder/subfolder_30/file_30.log";
+/// save_string_to_file("ERROR first\nINFO ok\nERROR last\nINFO done\n", path);
+///
+/// // Find the last error.
+/// let last_error = read_lines_reverse(path).find(|line| line.starts_with("ERROR"));
+/// assert_eq!(last_error.as_deref(), Some("ERROR last"));
+/// ```
+pub fn read_lines_reverse<P: AsRef<Path>>(path: P) -> ReverseLines {
+    let path = path.as_ref();
+    let (file, length) = std::fs::File::open(path)
+        .and_then(|file| {
+            let length = file.metadata()?.len();
+            Ok((file, length))
+        })
+        .unwrap_or_else(|_| panic!("Failed to read file at '{path:?}'."));
+    let mut lines = ReverseLines {
+        path: path.to_path_buf(),
+        file,
+        position: length,
+        chunks: VecDeque::new(),
+        finished: length == 0,
+    };
+
+    // A line break at the very end of the file terminates the last line (rather than starting an
+    // empty one).
+    if length > 0 {
+        lines.read_chunk();
+        let last_chunk = &mut lines.chunks[0];
+        if last_chunk.ends_with(b"\n") {
+            last_chunk.pop();
+        }
+    }
+    lines
+}
+
+/// Get the first lines of a file.
+///
+/// # Arguments
+///
+/// * `path` - The path to the file (can be a `&str`, [`String`], [`Path`], or
+///   [`std::path::PathBuf`]).
+/// * `n` - The maximum number of lines to return.
+///
+/// # Returns
+///
+/// The first `n` lines of the file (fewer if the file has fewer lines), without their line
+/// endings. Invalid UTF-8 is replaced with `U+FFFD`.
+///
+/// # Panics
+///
+/// If the file cannot be read.
+///
+/// # Note
+///
+/// Only the requested lines are read from the file.
+///
+/// # Example
+///
+/// ```
+/// use file_io::{head_of_file, save_string_to_file};
+///
+/// // Create a file.
+/// let path: &str = "folder/subfolder_26/file_22.txt";
+/// save_string_to_file("one\ntwo\nthree\n", path);
+///
+/// assert_eq!(head_of_file(path, 2), vec!["one", "two"]);
+/// ```
+pub fn head_of_file<P: AsRef<Path>>(path: P, n: usize) -> Vec<String> {
+    let path = path.as_ref();
+    let file =
+        std::fs::File::open(path).unwrap_or_else(|_| panic!("Failed to read file at '{path:?}'."));
+    let mut reader = std::io::BufReader::new(file);
+    let mut bytes = Vec::new();
+    for _ in 0..n {
+        let read = reader
+            .read_until(b'\n', &mut bytes)
+            .unwrap_or_else(|_| panic!("Failed to read file at '{path:?}'."));
+        if read == 0 {
+            break;
+        }
+    }
+    split_lines(&bytes)
+}
+
+/// Get the last lines of a file.
+///
+/// # Arguments
+///
+/// * `path` - The path to the file (can be a `&str`, [`String`], [`Path`], or
+///   [`std::path::PathBuf`]).
+/// * `n` - The maximum number of lines to return.
+///
+/// # Returns
+///
+/// The last `n` lines of the file (fewer if the file has fewer lines), without their line
+/// endings. Invalid UTF-8 is replaced with `U+FFFD`.
+///
+/// # Panics
+///
+/// If the file cannot be read.
+///
+/// # Note
+///
+/// The file is read backwards from its end in chunks, so only (roughly) the requested lines are
+/// read, no matter how large the file is.
+///
+/// # Example
+///
+/// ```
+/// use file_io::{save_string_to_file, tail_of_file};
+///
+/// // Create a file.
+/// let path: &str = "folder/subfolder_26/file_23.txt";
+/// save_string_to_file("one\ntwo\nthree\n", path);
+///
+/// assert_eq!(tail_of_file(path, 2), vec!["two", "three"]);
+/// ```
+pub fn tail_of_file<P: AsRef<Path>>(path: P, n: usize) -> Vec<String> {
+    let mut lines: Vec<String> = read_lines_reverse(path).take(n).collect();
+    lines.reverse();
+    lines
+}
+
+/// Get the first bytes of a file.
+///
+/// # Arguments
+///
+/// * `path` - The path to the file (can be a `&str`, [`String`], [`Path`], or
+///   [`std::path::PathBuf`]).
+/// * `n` - The maximum number of bytes to return.
+///
+/// # Returns
+///
+/// The first `n` bytes of the file (fewer if the file is shorter).
+///
+/// # Panics
+///
+/// If the file cannot be read.
+///
+/// # Example
+///
+/// ```
+/// use file_io::{head_bytes_of_file, save_string_to_file};
+///
+/// // Create a file.
+/// let path: &str = "folder/subfolder_26/file_24.txt";
+/// save_string_to_file("Hello, world!", path);
+///
+/// assert_eq!(head_bytes_of_file(path, 5), b"Hello");
+/// ```
+pub fn head_bytes_of_file<P: AsRef<Path>>(path: P, n: usize) -> Vec<u8> {
+    read_file_range(path, 0, n)
+}
+
+/// Get the last bytes of a file.
+///
+/// # Arguments
+///
+/// * `path` - The path to the file (can be a `&str`, [`String`], [`Path`], or
+///   [`std::path::PathBuf`]).
+/// * `n` - The maximum number of bytes to return.
+///
+/// # Returns
+///
+/// The last `n` bytes of the file (fewer if the file is shorter).
+///
+/// # Panics
+///
+/// If the file cannot be read.
+///
+/// # Note
+///
+/// Only the requested bytes are read from the file.
+///
+/// # Example
+///
+/// ```
+/// use file_io::{save_string_to_file, tail_bytes_of_file};
+///
+/// // Create a file.
+/// let path: &str = "folder/subfolder_26/file_25.txt";
+/// save_string_to_file("Hello, world!", path);
+///
+/// assert_eq!(tail_bytes_of_file(path, 6), b"world!");
+/// ```
+pub fn tail_bytes_of_file<P: AsRef<Path>>(path: P, n: usize) -> Vec<u8> {
+    let path = path.as_ref();
+    let mut bytes = Vec::new();
+    std::fs::File::open(path)
+        .and_then(|mut file| {
+            let length = file.metadata()?.len();
+            file.seek(SeekFrom::Start(length.saturating_sub(n as u64)))?;
+            file.read_to_end(&mut bytes)
+        })
+        .unwrap_or_else(|_| panic!("Failed to read file at '{path:?}'."));
+    bytes
+}
+
+/// Reads a range of bytes from a file.
+///
+/// # Arguments
+///
+/// * `path` - The path to the file (can be a `&str`, [`String`], [`Path`], or
+///   [`std::path::PathBuf`]).
+/// * `offset` - The offset (in bytes) of the first byte to read.
+/// * `len` - The maximum number of bytes to read.
+///
+/// # Returns
+///
+/// The bytes in the range (fewer than `len` if the end of the file is reached first, and none if
+/// `offset` is past the end of the file).
+///
+/// # Panics
+///
+/// If the file cannot be read.
+///
+/// # Note
+///
+/// Only the requested range is read from the file.
+///
+/// # Example
+///
+/// ```
+/// use file_io::{read_file_range, save_string_to_file};
+///
+/// // Create a file.
+/// let path: &str = "folder/subfolder_28/file_27.txt";
+/// save_string_to_file("Hello, world!", path);
+///
+/// assert_eq!(read_file_range(path, 7, 5), b"world");
+/// ```
+pub fn read_file_range<P: AsRef<Path>>(path: P, offset: u64, len: usize) -> Vec<u8> {
+    let path = path.as_ref();
+    let mut bytes = Vec::new();
+    std::fs::File::open(path)
+        .and_then(|mut file| {
+            file.seek(SeekFrom::Start(offset))?;
+            file.take(len as u64).read_to_end(&mut bytes)
+        })
+        .unwrap_or_else(|_| panic!("Failed to read file at '{path:?}'."));
+    bytes
+}
+
+/// Number of bytes read at a time when streaming through a file.
+const STREAM_CHUNK_LENGTH: usize = 64 * 1024;
+
+/// Streams through the bytes of a file in chunks.
+///
+/// # Arguments
+///
+/// * `path` - The path to the file.
+/// * `f` - Function called with each chunk of bytes.
+///
+/// # Panics
+///
+/// If the file cannot be read.
+fn for_each_chunk<F: FnMut(&[u8])>(path: &Path, mut f: F) {
+    let mut file =
+        std::fs::File::open(path).unwrap_or_else(|_| panic!("Failed to read file at '{path:?}'."));
+    let mut chunk = vec![0; STREAM_CHUNK_LENGTH];
+    loop {
+        match file.read(&mut chunk) {
+            Ok(0) => break,
+            Ok(read) => f(&chunk[..read]),
+            Err(error) if error.kind() == std::io::ErrorKind::Interrupted => {}
+            Err(_) => panic!("Failed to read file at '{path:?}'."),
+        }
+    }
+}
+
+/// Streams through the characters of a file.
+///
+/// # Arguments
+///
+/// * `path` - The path to the file.
+/// * `f` - Function called with each character. Invalid UTF-8 is replaced with `U+FFFD`.
+///
+/// # Panics
+///
+/// If the file cannot be read.
+fn for_each_char<F: FnMut(char)>(path: &Path, mut f: F) {
+    // Bytes of a character that was cut off at the end of the previous chunk.
+    let mut carry: Vec<u8> = Vec::new();
+    for_each_chunk(path, |chunk| {
+        carry.extend_from_slice(chunk);
+        let mut bytes = carry.as_slice();
+        loop {
+            match std::str::from_utf8(bytes) {
+                Ok(text) => {
+                    text.chars().for_each(&mut f);
+                    bytes = &[];
+                    break;
+                }
+                Err(error) => {
+                    let (valid, rest) = bytes.split_at(error.valid_up_to());
+                    std::str::from_utf8(valid).unwrap().chars().for_each(&mut f);
+                    match error.error_len() {
+                        // An invalid sequence.
+                        Some(len) => {
+                            f(char::REPLACEMENT_CHARACTER);
+                            bytes = &rest[len..];
+                        }
+                        // A character cut off at the end of the chunk.
+                        None => {
+                            bytes = rest;
+                            break;
+                        }
+                    }
+                }
+            }
+        }
+        carry = bytes.to_vec();
+    });
+
+    // A character cut off at the end of the file.
+    if !carry.is_empty() {
+        f(char::REPLACEMENT_CHARACTER);
+    }
+}
+
+/// Counts the lines of a file.
+///
+/// # Arguments
+///
+/// * `path` - The path to the file (can be a `&str`, [`String`], [`Path`], or
+///   [`std::path::PathBuf`]).
+///
+/// # Returns
+///
+/// The number of lines in the file. A last line without a trailing line break is counted (unlike
+/// `wc -l`), so this matches the number of lines returned by [`str::lines`].
+///
+/// # Panics
+///
+/// If the file cannot be read.
+///
+/// # Note
+///
+/// The file is streamed in chunks, so it is never loaded into memory as a whole.
+///
+/// # Example
+///
+/// ```
+/// use file_io::{count_lines, save_string_to_file};
+///
+/// // Create a file.
+/// let path: &str = "folder/subfolder_31/file_31.txt";
+/// save_string_to_file("one\ntwo\nthree", path);
+///
+/// assert_eq!(count_lines(path), 3);
+/// ```
+pub fn count_lines<P: AsRef<Path>>(path: P) -> usize {
+    let mut count = 0;
+    let mut last_byte = None;
+    for_each_chunk(path.as_ref(), |chunk| {
+        count += chunk.iter().filter(|&&byte| byte == b'\n').count();
+        last_byte = chunk.last().copied();
+    });
+    if last_byte.is_some_and(|byte| byte != b'\n') {
+        count += 1;
+    }
+    count
+}
+
+/// Counts the words of a file.
+///
+/// # Arguments
+///
+/// * `path` - The path to the file (can be a `&str`, [`String`], [`Path`], or
+///   [`std::path::PathBuf`]).
+///
+/// # Returns
+///
+/// The number of words (i.e. maximal sequences of non-whitespace characters) in the file, which
+/// matches the number of words returned by [`str::split_whitespace`].
+///
+/// # Panics
+///
+/// If the file cannot be read.
+///
+/// # Note
+///
+/// The file is streamed in chunks, so it is never loaded into memory as a whole.
+///
+/// # Example
+///
+/// ```
+/// use file_io::{count_words, save_string_to_file};
+///
+/// // Create a file.
+/// let path: &str = "folder/subfolder_31/file_32.txt";
+/// save_string_to_file("Hello,  world!\nGoodbye.", path);
+///
+/// assert_eq!(count_words(path), 3);
+/// ```
+pub fn count_words<P: AsRef<Path>>(path: P) -> usize {
+    let mut count = 0;
+    let mut in_word = false;
+    for_each_char(path.as_ref(), |c| {
+        if c.is_whitespace() {
+            in_word = false;
+        } else if !in_word {
+            in_word = true;
+            count += 1;
+        }
+    });
+    count
+}
+
+/// Counts the characters of a file.
+///
+/// # Arguments
+///
+/// * `path` - The path to the file (can be a `&str`, [`String`], [`Path`], or
+///   [`std::path::PathBuf`]).
+///
+/// # Returns
+///
+/// The number of Unicode characters (i.e. [`char`]s, not bytes) in the file. Each invalid UTF-8
+/// sequence counts as a single character.
+///
+/// # Panics
+///
+/// If the file cannot be read.
+///
+/// # Note
+///
+/// The file is streamed in chunks, so it is never loaded into memory as a whole.
+///
+/// # Example
+///
+/// ```
+/// use file_io::{count_chars, save_string_to_file};
+///
+/// // Create a file.
+/// let path: &str = "folder/subfolder_31/file_33.txt";
+/// save_string_to_file("Grüße", path);
+///
+/// assert_eq!(count_chars(path), 5);
+/// ```
+pub fn count_chars<P: AsRef<Path>>(path: P) -> usize {
+    let mut count = 0;
+    for_each_char(path.as_ref(), |_| count += 1);
+    count
+}
+
+/// Loads the content of a file as a base64 string.
+///
+/// # Arguments
+///
+/// * `path` - The path to the file to load (can be a `&str`, [`String`], [`Path`], or
+///   [`std::path::PathBuf`]).
+///
+/// # Returns
+///
+/// The contents of the file, encoded using the standard base64 alphabet with padding (RFC 4648).
+///
+/// # Panics
+///
+/// If the file cannot be read.
+///
+/// # Example
+///
+/// ```
+/// use file_io::{load_file_as_base64, save_string_to_file};
+///
+/// // Create a file.
+/// let path: &str = "folder/subfolder_32/file_34.txt";
+/// save_string_to_file("Hello, world!", path);
+///
+/// assert_eq!(load_file_as_base64(path), "SGVsbG8sIHdvcmxkIQ==");
+/// ```
+pub fn load_file_as_base64<P: AsRef<Path>>(path: P) -> String {
+    let path = path.as_ref();
+    let bytes =
+        std::fs::read(path).unwrap_or_else(|_| panic!("Failed to read file at '{path:?}'."));
+    STANDARD.encode(bytes)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::save::save_string_to_file;
     use crate::test_utils::get_temp_dir_path;
     use tempfile::tempdir;
 
@@ -104,4 +911,220 @@ mod tests {
             assert_eq!(loaded_content, content);
         }
     }
+
+    #[test]
+    fn test_is_binary_file() {
+        // Create a temporary directory.
+        let temp_dir = tempdir().unwrap();
+
+        // Get the path to the temporary directory.
+        let temp_dir_path = get_temp_dir_path(&temp_dir);
+
+        // Text files (including non-ASCII text and an empty file) are not binary.
+        save_string_to_file("Hello, world!", temp_dir_path.join("text.txt"));
+        save_string_to_file("Grüße, 世界!", temp_dir_path.join("unicode.txt"));
+        save_string_to_file("", temp_dir_path.join("empty.txt"));
+        assert!(!is_binary_file(temp_dir_path.join("text.txt")));
+        assert!(!is_binary_file(temp_dir_path.join("unicode.txt")));
+        assert!(!is_binary_file(temp_dir_path.join("empty.txt")));
+
+        // A multi-byte character cut off by the 8 KiB limit does not make a text file binary.
+        let mut content = "a".repeat(BINARY_SNIFF_LENGTH - 1);
+        content.push('é');
+        save_string_to_file(&content, temp_dir_path.join("long.txt"));
+        assert!(!is_binary_file(temp_dir_path.join("long.txt")));
+
+        // Files containing NUL bytes or invalid UTF-8 are binary.
+        std::fs::write(temp_dir_path.join("nul.bin"), b"abc\0def").unwrap();
+        std::fs::write(temp_dir_path.join("invalid.bin"), [0xff, 0xfe, 0xfd]).unwrap();
+        assert!(is_binary_file(temp_dir_path.join("nul.bin")));
+        assert!(is_binary_file(temp_dir_path.join("invalid.bin")));
+    }
+
+    #[test]
+    fn test_head_tail_of_file() {
+        // Create a temporary directory.
+        let temp_dir = tempdir().unwrap();
+
+        // Get the path to the temporary directory.
+        let temp_dir_path = get_temp_dir_path(&temp_dir);
+
+        // A file with and one without a trailing line break.
+        save_string_to_file("one\r\ntwo\nthree\n", temp_dir_path.join("a.txt"));
+        save_string_to_file("one\ntwo\nthree", temp_dir_path.join("b.txt"));
+        save_string_to_file("", temp_dir_path.join("empty.txt"));
+        for name in ["a.txt", "b.txt"] {
+            let path = temp_dir_path.join(name);
+            assert_eq!(head_of_file(&path, 2), vec!["one", "two"]);
+            assert_eq!(head_of_file(&path, 10), vec!["one", "two", "three"]);
+            assert_eq!(tail_of_file(&path, 2), vec!["two", "three"]);
+            assert_eq!(tail_of_file(&path, 10), vec!["one", "two", "three"]);
+            assert!(tail_of_file(&path, 0).is_empty());
+        }
+        assert!(head_of_file(temp_dir_path.join("empty.txt"), 3).is_empty());
+        assert!(tail_of_file(temp_dir_path.join("empty.txt"), 3).is_empty());
+
+        // Lines spanning multiple chunks.
+        let lines: Vec<String> = (0..5000).map(|i| format!("line {i}")).collect();
+        save_string_to_file(&lines.join("\n"), temp_dir_path.join("long.txt"));
+        assert_eq!(
+            tail_of_file(temp_dir_path.join("long.txt"), 3000),
+            lines[2000..]
+        );
+
+        // Bytes.
+        assert_eq!(head_bytes_of_file(temp_dir_path.join("b.txt"), 3), b"one");
+        assert_eq!(tail_bytes_of_file(temp_dir_path.join("b.txt"), 5), b"three");
+        assert_eq!(
+            tail_bytes_of_file(temp_dir_path.join("b.txt"), 100),
+            b"one\ntwo\nthree"
+        );
+    }
+
+    #[test]
+    fn test_read_file_range() {
+        // Create a temporary directory.
+        let temp_dir = tempdir().unwrap();
+
+        // Path to the file.
+        let file_path = get_temp_dir_path(&temp_dir).join("file.bin");
+        let bytes: Vec<u8> = (0u8..=255).collect();
+        std::fs::write(&file_path, &bytes).unwrap();
+
+        // Read ranges within, overlapping the end of, and past the end of the file.
+        assert_eq!(read_file_range(&file_path, 0, 4), [0, 1, 2, 3]);
+        assert_eq!(read_file_range(&file_path, 100, 3), [100, 101, 102]);
+        assert_eq!(read_file_range(&file_path, 254, 10), [254, 255]);
+        assert!(read_file_range(&file_path, 1000, 10).is_empty());
+    }
+
+    #[test]
+    fn test_read_lines_reverse() {
+        // Create a temporary directory.
+        let temp_dir = tempdir().unwrap();
+
+        // Get the path to the temporary directory.
+        let temp_dir_path = get_temp_dir_path(&temp_dir);
+
+        // Files with and without a trailing line break, with empty lines, and empty files.
+        let cases: [(&str, Vec<&str>); 6] = [
+            ("a\r\nb\nc\n", vec!["c", "b", "a"]),
+            ("a\nb\nc", vec!["c", "b", "a"]),
+            ("a\n\nb\n\n", vec!["", "b", "", "a"]),
+            ("\n", vec![""]),
+            ("a", vec!["a"]),
+            ("", vec![]),
+        ];
+        for (content, expected) in cases {
+            let path = temp_dir_path.join("file.txt");
+            save_string_to_file(content, &path);
+            assert_eq!(read_lines_reverse(&path).collect::<Vec<_>>(), expected);
+        }
+
+        // Lines spanning multiple chunks.
+        let lines: Vec<String> = (0..5000).map(|i| format!("line {i}")).collect();
+        save_string_to_file(&lines.join("\n"), temp_dir_path.join("long.txt"));
+        let mut reversed: Vec<String> =
+            read_lines_reverse(temp_dir_path.join("long.txt")).collect();
+        reversed.reverse();
+        assert_eq!(reversed, lines);
+
+        // Lines much longer than a chunk.
+        let long_line = "x".repeat(100_000) + "\r";
+        save_string_to_file(
+            &format!("first\n{long_line}\nlast\n"),
+            temp_dir_path.join("long_lines.txt"),
+        );
+        assert_eq!(
+            read_lines_reverse(temp_dir_path.join("long_lines.txt")).collect::<Vec<_>>(),
+            ["last", &long_line[..100_000], "first"]
+        );
+    }
+
+    #[test]
+    fn test_count_lines_words_chars() {
+        // Create a temporary directory.
+        let temp_dir = tempdir().unwrap();
+
+        // Get the path to the temporary directory.
+        let temp_dir_path = get_temp_dir_path(&temp_dir);
+
+        // Compare against the standard library for a variety of contents.
+        let long = "Grüße, 世界!\n".repeat(10_000);
+        let cases = [
+            "",
+            "\n",
+            "a",
+            "a\n",
+            "one two\n\nthree  four\r\n five",
+            &long,
+        ];
+        for content in cases {
+            let path = temp_dir_path.join("file.txt");
+            save_string_to_file(content, &path);
+            assert_eq!(count_lines(&path), content.lines().count());
+            assert_eq!(count_words(&path), content.split_whitespace().count());
+            assert_eq!(count_chars(&path), content.chars().count());
+        }
+
+        // Invalid UTF-8 (including a character cut off at the end of the file).
+        let path = temp_dir_path.join("invalid.bin");
+        std::fs::write(&path, [b'a', 0xff, b' ', b'b', 0xc3]).unwrap();
+        assert_eq!(count_chars(&path), 5);
+        assert_eq!(count_words(&path), 2);
+    }
+
+    #[test]
+    fn test_load_file_as_base64() {
+        // Create a temporary directory.
+        let temp_dir = tempdir().unwrap();
+
+        // Path to the file.
+        let file_path = get_temp_dir_path(&temp_dir).join("file.bin");
+
+        // Encode binary content.
+        std::fs::write(&file_path, [0u8, 1, 2, 253, 254, 255]).unwrap();
+        assert_eq!(load_file_as_base64(&file_path), "AAEC/f7/");
+    }
+
+    #[test]
+    fn test_load_file_or_default() {
+        // Create a temporary directory to work in.
+        let temp_dir = tempdir().unwrap();
+        let file_path = get_temp_dir_path(&temp_dir).join("config.toml");
+
+        // The default content is returned (without creating the file) if the file is missing.
+        assert_eq!(load_file_or_default(&file_path, "default"), "default");
+        assert!(!file_path.exists());
+
+        // The file's content is returned if it exists.
+        save_string_to_file("content", &file_path);
+        assert_eq!(load_file_or_default(&file_path, "default"), "content");
+    }
+
+    #[test]
+    fn test_load_or_create() {
+        // Create a temporary directory to work in.
+        let temp_dir = tempdir().unwrap();
+        let file_path = get_temp_dir_path(&temp_dir).join("a/config.toml");
+
+        // The file (and its parent folder) is created with the default content if it is missing.
+        assert_eq!(load_or_create(&file_path, "default"), "default");
+        assert_eq!(load_file_as_string(&file_path), "default");
+
+        // The file's content is returned if it exists.
+        save_string_to_file("content", &file_path);
+        assert_eq!(load_or_create(&file_path, "default"), "content");
+    }
+
+    #[test]
+    fn test_load_file_or_stdin() {
+        // Create a temporary directory to work in.
+        let temp_dir = tempdir().unwrap();
+        let file_path = get_temp_dir_path(&temp_dir).join("-.txt");
+        save_string_to_file("content", &file_path);
+
+        // Paths other than `-` are loaded as files.
+        assert_eq!(load_file_or_stdin(&file_path), "content");
+    }
 }
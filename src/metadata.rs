@@ -0,0 +1,447 @@
+use crate::instrument::Mutation;
+use std::fs::{File, FileTimes, Metadata};
+use std::path::Path;
+use std::time::SystemTime;
+
+/// Type of a filesystem entry.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum EntryType {
+    /// A regular file.
+    File,
+
+    /// A folder (directory).
+    Folder,
+
+    /// A symbolic link.
+    Symlink,
+
+    /// Any other type of entry (e.g. a socket, FIFO, or device).
+    Other,
+}
+
+/// Gets the metadata of a file or folder.
+///
+/// # Arguments
+///
+/// * `path` - The path to the file or folder.
+///
+/// # Returns
+///
+/// The metadata of the file or folder (following symbolic links).
+///
+/// # Panics
+///
+/// If the metadata cannot be read.
+fn get_metadata(path: &Path) -> Metadata {
+    std::fs::metadata(path).unwrap_or_else(|_| panic!("Failed to get metadata for '{path:?}'."))
+}
+
+/// Get the size of a file in bytes.
+///
+/// # Arguments
+///
+/// * `path` - The path to the file (can be a `&str`, [`String`], [`Path`], or
+///   [`std::path::PathBuf`]).
+///
+/// # Returns
+///
+/// The size of the file in bytes.
+///
+/// # Panics
+///
+/// If the metadata of the file cannot be read.
+///
+/// # Example
+///
+/// ```
+/// use file_io::{get_file_size, save_string_to_file};
+///
+/// let path: &str = "folder/subfolder_16/file_10.txt";
+/// save_string_to_file("Hello, world!", path);
+///
+/// assert_eq!(get_file_size(path), 13);
+/// ```
+pub fn get_file_size<P: AsRef<Path>>(path: P) -> u64 {
+    get_metadata(path.as_ref()).len()
+}
+
+/// Get the last modification time of a file or folder.
+///
+/// # Arguments
+///
+/// * `path` - The path to the file or folder (can be a `&str`, [`String`], [`Path`], or
+///   [`std::path::PathBuf`]).
+///
+/// # Returns
+///
+/// The last modification time.
+///
+/// # Panics
+///
+/// If the metadata cannot be read or the modification time is not available on this platform.
+///
+/// # Example
+///
+/// ```
+/// use file_io::get_modified_time;
+/// use std::time::SystemTime;
+///
+/// let modified: SystemTime = get_modified_time("Cargo.toml");
+/// ```
+pub fn get_modified_time<P: AsRef<Path>>(path: P) -> SystemTime {
+    let path = path.as_ref();
+    get_metadata(path)
+        .modified()
+        .unwrap_or_else(|_| panic!("Failed to get the modification time of '{path:?}'."))
+}
+
+/// Get the creation time of a file or folder.
+///
+/// # Arguments
+///
+/// * `path` - The path to the file or folder (can be a `&str`, [`String`], [`Path`], or
+///   [`std::path::PathBuf`]).
+///
+/// # Returns
+///
+/// The creation time.
+///
+/// # Panics
+///
+/// If the metadata cannot be read or the creation time is not available on this platform or
+/// filesystem.
+///
+/// # Example
+///
+/// ```no_run
+/// use file_io::get_created_time;
+/// use std::time::SystemTime;
+///
+/// let created: SystemTime = get_created_time("Cargo.toml");
+/// ```
+pub fn get_created_time<P: AsRef<Path>>(path: P) -> SystemTime {
+    let path = path.as_ref();
+    get_metadata(path)
+        .created()
+        .unwrap_or_else(|_| panic!("Failed to get the creation time of '{path:?}'."))
+}
+
+/// Get the last access time of a file or folder.
+///
+/// # Arguments
+///
+/// * `path` - The path to the file or folder (can be a `&str`, [`String`], [`Path`], or
+///   [`std::path::PathBuf`]).
+///
+/// # Returns
+///
+/// The last access time.
+///
+/// # Panics
+///
+/// If the metadata cannot be read or the access time is not available on this platform.
+///
+/// # Example
+///
+/// ```
+/// use file_io::get_accessed_time;
+/// use std::time::SystemTime;
+///
+/// let accessed: SystemTime = get_accessed_time("Cargo.toml");
+/// ```
+pub fn get_accessed_time<P: AsRef<Path>>(path: P) -> SystemTime {
+    let path = path.as_ref();
+    get_metadata(path)
+        .accessed()
+        .unwrap_or_else(|_| panic!("Failed to get the access time of '{path:?}'."))
+}
+
+/// Sets the timestamps of a file or folder.
+///
+/// # Arguments
+///
+/// * `path` - The path to the file or folder.
+/// * `times` - The timestamps to set (any timestamps not set in `times` are left unchanged).
+///
+/// # Panics
+///
+/// If the timestamps cannot be set.
+fn set_times(path: &Path, times: FileTimes) {
+    let mut mutation = Mutation::start("set_times", path);
+    if mutation.is_dry_run() {
+        return;
+    }
+    File::options()
+        .write(true)
+        .open(path)
+        .or_else(|_| File::open(path))
+        .and_then(|file| file.set_times(times))
+        .unwrap_or_else(|_| panic!("Failed to set the timestamps of '{path:?}'."));
+    mutation.succeeded();
+}
+
+/// Set the last modification time of a file or folder.
+///
+/// # Arguments
+///
+/// * `path` - The path to the file or folder (can be a `&str`, [`String`], [`Path`], or
+///   [`std::path::PathBuf`]).
+/// * `time` - The modification time to set.
+///
+/// # Panics
+///
+/// If the modification time cannot be set.
+///
+/// # Example
+///
+/// ```
+/// use file_io::{get_modified_time, save_string_to_file, set_modified_time};
+/// use std::time::{Duration, UNIX_EPOCH};
+///
+/// // Create a file.
+/// let path: &str = "folder/subfolder_17/file_11.txt";
+/// save_string_to_file("Hello, world!", path);
+///
+/// // Set its modification time.
+/// let time = UNIX_EPOCH + Duration::from_secs(1_000_000_000);
+/// set_modified_time(path, time);
+/// assert_eq!(get_modified_time(path), time);
+/// ```
+pub fn set_modified_time<P: AsRef<Path>>(path: P, time: SystemTime) {
+    set_times(path.as_ref(), FileTimes::new().set_modified(time));
+}
+
+/// Set the last access time of a file or folder.
+///
+/// # Arguments
+///
+/// * `path` - The path to the file or folder (can be a `&str`, [`String`], [`Path`], or
+///   [`std::path::PathBuf`]).
+/// * `time` - The access time to set.
+///
+/// # Panics
+///
+/// If the access time cannot be set.
+///
+/// # Example
+///
+/// ```
+/// use file_io::{get_accessed_time, save_string_to_file, set_accessed_time};
+/// use std::time::{Duration, UNIX_EPOCH};
+///
+/// // Create a file.
+/// let path: &str = "folder/subfolder_17/file_12.txt";
+/// save_string_to_file("Hello, world!", path);
+///
+/// // Set its access time.
+/// let time = UNIX_EPOCH + Duration::from_secs(1_000_000_000);
+/// set_accessed_time(path, time);
+/// assert_eq!(get_accessed_time(path), time);
+/// ```
+pub fn set_accessed_time<P: AsRef<Path>>(path: P, time: SystemTime) {
+    set_times(path.as_ref(), FileTimes::new().set_accessed(time));
+}
+
+/// Get the type of a filesystem entry.
+///
+/// # Arguments
+///
+/// * `path` - The path to the entry (can be a `&str`, [`String`], [`Path`], or
+///   [`std::path::PathBuf`]).
+///
+/// # Returns
+///
+/// The type of the entry, or `None` if nothing exists at `path`.
+///
+/// # Note
+///
+/// Symbolic links are not followed (i.e. a symbolic link to a file is reported as
+/// [`EntryType::Symlink`], not [`EntryType::File`]).
+///
+/// # Example
+///
+/// ```
+/// use file_io::{EntryType, get_entry_type};
+///
+/// assert_eq!(get_entry_type("Cargo.toml"), Some(EntryType::File));
+/// assert_eq!(get_entry_type("src"), Some(EntryType::Folder));
+/// assert_eq!(get_entry_type("does_not_exist"), None);
+/// ```
+pub fn get_entry_type<P: AsRef<Path>>(path: P) -> Option<EntryType> {
+    let file_type = std::fs::symlink_metadata(path).ok()?.file_type();
+    Some(if file_type.is_symlink() {
+        EntryType::Symlink
+    } else if file_type.is_dir() {
+        EntryType::Folder
+    } else if file_type.is_file() {
+        EntryType::File
+    } else {
+        EntryType::Other
+    })
+}
+
+/// Check whether a file or folder is hidden.
+///
+/// # Arguments
+///
+/// * `path` - The path to the file or folder (can be a `&str`, [`String`], [`Path`], or
+///   [`std::path::PathBuf`]).
+///
+/// # Returns
+///
+/// `true` if the entry is hidden, `false` otherwise. On Windows, an entry is hidden if it has the
+/// hidden attribute set. On all other platforms, an entry is hidden if its name starts with a `.`.
+///
+/// # Note
+///
+/// On Windows, this function returns `false` if the attributes of the entry cannot be read (e.g.
+/// because it does not exist). On all other platforms, the filesystem is not accessed.
+///
+/// # Example
+///
+/// ```
+/// use file_io::is_hidden;
+///
+/// #[cfg(unix)]
+/// assert!(is_hidden(".vscode"));
+/// assert!(!is_hidden("src"));
+/// ```
+pub fn is_hidden<P: AsRef<Path>>(path: P) -> bool {
+    let path = path.as_ref();
+    #[cfg(windows)]
+    {
+        use std::os::windows::fs::MetadataExt;
+        const FILE_ATTRIBUTE_HIDDEN: u32 = 0x2;
+        std::fs::symlink_metadata(path)
+            .is_ok_and(|metadata| metadata.file_attributes() & FILE_ATTRIBUTE_HIDDEN != 0)
+    }
+    #[cfg(not(windows))]
+    {
+        path.file_name()
+            .is_some_and(|name| name.as_encoded_bytes().starts_with(b"."))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::save::save_string_to_file;
+    use crate::test_utils::get_temp_dir_path;
+    use std::time::{Duration, UNIX_EPOCH};
+    use tempfile::tempdir;
+
+    #[test]
+    fn test_get_file_size() {
+        // Create a temporary directory to work in.
+        let temp_dir = tempdir().unwrap();
+
+        // Path to the file.
+        let file_path = get_temp_dir_path(&temp_dir).join("file.txt");
+
+        // File path in different formats.
+        let file_paths: Vec<Box<dyn AsRef<Path>>> = vec![
+            Box::new(file_path.to_str().unwrap()),             // &str
+            Box::new(file_path.to_str().unwrap().to_string()), // String
+            Box::new(file_path.as_path()),                     // Path
+            Box::new(file_path.clone()),                       // PathBuf
+        ];
+
+        // Create the file.
+        save_string_to_file("Hello, world!", &file_path);
+
+        // Test with all different path formats.
+        for file_path in file_paths {
+            // Get a reference to this path representation (i.e. "unbox").
+            let file_path = file_path.as_ref();
+
+            // Check the file size.
+            assert_eq!(get_file_size(file_path), 13);
+        }
+    }
+
+    #[test]
+    fn test_get_times() {
+        // Create a temporary directory to work in.
+        let temp_dir = tempdir().unwrap();
+
+        // Create a file.
+        let file_path = get_temp_dir_path(&temp_dir).join("file.txt");
+        save_string_to_file("Hello, world!", &file_path);
+
+        // Set the file's timestamps to known times.
+        let accessed = UNIX_EPOCH + Duration::from_secs(1_000_000_000);
+        let modified = UNIX_EPOCH + Duration::from_secs(1_100_000_000);
+        File::options()
+            .write(true)
+            .open(&file_path)
+            .unwrap()
+            .set_times(
+                FileTimes::new()
+                    .set_accessed(accessed)
+                    .set_modified(modified),
+            )
+            .unwrap();
+
+        // Check the timestamps.
+        assert_eq!(get_accessed_time(&file_path), accessed);
+        assert_eq!(get_modified_time(&file_path), modified);
+    }
+
+    #[test]
+    fn test_get_entry_type() {
+        // Create a temporary directory to work in.
+        let temp_dir = tempdir().unwrap();
+        let temp_dir_path = get_temp_dir_path(&temp_dir);
+
+        // Create a file.
+        let file_path = temp_dir_path.join("file.txt");
+        save_string_to_file("Hello, world!", &file_path);
+
+        // Check the entry types.
+        assert_eq!(get_entry_type(&file_path), Some(EntryType::File));
+        assert_eq!(get_entry_type(&temp_dir_path), Some(EntryType::Folder));
+        assert_eq!(get_entry_type(temp_dir_path.join("missing")), None);
+
+        // Check the entry type of a symbolic link (Unix only).
+        #[cfg(unix)]
+        {
+            let link_path = temp_dir_path.join("link");
+            std::os::unix::fs::symlink(&file_path, &link_path).unwrap();
+            assert_eq!(get_entry_type(&link_path), Some(EntryType::Symlink));
+        }
+    }
+
+    #[test]
+    fn test_set_times() {
+        // Create a temporary directory to work in.
+        let temp_dir = tempdir().unwrap();
+        let temp_dir_path = get_temp_dir_path(&temp_dir);
+
+        // Create a file.
+        let file_path = temp_dir_path.join("file.txt");
+        save_string_to_file("Hello, world!", &file_path);
+
+        // Set the timestamps of the file independently.
+        let accessed = UNIX_EPOCH + Duration::from_secs(1_000_000_000);
+        let modified = UNIX_EPOCH + Duration::from_secs(1_100_000_000);
+        set_accessed_time(&file_path, accessed);
+        set_modified_time(&file_path, modified);
+        assert_eq!(get_accessed_time(&file_path), accessed);
+        assert_eq!(get_modified_time(&file_path), modified);
+
+        // Set the modification time of a folder.
+        set_modified_time(&temp_dir_path, modified);
+        assert_eq!(get_modified_time(&temp_dir_path), modified);
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_is_hidden() {
+        assert!(is_hidden(".gitignore"));
+        assert!(is_hidden("some/path/.config"));
+        assert!(!is_hidden("some/.path/config"));
+        assert!(!is_hidden("file.txt"));
+        assert!(!is_hidden("."));
+        assert!(!is_hidden(".."));
+    }
+}
@@ -1,9 +1,412 @@
-use crate::load::load_file_as_string;
+use crate::create::create_folder_for_file;
+use crate::diff::unified_diff;
+use crate::dry_run::{DryRunGuard, current_plan};
+use crate::instrument::Mutation;
+use crate::load::{is_binary, load_file_as_string};
+use crate::metadata::is_hidden;
+use crate::path::{extension_matches, names_equal_ignore_case};
+use crate::progress::{NoProgress, ProgressSink, file_size};
 use crate::save::save_string_to_file;
-use std::panic;
-use std::path::Path;
+use crate::timestamp::current_timestamp;
+use glob::{MatchOptions, Pattern};
+use std::fs::{File, FileTimes};
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+use std::sync::atomic::{AtomicUsize, Ordering};
 use walkdir::WalkDir;
 
+/// Options controlling which files are visited by [`replace_str_in_files_with_options`].
+///
+/// The default options visit every file (including files inside hidden folders such as `.git`),
+/// which matches the behavior of [`replace_str_in_files`].
+///
+/// # Example
+///
+/// ```
+/// use file_io::ModifyOptions;
+///
+/// // Only modify Rust and TOML files, and skip the `.git` and `target` folders.
+/// let options = ModifyOptions {
+///     extensions: vec![String::from("rs"), String::from("toml")],
+///     exclude_folders: vec![String::from(".git"), String::from("target")],
+///     ..Default::default()
+/// };
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct ModifyOptions {
+    /// Only files with one of these extensions (without the leading `.`) are modified. If empty,
+    /// files with any extension are modified.
+    pub extensions: Vec<String>,
+
+    /// Only files whose path (relative to the top-level folder) matches one of these glob patterns
+    /// (e.g. `"src/**/*.rs"`) are modified. If empty, all files are modified.
+    pub include_globs: Vec<String>,
+
+    /// Files whose path (relative to the top-level folder) matches one of these glob patterns are
+    /// not modified.
+    pub exclude_globs: Vec<String>,
+
+    /// Folders with one of these names (e.g. `".git"` or `"target"`) are skipped entirely.
+    pub exclude_folders: Vec<String>,
+
+    /// Maximum depth to descend to, where files directly inside the top-level folder are at depth
+    /// 1. If `None`, there is no limit.
+    pub max_depth: Option<usize>,
+
+    /// Whether to follow symbolic links.
+    pub follow_symlinks: bool,
+
+    /// If `true`, the extension, glob, and excluded folder filters ignore case (which matches the
+    /// behavior of the default filesystems on Windows and macOS).
+    pub case_insensitive: bool,
+
+    /// If `true`, hidden files and folders (see [`crate::is_hidden`]) are skipped, along with the
+    /// contents of hidden folders.
+    pub skip_hidden: bool,
+
+    /// If `true`, no files are modified. Instead, the returned report lists the files that would
+    /// be modified along with a unified diff of the changes for each file.
+    pub dry_run: bool,
+
+    /// Whether (and where) to back up each file before it is modified. Backups made in this mode
+    /// (i.e. sibling `.bak` files, or the contents of the backup folder) are never modified
+    /// themselves, so that running the same replacement again does not overwrite them.
+    pub backup: BackupMode,
+
+    /// If `true`, the original permissions of each file (e.g. the Unix executable bit) are
+    /// restored after it is modified.
+    pub preserve_permissions: bool,
+
+    /// If `true`, the original access and modification times of each file are restored after it
+    /// is modified.
+    pub preserve_timestamps: bool,
+}
+
+/// Specifies whether (and where) a file is backed up before it is modified in place.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub enum BackupMode {
+    /// No backups are made.
+    #[default]
+    None,
+
+    /// The original file is copied to a sibling file with a `.bak` suffix appended to its name
+    /// (e.g. `file.txt` is backed up to `file.txt.bak`). Any existing backup is overwritten.
+    Sibling,
+
+    /// The original file is copied into the specified backup folder, with a timestamp appended to
+    /// its name (e.g. `file.txt` is backed up to `<folder>/file.txt.20261016T093015.123456Z.bak`).
+    /// For bulk replacements, the folder structure relative to the top-level folder is preserved.
+    Folder(PathBuf),
+}
+
+/// A backup of a file made before it was modified.
+#[derive(Clone, Debug, PartialEq)]
+pub struct FileBackup {
+    /// Path to the original file.
+    pub original: PathBuf,
+
+    /// Path to the backup of the original file.
+    pub backup: PathBuf,
+}
+
+/// Determines the path where a file should be backed up.
+///
+/// # Arguments
+///
+/// * `path` - Path to the file to back up.
+/// * `root` - Path to the top-level folder (used to preserve the folder structure when backing up
+///   into a backup folder).
+/// * `mode` - Backup mode.
+/// * `timestamp` - Timestamp to append to the names of backups made into a backup folder.
+///
+/// # Returns
+///
+/// Path where the file should be backed up, or `None` if no backup should be made.
+fn backup_path(path: &Path, root: &Path, mode: &BackupMode, timestamp: &str) -> Option<PathBuf> {
+    match mode {
+        BackupMode::None => None,
+        BackupMode::Sibling => {
+            let mut backup = path.as_os_str().to_owned();
+            backup.push(".bak");
+            Some(PathBuf::from(backup))
+        }
+        BackupMode::Folder(folder) => {
+            let relative_path = match path.strip_prefix(root) {
+                Ok(relative_path) if !relative_path.as_os_str().is_empty() => relative_path,
+                _ => Path::new(path.file_name()?),
+            };
+            let mut backup = folder.join(relative_path).into_os_string();
+            backup.push(format!(".{timestamp}.bak"));
+            Some(PathBuf::from(backup))
+        }
+    }
+}
+
+/// Determines whether a path is (or contains) backups made in the specified backup mode.
+///
+/// # Arguments
+///
+/// * `path` - The path to check.
+/// * `mode` - Backup mode.
+///
+/// # Returns
+///
+/// `true` if `path` is a sibling backup of an existing file (i.e. `file.txt.bak` next to
+/// `file.txt`) in [`BackupMode::Sibling`], or the backup folder itself in [`BackupMode::Folder`],
+/// `false` otherwise.
+fn is_backup(path: &Path, mode: &BackupMode) -> bool {
+    match mode {
+        BackupMode::None => false,
+        BackupMode::Sibling => path
+            .to_str()
+            .and_then(|path| path.strip_suffix(".bak"))
+            .is_some_and(|original| Path::new(original).is_file()),
+        BackupMode::Folder(folder) => {
+            path.is_dir() && same_file::is_same_file(path, folder).unwrap_or(false)
+        }
+    }
+}
+
+/// Compiles a list of glob patterns.
+///
+/// # Arguments
+///
+/// * `globs` - The glob patterns to compile.
+///
+/// # Returns
+///
+/// The compiled glob patterns.
+///
+/// # Panics
+///
+/// If any of the glob patterns is invalid.
+pub(crate) fn compile_globs(globs: &[String]) -> Vec<Pattern> {
+    globs
+        .iter()
+        .map(|glob| Pattern::new(glob).unwrap_or_else(|_| panic!("Invalid glob pattern '{glob}'.")))
+        .collect()
+}
+
+/// Collects the paths of all files within a directory that should be visited according to the
+/// specified options.
+///
+/// # Arguments
+///
+/// * `path` - Path to the directory (or file) to search.
+/// * `options` - Options controlling which files are visited.
+///
+/// # Returns
+///
+/// Paths of the files to visit.
+///
+/// # Note
+///
+/// Backups made according to `options.backup` are never visited (see [`is_backup`]).
+fn collect_files(path: &Path, options: &ModifyOptions) -> Vec<PathBuf> {
+    // Compile the glob patterns.
+    let include_globs = compile_globs(&options.include_globs);
+    let exclude_globs = compile_globs(&options.exclude_globs);
+    let match_options = MatchOptions {
+        case_sensitive: !options.case_insensitive,
+        ..MatchOptions::new()
+    };
+
+    // Extensions to filter by.
+    let extensions: Vec<&str> = options.extensions.iter().map(String::as_str).collect();
+
+    // Configure the directory traversal.
+    let mut walk_dir = WalkDir::new(path).follow_links(options.follow_symlinks);
+    if let Some(max_depth) = options.max_depth {
+        walk_dir = walk_dir.max_depth(max_depth);
+    }
+
+    // Traverse over all entries, skipping excluded and hidden folders (and their contents)
+    // entirely.
+    walk_dir
+        .into_iter()
+        .filter_entry(|entry| {
+            if entry.depth() == 0 {
+                return true;
+            }
+            let file_name = entry.file_name().to_string_lossy();
+            let excluded = entry.file_type().is_dir()
+                && options.exclude_folders.iter().any(|name| {
+                    if options.case_insensitive {
+                        names_equal_ignore_case(&file_name, name)
+                    } else {
+                        entry.file_name() == name.as_str()
+                    }
+                });
+            let hidden = options.skip_hidden && is_hidden(entry.path());
+            !excluded && !hidden && !is_backup(entry.path(), &options.backup)
+        })
+        .filter_map(Result::ok)
+        .filter(|entry| entry.file_type().is_file())
+        .map(|entry| entry.into_path())
+        .filter(|file_path| {
+            // Path relative to the top-level folder (used for glob matching).
+            let relative_path = file_path.strip_prefix(path).unwrap_or(file_path);
+
+            // Check the extension filter.
+            let extension_ok = extensions.is_empty()
+                || extension_matches(file_path, &extensions, options.case_insensitive);
+
+            // Check the glob filters.
+            let include_ok = include_globs.is_empty()
+                || include_globs
+                    .iter()
+                    .any(|glob| glob.matches_path_with(relative_path, match_options));
+            let exclude_ok = !exclude_globs
+                .iter()
+                .any(|glob| glob.matches_path_with(relative_path, match_options));
+
+            extension_ok && include_ok && exclude_ok
+        })
+        .collect()
+}
+
+/// A file that could not be processed by [`replace_str_in_files`].
+#[derive(Debug)]
+pub struct ReplaceFailure {
+    /// Path to the file that could not be processed.
+    pub path: PathBuf,
+
+    /// The error that was encountered while reading from or writing to the file.
+    pub error: std::io::Error,
+}
+
+/// Preview of the changes that would be made to a file (see [`ModifyOptions::dry_run`]).
+#[derive(Clone, Debug)]
+pub struct FileDiff {
+    /// Path to the file.
+    pub path: PathBuf,
+
+    /// Unified diff (in the format used by `diff -u` and `git diff`) of the changes.
+    pub diff: String,
+}
+
+/// Report summarizing the result of [`replace_str_in_files`].
+#[derive(Debug, Default)]
+pub struct ReplaceReport {
+    /// Paths of the files that were modified (or, for a dry run, that would be modified) in
+    /// alphabetical order.
+    pub modified: Vec<PathBuf>,
+
+    /// Files that could not be processed (in alphabetical order of their paths).
+    pub failures: Vec<ReplaceFailure>,
+
+    /// Paths of the binary files that were skipped (in alphabetical order).
+    pub skipped: Vec<PathBuf>,
+
+    /// Previews of the changes to each file that would be modified (in alphabetical order of their
+    /// paths). This is only populated for a dry run (see [`ModifyOptions::dry_run`]).
+    pub diffs: Vec<FileDiff>,
+
+    /// Backups made before modifying files (in alphabetical order of the original paths). This is
+    /// only populated if backups are enabled (see [`ModifyOptions::backup`]).
+    pub backups: Vec<FileBackup>,
+}
+
+impl ReplaceReport {
+    /// Prints the previews of the changes (see [`ReplaceReport::diffs`]) to `stdout`.
+    pub fn print_diffs(&self) {
+        for file_diff in &self.diffs {
+            print!("{}", file_diff.diff);
+        }
+    }
+}
+
+/// Outcome of replacing a string in a single file.
+enum Replacement {
+    /// The file did not contain the string to replace.
+    Unchanged,
+
+    /// The file is binary, so it was skipped.
+    Skipped,
+
+    /// The file was modified (after optionally being backed up).
+    Modified(Option<FileBackup>),
+
+    /// The file would have been modified (dry run), with the given unified diff.
+    Previewed(String),
+}
+
+/// Replaces all occurrences of a string in a file, returning any error instead of panicking.
+///
+/// # Arguments
+///
+/// * `path` - Path to the file where the replacements will be performed.
+/// * `old_string` - The substring to find and replace.
+/// * `new_string` - The replacement string.
+/// * `options` - Options controlling how the file is modified (the options controlling which files
+///   are visited are ignored).
+/// * `backup` - Path to back the file up to before modifying it (if any).
+///
+/// # Returns
+///
+/// The outcome of the replacement.
+fn try_replace_str_in_file(
+    path: &Path,
+    old_string: &str,
+    new_string: &str,
+    options: &ModifyOptions,
+    backup: Option<PathBuf>,
+) -> std::io::Result<Replacement> {
+    let bytes = std::fs::read(path)?;
+    if is_binary(&bytes) {
+        return Ok(Replacement::Skipped);
+    }
+    let content = String::from_utf8(bytes)
+        .map_err(|error| std::io::Error::new(std::io::ErrorKind::InvalidData, error))?;
+    if !content.contains(old_string) {
+        return Ok(Replacement::Unchanged);
+    }
+    let new_content = content.replace(old_string, new_string);
+    if options.dry_run {
+        let label = path.display().to_string();
+        return Ok(Replacement::Previewed(unified_diff(
+            &content,
+            &new_content,
+            &label,
+            &label,
+        )));
+    }
+    let mut mutation = Mutation::start("replace_str_in_file", path);
+    mutation.bytes(new_content.len() as u64);
+    if mutation.is_dry_run() {
+        return Ok(Replacement::Modified(backup.map(|backup| FileBackup {
+            original: path.to_path_buf(),
+            backup,
+        })));
+    }
+    let backup = match backup {
+        Some(backup) => {
+            if let Some(parent) = backup.parent() {
+                std::fs::create_dir_all(parent)?;
+            }
+            std::fs::copy(path, &backup)?;
+            Some(FileBackup {
+                original: path.to_path_buf(),
+                backup,
+            })
+        }
+        None => None,
+    };
+    let metadata = std::fs::metadata(path)?;
+    std::fs::write(path, new_content)?;
+    if options.preserve_permissions {
+        std::fs::set_permissions(path, metadata.permissions())?;
+    }
+    if options.preserve_timestamps {
+        let times = FileTimes::new()
+            .set_accessed(metadata.accessed()?)
+            .set_modified(metadata.modified()?);
+        File::options().write(true).open(path)?.set_times(times)?;
+    }
+    mutation.succeeded();
+    Ok(Replacement::Modified(backup))
+}
+
 /// Replaces all occurrences of a string in a file.
 ///
 /// # Arguments
@@ -68,6 +471,98 @@ pub fn replace_str_in_file<P: AsRef<Path>>(path: P, old_string: &str, new_string
     }
 }
 
+/// Replaces all occurrences of a string in a file, backing the file up first.
+///
+/// # Arguments
+///
+/// * `path` - Path to the file where the replacements will be performed (can be a `&str`,
+///   [`String`], [`Path`], or [`std::path::PathBuf`]).
+/// * `old_string` - The substring to find and replace.
+/// * `new_string` - The replacement string.
+/// * `backup` - Whether (and where) to back up the file before it is modified.
+///
+/// # Returns
+///
+/// The backup that was made, or `None` if the file did not contain `old_string` (in which case it
+/// is not modified) or if `backup` is [`BackupMode::None`].
+///
+/// # Panics
+///
+/// If some error is encountered while reading from, backing up, or writing to the file.
+///
+/// # Example
+///
+/// ```
+/// use file_io::{
+///     BackupMode, load_file_as_string, replace_str_in_file_with_backup, restore_backups,
+///     save_string_to_file,
+/// };
+///
+/// // Path to file.
+/// let path: &str = "folder/subfolder_13/file_8.txt";
+///
+/// // Create a file with some content.
+/// save_string_to_file("Hello, world!", path);
+///
+/// // Replace "Hello" with "Goodbye", backing up the original file to "file_8.txt.bak".
+/// let backup = replace_str_in_file_with_backup(path, "Hello", "Goodbye", &BackupMode::Sibling);
+/// assert_eq!(load_file_as_string(path), "Goodbye, world!");
+///
+/// // Restore the original file from the backup.
+/// restore_backups(&[backup.unwrap()]);
+/// assert_eq!(load_file_as_string(path), "Hello, world!");
+/// ```
+pub fn replace_str_in_file_with_backup<P: AsRef<Path>>(
+    path: P,
+    old_string: &str,
+    new_string: &str,
+    backup: &BackupMode,
+) -> Option<FileBackup> {
+    let path = path.as_ref();
+    let backup_path = backup_path(path, path, backup, &current_timestamp());
+    match try_replace_str_in_file(
+        path,
+        old_string,
+        new_string,
+        &ModifyOptions::default(),
+        backup_path,
+    ) {
+        Ok(Replacement::Modified(backup)) => backup,
+        Ok(_) => None,
+        Err(_) => panic!("Failed to replace string in file '{path:?}'."),
+    }
+}
+
+/// Restores files from their backups.
+///
+/// # Arguments
+///
+/// * `backups` - The backups to restore (e.g. [`ReplaceReport::backups`]).
+///
+/// # Panics
+///
+/// If some error is encountered while restoring a file.
+///
+/// # Note
+///
+/// Each original file is overwritten with the contents of its backup, and the backup is then
+/// deleted.
+pub fn restore_backups(backups: &[FileBackup]) {
+    for FileBackup { original, backup } in backups {
+        let mut mutation = Mutation::start("restore_backup", backup).to(original);
+        if mutation.is_dry_run() {
+            continue;
+        }
+        create_folder_for_file(original);
+        std::fs::copy(backup, original).unwrap_or_else(|_| {
+            panic!("Failed to restore file '{original:?}' from backup '{backup:?}'.")
+        });
+        std::fs::remove_file(backup)
+            .unwrap_or_else(|_| panic!("Failed to delete backup '{backup:?}'."));
+        mutation.succeeded();
+    }
+}
+
 /// Replaces all occurrences of a string in all files within a directory (including subdirectories).
 ///
 /// # Arguments
@@ -77,10 +572,20 @@ pub fn replace_str_in_file<P: AsRef<Path>>(path: P, old_string: &str, new_string
 /// * `old_string` - The substring to find and replace in all files.
 /// * `new_string` - The replacement string.
 ///
+/// # Returns
+///
+/// A [`ReplaceReport`] listing the files that were modified and the files that could not be
+/// processed.
+///
 /// # Note
 ///
-/// This function will not panic if a single read/write fails (since this function may pull in
-/// private, inaccessible files). However, a warning will be printed to `stderr`.
+/// * Files are processed concurrently using a pool of worker threads (one per available CPU).
+/// * Binary files (see [`crate::is_binary_file`]) are skipped and listed in the returned report.
+/// * `path` may also be the path to a single file, in which case only that file is modified (this
+///   is useful for applying [`ModifyOptions`] such as backups or permission preservation to a
+///   single file).
+/// * This function will not panic if a single read/write fails (since this function may pull in
+///   private, inaccessible files). Instead, the failure is recorded in the returned report.
 ///
 /// # Examples
 ///
@@ -91,37 +596,211 @@ pub fn replace_str_in_file<P: AsRef<Path>>(path: P, old_string: &str, new_string
 ///
 /// // Replace "foo" with "bar" in all files within the "/path/to/folder/" directory (including
 /// // subdirectories).
-/// replace_str_in_files(dir, "foo", "bar");
+/// let report = replace_str_in_files(dir, "foo", "bar");
+///
+/// // Print any files that could not be processed.
+/// for failure in &report.failures {
+///     eprintln!("Failed to replace string in file '{}'.", failure.path.display());
+/// }
 /// ```
-pub fn replace_str_in_files<P: AsRef<Path>>(path: P, old_string: &str, new_string: &str) {
-    // Traverse over all entries (files and folders) in the directory and its subdirectories.
-    for entry in WalkDir::new(path).into_iter().filter_map(Result::ok) {
-        // Get the path of the current entry.
-        let entry_path = entry.path();
-
-        // If the entry is a file, replace any instances of `old_string` with `new_string`.
-        if entry_path.is_file() {
-            // We use `panic::catch_unwind` to handle any potential panics gracefully (since some
-            // folders could have private, inaccessible files).
-            let result =
-                panic::catch_unwind(|| replace_str_in_file(entry_path, old_string, new_string));
-
-            // If the replacement failed, print an error message to `stderr`.
-            if result.is_err() {
-                eprintln!(
-                    "Failed to replace string in file '{}'.",
-                    entry_path.display(),
-                );
-            }
+pub fn replace_str_in_files<P: AsRef<Path>>(
+    path: P,
+    old_string: &str,
+    new_string: &str,
+) -> ReplaceReport {
+    replace_str_in_files_with_options(path, old_string, new_string, &ModifyOptions::default())
+}
+
+/// Replaces all occurrences of a string in the files within a directory (including subdirectories)
+/// that are selected by the specified options.
+///
+/// # Arguments
+///
+/// * `path` - Path to the directory or file where the replacements will be performed (can be a
+///   `&str`, [`String`], [`Path`], or [`std::path::PathBuf`]).
+/// * `old_string` - The substring to find and replace in all files.
+/// * `new_string` - The replacement string.
+/// * `options` - Options controlling which files are modified.
+///
+/// # Returns
+///
+/// A [`ReplaceReport`] listing the files that were modified and the files that could not be
+/// processed.
+///
+/// # Panics
+///
+/// If any of the glob patterns in `options` is invalid.
+///
+/// # Note
+///
+/// See [`replace_str_in_files`] for details on concurrency and failure handling.
+///
+/// # Examples
+///
+/// ```ignore
+/// use file_io::{ModifyOptions, replace_str_in_files_with_options};
+///
+/// // Only modify Rust files, and skip the `.git` and `target` folders.
+/// let options = ModifyOptions {
+///     extensions: vec![String::from("rs")],
+///     exclude_folders: vec![String::from(".git"), String::from("target")],
+///     ..Default::default()
+/// };
+///
+/// // Replace "foo" with "bar" in the selected files within the "/path/to/folder/" directory.
+/// let report = replace_str_in_files_with_options("/path/to/folder", "foo", "bar", &options);
+/// ```
+pub fn replace_str_in_files_with_options<P: AsRef<Path>>(
+    path: P,
+    old_string: &str,
+    new_string: &str,
+    options: &ModifyOptions,
+) -> ReplaceReport {
+    replace_str_in_files_with_progress(path, old_string, new_string, options, &NoProgress)
+}
+
+/// Replaces all occurrences of a string in the files within a directory (including subdirectories)
+/// that are selected by the specified options, reporting progress along the way.
+///
+/// # Arguments
+///
+/// * `path` - Path to the directory or file where the replacements will be performed (can be a
+///   `&str`, [`String`], [`Path`], or [`std::path::PathBuf`]).
+/// * `old_string` - The substring to find and replace in all files.
+/// * `new_string` - The replacement string.
+/// * `options` - Options controlling which files are modified.
+/// * `progress` - Receives progress updates (one item per file processed, whether or not it was
+///   modified).
+///
+/// # Returns
+///
+/// A [`ReplaceReport`] listing the files that were modified and the files that could not be
+/// processed.
+///
+/// # Panics
+///
+/// If any of the glob patterns in `options` is invalid.
+///
+/// # Note
+///
+/// Files are processed on several threads at once, so progress updates may be received from any
+/// of them (see [`replace_str_in_files`]).
+///
+/// # Examples
+///
+/// ```ignore
+/// use file_io::{ModifyOptions, NoProgress, replace_str_in_files_with_progress};
+///
+/// // Replace "foo" with "bar" in all files within the "/path/to/folder/" directory.
+/// let report = replace_str_in_files_with_progress(
+///     "/path/to/folder",
+///     "foo",
+///     "bar",
+///     &ModifyOptions::default(),
+///     &NoProgress,
+/// );
+/// ```
+pub fn replace_str_in_files_with_progress<P: AsRef<Path>>(
+    path: P,
+    old_string: &str,
+    new_string: &str,
+    options: &ModifyOptions,
+    progress: &dyn ProgressSink,
+) -> ReplaceReport {
+    // Collect the paths of all files to modify.
+    let path = path.as_ref();
+    let mut mutation = Mutation::start_composite("replace_str_in_files", path);
+    let file_paths = collect_files(path, options);
+    progress.on_start(
+        file_paths.len(),
+        file_paths
+            .iter()
+            .map(|file_path| file_size(file_path))
+            .sum(),
+    );
+
+    // Timestamp shared by all backups made during this replacement.
+    let timestamp = current_timestamp();
+
+    // Number of worker threads to use.
+    let num_threads = std::thread::available_parallelism()
+        .map(|n| n.get())
+        .unwrap_or(1)
+        .min(file_paths.len().max(1));
+
+    // Index of the next file to process (shared between the worker threads).
+    let next_index = AtomicUsize::new(0);
+
+    // Report shared between the worker threads.
+    let report = Mutex::new(ReplaceReport::default());
+
+    // Dry run that the worker threads should take part in (if any).
+    let plan = current_plan();
+
+    // Each worker thread repeatedly claims the next unprocessed file until none are left.
+    std::thread::scope(|scope| {
+        for _ in 0..num_threads {
+            scope.spawn(|| {
+                let _dry_run = plan.clone().map(DryRunGuard::new);
+                while let Some(file_path) =
+                    file_paths.get(next_index.fetch_add(1, Ordering::Relaxed))
+                {
+                    let bytes = file_size(file_path);
+                    let result = try_replace_str_in_file(
+                        file_path,
+                        old_string,
+                        new_string,
+                        options,
+                        backup_path(file_path, path, &options.backup, &timestamp),
+                    );
+                    progress.on_item(file_path);
+                    progress.on_bytes(bytes);
+                    let mut report = report.lock().unwrap();
+                    match result {
+                        Ok(Replacement::Unchanged) => {}
+                        Ok(Replacement::Skipped) => report.skipped.push(file_path.clone()),
+                        Ok(Replacement::Modified(backup)) => {
+                            report.modified.push(file_path.clone());
+                            report.backups.extend(backup);
+                        }
+                        Ok(Replacement::Previewed(diff)) => {
+                            report.modified.push(file_path.clone());
+                            report.diffs.push(FileDiff {
+                                path: file_path.clone(),
+                                diff,
+                            });
+                        }
+                        Err(error) => report.failures.push(ReplaceFailure {
+                            path: file_path.clone(),
+                            error,
+                        }),
+                    }
+                }
+            });
         }
+    });
+
+    progress.on_finish();
+
+    // Sort the report so that its contents do not depend on thread scheduling.
+    let mut report = report.into_inner().unwrap();
+    report.modified.sort();
+    report.failures.sort_by(|a, b| a.path.cmp(&b.path));
+    report.skipped.sort();
+    report.diffs.sort_by(|a, b| a.path.cmp(&b.path));
+    report.backups.sort_by(|a, b| a.original.cmp(&b.original));
+
+    // The replacement only succeeded if every file could be processed.
+    if report.failures.is_empty() {
+        mutation.succeeded();
     }
+    report
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::test_utils::get_temp_dir_path;
-    use std::path::PathBuf;
     use tempfile::tempdir;
 
     #[test]
@@ -278,4 +957,388 @@ mod tests {
             assert_eq!(nested_content, "changed me too");
         }
     }
+
+    #[test]
+    fn test_replace_str_in_files_report() {
+        // Create a temporary directory.
+        let temp_dir = tempdir().unwrap();
+
+        // Get the path to the temporary directory.
+        let temp_dir_path = get_temp_dir_path(&temp_dir);
+
+        // Create many files (so that the work is spread across multiple threads), only some of
+        // which contain the string to replace.
+        for i in 0..50 {
+            let content = if i % 2 == 0 { "foo" } else { "baz" };
+            save_string_to_file(content, temp_dir_path.join(format!("file_{i:02}.txt")));
+        }
+
+        // Create a file that is not valid UTF-8 (so it cannot be loaded as a string).
+        let binary_file_path = temp_dir_path.join("binary.bin");
+        std::fs::write(&binary_file_path, [0xff, 0xfe, 0xfd]).unwrap();
+
+        // Run the replacement function.
+        let report = replace_str_in_files(&temp_dir_path, "foo", "bar");
+
+        // Check that exactly the files containing "foo" were modified (in alphabetical order).
+        let expected_modified: Vec<PathBuf> = (0..50)
+            .step_by(2)
+            .map(|i| temp_dir_path.join(format!("file_{i:02}.txt")))
+            .collect();
+        assert_eq!(report.modified, expected_modified);
+
+        // Check that the file contents are as expected.
+        for i in 0..50 {
+            let expected = if i % 2 == 0 { "bar" } else { "baz" };
+            assert_eq!(
+                load_file_as_string(temp_dir_path.join(format!("file_{i:02}.txt"))),
+                expected
+            );
+        }
+
+        // Check that the binary file was skipped (rather than reported as a failure).
+        assert_eq!(report.skipped, vec![binary_file_path.clone()]);
+        assert!(report.failures.is_empty());
+        assert_eq!(
+            std::fs::read(&binary_file_path).unwrap(),
+            [0xff, 0xfe, 0xfd]
+        );
+    }
+
+    #[test]
+    fn test_replace_str_in_files_with_options() {
+        // Create a temporary directory.
+        let temp_dir = tempdir().unwrap();
+
+        // Get the path to the temporary directory.
+        let temp_dir_path = get_temp_dir_path(&temp_dir);
+
+        // Create files in various locations.
+        let paths = [
+            "main.rs",
+            "notes.txt",
+            "src/lib.rs",
+            "src/deep/nested/mod.rs",
+            "src/generated.rs",
+            ".git/config.rs",
+            "target/debug/build.rs",
+        ];
+        for path in paths {
+            save_string_to_file("foo", temp_dir_path.join(path));
+        }
+
+        // Only modify Rust files up to a depth of 2, excluding generated files and the `.git` and
+        // `target` folders.
+        let options = ModifyOptions {
+            extensions: vec![String::from("rs")],
+            exclude_globs: vec![String::from("**/generated.rs")],
+            exclude_folders: vec![String::from(".git"), String::from("target")],
+            max_depth: Some(2),
+            ..Default::default()
+        };
+        let report = replace_str_in_files_with_options(&temp_dir_path, "foo", "bar", &options);
+
+        // Check that only the expected files were modified.
+        assert_eq!(
+            report.modified,
+            vec![
+                temp_dir_path.join("main.rs"),
+                temp_dir_path.join("src/lib.rs")
+            ]
+        );
+        for path in paths {
+            let expected = if report.modified.contains(&temp_dir_path.join(path)) {
+                "bar"
+            } else {
+                "foo"
+            };
+            assert_eq!(load_file_as_string(temp_dir_path.join(path)), expected);
+        }
+
+        // Now only modify files matching an include glob.
+        let options = ModifyOptions {
+            include_globs: vec![String::from("src/deep/**/*.rs")],
+            ..Default::default()
+        };
+        let report = replace_str_in_files_with_options(&temp_dir_path, "foo", "bar", &options);
+        assert_eq!(
+            report.modified,
+            vec![temp_dir_path.join("src/deep/nested/mod.rs")]
+        );
+    }
+
+    #[test]
+    fn test_replace_str_in_files_dry_run() {
+        // Create a temporary directory.
+        let temp_dir = tempdir().unwrap();
+
+        // Get the path to the temporary directory.
+        let temp_dir_path = get_temp_dir_path(&temp_dir);
+
+        // Create files with known content.
+        let file_1_path = temp_dir_path.join("file_1.txt");
+        let file_2_path = temp_dir_path.join("file_2.txt");
+        save_string_to_file("first line\nhello foo\nlast line\n", &file_1_path);
+        save_string_to_file("nothing to replace\n", &file_2_path);
+
+        // Perform a dry run.
+        let options = ModifyOptions {
+            dry_run: true,
+            ..Default::default()
+        };
+        let report = replace_str_in_files_with_options(&temp_dir_path, "foo", "bar", &options);
+
+        // Check that the file that would be modified is reported.
+        assert_eq!(report.modified, vec![file_1_path.clone()]);
+
+        // Check the preview of the changes.
+        assert_eq!(report.diffs.len(), 1);
+        assert_eq!(report.diffs[0].path, file_1_path);
+        assert_eq!(
+            report.diffs[0].diff,
+            format!(
+                "--- {0}\n+++ {0}\n@@ -1,3 +1,3 @@\n \
+                 first line\n-hello foo\n+hello bar\n last line\n",
+                file_1_path.display()
+            )
+        );
+
+        // Check that no files were actually modified.
+        assert_eq!(
+            load_file_as_string(&file_1_path),
+            "first line\nhello foo\nlast line\n"
+        );
+        assert_eq!(load_file_as_string(&file_2_path), "nothing to replace\n");
+    }
+
+    #[test]
+    fn test_replace_str_in_files_with_backups() {
+        // Create a temporary directory.
+        let temp_dir = tempdir().unwrap();
+
+        // Get the path to the temporary directory.
+        let temp_dir_path = get_temp_dir_path(&temp_dir);
+
+        // Create files with known content.
+        let project_path = temp_dir_path.join("project");
+        let file_1_path = project_path.join("file_1.txt");
+        let file_2_path = project_path.join("nested/file_2.txt");
+        save_string_to_file("hello foo", &file_1_path);
+        save_string_to_file("nothing to replace", &file_2_path);
+
+        // Test with both sibling backups and backups into a backup folder.
+        let backup_folder_path = temp_dir_path.join("backups");
+        for backup in [
+            BackupMode::Sibling,
+            BackupMode::Folder(backup_folder_path.clone()),
+        ] {
+            // Run the replacement function.
+            let options = ModifyOptions {
+                backup: backup.clone(),
+                ..Default::default()
+            };
+            let report = replace_str_in_files_with_options(&project_path, "foo", "bar", &options);
+            assert_eq!(load_file_as_string(&file_1_path), "hello bar");
+
+            // Check that only the modified file was backed up.
+            assert_eq!(report.backups.len(), 1);
+            assert_eq!(report.backups[0].original, file_1_path);
+            assert_eq!(load_file_as_string(&report.backups[0].backup), "hello foo");
+            match backup {
+                BackupMode::Sibling => {
+                    assert_eq!(
+                        report.backups[0].backup,
+                        project_path.join("file_1.txt.bak")
+                    )
+                }
+                _ => assert!(report.backups[0].backup.starts_with(&backup_folder_path)),
+            }
+
+            // Restore the backups.
+            restore_backups(&report.backups);
+            assert_eq!(load_file_as_string(&file_1_path), "hello foo");
+            assert!(!report.backups[0].backup.exists());
+        }
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_replace_str_in_files_does_not_follow_symlinks() {
+        // Create a temporary directory to work in.
+        let temp_dir = tempdir().unwrap();
+        let temp_dir_path = get_temp_dir_path(&temp_dir);
+        let project_path = temp_dir_path.join("project");
+        let outside_path = temp_dir_path.join("outside.txt");
+        save_string_to_file("foo", project_path.join("file.txt"));
+        save_string_to_file("foo", &outside_path);
+        std::os::unix::fs::symlink("../outside.txt", project_path.join("link.txt")).unwrap();
+
+        // Files outside the tree are not modified through symbolic links, unless they are
+        // followed.
+        let report = replace_str_in_files(&project_path, "foo", "bar");
+        assert_eq!(report.modified, [project_path.join("file.txt")]);
+        assert_eq!(load_file_as_string(&outside_path), "foo");
+        let options = ModifyOptions {
+            follow_symlinks: true,
+            ..Default::default()
+        };
+        let report = replace_str_in_files_with_options(&project_path, "foo", "bar", &options);
+        assert_eq!(report.modified, [project_path.join("link.txt")]);
+        assert_eq!(load_file_as_string(&outside_path), "bar");
+    }
+
+    #[test]
+    fn test_replace_str_in_files_skips_backups() {
+        // Create a temporary directory to work in.
+        let temp_dir = tempdir().unwrap();
+        let project_path = get_temp_dir_path(&temp_dir).join("project");
+        let file_path = project_path.join("file.txt");
+
+        // Test with sibling backups and with a backup folder inside the folder being modified.
+        for backup in [
+            BackupMode::Sibling,
+            BackupMode::Folder(project_path.join("backups")),
+        ] {
+            let options = ModifyOptions {
+                backup,
+                ..Default::default()
+            };
+
+            // The first replacement backs up the original file.
+            std::fs::remove_dir_all(&project_path).ok();
+            save_string_to_file("foo foo", &file_path);
+            let report = replace_str_in_files_with_options(&project_path, "foo", "bar", &options);
+            let backup_path = &report.backups[0].backup;
+            assert_eq!(load_file_as_string(backup_path), "foo foo");
+
+            // Running the replacement again leaves the backup (which still contains the old string)
+            // untouched.
+            let report = replace_str_in_files_with_options(&project_path, "foo", "bar", &options);
+            assert!(report.modified.is_empty());
+            assert_eq!(load_file_as_string(backup_path), "foo foo");
+        }
+    }
+
+    #[test]
+    fn test_replace_str_in_files_preserve_metadata() {
+        // Create a temporary directory.
+        let temp_dir = tempdir().unwrap();
+
+        // Get the path to the temporary directory.
+        let temp_dir_path = get_temp_dir_path(&temp_dir);
+
+        // Create a file with known content.
+        let file_path = temp_dir_path.join("script.sh");
+        save_string_to_file("echo foo", &file_path);
+
+        // Make the file executable (Unix only).
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::PermissionsExt;
+            std::fs::set_permissions(&file_path, std::fs::Permissions::from_mode(0o755)).unwrap();
+        }
+
+        // Set the file's timestamps to a known time in the past.
+        let time = std::time::UNIX_EPOCH + std::time::Duration::from_secs(1_000_000_000);
+        File::options()
+            .write(true)
+            .open(&file_path)
+            .unwrap()
+            .set_times(FileTimes::new().set_accessed(time).set_modified(time))
+            .unwrap();
+
+        // Run the replacement function on the single file, preserving its metadata.
+        let options = ModifyOptions {
+            preserve_permissions: true,
+            preserve_timestamps: true,
+            ..Default::default()
+        };
+        let report = replace_str_in_files_with_options(&file_path, "foo", "bar", &options);
+        assert_eq!(report.modified, vec![file_path.clone()]);
+        assert_eq!(load_file_as_string(&file_path), "echo bar");
+
+        // Check that the metadata was preserved.
+        let metadata = std::fs::metadata(&file_path).unwrap();
+        assert_eq!(metadata.modified().unwrap(), time);
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::PermissionsExt;
+            assert_eq!(metadata.permissions().mode() & 0o777, 0o755);
+        }
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_replace_str_in_files_skip_hidden() {
+        // Create a temporary directory.
+        let temp_dir = tempdir().unwrap();
+
+        // Get the path to the temporary directory.
+        let temp_dir_path = get_temp_dir_path(&temp_dir);
+
+        // Create visible and hidden files.
+        for path in [
+            "file.txt",
+            ".hidden.txt",
+            ".git/config",
+            "src/.env",
+            "src/lib.rs",
+        ] {
+            save_string_to_file("foo", temp_dir_path.join(path));
+        }
+
+        // Skip hidden files and folders.
+        let options = ModifyOptions {
+            skip_hidden: true,
+            ..Default::default()
+        };
+        let report = replace_str_in_files_with_options(&temp_dir_path, "foo", "bar", &options);
+        assert_eq!(
+            report.modified,
+            vec![
+                temp_dir_path.join("file.txt"),
+                temp_dir_path.join("src/lib.rs")
+            ]
+        );
+        assert_eq!(
+            load_file_as_string(temp_dir_path.join(".git/config")),
+            "foo"
+        );
+    }
+
+    #[test]
+    fn test_replace_str_in_files_case_insensitive() {
+        // Create a temporary directory.
+        let temp_dir = tempdir().unwrap();
+
+        // Get the path to the temporary directory.
+        let temp_dir_path = get_temp_dir_path(&temp_dir);
+
+        // Create files whose names differ from the filters in case only.
+        for path in ["Main.RS", "Docs/guide.MD", "Target/build.rs", "notes.txt"] {
+            save_string_to_file("foo", temp_dir_path.join(path));
+        }
+
+        // Filters are case-sensitive by default.
+        let mut options = ModifyOptions {
+            extensions: vec![String::from("rs"), String::from("md")],
+            exclude_folders: vec![String::from("target")],
+            include_globs: vec![String::from("**/*.rs"), String::from("docs/*")],
+            dry_run: true,
+            ..Default::default()
+        };
+        let report = replace_str_in_files_with_options(&temp_dir_path, "foo", "bar", &options);
+        assert_eq!(report.modified, vec![temp_dir_path.join("Target/build.rs")]);
+
+        // Filters ignore case on request.
+        options.case_insensitive = true;
+        let report = replace_str_in_files_with_options(&temp_dir_path, "foo", "bar", &options);
+        assert_eq!(
+            report.modified,
+            vec![
+                temp_dir_path.join("Docs/guide.MD"),
+                temp_dir_path.join("Main.RS")
+            ]
+        );
+    }
 }
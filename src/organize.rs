@@ -0,0 +1,198 @@
+use crate::create::create_folder;
+use crate::instrument::Mutation;
+use crate::metadata::get_modified_time;
+use crate::path::unique_path;
+use crate::rename::FileRename;
+use crate::timestamp::format_time_pattern;
+use std::path::{Path, PathBuf};
+
+/// Rule used by [`organize_folder`] to determine the subfolder each file is moved into.
+#[derive(Clone, Debug, PartialEq)]
+pub enum OrganizeBy {
+    /// Files are grouped by their lowercase extension (e.g. `photo.JPG` is moved into `jpg/`).
+    /// Files without an extension are moved into `no_extension/`.
+    Extension,
+
+    /// Files are grouped by their last modification time (in UTC), formatted with the specified
+    /// pattern. In the pattern, `%Y` (year), `%m` (month), `%d` (day), `%H` (hour), `%M` (minute),
+    /// and `%S` (second) are replaced by the corresponding (zero-padded) parts of the time, and
+    /// `%%` by a literal `%`. The pattern may contain `/` to create nested subfolders (e.g. a file
+    /// last modified in October 2026 is moved into `2026/10/` with the pattern `%Y/%m`).
+    ModifiedDate(String),
+
+    /// Files are grouped by their size: `small/` (less than 1 MiB), `medium/` (less than 100 MiB),
+    /// `large/` (less than 1 GiB), or `huge/` (1 GiB or more).
+    SizeBucket,
+}
+
+/// Determines the subfolder a file should be moved into.
+///
+/// # Arguments
+///
+/// * `file` - The path to the file.
+/// * `rule` - The rule used to organize the files.
+///
+/// # Returns
+///
+/// The path of the subfolder, relative to the folder being organized.
+fn subfolder_for(file: &Path, rule: &OrganizeBy) -> PathBuf {
+    match rule {
+        OrganizeBy::Extension => match file.extension() {
+            Some(extension) => PathBuf::from(extension.to_string_lossy().to_lowercase()),
+            None => PathBuf::from("no_extension"),
+        },
+        OrganizeBy::ModifiedDate(pattern) => {
+            PathBuf::from(format_time_pattern(get_modified_time(file), pattern))
+        }
+        OrganizeBy::SizeBucket => {
+            const MIB: u64 = 1024 * 1024;
+            let size = std::fs::metadata(file)
+                .unwrap_or_else(|_| panic!("Failed to get metadata for '{file:?}'."))
+                .len();
+            PathBuf::from(match size {
+                size if size < MIB => "small",
+                size if size < 100 * MIB => "medium",
+                size if size < 1024 * MIB => "large",
+                _ => "huge",
+            })
+        }
+    }
+}
+
+/// Organizes the files directly within a folder by moving them into subfolders generated
+/// according to a rule.
+///
+/// # Arguments
+///
+/// * `path` - The path to the folder (can be a `&str`, [`String`], [`Path`], or
+///   [`std::path::PathBuf`]).
+/// * `rule` - The rule determining the subfolder each file is moved into.
+///
+/// # Returns
+///
+/// The files that were moved, mapping their original paths to their new paths (in alphabetical
+/// order of their original paths).
+///
+/// # Panics
+///
+/// If some error is encountered while reading the folder, creating a subfolder, or moving a file.
+///
+/// # Note
+///
+/// * Only files directly within the folder are moved (existing subfolders and their contents are
+///   left untouched). Symbolic links are not moved.
+/// * Subfolders are created as needed. If a file with the same name already exists in the
+///   subfolder, the moved file is given a numbered name instead (see [`crate::unique_path`]).
+///
+/// # Example
+///
+/// ```
+/// use file_io::{OrganizeBy, organize_folder, save_string_to_file};
+/// use std::path::Path;
+///
+/// save_string_to_file("", "folder/subfolder_48/photo.JPG");
+/// save_string_to_file("", "folder/subfolder_48/notes.txt");
+///
+/// organize_folder("folder/subfolder_48", &OrganizeBy::Extension);
+///
+/// assert!(Path::new("folder/subfolder_48/jpg/photo.JPG").exists());
+/// assert!(Path::new("folder/subfolder_48/txt/notes.txt").exists());
+/// ```
+pub fn organize_folder<P: AsRef<Path>>(path: P, rule: &OrganizeBy) -> Vec<FileRename> {
+    let path = path.as_ref();
+
+    // Collect the files directly within the folder.
+    let mut files: Vec<PathBuf> = std::fs::read_dir(path)
+        .unwrap_or_else(|_| panic!("Failed to read folder '{path:?}'."))
+        .filter_map(Result::ok)
+        .filter(|entry| entry.file_type().is_ok_and(|file_type| file_type.is_file()))
+        .map(|entry| entry.path())
+        .collect();
+    files.sort();
+
+    // Move each file into its subfolder.
+    files
+        .into_iter()
+        .map(|from| {
+            let subfolder = path.join(subfolder_for(&from, rule));
+            let to = unique_path(subfolder.join(from.file_name().unwrap()));
+            create_folder(&subfolder);
+            let mut mutation = Mutation::start("move_file", &from).to(&to);
+            if !mutation.is_dry_run() {
+                std::fs::rename(&from, &to)
+                    .unwrap_or_else(|_| panic!("Failed to move file '{from:?}' to '{to:?}'."));
+                mutation.succeeded();
+            }
+            FileRename { from, to }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::load::load_file_as_string;
+    use crate::metadata::set_modified_time;
+    use crate::save::save_string_to_file;
+    use crate::test_utils::get_temp_dir_path;
+    use std::time::{Duration, UNIX_EPOCH};
+    use tempfile::tempdir;
+
+    #[test]
+    fn test_organize_folder_by_extension() {
+        // Create a temporary directory to work in.
+        let temp_dir = tempdir().unwrap();
+        let temp_dir_path = get_temp_dir_path(&temp_dir);
+        save_string_to_file("a", temp_dir_path.join("a.PNG"));
+        save_string_to_file("b", temp_dir_path.join("b.png"));
+        save_string_to_file("c", temp_dir_path.join("Makefile"));
+        save_string_to_file("existing", temp_dir_path.join("png/b.png"));
+
+        // Organize the files.
+        let moves = organize_folder(&temp_dir_path, &OrganizeBy::Extension);
+        assert_eq!(
+            moves,
+            [
+                FileRename {
+                    from: temp_dir_path.join("Makefile"),
+                    to: temp_dir_path.join("no_extension/Makefile"),
+                },
+                FileRename {
+                    from: temp_dir_path.join("a.PNG"),
+                    to: temp_dir_path.join("png/a.PNG"),
+                },
+                FileRename {
+                    from: temp_dir_path.join("b.png"),
+                    to: temp_dir_path.join("png/b (1).png"),
+                },
+            ]
+        );
+        assert_eq!(
+            load_file_as_string(temp_dir_path.join("png/b.png")),
+            "existing"
+        );
+        assert_eq!(
+            load_file_as_string(temp_dir_path.join("png/b (1).png")),
+            "b"
+        );
+    }
+
+    #[test]
+    fn test_organize_folder_by_date_and_size() {
+        // Create a temporary directory to work in.
+        let temp_dir = tempdir().unwrap();
+        let temp_dir_path = get_temp_dir_path(&temp_dir);
+        let file_path = temp_dir_path.join("report.pdf");
+        save_string_to_file("report", &file_path);
+        set_modified_time(&file_path, UNIX_EPOCH + Duration::from_secs(1_791_970_215));
+
+        // Organize the files by modification date, then by size.
+        organize_folder(
+            &temp_dir_path,
+            &OrganizeBy::ModifiedDate(String::from("%Y/%m")),
+        );
+        assert!(temp_dir_path.join("2026/10/report.pdf").is_file());
+        organize_folder(temp_dir_path.join("2026/10"), &OrganizeBy::SizeBucket);
+        assert!(temp_dir_path.join("2026/10/small/report.pdf").is_file());
+    }
+}
@@ -0,0 +1,375 @@
+use crate::instrument::Mutation;
+use std::ffi::CString;
+use std::os::unix::fs::{chown, lchown};
+use std::path::Path;
+use walkdir::WalkDir;
+
+/// Looks up an entry in the user or group database by name.
+///
+/// # Arguments
+///
+/// * `name` - The user or group name.
+/// * `lookup` - Function performing the reentrant lookup (`getpwnam_r` or `getgrnam_r`) and
+///   returning the ID if an entry was found.
+///
+/// # Returns
+///
+/// The user or group ID, or `None` if there is no entry with the given name.
+fn lookup_id(
+    name: &str,
+    lookup: impl Fn(&CString, &mut Vec<libc::c_char>) -> Result<Option<u32>, libc::c_int>,
+) -> Option<u32> {
+    let name = CString::new(name).ok()?;
+    let mut buffer: Vec<libc::c_char> = vec![0; 1024];
+    loop {
+        match lookup(&name, &mut buffer) {
+            Ok(id) => return id,
+            // The buffer is too small to hold the entry, so try again with a larger one.
+            Err(libc::ERANGE) => buffer.resize(buffer.len() * 2, 0),
+            Err(_) => return None,
+        }
+    }
+}
+
+/// Get the user ID (UID) of a user.
+///
+/// # Arguments
+///
+/// * `name` - The user name.
+///
+/// # Returns
+///
+/// The user ID, or `None` if there is no user with the given name.
+///
+/// # Example
+///
+/// ```
+/// use file_io::get_uid_by_name;
+///
+/// assert_eq!(get_uid_by_name("root"), Some(0));
+/// ```
+pub fn get_uid_by_name(name: &str) -> Option<u32> {
+    lookup_id(name, |name, buffer| {
+        // SAFETY: `passwd` is plain old data, so the all-zero bit pattern is valid.
+        let mut entry: libc::passwd = unsafe { std::mem::zeroed() };
+        let mut result: *mut libc::passwd = std::ptr::null_mut();
+        // SAFETY: all pointers are valid for the duration of the call, and `buffer.len()` is the
+        // size of the buffer.
+        let code = unsafe {
+            libc::getpwnam_r(
+                name.as_ptr(),
+                &mut entry,
+                buffer.as_mut_ptr(),
+                buffer.len(),
+                &mut result,
+            )
+        };
+        match code {
+            0 => Ok((!result.is_null()).then_some(entry.pw_uid)),
+            code => Err(code),
+        }
+    })
+}
+
+/// Get the group ID (GID) of a group.
+///
+/// # Arguments
+///
+/// * `name` - The group name.
+///
+/// # Returns
+///
+/// The group ID, or `None` if there is no group with the given name.
+///
+/// # Example
+///
+/// ```
+/// use file_io::get_gid_by_name;
+///
+/// assert_eq!(get_gid_by_name("root"), Some(0));
+/// ```
+pub fn get_gid_by_name(name: &str) -> Option<u32> {
+    lookup_id(name, |name, buffer| {
+        // SAFETY: `group` is plain old data, so the all-zero bit pattern is valid.
+        let mut entry: libc::group = unsafe { std::mem::zeroed() };
+        let mut result: *mut libc::group = std::ptr::null_mut();
+        // SAFETY: all pointers are valid for the duration of the call, and `buffer.len()` is the
+        // size of the buffer.
+        let code = unsafe {
+            libc::getgrnam_r(
+                name.as_ptr(),
+                &mut entry,
+                buffer.as_mut_ptr(),
+                buffer.len(),
+                &mut result,
+            )
+        };
+        match code {
+            0 => Ok((!result.is_null()).then_some(entry.gr_gid)),
+            code => Err(code),
+        }
+    })
+}
+
+/// Get the name of a user.
+///
+/// # Arguments
+///
+/// * `uid` - The user ID.
+///
+/// # Returns
+///
+/// The user name, or `None` if there is no user with the given ID.
+pub(crate) fn get_user_name_by_uid(uid: u32) -> Option<String> {
+    let mut buffer: Vec<libc::c_char> = vec![0; 1024];
+    loop {
+        // SAFETY: `passwd` is plain old data, so the all-zero bit pattern is valid.
+        let mut entry: libc::passwd = unsafe { std::mem::zeroed() };
+        let mut result: *mut libc::passwd = std::ptr::null_mut();
+        // SAFETY: all pointers are valid for the duration of the call, and `buffer.len()` is the
+        // size of the buffer.
+        let code = unsafe {
+            libc::getpwuid_r(
+                uid,
+                &mut entry,
+                buffer.as_mut_ptr(),
+                buffer.len(),
+                &mut result,
+            )
+        };
+        match code {
+            0 if result.is_null() => return None,
+            0 => {
+                // SAFETY: on success, `pw_name` points to a NUL-terminated string within `buffer`.
+                let name = unsafe { std::ffi::CStr::from_ptr(entry.pw_name) };
+                return Some(name.to_string_lossy().into_owned());
+            }
+            // The buffer is too small to hold the entry, so try again with a larger one.
+            libc::ERANGE => buffer.resize(buffer.len() * 2, 0),
+            _ => return None,
+        }
+    }
+}
+
+/// Resolves optional user and group names to IDs.
+///
+/// # Arguments
+///
+/// * `user` - The user name (if any).
+/// * `group` - The group name (if any).
+///
+/// # Returns
+///
+/// The user and group IDs.
+///
+/// # Panics
+///
+/// If the user or group does not exist.
+fn resolve_names(user: Option<&str>, group: Option<&str>) -> (Option<u32>, Option<u32>) {
+    let uid = user.map(|user| {
+        get_uid_by_name(user).unwrap_or_else(|| panic!("User '{user}' does not exist."))
+    });
+    let gid = group.map(|group| {
+        get_gid_by_name(group).unwrap_or_else(|| panic!("Group '{group}' does not exist."))
+    });
+    (uid, gid)
+}
+
+/// Changes the owner and/or group of a file or folder.
+///
+/// # Arguments
+///
+/// * `path` - The path to the file or folder (can be a `&str`, [`String`], [`Path`], or
+///   [`std::path::PathBuf`]).
+/// * `uid` - The new owner's user ID (if `None`, the owner is left unchanged).
+/// * `gid` - The new group ID (if `None`, the group is left unchanged).
+///
+/// # Panics
+///
+/// If the owner or group cannot be changed (e.g. due to insufficient privileges).
+///
+/// # Note
+///
+/// * This function is only available on Unix with the `ownership` feature enabled.
+/// * If `path` is a symbolic link, the ownership of its target is changed.
+///
+/// # Example
+///
+/// ```no_run
+/// use file_io::chown_file;
+///
+/// // Change the owner of a file to the user with UID 1000 (leaving its group unchanged).
+/// chown_file("/srv/app/config.toml", Some(1000), None);
+/// ```
+pub fn chown_file<P: AsRef<Path>>(path: P, uid: Option<u32>, gid: Option<u32>) {
+    let path = path.as_ref();
+    let mut mutation = Mutation::start("chown_file", path);
+    if mutation.is_dry_run() {
+        return;
+    }
+    chown(path, uid, gid).unwrap_or_else(|_| panic!("Failed to change ownership of '{path:?}'."));
+    mutation.succeeded();
+}
+
+/// Changes the owner and/or group of a file or folder, specified by name.
+///
+/// # Arguments
+///
+/// * `path` - The path to the file or folder (can be a `&str`, [`String`], [`Path`], or
+///   [`std::path::PathBuf`]).
+/// * `user` - The new owner's user name (if `None`, the owner is left unchanged).
+/// * `group` - The new group name (if `None`, the group is left unchanged).
+///
+/// # Panics
+///
+/// If the user or group does not exist, or if the owner or group cannot be changed.
+///
+/// # Note
+///
+/// See [`chown_file`].
+///
+/// # Example
+///
+/// ```no_run
+/// use file_io::chown_file_by_name;
+///
+/// chown_file_by_name("/srv/app/config.toml", Some("app"), Some("app"));
+/// ```
+pub fn chown_file_by_name<P: AsRef<Path>>(path: P, user: Option<&str>, group: Option<&str>) {
+    let (uid, gid) = resolve_names(user, group);
+    chown_file(path, uid, gid);
+}
+
+/// Recursively changes the owner and/or group of a folder and all of its contents.
+///
+/// # Arguments
+///
+/// * `path` - The path to the folder (can be a `&str`, [`String`], [`Path`], or
+///   [`std::path::PathBuf`]).
+/// * `uid` - The new owner's user ID (if `None`, owners are left unchanged).
+/// * `gid` - The new group ID (if `None`, groups are left unchanged).
+///
+/// # Panics
+///
+/// If the folder cannot be traversed or the ownership of any entry cannot be changed.
+///
+/// # Note
+///
+/// * This function is only available on Unix with the `ownership` feature enabled.
+/// * Symbolic links are not followed (the ownership of the links themselves is changed).
+///
+/// # Example
+///
+/// ```no_run
+/// use file_io::chown_folder_recursive;
+///
+/// chown_folder_recursive("/srv/app", Some(1000), Some(1000));
+/// ```
+pub fn chown_folder_recursive<P: AsRef<Path>>(path: P, uid: Option<u32>, gid: Option<u32>) {
+    let path = path.as_ref();
+    let mut mutation = Mutation::start("chown_folder_recursive", path);
+    if mutation.is_dry_run() {
+        return;
+    }
+    for entry in WalkDir::new(path) {
+        let entry = entry.unwrap_or_else(|_| panic!("Failed to traverse folder '{path:?}'."));
+        let entry_path = entry.path();
+        lchown(entry_path, uid, gid)
+            .unwrap_or_else(|_| panic!("Failed to change ownership of '{entry_path:?}'."));
+    }
+    mutation.succeeded();
+}
+
+/// Recursively changes the owner and/or group of a folder and all of its contents, specified by
+/// name.
+///
+/// # Arguments
+///
+/// * `path` - The path to the folder (can be a `&str`, [`String`], [`Path`], or
+///   [`std::path::PathBuf`]).
+/// * `user` - The new owner's user name (if `None`, owners are left unchanged).
+/// * `group` - The new group name (if `None`, groups are left unchanged).
+///
+/// # Panics
+///
+/// If the user or group does not exist, or if the folder cannot be traversed or the ownership of
+/// any entry cannot be changed.
+///
+/// # Note
+///
+/// See [`chown_folder_recursive`].
+///
+/// # Example
+///
+/// ```no_run
+/// use file_io::chown_folder_recursive_by_name;
+///
+/// chown_folder_recursive_by_name("/srv/app", Some("app"), None);
+/// ```
+pub fn chown_folder_recursive_by_name<P: AsRef<Path>>(
+    path: P,
+    user: Option<&str>,
+    group: Option<&str>,
+) {
+    let (uid, gid) = resolve_names(user, group);
+    chown_folder_recursive(path, uid, gid);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::save::save_string_to_file;
+    use crate::test_utils::get_temp_dir_path;
+    use std::os::unix::fs::MetadataExt;
+    use tempfile::tempdir;
+
+    #[test]
+    fn test_get_id_by_name() {
+        assert_eq!(get_uid_by_name("root"), Some(0));
+        assert_eq!(get_gid_by_name("root"), Some(0));
+        assert_eq!(get_uid_by_name("no_such_user_file_io"), None);
+        assert_eq!(get_gid_by_name("no_such_group_file_io"), None);
+    }
+
+    #[test]
+    fn test_get_user_name_by_uid() {
+        assert_eq!(get_user_name_by_uid(0).as_deref(), Some("root"));
+        assert_eq!(get_user_name_by_uid(u32::MAX - 1), None);
+    }
+
+    #[test]
+    fn test_chown() {
+        // Create a temporary directory to work in.
+        let temp_dir = tempdir().unwrap();
+        let temp_dir_path = get_temp_dir_path(&temp_dir);
+
+        // Create some files.
+        let file_path = temp_dir_path.join("file.txt");
+        let nested_file_path = temp_dir_path.join("nested/file.txt");
+        save_string_to_file("Hello, world!", &file_path);
+        save_string_to_file("Hello, world!", &nested_file_path);
+
+        // The current user's IDs (changing ownership to the current owner is always permitted).
+        let metadata = std::fs::metadata(&file_path).unwrap();
+        let (uid, gid) = (metadata.uid(), metadata.gid());
+
+        // Change the ownership of a single file.
+        chown_file(&file_path, Some(uid), Some(gid));
+        chown_file(&file_path, None, None);
+
+        // Change the ownership of the folder recursively.
+        chown_folder_recursive(&temp_dir_path, Some(uid), Some(gid));
+
+        // Check that the ownership is as expected.
+        for path in [&file_path, &nested_file_path] {
+            let metadata = std::fs::metadata(path).unwrap();
+            assert_eq!((metadata.uid(), metadata.gid()), (uid, gid));
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "User 'no_such_user_file_io' does not exist.")]
+    fn test_chown_file_by_name_unknown_user() {
+        chown_file_by_name("Cargo.toml", Some("no_such_user_file_io"), None);
+    }
+}
@@ -1,6 +1,38 @@
-use std::path::{Path, PathBuf};
+use std::path::{Component, Path, PathBuf};
 
-/// Retrieves the user's home directory from the `$HOME` environment variable.
+/// Retrieves the user's home directory.
+///
+/// # Returns
+///
+/// Path to the user's home directory, or `None` if it cannot be determined.
+///
+/// # Note
+///
+/// On Windows, the home directory is read from the `%USERPROFILE%` environment variable, falling
+/// back to `%HOMEDRIVE%` followed by `%HOMEPATH%`. On all other platforms, it is read from the
+/// `$HOME` environment variable. Empty variables are treated as unset.
+///
+/// # Example
+///
+/// ```
+/// use file_io::try_get_home;
+///
+/// if let Some(home) = try_get_home() {
+///     println!("Home directory: {home:?}");
+/// }
+/// ```
+pub fn try_get_home() -> Option<PathBuf> {
+    let var = |name: &str| std::env::var_os(name).filter(|value| !value.is_empty());
+    if cfg!(windows) {
+        var("USERPROFILE")
+            .map(PathBuf::from)
+            .or_else(|| Some(PathBuf::from(var("HOMEDRIVE")?).join(var("HOMEPATH")?)))
+    } else {
+        var("HOME").map(PathBuf::from)
+    }
+}
+
+/// Retrieves the user's home directory as a [`PathBuf`].
 ///
 /// # Returns
 ///
@@ -8,7 +40,29 @@ use std::path::{Path, PathBuf};
 ///
 /// # Panics
 ///
-/// If the `$HOME` environment variable is not set.
+/// If the home directory cannot be determined (see [`try_get_home`]).
+///
+/// # Example
+///
+/// ```
+/// use file_io::get_home_path;
+/// use std::path::PathBuf;
+///
+/// let home: PathBuf = get_home_path();
+/// ```
+pub fn get_home_path() -> PathBuf {
+    try_get_home().expect("Failed to determine the home directory.")
+}
+
+/// Retrieves the user's home directory.
+///
+/// # Returns
+///
+/// Path to the user's home directory (any invalid UTF-8 is replaced with `�`).
+///
+/// # Panics
+///
+/// If the home directory cannot be determined (see [`try_get_home`]).
 ///
 /// # Example
 ///
@@ -18,7 +72,7 @@ use std::path::{Path, PathBuf};
 /// let home: String = get_home();
 /// ```
 pub fn get_home() -> String {
-    std::env::var("HOME").expect("HOME environment variable is not set.")
+    get_home_path().to_string_lossy().into_owned()
 }
 
 /// Get the current working directory.
@@ -77,11 +131,63 @@ pub fn get_cwd() -> PathBuf {
 /// assert_eq!(name, "folder");
 /// ```
 pub fn get_last_path_component<P: AsRef<Path>>(path: P) -> String {
+    split_path(path).pop().unwrap()
+}
+
+/// Split a path into its components.
+///
+/// # Arguments
+///
+/// * `path` - The path to split (can be a `&str`, [`String`], [`Path`], or [`PathBuf`]).
+///
+/// # Returns
+///
+/// The components of the path, as strings (any invalid UTF-8 is replaced with `�`). For absolute
+/// paths, the first component is the root (e.g. `/` on Unix, or `C:` followed by `\` on Windows).
+///
+/// # Example
+///
+/// ```
+/// use file_io::split_path;
+///
+/// assert_eq!(split_path("some/path/to/file.txt"), ["some", "path", "to", "file.txt"]);
+/// ```
+pub fn split_path<P: AsRef<Path>>(path: P) -> Vec<String> {
     path.as_ref()
         .components()
-        .next_back()
         .map(|comp| comp.as_os_str().to_string_lossy().into_owned())
-        .unwrap()
+        .collect()
+}
+
+/// Get the depth of a path (i.e. the number of named components it contains).
+///
+/// # Arguments
+///
+/// * `path` - The path (can be a `&str`, [`String`], [`Path`], or [`PathBuf`]).
+///
+/// # Returns
+///
+/// The number of components of the path, not counting any root, prefix, or `.` components.
+///
+/// # Note
+///
+/// This is purely lexical (i.e. `..` components are counted like any other component). Use
+/// [`normalize_path`] first if they should be resolved.
+///
+/// # Example
+///
+/// ```
+/// use file_io::path_depth;
+///
+/// assert_eq!(path_depth("some/path/to/file.txt"), 4);
+/// assert_eq!(path_depth("/some/path"), 2);
+/// assert_eq!(path_depth("."), 0);
+/// ```
+pub fn path_depth<P: AsRef<Path>>(path: P) -> usize {
+    path.as_ref()
+        .components()
+        .filter(|comp| matches!(comp, Component::Normal(_) | Component::ParentDir))
+        .count()
 }
 
 /// Get the file name (including any extension).
@@ -136,88 +242,1145 @@ pub fn get_file_name<P: AsRef<Path>>(path: P) -> String {
 /// let file_stem = get_file_stem("/some/path/to/file.txt");
 /// assert_eq!(file_stem, "file");
 /// ```
-pub fn get_file_stem<P: AsRef<Path>>(path: P) -> String {
-    path.as_ref()
-        .file_stem()
-        .and_then(|s| s.to_str())
-        .map(String::from)
-        .expect("Failed to get the file stem.")
+pub fn get_file_stem<P: AsRef<Path>>(path: P) -> String {
+    path.as_ref()
+        .file_stem()
+        .and_then(|s| s.to_str())
+        .map(String::from)
+        .expect("Failed to get the file stem.")
+}
+
+/// Get the file extension.
+///
+/// # Arguments
+///     
+/// * `path` - The path to the file (can be a `&str`, [`String`], [`Path`], or [`PathBuf`]).
+///
+/// # Returns
+///
+/// The file extension. If the file has no extension, or if the extension cannot be determined, this
+/// function returns an empty string.
+///
+/// # Example
+///
+/// ```
+/// use file_io::get_file_extension;
+///
+/// let file_extension = get_file_extension("/some/path/to/file.txt");
+/// assert_eq!(file_extension, "txt");
+/// ```
+pub fn get_file_extension<P: AsRef<Path>>(path: P) -> String {
+    path.as_ref()
+        .extension()
+        .and_then(|s| s.to_str())
+        .map(String::from)
+        .unwrap_or(String::from(""))
+}
+
+/// Replace (or add) the file extension of a path.
+///
+/// # Arguments
+///
+/// * `path` - The path to the file (can be a `&str`, [`String`], [`Path`], or [`PathBuf`]).
+/// * `extension` - The new extension (with or without a leading `.`). If empty, the extension is
+///   removed.
+///
+/// # Returns
+///
+/// The path with its extension replaced.
+///
+/// # Example
+///
+/// ```
+/// use file_io::with_extension;
+/// use std::path::PathBuf;
+///
+/// assert_eq!(
+///     with_extension("/some/path/to/file.txt", "md"),
+///     PathBuf::from("/some/path/to/file.md")
+/// );
+/// assert_eq!(
+///     with_extension("/some/path/to/file", ".md"),
+///     PathBuf::from("/some/path/to/file.md")
+/// );
+/// ```
+pub fn with_extension<P: AsRef<Path>>(path: P, extension: &str) -> PathBuf {
+    path.as_ref()
+        .with_extension(extension.strip_prefix('.').unwrap_or(extension))
+}
+
+/// Remove the file extension of a path.
+///
+/// # Arguments
+///
+/// * `path` - The path to the file (can be a `&str`, [`String`], [`Path`], or [`PathBuf`]).
+///
+/// # Returns
+///
+/// The path without its (last) extension. If the file has no extension, the path is returned
+/// unchanged.
+///
+/// # Example
+///
+/// ```
+/// use file_io::remove_extension;
+/// use std::path::PathBuf;
+///
+/// assert_eq!(
+///     remove_extension("/some/path/to/file.txt"),
+///     PathBuf::from("/some/path/to/file")
+/// );
+/// assert_eq!(
+///     remove_extension("archive.tar.gz"),
+///     PathBuf::from("archive.tar")
+/// );
+/// ```
+pub fn remove_extension<P: AsRef<Path>>(path: P) -> PathBuf {
+    path.as_ref().with_extension("")
+}
+
+/// Check whether a path has one of the given file extensions.
+///
+/// # Arguments
+///
+/// * `path` - The path to the file (can be a `&str`, [`String`], [`Path`], or [`PathBuf`]).
+/// * `extensions` - The extensions to check for (with or without a leading `.`).
+///
+/// # Returns
+///
+/// `true` if the extension of `path` matches any of `extensions` (case-sensitively), `false`
+/// otherwise (including if `path` has no extension).
+///
+/// # Example
+///
+/// ```
+/// use file_io::has_extension;
+///
+/// assert!(has_extension("src/lib.rs", &["rs", "toml"]));
+/// assert!(!has_extension("src/lib.RS", &["rs", "toml"]));
+/// assert!(!has_extension("README.md", &["rs", "toml"]));
+/// ```
+pub fn has_extension<P: AsRef<Path>>(path: P, extensions: &[&str]) -> bool {
+    extension_matches(path.as_ref(), extensions, false)
+}
+
+/// Check whether a path has one of the given file extensions, ignoring ASCII case.
+///
+/// # Arguments
+///
+/// * `path` - The path to the file (can be a `&str`, [`String`], [`Path`], or [`PathBuf`]).
+/// * `extensions` - The extensions to check for (with or without a leading `.`).
+///
+/// # Returns
+///
+/// `true` if the extension of `path` matches any of `extensions` (ignoring ASCII case), `false`
+/// otherwise (including if `path` has no extension).
+///
+/// # Example
+///
+/// ```
+/// use file_io::has_extension_ignore_case;
+///
+/// assert!(has_extension_ignore_case("photo.JPG", &["jpg", "png"]));
+/// assert!(!has_extension_ignore_case("photo.gif", &["jpg", "png"]));
+/// ```
+pub fn has_extension_ignore_case<P: AsRef<Path>>(path: P, extensions: &[&str]) -> bool {
+    extension_matches(path.as_ref(), extensions, true)
+}
+
+/// Check whether a path has one of the given file extensions.
+///
+/// # Arguments
+///
+/// * `path` - The path to the file.
+/// * `extensions` - The extensions to check for (with or without a leading `.`).
+/// * `ignore_case` - Whether to ignore ASCII case when comparing extensions.
+///
+/// # Returns
+///
+/// `true` if the extension of `path` matches any of `extensions`, `false` otherwise.
+pub(crate) fn extension_matches(path: &Path, extensions: &[&str], ignore_case: bool) -> bool {
+    let Some(actual) = path.extension().and_then(|s| s.to_str()) else {
+        return false;
+    };
+    extensions.iter().any(|extension| {
+        let extension = extension.strip_prefix('.').unwrap_or(extension);
+        if ignore_case {
+            actual.eq_ignore_ascii_case(extension)
+        } else {
+            actual == extension
+        }
+    })
+}
+
+/// Add a suffix to the file stem of a path (i.e. before the extension).
+///
+/// # Arguments
+///
+/// * `path` - The path to the file (can be a `&str`, [`String`], [`Path`], or [`PathBuf`]).
+/// * `suffix` - The suffix to add.
+///
+/// # Returns
+///
+/// The path with `suffix` appended to its file stem.
+///
+/// # Example
+///
+/// ```
+/// use file_io::add_suffix_to_stem;
+/// use std::path::PathBuf;
+///
+/// assert_eq!(
+///     add_suffix_to_stem("a/b/report.csv", "_v2"),
+///     PathBuf::from("a/b/report_v2.csv")
+/// );
+/// ```
+pub fn add_suffix_to_stem<P: AsRef<Path>>(path: P, suffix: &str) -> PathBuf {
+    rename_stem(path.as_ref(), |stem| format!("{stem}{suffix}"))
+}
+
+/// Add a prefix to the file stem of a path (i.e. to the start of the file name).
+///
+/// # Arguments
+///
+/// * `path` - The path to the file (can be a `&str`, [`String`], [`Path`], or [`PathBuf`]).
+/// * `prefix` - The prefix to add.
+///
+/// # Returns
+///
+/// The path with `prefix` prepended to its file stem.
+///
+/// # Example
+///
+/// ```
+/// use file_io::add_prefix_to_stem;
+/// use std::path::PathBuf;
+///
+/// assert_eq!(
+///     add_prefix_to_stem("a/b/report.csv", "clean_"),
+///     PathBuf::from("a/b/clean_report.csv")
+/// );
+/// ```
+pub fn add_prefix_to_stem<P: AsRef<Path>>(path: P, prefix: &str) -> PathBuf {
+    rename_stem(path.as_ref(), |stem| format!("{prefix}{stem}"))
+}
+
+/// Replaces the file stem of a path, keeping its extension.
+///
+/// # Arguments
+///
+/// * `path` - The path to the file.
+/// * `rename` - Function mapping the old file stem to the new file stem.
+///
+/// # Returns
+///
+/// The path with its file stem replaced.
+fn rename_stem(path: &Path, rename: impl FnOnce(&str) -> String) -> PathBuf {
+    let stem = path
+        .file_stem()
+        .map(|stem| stem.to_string_lossy().into_owned())
+        .unwrap_or_default();
+    let file_name = match path.extension() {
+        Some(extension) => format!("{}.{}", rename(&stem), extension.to_string_lossy()),
+        None => rename(&stem),
+    };
+    path.with_file_name(file_name)
+}
+
+/// Converts a path to a `PathBuf`.
+///
+/// # Arguments
+///
+/// * `path` - The path to convert (can be a `&str`, [`String`], [`Path`], or [`PathBuf`]).
+///
+/// # Returns
+///
+/// A `PathBuf` representation of the path.
+///
+/// # Examples
+///
+/// ## Using a string literal
+///
+/// ```
+/// use file_io::to_path_buf;
+/// use std::path::PathBuf;
+///
+/// let path: &str = "folder/subfolder_9/file.txt";
+/// let path_buf: PathBuf = to_path_buf(path);
+/// ```
+///
+/// ## Using a `Path` reference
+///
+/// ```
+/// use file_io::to_path_buf;
+/// use std::path::{Path, PathBuf};
+///
+/// let path: &Path = Path::new("folder/subfolder_10/file.txt");
+/// let path_buf: PathBuf = to_path_buf(path);
+/// ```
+pub fn to_path_buf<P: AsRef<Path>>(path: P) -> PathBuf {
+    path.as_ref().to_path_buf()
+}
+
+/// Converts a path to a string using forward slashes (`/`) as separators.
+///
+/// # Arguments
+///
+/// * `path` - The path to convert (can be a `&str`, [`String`], [`Path`], or [`PathBuf`]).
+///
+/// # Returns
+///
+/// The path as a string (any invalid UTF-8 is replaced with `�`), with all platform-specific
+/// separators replaced with `/`.
+///
+/// # Note
+///
+/// On Windows, backslashes (`\`) are replaced with `/`. On all other platforms, `/` already is the
+/// separator and backslashes are valid characters in file names, so the path is left unchanged.
+///
+/// # Example
+///
+/// ```
+/// use file_io::to_forward_slashes;
+/// use std::path::Path;
+///
+/// let path = Path::new("some").join("path").join("file.txt");
+/// assert_eq!(to_forward_slashes(path), "some/path/file.txt");
+/// ```
+pub fn to_forward_slashes<P: AsRef<Path>>(path: P) -> String {
+    let path = path.as_ref().to_string_lossy();
+    if std::path::MAIN_SEPARATOR == '/' {
+        path.into_owned()
+    } else {
+        path.replace(std::path::MAIN_SEPARATOR, "/")
+    }
+}
+
+/// Converts a path to use the separator of the current platform.
+///
+/// # Arguments
+///
+/// * `path` - The path to convert (can be a `&str`, [`String`], [`Path`], or [`PathBuf`]).
+///
+/// # Returns
+///
+/// The path, with all forward slashes (`/`) and backslashes (`\`) replaced with the separator of
+/// the current platform (i.e. `\` on Windows and `/` everywhere else). Any invalid UTF-8 is
+/// replaced with `�`.
+///
+/// # Note
+///
+/// This is intended for paths read from sources that use a fixed separator style (e.g. config
+/// files). On platforms other than Windows, backslashes are valid characters in file names, so
+/// this function should not be used on paths that may legitimately contain them.
+///
+/// # Example
+///
+/// ```
+/// use file_io::to_native_separators;
+/// use std::path::Path;
+///
+/// let expected = Path::new("some").join("path").join("file.txt");
+/// assert_eq!(to_native_separators("some/path/file.txt"), expected);
+/// assert_eq!(to_native_separators(r"some\path\file.txt"), expected);
+/// ```
+pub fn to_native_separators<P: AsRef<Path>>(path: P) -> PathBuf {
+    PathBuf::from(
+        path.as_ref()
+            .to_string_lossy()
+            .replace(['/', '\\'], std::path::MAIN_SEPARATOR_STR),
+    )
+}
+
+/// Converts a path to the Windows extended-length form (e.g. `\\?\C:\folder\file.txt`), which is
+/// not subject to the 260 character `MAX_PATH` limit.
+///
+/// # Arguments
+///
+/// * `path` - The path to convert (can be a `&str`, [`String`], [`Path`], or [`PathBuf`]).
+///
+/// # Returns
+///
+/// On Windows, the absolute, normalized path with the `\\?\` prefix (or `\\?\UNC\` for network
+/// paths). Paths that already start with `\\?\` or `\\.\` are returned unchanged. On all other
+/// platforms, `path` is returned unchanged.
+///
+/// # Note
+///
+/// * The standard library already applies this prefix internally when a long path is passed to
+///   a filesystem operation, so all of this crate's functions work with long paths. This function
+///   is intended for passing long paths to other programs or APIs that do not.
+/// * Extended-length paths are used verbatim by Windows (i.e. `/` is not treated as a separator,
+///   and `.` and `..` are not resolved), which is why the path is normalized first.
+///
+/// # Example
+///
+/// ```
+/// use file_io::to_long_path;
+/// use std::path::Path;
+///
+/// let long_path = to_long_path("folder/file.txt");
+///
+/// #[cfg(windows)]
+/// assert!(long_path.to_string_lossy().starts_with(r"\\?\"));
+/// #[cfg(not(windows))]
+/// assert_eq!(long_path, Path::new("folder/file.txt"));
+/// ```
+pub fn to_long_path<P: AsRef<Path>>(path: P) -> PathBuf {
+    let path = path.as_ref();
+    let path_str = path.to_string_lossy();
+    if !cfg!(windows) || path_str.starts_with(r"\\?\") || path_str.starts_with(r"\\.\") {
+        return path.to_path_buf();
+    }
+    let absolute = to_native_separators(absolute_path(path));
+    let absolute = absolute.to_string_lossy();
+    match absolute.strip_prefix(r"\\") {
+        Some(unc) => PathBuf::from(format!(r"\\?\UNC\{unc}")),
+        None => PathBuf::from(format!(r"\\?\{absolute}")),
+    }
+}
+
+/// Strips the Windows extended-length (verbatim) prefix from a path, if present.
+///
+/// # Arguments
+///
+/// * `path` - The path to simplify.
+///
+/// # Returns
+///
+/// The path without its verbatim prefix (i.e. `\\?\C:\folder` becomes `C:\folder`, and
+/// `\\?\UNC\server\share` becomes `\\server\share`). Paths without a verbatim prefix (or with a
+/// verbatim prefix that has no non-verbatim equivalent) are returned unchanged.
+fn strip_verbatim_prefix(path: &Path) -> PathBuf {
+    let path_str = path.to_string_lossy();
+    if let Some(rest) = path_str.strip_prefix(r"\\?\UNC\") {
+        return PathBuf::from(format!(r"\\{rest}"));
+    }
+    if let Some(rest) = path_str.strip_prefix(r"\\?\") {
+        let bytes = rest.as_bytes();
+        if bytes.len() >= 2 && bytes[0].is_ascii_alphabetic() && bytes[1] == b':' {
+            return PathBuf::from(rest);
+        }
+    }
+    path.to_path_buf()
+}
+
+/// Get the canonical (i.e. absolute) form of a path, with all intermediate components normalized
+/// and symbolic links resolved.
+///
+/// # Arguments
+///
+/// * `path` - The path to canonicalize (can be a `&str`, [`String`], [`Path`], or [`PathBuf`]).
+///
+/// # Returns
+///
+/// The canonical path.
+///
+/// # Panics
+///
+/// If the path does not exist or cannot be canonicalized.
+///
+/// # Note
+///
+/// On Windows, the canonical path uses the extended-length `\\?\` prefix (e.g.
+/// `\\?\C:\Users\me`). Use [`canonicalize_path_for_display`] to obtain a path without this prefix.
+///
+/// # Example
+///
+/// ```
+/// use file_io::{canonicalize_path, get_cwd};
+///
+/// // Canonicalize a path containing a `..` component.
+/// let path = canonicalize_path("src/../Cargo.toml");
+/// assert_eq!(path, get_cwd().join("Cargo.toml"));
+/// ```
+pub fn canonicalize_path<P: AsRef<Path>>(path: P) -> PathBuf {
+    let path = path.as_ref();
+    std::fs::canonicalize(path).unwrap_or_else(|_| panic!("Failed to canonicalize '{path:?}'."))
+}
+
+/// Get the canonical form of a path (see [`canonicalize_path`]), without the Windows
+/// extended-length `\\?\` prefix.
+///
+/// # Arguments
+///
+/// * `path` - The path to canonicalize (can be a `&str`, [`String`], [`Path`], or [`PathBuf`]).
+///
+/// # Returns
+///
+/// The canonical path, in a form suitable for display to users or for passing to programs that do
+/// not understand extended-length paths.
+///
+/// # Panics
+///
+/// If the path does not exist or cannot be canonicalized.
+///
+/// # Note
+///
+/// On platforms other than Windows, this function is equivalent to [`canonicalize_path`].
+///
+/// # Example
+///
+/// ```
+/// use file_io::{canonicalize_path_for_display, get_cwd};
+///
+/// let path = canonicalize_path_for_display("src/../Cargo.toml");
+/// assert_eq!(path, get_cwd().join("Cargo.toml"));
+/// ```
+pub fn canonicalize_path_for_display<P: AsRef<Path>>(path: P) -> PathBuf {
+    strip_verbatim_prefix(&canonicalize_path(path))
+}
+
+/// Lexically normalizes a path (without touching the filesystem).
+///
+/// # Arguments
+///
+/// * `path` - The path to normalize (can be a `&str`, [`String`], [`Path`], or [`PathBuf`]).
+///
+/// # Returns
+///
+/// The normalized path, where:
+///
+/// * duplicate separators and `.` components are removed,
+/// * each `..` component is resolved against the preceding component where possible,
+/// * `..` components at the start of a relative path are kept, and
+/// * `..` components directly after the root of an absolute path are removed.
+///
+/// If the normalized path is empty, `.` is returned.
+///
+/// # Note
+///
+/// Since this function does not access the filesystem, it works for paths that do not exist.
+/// However, resolving `..` lexically can give a different result than the filesystem would if the
+/// preceding component is a symbolic link. Use [`canonicalize_path`] to resolve symbolic links.
+///
+/// # Example
+///
+/// ```
+/// use file_io::normalize_path;
+/// use std::path::PathBuf;
+///
+/// assert_eq!(normalize_path("a/./b//../c"), PathBuf::from("a/c"));
+/// assert_eq!(normalize_path("../a/../../b"), PathBuf::from("../../b"));
+/// assert_eq!(normalize_path("/../a"), PathBuf::from("/a"));
+/// ```
+pub fn normalize_path<P: AsRef<Path>>(path: P) -> PathBuf {
+    let mut components: Vec<Component> = Vec::new();
+    for component in path.as_ref().components() {
+        match component {
+            Component::CurDir => {}
+            Component::ParentDir => match components.last() {
+                Some(Component::Normal(_)) => {
+                    components.pop();
+                }
+                Some(Component::RootDir) | Some(Component::Prefix(_)) => {}
+                _ => components.push(component),
+            },
+            _ => components.push(component),
+        }
+    }
+    if components.is_empty() {
+        PathBuf::from(".")
+    } else {
+        components.iter().collect()
+    }
+}
+
+/// Converts a path into an absolute path, relative to the current working directory.
+///
+/// # Arguments
+///
+/// * `path` - The path to convert (can be a `&str`, [`String`], [`Path`], or [`PathBuf`]).
+///
+/// # Returns
+///
+/// The absolute path (lexically normalized using [`normalize_path`]). If `path` is already
+/// absolute, it is only normalized.
+///
+/// # Panics
+///
+/// If the current working directory cannot be determined.
+///
+/// # Note
+///
+/// Unlike [`canonicalize_path`], this function does not require the path to exist, and does not
+/// resolve symbolic links.
+///
+/// # Example
+///
+/// ```
+/// use file_io::{absolute_path, get_cwd};
+///
+/// assert_eq!(absolute_path("does/not/../exist.txt"), get_cwd().join("does/exist.txt"));
+/// ```
+pub fn absolute_path<P: AsRef<Path>>(path: P) -> PathBuf {
+    absolute_path_from(path, get_cwd())
+}
+
+/// Converts a path into an absolute path, relative to a given base folder.
+///
+/// # Arguments
+///
+/// * `path` - The path to convert (can be a `&str`, [`String`], [`Path`], or [`PathBuf`]).
+/// * `base` - The folder that `path` is relative to (can be a `&str`, [`String`], [`Path`], or
+///   [`PathBuf`]). If `base` is itself relative, it is interpreted relative to the current working
+///   directory.
+///
+/// # Returns
+///
+/// The absolute path (lexically normalized using [`normalize_path`]). If `path` is already
+/// absolute, it is only normalized (and `base` is ignored).
+///
+/// # Panics
+///
+/// If `base` is relative and the current working directory cannot be determined.
+///
+/// # Note
+///
+/// Unlike [`canonicalize_path`], this function does not require either path to exist, and does
+/// not resolve symbolic links.
+///
+/// # Example
+///
+/// ```
+/// use file_io::absolute_path_from;
+/// use std::path::PathBuf;
+///
+/// assert_eq!(
+///     absolute_path_from("../config/settings.toml", "/srv/app/bin"),
+///     PathBuf::from("/srv/app/config/settings.toml")
+/// );
+/// ```
+pub fn absolute_path_from<P: AsRef<Path>, Q: AsRef<Path>>(path: P, base: Q) -> PathBuf {
+    let path = path.as_ref();
+    let base = base.as_ref();
+    if path.is_absolute() {
+        normalize_path(path)
+    } else if base.is_absolute() {
+        normalize_path(base.join(path))
+    } else {
+        normalize_path(get_cwd().join(base).join(path))
+    }
+}
+
+/// Computes the relative path from one folder to another path.
+///
+/// # Arguments
+///
+/// * `from` - The folder the relative path starts from (can be a `&str`, [`String`], [`Path`], or
+///   [`PathBuf`]).
+/// * `to` - The path the relative path leads to (can be a `&str`, [`String`], [`Path`], or
+///   [`PathBuf`]).
+///
+/// # Returns
+///
+/// The relative path which, when joined onto `from`, leads to `to` (using `..` components as
+/// needed). If `from` and `to` are the same path, `.` is returned.
+///
+/// # Panics
+///
+/// If no relative path exists between the two paths (e.g. they are on different drives on
+/// Windows), or if either path is relative and the current working directory cannot be determined.
+///
+/// # Note
+///
+/// Both paths are first converted to absolute paths using [`absolute_path`], so they do not need
+/// to exist. Symbolic links are not resolved.
+///
+/// # Example
+///
+/// ```
+/// use file_io::relative_path_between;
+/// use std::path::PathBuf;
+///
+/// assert_eq!(
+///     relative_path_between("/project/docs/api", "/project/src/lib.rs"),
+///     PathBuf::from("../../src/lib.rs")
+/// );
+/// ```
+pub fn relative_path_between<P: AsRef<Path>, Q: AsRef<Path>>(from: P, to: Q) -> PathBuf {
+    let from = absolute_path(from);
+    let to = absolute_path(to);
+    let from_components: Vec<Component> = from.components().collect();
+    let to_components: Vec<Component> = to.components().collect();
+
+    // Both paths must share the same root (and, on Windows, the same prefix).
+    let roots_match = match (from_components.first(), to_components.first()) {
+        (Some(Component::Prefix(a)), Some(Component::Prefix(b))) => a == b,
+        (Some(Component::Prefix(_)), _) | (_, Some(Component::Prefix(_))) => false,
+        _ => true,
+    };
+    if !roots_match {
+        panic!("No relative path exists from '{from:?}' to '{to:?}'.");
+    }
+
+    // Number of leading components shared by both paths.
+    let common = from_components
+        .iter()
+        .zip(&to_components)
+        .take_while(|(a, b)| a == b)
+        .count();
+
+    // Go up out of the remaining components of `from`, then down into the remaining components of
+    // `to`.
+    let relative_path: PathBuf =
+        std::iter::repeat_n(Component::ParentDir, from_components.len() - common)
+            .chain(to_components[common..].iter().copied())
+            .collect();
+    if relative_path.as_os_str().is_empty() {
+        PathBuf::from(".")
+    } else {
+        relative_path
+    }
+}
+
+/// Resolves a path as far as possible without requiring it to exist.
+///
+/// # Arguments
+///
+/// * `path` - The path to resolve.
+///
+/// # Returns
+///
+/// The absolute, normalized path. Symbolic links are resolved in the longest leading portion of
+/// the path that exists; the remaining (non-existent) components are normalized lexically.
+fn resolve_path(path: &Path) -> PathBuf {
+    let path = if path.is_absolute() {
+        path.to_path_buf()
+    } else {
+        get_cwd().join(path)
+    };
+    for ancestor in path.ancestors() {
+        if let Ok(canonical) = std::fs::canonicalize(ancestor) {
+            let remainder = path.strip_prefix(ancestor).unwrap();
+            return normalize_path(strip_verbatim_prefix(&canonical).join(remainder));
+        }
+    }
+    normalize_path(path)
+}
+
+/// Check whether a path is located inside another path.
+///
+/// # Arguments
+///
+/// * `child` - The path to check (can be a `&str`, [`String`], [`Path`], or [`PathBuf`]).
+/// * `ancestor` - The path that `child` should be inside (can be a `&str`, [`String`], [`Path`],
+///   or [`PathBuf`]).
+///
+/// # Returns
+///
+/// `true` if `child` is `ancestor` itself or located (at any depth) below it, `false` otherwise.
+///
+/// # Note
+///
+/// Both paths are made absolute (relative to the current working directory) and normalized before
+/// they are compared, so `..` components cannot be used to escape `ancestor`. Symbolic links are
+/// resolved in the portion of each path that exists, so a symbolic link inside `ancestor` that
+/// points outside of it is also detected. Neither path needs to exist.
+///
+/// # Example
+///
+/// ```
+/// use file_io::is_inside;
+///
+/// assert!(is_inside("folder/subfolder/file.txt", "folder"));
+/// assert!(!is_inside("folder/../file.txt", "folder"));
+/// assert!(!is_inside("folder_2/file.txt", "folder"));
+/// ```
+pub fn is_inside<P: AsRef<Path>, Q: AsRef<Path>>(child: P, ancestor: Q) -> bool {
+    resolve_path(child.as_ref()).starts_with(resolve_path(ancestor.as_ref()))
+}
+
+/// Get the deepest common ancestor of multiple paths.
+///
+/// # Arguments
+///
+/// * `paths` - The paths (each can be a `&str`, [`String`], [`Path`], or [`PathBuf`]).
+///
+/// # Returns
+///
+/// The longest path that all of `paths` start with, or `None` if `paths` is empty or the paths
+/// do not share any leading component (e.g. an absolute and a relative path, or two relative paths
+/// starting with different folders).
+///
+/// # Note
+///
+/// * This is purely lexical (the filesystem is not accessed). Use [`normalize_path`] or
+///   [`absolute_path`] first if the paths may contain `.`/`..` components or mix absolute and
+///   relative paths.
+/// * If one of the paths is an ancestor of all the others, that path itself is returned (even if
+///   it points to a file).
+///
+/// # Example
+///
+/// ```
+/// use file_io::common_ancestor;
+/// use std::path::PathBuf;
+///
+/// assert_eq!(
+///     common_ancestor(&["/data/in/a.csv", "/data/in/sub/b.csv", "/data/out/c.csv"]),
+///     Some(PathBuf::from("/data"))
+/// );
+/// assert_eq!(common_ancestor(&["a/b", "c/d"]), None);
+/// ```
+pub fn common_ancestor<P: AsRef<Path>>(paths: &[P]) -> Option<PathBuf> {
+    let (first, rest) = paths.split_first()?;
+    let mut common: Vec<Component> = first.as_ref().components().collect();
+    for path in rest {
+        let shared = common
+            .iter()
+            .zip(path.as_ref().components())
+            .take_while(|(a, b)| **a == *b)
+            .count();
+        common.truncate(shared);
+    }
+    if common.is_empty() {
+        None
+    } else {
+        Some(common.iter().collect())
+    }
+}
+
+/// Check whether two paths are equal, ignoring case.
+///
+/// # Arguments
+///
+/// * `a` - The first path (can be a `&str`, [`String`], [`Path`], or [`PathBuf`]).
+/// * `b` - The second path (can be a `&str`, [`String`], [`Path`], or [`PathBuf`]).
+///
+/// # Returns
+///
+/// `true` if the paths are equal when compared component by component without regard to case,
+/// `false` otherwise.
+///
+/// # Note
+///
+/// * Both paths are normalized lexically first (see [`normalize_path`]), so e.g. `a/./B` and
+///   `A/b/` are considered equal. The filesystem is not accessed.
+/// * Case is folded using Unicode lowercase mapping, which matches the behavior of the default
+///   case-insensitive filesystems on Windows and macOS for the vast majority of file names.
+///
+/// # Example
+///
+/// ```
+/// use file_io::paths_equal_ignore_case;
+///
+/// assert!(paths_equal_ignore_case("Folder/File.TXT", "folder/file.txt"));
+/// assert!(!paths_equal_ignore_case("folder/file.txt", "folder/file.md"));
+/// ```
+pub fn paths_equal_ignore_case<P: AsRef<Path>, Q: AsRef<Path>>(a: P, b: Q) -> bool {
+    let a = normalize_path(a);
+    let b = normalize_path(b);
+    a.components().count() == b.components().count()
+        && a.components().zip(b.components()).all(|(a, b)| {
+            names_equal_ignore_case(
+                &a.as_os_str().to_string_lossy(),
+                &b.as_os_str().to_string_lossy(),
+            )
+        })
+}
+
+/// Check whether two file or folder names are equal, ignoring case.
+///
+/// # Arguments
+///
+/// * `a` - The first name.
+/// * `b` - The second name.
+///
+/// # Returns
+///
+/// `true` if the names are equal when compared using Unicode lowercase mapping, `false`
+/// otherwise.
+pub(crate) fn names_equal_ignore_case(a: &str, b: &str) -> bool {
+    a == b || a.to_lowercase() == b.to_lowercase()
+}
+
+/// Expands a leading `~` in a path to the user's home directory (like a shell does).
+///
+/// # Arguments
+///
+/// * `path` - The path to expand (can be a `&str`, [`String`], [`Path`], or [`PathBuf`]).
+///
+/// # Returns
+///
+/// The path with a leading `~` component replaced by the user's home directory (see
+/// [`get_home`]). Paths that do not start with a `~` component (including paths starting with
+/// `~user`, which are not supported) are returned unchanged.
+///
+/// # Panics
+///
+/// If the path starts with a `~` component and the home directory cannot be determined.
+///
+/// # Example
+///
+/// ```
+/// use file_io::{expand_tilde, get_home};
+/// use std::path::{Path, PathBuf};
+///
+/// assert_eq!(expand_tilde("~/projects/foo"), Path::new(&get_home()).join("projects/foo"));
+/// assert_eq!(expand_tilde("projects/foo"), PathBuf::from("projects/foo"));
+/// ```
+pub fn expand_tilde<P: AsRef<Path>>(path: P) -> PathBuf {
+    let path = path.as_ref();
+    let mut components = path.components();
+    match components.next() {
+        Some(Component::Normal(first)) if first == "~" => {
+            get_home_path().join(components.as_path())
+        }
+        _ => path.to_path_buf(),
+    }
+}
+
+/// A path with a leading `~` expanded to the user's home directory.
+///
+/// This wrapper can be passed to any function in this crate that accepts a path, so that
+/// user-supplied paths behave like they do in a shell. The expansion is performed once, when the
+/// wrapper is constructed (see [`expand_tilde`]).
+///
+/// # Example
+///
+/// ```
+/// use file_io::{Tilde, get_home};
+/// use std::path::Path;
+///
+/// // Path supplied by a user.
+/// let path = Tilde::new("~/notes.txt");
+/// assert_eq!(path.as_ref(), Path::new(&get_home()).join("notes.txt"));
+///
+/// // The wrapper can be passed to any function in this crate, e.g.
+/// // `load_file_as_string(Tilde::new("~/notes.txt"))`.
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Tilde(PathBuf);
+
+impl Tilde {
+    /// Constructor.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - The path to expand (can be a `&str`, [`String`], [`Path`], or [`PathBuf`]).
+    ///
+    /// # Returns
+    ///
+    /// The path with a leading `~` expanded to the user's home directory.
+    ///
+    /// # Panics
+    ///
+    /// If the path starts with a `~` component and the home directory cannot be determined.
+    pub fn new<P: AsRef<Path>>(path: P) -> Self {
+        Self(expand_tilde(path))
+    }
+
+    /// Consumes the wrapper, returning the expanded path.
+    ///
+    /// # Returns
+    ///
+    /// The expanded path.
+    pub fn into_path_buf(self) -> PathBuf {
+        self.0
+    }
+}
+
+impl AsRef<Path> for Tilde {
+    fn as_ref(&self) -> &Path {
+        &self.0
+    }
+}
+
+/// Lexically resolves an untrusted relative path so that it stays within a base folder.
+///
+/// # Arguments
+///
+/// * `untrusted` - The untrusted path.
+/// * `clamp` - If `true`, components that would escape the base folder are dropped. If `false`,
+///   such components cause the path to be rejected.
+///
+/// # Returns
+///
+/// The resolved relative path, or `None` if the path was rejected.
+fn resolve_untrusted_path(untrusted: &Path, clamp: bool) -> Option<PathBuf> {
+    let mut components: Vec<Component> = Vec::new();
+    for component in untrusted.components() {
+        match component {
+            Component::Prefix(_) | Component::RootDir if !clamp => return None,
+            Component::Prefix(_) | Component::RootDir | Component::CurDir => {}
+            Component::ParentDir => {
+                if components.pop().is_none() && !clamp {
+                    return None;
+                }
+            }
+            Component::Normal(_) => components.push(component),
+        }
+    }
+    Some(components.iter().collect())
+}
+
+/// Joins an untrusted relative path onto a base folder, rejecting any path that would escape the
+/// base folder.
+///
+/// # Arguments
+///
+/// * `base` - The base folder (can be a `&str`, [`String`], [`Path`], or [`PathBuf`]).
+/// * `untrusted` - The untrusted relative path, e.g. supplied by a user, an archive, or a web
+///   upload (can be a `&str`, [`String`], [`Path`], or [`PathBuf`]).
+///
+/// # Returns
+///
+/// The joined path, or `None` if `untrusted` is absolute (or has a Windows prefix such as `C:`),
+/// or contains `..` components that would lead outside of `base`.
+///
+/// # Note
+///
+/// * `..` components that stay within `base` (e.g. `a/../b`) are resolved lexically.
+/// * This check is purely lexical. Symbolic links inside `base` that point outside of it are not
+///   detected.
+///
+/// # Example
+///
+/// ```
+/// use file_io::safe_join;
+/// use std::path::PathBuf;
+///
+/// assert_eq!(
+///     safe_join("/uploads", "images/cat.png"),
+///     Some(PathBuf::from("/uploads/images/cat.png"))
+/// );
+/// assert_eq!(safe_join("/uploads", "images/../../etc/passwd"), None);
+/// assert_eq!(safe_join("/uploads", "/etc/passwd"), None);
+/// ```
+pub fn safe_join<P: AsRef<Path>, Q: AsRef<Path>>(base: P, untrusted: Q) -> Option<PathBuf> {
+    resolve_untrusted_path(untrusted.as_ref(), false).map(|path| base.as_ref().join(path))
 }
 
-/// Get the file extension.
+/// Joins an untrusted relative path onto a base folder, clamping the path so that it can never
+/// escape the base folder.
 ///
 /// # Arguments
-///     
-/// * `path` - The path to the file (can be a `&str`, [`String`], [`Path`], or [`PathBuf`]).
+///
+/// * `base` - The base folder (can be a `&str`, [`String`], [`Path`], or [`PathBuf`]).
+/// * `untrusted` - The untrusted relative path, e.g. supplied by a user, an archive, or a web
+///   upload (can be a `&str`, [`String`], [`Path`], or [`PathBuf`]).
 ///
 /// # Returns
 ///
-/// The file extension. If the file has no extension, or if the extension cannot be determined, this
-/// function returns an empty string.
+/// The joined path, where any root or Windows prefix of `untrusted` is removed, and any `..`
+/// components that would lead outside of `base` are dropped (similar to how `..` at the root of a
+/// filesystem refers to the root itself).
+///
+/// # Note
+///
+/// This check is purely lexical. Symbolic links inside `base` that point outside of it are not
+/// detected.
 ///
 /// # Example
 ///
 /// ```
-/// use file_io::get_file_extension;
+/// use file_io::safe_join_clamped;
+/// use std::path::PathBuf;
 ///
-/// let file_extension = get_file_extension("/some/path/to/file.txt");
-/// assert_eq!(file_extension, "txt");
+/// assert_eq!(
+///     safe_join_clamped("/uploads", "../../etc/passwd"),
+///     PathBuf::from("/uploads/etc/passwd")
+/// );
+/// assert_eq!(safe_join_clamped("/uploads", "/etc/passwd"), PathBuf::from("/uploads/etc/passwd"));
 /// ```
-pub fn get_file_extension<P: AsRef<Path>>(path: P) -> String {
-    path.as_ref()
-        .extension()
-        .and_then(|s| s.to_str())
-        .map(String::from)
-        .unwrap_or(String::from(""))
+pub fn safe_join_clamped<P: AsRef<Path>, Q: AsRef<Path>>(base: P, untrusted: Q) -> PathBuf {
+    base.as_ref()
+        .join(resolve_untrusted_path(untrusted.as_ref(), true).unwrap_or_default())
 }
 
-/// Converts a path to a `PathBuf`.
+/// Constructs a numbered variant of a path by appending ` (n)` to its file stem.
 ///
 /// # Arguments
 ///
-/// * `path` - The path to convert (can be a `&str`, [`String`], [`Path`], or [`PathBuf`]).
+/// * `path` - The original path.
+/// * `n` - The number to append.
 ///
 /// # Returns
 ///
-/// A `PathBuf` representation of the path.
+/// The numbered path (e.g. `folder/file (2).txt` for `folder/file.txt` and `n = 2`).
+pub(crate) fn numbered_path(path: &Path, n: usize) -> PathBuf {
+    rename_stem(path, |stem| format!("{stem} ({n})"))
+}
+
+/// Get a path that does not already exist, by appending a number to the file stem if needed.
 ///
-/// # Examples
+/// # Arguments
 ///
-/// ## Using a string literal
+/// * `path` - The desired path (can be a `&str`, [`String`], [`Path`], or [`PathBuf`]).
 ///
-/// ```
-/// use file_io::to_path_buf;
-/// use std::path::PathBuf;
+/// # Returns
 ///
-/// let path: &str = "folder/subfolder_9/file.txt";
-/// let path_buf: PathBuf = to_path_buf(path);
-/// ```
+/// `path` itself if nothing exists there yet. Otherwise, the first of `file (1).txt`,
+/// `file (2).txt`, ... (for a desired path of `file.txt`) that does not exist yet.
 ///
-/// ## Using a `Path` reference
+/// # Note
+///
+/// Another process may create a file at the returned path before the caller does. Functions such
+/// as [`crate::save_string_to_unique_file`] and [`crate::copy_file_to_unique`] avoid this race by
+/// creating the file exclusively.
+///
+/// # Example
 ///
 /// ```
-/// use file_io::to_path_buf;
-/// use std::path::{Path, PathBuf};
+/// use file_io::{save_string_to_file, unique_path};
+/// use std::path::PathBuf;
 ///
-/// let path: &Path = Path::new("folder/subfolder_10/file.txt");
-/// let path_buf: PathBuf = to_path_buf(path);
+/// // Create a file.
+/// save_string_to_file("Hello, world!", "folder/subfolder_21/file_16.txt");
+///
+/// // The path is taken, so a numbered variant is returned.
+/// assert_eq!(
+///     unique_path("folder/subfolder_21/file_16.txt"),
+///     PathBuf::from("folder/subfolder_21/file_16 (1).txt")
+/// );
 /// ```
-pub fn to_path_buf<P: AsRef<Path>>(path: P) -> PathBuf {
-    path.as_ref().to_path_buf()
+pub fn unique_path<P: AsRef<Path>>(path: P) -> PathBuf {
+    let path = path.as_ref();
+    if !path.exists() && !path.is_symlink() {
+        return path.to_path_buf();
+    }
+    (1..)
+        .map(|n| numbered_path(path, n))
+        .find(|candidate| !candidate.exists() && !candidate.is_symlink())
+        .unwrap()
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::get_cwd;
+    use crate::save::save_string_to_file;
+    use crate::test_utils::get_temp_dir_path;
     use serial_test::serial;
     use temp_env::with_var;
+    use tempfile::tempdir;
 
+    #[cfg(not(windows))]
     #[test]
     fn test_get_home() {
         with_var("HOME", Some("/tmp/test_home"), || {
             let home = get_home();
             assert_eq!(home, "/tmp/test_home");
+            assert_eq!(get_home_path(), PathBuf::from("/tmp/test_home"));
+        });
+        with_var("HOME", Some(""), || assert_eq!(try_get_home(), None));
+        with_var("HOME", None::<&str>, || assert_eq!(try_get_home(), None));
+    }
+
+    #[cfg(windows)]
+    #[test]
+    fn test_get_home() {
+        temp_env::with_vars(
+            [
+                ("USERPROFILE", None),
+                ("HOMEDRIVE", Some("C:")),
+                ("HOMEPATH", Some("\\Users\\test")),
+            ],
+            || assert_eq!(get_home_path(), PathBuf::from("C:\\Users\\test")),
+        );
+        with_var("USERPROFILE", Some("D:\\test"), || {
+            assert_eq!(get_home(), "D:\\test");
         });
     }
 
@@ -388,4 +1551,499 @@ mod tests {
             "folder/subfolder/file.txt"
         );
     }
+
+    #[test]
+    fn test_strip_verbatim_prefix() {
+        assert_eq!(
+            strip_verbatim_prefix(Path::new(r"\\?\C:\Users\me")),
+            PathBuf::from(r"C:\Users\me")
+        );
+        assert_eq!(
+            strip_verbatim_prefix(Path::new(r"\\?\UNC\server\share\file.txt")),
+            PathBuf::from(r"\\server\share\file.txt")
+        );
+        assert_eq!(
+            strip_verbatim_prefix(Path::new(r"\\?\Volume{1234}\folder")),
+            PathBuf::from(r"\\?\Volume{1234}\folder")
+        );
+        assert_eq!(
+            strip_verbatim_prefix(Path::new("/some/path/to/file.txt")),
+            PathBuf::from("/some/path/to/file.txt")
+        );
+    }
+
+    #[test]
+    fn test_canonicalize_path() {
+        // Create a temporary directory to work in.
+        let temp_dir = tempdir().unwrap();
+        let temp_dir_path = get_temp_dir_path(&temp_dir);
+
+        // Create a file in a subfolder.
+        let file_path = temp_dir_path.join("subfolder/file.txt");
+        save_string_to_file("Hello, world!", &file_path);
+
+        // Path to the file containing `.` and `..` components.
+        let path = temp_dir_path.join("subfolder/./../subfolder/file.txt");
+
+        // Path in different formats.
+        let paths: Vec<Box<dyn AsRef<Path>>> = vec![
+            Box::new(path.to_str().unwrap()),             // &str
+            Box::new(path.to_str().unwrap().to_string()), // String
+            Box::new(path.as_path()),                     // Path
+            Box::new(path.clone()),                       // PathBuf
+        ];
+
+        // Test with all different path formats.
+        for path in paths {
+            // Get a reference to this path representation (i.e. "unbox").
+            let path = path.as_ref();
+
+            // Canonicalize the path.
+            assert_eq!(canonicalize_path(path), file_path);
+            assert_eq!(canonicalize_path_for_display(path), file_path);
+        }
+    }
+
+    #[test]
+    fn test_normalize_path() {
+        assert_eq!(normalize_path("a/./b/../c"), PathBuf::from("a/c"));
+        assert_eq!(normalize_path("a//b///c/"), PathBuf::from("a/b/c"));
+        assert_eq!(normalize_path("./a/b"), PathBuf::from("a/b"));
+        assert_eq!(normalize_path("../a/../../b"), PathBuf::from("../../b"));
+        assert_eq!(normalize_path("/../a/./b/.."), PathBuf::from("/a"));
+        assert_eq!(normalize_path("/"), PathBuf::from("/"));
+        assert_eq!(normalize_path("a/.."), PathBuf::from("."));
+        assert_eq!(normalize_path("./"), PathBuf::from("."));
+        assert_eq!(normalize_path(""), PathBuf::from("."));
+    }
+
+    #[test]
+    fn test_normalize_path_other_type_spot_checks() {
+        // Spot check with `String`.
+        assert_eq!(
+            normalize_path(String::from("a/./b/../c")),
+            PathBuf::from("a/c")
+        );
+
+        // Spot check with `Path`.
+        assert_eq!(
+            normalize_path(Path::new("a/./b/../c")),
+            PathBuf::from("a/c")
+        );
+
+        // Spot check with `PathBuf`.
+        assert_eq!(
+            normalize_path(PathBuf::from("a/./b/../c")),
+            PathBuf::from("a/c")
+        );
+    }
+
+    #[test]
+    #[serial]
+    fn test_absolute_path() {
+        // Relative paths are resolved against the current working directory.
+        assert_eq!(absolute_path("a/./b/../c.txt"), get_cwd().join("a/c.txt"));
+        assert_eq!(absolute_path("."), get_cwd());
+
+        // Absolute paths are only normalized.
+        assert_eq!(absolute_path("/a/b/../c"), PathBuf::from("/a/c"));
+    }
+
+    #[test]
+    #[serial]
+    fn test_absolute_path_from() {
+        // Relative paths are resolved against the base folder.
+        assert_eq!(
+            absolute_path_from("../c/file.txt", "/a/b"),
+            PathBuf::from("/a/c/file.txt")
+        );
+        assert_eq!(
+            absolute_path_from(String::from("file.txt"), Path::new("/a")),
+            PathBuf::from("/a/file.txt")
+        );
+
+        // Absolute paths ignore the base folder.
+        assert_eq!(absolute_path_from("/x/y", "/a/b"), PathBuf::from("/x/y"));
+
+        // Relative base folders are resolved against the current working directory.
+        assert_eq!(
+            absolute_path_from("file.txt", "folder"),
+            get_cwd().join("folder/file.txt")
+        );
+    }
+
+    #[test]
+    fn test_relative_path_between() {
+        assert_eq!(
+            relative_path_between("/a/b/c", "/a/d/e.txt"),
+            PathBuf::from("../../d/e.txt")
+        );
+        assert_eq!(
+            relative_path_between("/a", "/a/b/c.txt"),
+            PathBuf::from("b/c.txt")
+        );
+        assert_eq!(
+            relative_path_between("/a/b/c", "/a"),
+            PathBuf::from("../..")
+        );
+        assert_eq!(relative_path_between("/a/b", "/a/b/"), PathBuf::from("."));
+        assert_eq!(relative_path_between("/", "/a"), PathBuf::from("a"));
+        assert_eq!(
+            relative_path_between("/a/./b/../c", "/a/c/../d"),
+            PathBuf::from("../d")
+        );
+    }
+
+    #[test]
+    #[serial]
+    fn test_relative_path_between_relative_paths() {
+        // Relative paths are resolved against the current working directory.
+        assert_eq!(
+            relative_path_between(String::from("docs/api"), Path::new("src/lib.rs")),
+            PathBuf::from("../../src/lib.rs")
+        );
+        assert_eq!(
+            relative_path_between(PathBuf::from("src"), get_cwd().join("Cargo.toml")),
+            PathBuf::from("../Cargo.toml")
+        );
+    }
+
+    #[test]
+    fn test_expand_tilde() {
+        with_var("HOME", Some("/tmp/test_home"), || {
+            assert_eq!(expand_tilde("~"), PathBuf::from("/tmp/test_home"));
+            assert_eq!(expand_tilde("~/"), PathBuf::from("/tmp/test_home"));
+            assert_eq!(
+                expand_tilde("~/projects/foo"),
+                PathBuf::from("/tmp/test_home/projects/foo")
+            );
+            assert_eq!(
+                expand_tilde(String::from("~/a.txt")),
+                PathBuf::from("/tmp/test_home/a.txt")
+            );
+            assert_eq!(
+                expand_tilde(Path::new("~/a.txt")),
+                PathBuf::from("/tmp/test_home/a.txt")
+            );
+            assert_eq!(
+                expand_tilde(PathBuf::from("~/a.txt")),
+                PathBuf::from("/tmp/test_home/a.txt")
+            );
+
+            // Paths without a leading `~` component are unchanged.
+            assert_eq!(expand_tilde("~user/a.txt"), PathBuf::from("~user/a.txt"));
+            assert_eq!(expand_tilde("a/~/b"), PathBuf::from("a/~/b"));
+            assert_eq!(expand_tilde("/a/b"), PathBuf::from("/a/b"));
+        });
+    }
+
+    #[test]
+    fn test_tilde() {
+        // Create a temporary directory to use as the home directory.
+        let temp_dir = tempdir().unwrap();
+        let temp_dir_path = get_temp_dir_path(&temp_dir);
+
+        with_var("HOME", Some(&temp_dir_path), || {
+            // Use the wrapper with functions from this crate.
+            save_string_to_file("Hello, world!", Tilde::new("~/folder/file.txt"));
+            assert_eq!(
+                crate::load_file_as_string(Tilde::new("~/folder/file.txt")),
+                "Hello, world!"
+            );
+            assert_eq!(
+                Tilde::new("~/folder/file.txt").into_path_buf(),
+                temp_dir_path.join("folder/file.txt")
+            );
+        });
+    }
+
+    #[test]
+    fn test_safe_join() {
+        // Paths that stay within the base folder.
+        assert_eq!(
+            safe_join("/base", "a/b.txt"),
+            Some(PathBuf::from("/base/a/b.txt"))
+        );
+        assert_eq!(
+            safe_join("/base", "./a/../b/./c.txt"),
+            Some(PathBuf::from("/base/b/c.txt"))
+        );
+        assert_eq!(safe_join("/base", "a/.."), Some(PathBuf::from("/base")));
+        assert_eq!(safe_join("/base", ""), Some(PathBuf::from("/base")));
+
+        // Paths that would escape the base folder.
+        assert_eq!(safe_join("/base", ".."), None);
+        assert_eq!(safe_join("/base", "a/../../b"), None);
+        assert_eq!(safe_join("/base", "/etc/passwd"), None);
+
+        // Spot checks with other types.
+        assert_eq!(
+            safe_join(String::from("/base"), PathBuf::from("a")),
+            Some(PathBuf::from("/base/a"))
+        );
+        assert_eq!(safe_join(Path::new("/base"), Path::new("../a")), None);
+    }
+
+    #[test]
+    fn test_safe_join_clamped() {
+        assert_eq!(
+            safe_join_clamped("/base", "a/b.txt"),
+            PathBuf::from("/base/a/b.txt")
+        );
+        assert_eq!(
+            safe_join_clamped("/base", "../../a/b.txt"),
+            PathBuf::from("/base/a/b.txt")
+        );
+        assert_eq!(
+            safe_join_clamped("/base", "a/../../b"),
+            PathBuf::from("/base/b")
+        );
+        assert_eq!(
+            safe_join_clamped("/base", "/etc/passwd"),
+            PathBuf::from("/base/etc/passwd")
+        );
+        assert_eq!(safe_join_clamped("/base", ".."), PathBuf::from("/base"));
+    }
+
+    #[test]
+    fn test_numbered_path() {
+        assert_eq!(
+            numbered_path(Path::new("/a/file.txt"), 1),
+            PathBuf::from("/a/file (1).txt")
+        );
+        assert_eq!(
+            numbered_path(Path::new("a/file"), 12),
+            PathBuf::from("a/file (12)")
+        );
+        assert_eq!(
+            numbered_path(Path::new(".bashrc"), 2),
+            PathBuf::from(".bashrc (2)")
+        );
+        assert_eq!(
+            numbered_path(Path::new("archive.tar.gz"), 3),
+            PathBuf::from("archive.tar (3).gz")
+        );
+    }
+
+    #[test]
+    fn test_unique_path() {
+        // Create a temporary directory to work in.
+        let temp_dir = tempdir().unwrap();
+        let temp_dir_path = get_temp_dir_path(&temp_dir);
+
+        // The desired path is free.
+        let file_path = temp_dir_path.join("file.txt");
+        assert_eq!(unique_path(&file_path), file_path);
+
+        // The desired path is taken.
+        save_string_to_file("", &file_path);
+        assert_eq!(unique_path(&file_path), temp_dir_path.join("file (1).txt"));
+
+        // The first numbered variant is also taken.
+        save_string_to_file("", temp_dir_path.join("file (1).txt"));
+        assert_eq!(
+            unique_path(file_path.to_str().unwrap()),
+            temp_dir_path.join("file (2).txt")
+        );
+
+        // Folders are also taken into account.
+        crate::create_folder(temp_dir_path.join("folder"));
+        assert_eq!(
+            unique_path(temp_dir_path.join("folder")),
+            temp_dir_path.join("folder (1)")
+        );
+    }
+
+    #[test]
+    fn test_with_and_remove_extension() {
+        assert_eq!(
+            with_extension("dir/file.txt", "md"),
+            PathBuf::from("dir/file.md")
+        );
+        assert_eq!(
+            with_extension("dir/file", ".md"),
+            PathBuf::from("dir/file.md")
+        );
+        assert_eq!(
+            with_extension("dir/file.txt", ""),
+            PathBuf::from("dir/file")
+        );
+        assert_eq!(remove_extension("dir/file.txt"), PathBuf::from("dir/file"));
+        assert_eq!(remove_extension("dir/file"), PathBuf::from("dir/file"));
+        assert_eq!(remove_extension(".bashrc"), PathBuf::from(".bashrc"));
+    }
+
+    #[test]
+    fn test_has_extension() {
+        assert!(has_extension("lib.rs", &["rs", "toml"]));
+        assert!(has_extension("Cargo.toml", &[".rs", ".toml"]));
+        assert!(!has_extension("lib.RS", &["rs"]));
+        assert!(!has_extension("README", &["rs"]));
+        assert!(!has_extension("lib.rs", &[]));
+        assert!(has_extension_ignore_case("lib.RS", &["rs"]));
+        assert!(has_extension_ignore_case(Path::new("photo.jpg"), &["JPG"]));
+        assert!(!has_extension_ignore_case("photo.gif", &["jpg"]));
+    }
+
+    #[test]
+    fn test_add_suffix_and_prefix_to_stem() {
+        assert_eq!(
+            add_suffix_to_stem("a/b/report.csv", "_v2"),
+            PathBuf::from("a/b/report_v2.csv")
+        );
+        assert_eq!(
+            add_suffix_to_stem(Path::new("report"), "_v2"),
+            PathBuf::from("report_v2")
+        );
+        assert_eq!(
+            add_suffix_to_stem("archive.tar.gz", "_old"),
+            PathBuf::from("archive.tar_old.gz")
+        );
+        assert_eq!(
+            add_prefix_to_stem("a/b/report.csv", "clean_"),
+            PathBuf::from("a/b/clean_report.csv")
+        );
+        assert_eq!(
+            add_prefix_to_stem(String::from(".bashrc"), "old"),
+            PathBuf::from("old.bashrc")
+        );
+    }
+
+    #[test]
+    fn test_split_path() {
+        assert_eq!(split_path("a/b/c.txt"), ["a", "b", "c.txt"]);
+        assert_eq!(split_path("./a//b/"), [".", "a", "b"]);
+        assert!(split_path("").is_empty());
+        #[cfg(unix)]
+        assert_eq!(split_path(Path::new("/a/b")), ["/", "a", "b"]);
+    }
+
+    #[test]
+    fn test_path_depth() {
+        assert_eq!(path_depth("a/b/c.txt"), 3);
+        assert_eq!(path_depth(String::from("./a/../b")), 3);
+        assert_eq!(path_depth(""), 0);
+        assert_eq!(path_depth("."), 0);
+        #[cfg(unix)]
+        assert_eq!(path_depth(PathBuf::from("/a/b")), 2);
+    }
+
+    #[test]
+    fn test_common_ancestor() {
+        assert_eq!(
+            common_ancestor(&["a/b/c.txt", "a/b/d.txt"]),
+            Some(PathBuf::from("a/b"))
+        );
+        assert_eq!(
+            common_ancestor(&[Path::new("a/b"), Path::new("a/b/c/d")]),
+            Some(PathBuf::from("a/b"))
+        );
+        assert_eq!(
+            common_ancestor(&[PathBuf::from("a/b/c.txt")]),
+            Some(PathBuf::from("a/b/c.txt"))
+        );
+        assert_eq!(common_ancestor(&["a/bc", "a/b"]), Some(PathBuf::from("a")));
+        assert_eq!(common_ancestor(&["a", "b"]), None);
+        assert_eq!(common_ancestor::<&str>(&[]), None);
+        #[cfg(unix)]
+        {
+            assert_eq!(common_ancestor(&["/x/y", "/z"]), Some(PathBuf::from("/")));
+            assert_eq!(common_ancestor(&["/x/y", "x/y"]), None);
+        }
+    }
+
+    #[test]
+    fn test_is_inside() {
+        // Create a temporary directory to work in.
+        let temp_dir = tempdir().unwrap();
+        let temp_dir_path = get_temp_dir_path(&temp_dir);
+        let base = temp_dir_path.join("base");
+        save_string_to_file("", base.join("file.txt"));
+
+        // Existing and non-existent paths.
+        assert!(is_inside(base.join("file.txt"), &base));
+        assert!(is_inside(base.join("missing/deeper.txt"), &base));
+        assert!(is_inside(&base, &base));
+        assert!(is_inside(base.join("a/../b"), base.to_str().unwrap()));
+
+        // Paths escaping the base folder.
+        assert!(!is_inside(base.join("../file.txt"), &base));
+        assert!(!is_inside(base.join("missing/../../file.txt"), &base));
+        assert!(!is_inside(temp_dir_path.join("base_2"), &base));
+        assert!(!is_inside(&temp_dir_path, &base));
+
+        // A symbolic link inside the base folder that points outside of it.
+        #[cfg(unix)]
+        {
+            let outside = temp_dir_path.join("outside");
+            crate::create_folder(&outside);
+            std::os::unix::fs::symlink(&outside, base.join("link")).unwrap();
+            assert!(!is_inside(base.join("link/file.txt"), &base));
+            assert!(is_inside(base.join("link/file.txt"), &outside));
+        }
+    }
+
+    #[test]
+    #[serial]
+    fn test_is_inside_relative() {
+        assert!(is_inside("src/lib.rs", get_cwd()));
+        assert!(is_inside("src/../Cargo.toml", "."));
+        assert!(!is_inside("..", "."));
+    }
+
+    #[test]
+    fn test_separator_conversion() {
+        let native = Path::new("a").join("b").join("c.txt");
+        assert_eq!(to_forward_slashes(&native), "a/b/c.txt");
+        assert_eq!(to_forward_slashes("a/b/c.txt"), "a/b/c.txt");
+        assert_eq!(to_native_separators("a/b/c.txt"), native);
+        assert_eq!(to_native_separators(String::from(r"a\b/c.txt")), native);
+        #[cfg(unix)]
+        assert_eq!(to_forward_slashes(r"a\b"), r"a\b");
+        #[cfg(windows)]
+        assert_eq!(to_forward_slashes(r"C:\a\b"), "C:/a/b");
+    }
+
+    #[cfg(windows)]
+    #[test]
+    fn test_to_long_path() {
+        assert_eq!(
+            to_long_path(r"C:\a\..\b/c.txt"),
+            PathBuf::from(r"\\?\C:\b\c.txt")
+        );
+        assert_eq!(
+            to_long_path(r"\\server\share\file.txt"),
+            PathBuf::from(r"\\?\UNC\server\share\file.txt")
+        );
+        assert_eq!(
+            to_long_path(r"\\?\C:\a\..\b"),
+            PathBuf::from(r"\\?\C:\a\..\b")
+        );
+
+        // File operations on a path longer than `MAX_PATH`.
+        let temp_dir = tempdir().unwrap();
+        let long_path = to_long_path(get_temp_dir_path(&temp_dir))
+            .join("a".repeat(200))
+            .join("b".repeat(200))
+            .join("file.txt");
+        save_string_to_file("Hello, world!", &long_path);
+        assert!(long_path.is_file());
+    }
+
+    #[cfg(not(windows))]
+    #[test]
+    fn test_to_long_path() {
+        assert_eq!(to_long_path("a/../b"), PathBuf::from("a/../b"));
+    }
+
+    #[test]
+    fn test_paths_equal_ignore_case() {
+        assert!(paths_equal_ignore_case("a/B/c.TXT", "A/b/C.txt"));
+        assert!(paths_equal_ignore_case("a/./b/", Path::new("A/B")));
+        assert!(paths_equal_ignore_case("Ärger/ÜBER", "ärger/über"));
+        assert!(!paths_equal_ignore_case("a/b", "a/b/c"));
+        assert!(!paths_equal_ignore_case("a/b", "a/c"));
+        #[cfg(unix)]
+        assert!(!paths_equal_ignore_case("/a/b", "a/b"));
+    }
 }
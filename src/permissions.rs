@@ -0,0 +1,312 @@
+use crate::instrument::Mutation;
+use std::path::Path;
+
+#[cfg(unix)]
+use std::os::unix::fs::PermissionsExt;
+
+/// Cross-platform representation of the permissions of a file or folder.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Permissions {
+    /// Whether the file or folder is read-only (i.e. no one can write to it).
+    pub readonly: bool,
+
+    /// Unix permission bits (e.g. `0o755`), or `None` on platforms without Unix permissions.
+    pub mode: Option<u32>,
+}
+
+/// Get the permissions of a file or folder.
+///
+/// # Arguments
+///
+/// * `path` - The path to the file or folder (can be a `&str`, [`String`], [`Path`], or
+///   [`std::path::PathBuf`]).
+///
+/// # Returns
+///
+/// The permissions of the file or folder.
+///
+/// # Panics
+///
+/// If the metadata of the file or folder cannot be read.
+///
+/// # Example
+///
+/// ```
+/// use file_io::{Permissions, get_permissions};
+///
+/// let permissions: Permissions = get_permissions("Cargo.toml");
+/// ```
+pub fn get_permissions<P: AsRef<Path>>(path: P) -> Permissions {
+    let path = path.as_ref();
+    let permissions = std::fs::metadata(path)
+        .unwrap_or_else(|_| panic!("Failed to get metadata for '{path:?}'."))
+        .permissions();
+    Permissions {
+        readonly: permissions.readonly(),
+        #[cfg(unix)]
+        mode: Some(permissions.mode() & 0o7777),
+        #[cfg(not(unix))]
+        mode: None,
+    }
+}
+
+/// Set the permissions of a file or folder.
+///
+/// # Arguments
+///
+/// * `path` - The path to the file or folder (can be a `&str`, [`String`], [`Path`], or
+///   [`std::path::PathBuf`]).
+/// * `permissions` - The permissions to set.
+///
+/// # Panics
+///
+/// If the permissions cannot be set.
+///
+/// # Note
+///
+/// * On Unix, if `permissions.mode` is set, those permission bits are applied. If
+///   `permissions.readonly` is `true`, all write bits are then cleared. If `permissions.readonly`
+///   is `false`, the owner write bit is set if `permissions.mode` is not set or does not have any
+///   write bits (e.g. `0o444` is applied as `0o644`), so the file or folder is never left
+///   read-only.
+/// * On other platforms, `permissions.mode` is ignored and only the read-only flag is applied.
+///
+/// # Example
+///
+/// ```
+/// use file_io::{Permissions, get_permissions, save_string_to_file, set_permissions};
+///
+/// // Create a file.
+/// let path: &str = "folder/subfolder_18/file_13.txt";
+/// save_string_to_file("Hello, world!", path);
+///
+/// // Make it read-only.
+/// set_permissions(path, Permissions { readonly: true, mode: None });
+/// assert!(get_permissions(path).readonly);
+///
+/// // Make it writable again.
+/// set_permissions(path, Permissions { readonly: false, mode: None });
+/// assert!(!get_permissions(path).readonly);
+/// ```
+pub fn set_permissions<P: AsRef<Path>>(path: P, permissions: Permissions) {
+    let path = path.as_ref();
+    let mut mutation = Mutation::start("set_permissions", path);
+    if mutation.is_dry_run() {
+        return;
+    }
+    let mut new_permissions = std::fs::metadata(path)
+        .unwrap_or_else(|_| panic!("Failed to get metadata for '{path:?}'."))
+        .permissions();
+
+    #[cfg(unix)]
+    {
+        let mut mode = permissions.mode.unwrap_or(new_permissions.mode() & 0o7777);
+        if permissions.readonly {
+            mode &= !0o222;
+        } else if permissions.mode.is_none() || mode & 0o222 == 0 {
+            mode |= 0o200;
+        }
+        new_permissions.set_mode(mode);
+    }
+    #[cfg(not(unix))]
+    #[allow(clippy::permissions_set_readonly_false)]
+    new_permissions.set_readonly(permissions.readonly);
+
+    std::fs::set_permissions(path, new_permissions)
+        .unwrap_or_else(|_| panic!("Failed to set permissions for '{path:?}'."));
+    mutation.succeeded();
+}
+
+/// Makes a file executable.
+///
+/// # Arguments
+///
+/// * `path` - The path to the file (can be a `&str`, [`String`], [`Path`], or
+///   [`std::path::PathBuf`]).
+///
+/// # Panics
+///
+/// If the permissions of the file cannot be read or set.
+///
+/// # Note
+///
+/// * On Unix, the execute bit is set for everyone who can read the file (i.e. like `chmod +x` with
+///   a typical umask, a file with mode `0o644` ends up with mode `0o755`).
+/// * On other platforms, executability is not a file permission, so this function does nothing.
+///
+/// # Example
+///
+/// ```
+/// use file_io::{make_executable, save_string_to_file};
+///
+/// // Create a script.
+/// let path: &str = "folder/subfolder_18/script.sh";
+/// save_string_to_file("#!/bin/sh\necho 'Hello, world!'\n", path);
+///
+/// // Make it executable.
+/// make_executable(path);
+/// ```
+pub fn make_executable<P: AsRef<Path>>(path: P) {
+    #[cfg(unix)]
+    {
+        let path = path.as_ref();
+        let permissions = get_permissions(path);
+        let mode = permissions.mode.unwrap_or(0);
+        set_permissions(
+            path,
+            Permissions {
+                mode: Some(mode | ((mode & 0o444) >> 2)),
+                ..permissions
+            },
+        );
+    }
+    #[cfg(not(unix))]
+    let _ = path;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::save::save_string_to_file;
+    use crate::test_utils::get_temp_dir_path;
+    use tempfile::tempdir;
+
+    #[test]
+    fn test_get_set_permissions() {
+        // Create a temporary directory to work in.
+        let temp_dir = tempdir().unwrap();
+
+        // Path to the file.
+        let file_path = get_temp_dir_path(&temp_dir).join("file.txt");
+
+        // File path in different formats.
+        let file_paths: Vec<Box<dyn AsRef<Path>>> = vec![
+            Box::new(file_path.to_str().unwrap()),             // &str
+            Box::new(file_path.to_str().unwrap().to_string()), // String
+            Box::new(file_path.as_path()),                     // Path
+            Box::new(file_path.clone()),                       // PathBuf
+        ];
+
+        // Create the file.
+        save_string_to_file("Hello, world!", &file_path);
+
+        // Test with all different path formats.
+        for file_path in file_paths {
+            // Get a reference to this path representation (i.e. "unbox").
+            let file_path = file_path.as_ref();
+
+            // Make the file read-only.
+            set_permissions(
+                file_path,
+                Permissions {
+                    readonly: true,
+                    mode: None,
+                },
+            );
+            assert!(get_permissions(file_path).readonly);
+
+            // Make the file writable again.
+            set_permissions(
+                file_path,
+                Permissions {
+                    readonly: false,
+                    mode: None,
+                },
+            );
+            assert!(!get_permissions(file_path).readonly);
+        }
+    }
+
+    #[test]
+    #[cfg(unix)]
+    fn test_set_permissions_mode() {
+        // Create a temporary directory to work in.
+        let temp_dir = tempdir().unwrap();
+
+        // Create a file.
+        let file_path = get_temp_dir_path(&temp_dir).join("file.txt");
+        save_string_to_file("Hello, world!", &file_path);
+
+        // Set explicit permission bits.
+        set_permissions(
+            &file_path,
+            Permissions {
+                readonly: false,
+                mode: Some(0o640),
+            },
+        );
+        assert_eq!(
+            get_permissions(&file_path),
+            Permissions {
+                readonly: false,
+                mode: Some(0o640),
+            }
+        );
+
+        // Setting explicit permission bits without any write bits and without the read-only flag
+        // sets the owner write bit.
+        set_permissions(
+            &file_path,
+            Permissions {
+                readonly: false,
+                mode: Some(0o444),
+            },
+        );
+        assert_eq!(
+            get_permissions(&file_path),
+            Permissions {
+                readonly: false,
+                mode: Some(0o644),
+            }
+        );
+
+        // Setting explicit permission bits with the read-only flag clears the write bits.
+        set_permissions(
+            &file_path,
+            Permissions {
+                readonly: true,
+                mode: Some(0o664),
+            },
+        );
+        assert_eq!(
+            get_permissions(&file_path),
+            Permissions {
+                readonly: true,
+                mode: Some(0o444),
+            }
+        );
+    }
+
+    #[test]
+    #[cfg(unix)]
+    fn test_make_executable() {
+        // Create a temporary directory to work in.
+        let temp_dir = tempdir().unwrap();
+
+        // Create a file with mode 0o644.
+        let file_path = get_temp_dir_path(&temp_dir).join("script.sh");
+        save_string_to_file("#!/bin/sh\n", &file_path);
+        set_permissions(
+            &file_path,
+            Permissions {
+                readonly: false,
+                mode: Some(0o644),
+            },
+        );
+
+        // Make the file executable.
+        make_executable(&file_path);
+        assert_eq!(get_permissions(&file_path).mode, Some(0o755));
+
+        // A file only readable by its owner only becomes executable by its owner.
+        set_permissions(
+            &file_path,
+            Permissions {
+                readonly: false,
+                mode: Some(0o600),
+            },
+        );
+        make_executable(&file_path);
+        assert_eq!(get_permissions(&file_path).mode, Some(0o700));
+    }
+}
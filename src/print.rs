@@ -1,97 +1,1422 @@
+use crate::gitignore::GitIgnore;
 use crate::list::list_folder_contents;
+use crate::load::read_file_range;
+use crate::metadata::{EntryType, get_entry_type};
+use crate::modify::compile_globs;
 use crate::path::get_last_path_component;
-use std::path::Path;
+use crate::timestamp::format_datetime;
+use glob::Pattern;
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::io::{BufRead, IsTerminal};
+use std::path::{Path, PathBuf};
+use walkdir::WalkDir;
 
-/// Helper function to recursively print the folder tree.
+/// Options controlling how a folder tree is printed by [`print_folder_tree_with_options`] (and
+/// related functions).
+///
+/// The default options print the entire tree, which matches the behavior of
+/// [`print_folder_tree`].
+///
+/// Glob patterns are matched against both the path of each entry relative to the top-level folder
+/// and its name, so `target` excludes any entry named `target` at any depth.
+///
+/// # Example
+///
+/// ```
+/// use file_io::TreeOptions;
+///
+/// // Only print the top two levels, with at most 20 entries per folder.
+/// let options = TreeOptions {
+///     max_depth: Some(2),
+///     max_entries: Some(20),
+///     ..Default::default()
+/// };
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct TreeOptions {
+    /// The maximum depth to descend to (e.g. `Some(1)` only prints the direct children of the
+    /// top-level folder). Folders at the maximum depth are printed, but their contents are not.
+    pub max_depth: Option<usize>,
+
+    /// The maximum number of entries to print per folder. Any remaining entries are summarized as
+    /// `… N more`.
+    pub max_entries: Option<usize>,
+
+    /// If `true`, each entry is annotated with its human-readable size (e.g. `1.5 KiB`). The size
+    /// of a folder is the cumulative size of all files below it (including any that are not
+    /// printed because of `max_depth` or `max_entries`).
+    pub show_sizes: bool,
+
+    /// Whether entry names are colored (folders in blue, symbolic links in cyan, and executable
+    /// files in green) using ANSI escape codes.
+    pub color: ColorMode,
+
+    /// The order in which the entries of each folder are printed.
+    pub sort: TreeSort,
+
+    /// If `true`, folders are printed before files (each group is ordered according to `sort`).
+    pub dirs_first: bool,
+
+    /// Glob patterns (e.g. `**/*.rs`) of the files to print. If empty, all files are printed.
+    /// Folders are always printed (unless excluded).
+    pub include_globs: Vec<String>,
+
+    /// Glob patterns (e.g. `target` or `**/*.lock`) of the files and folders to leave out. The
+    /// contents of excluded folders are left out as well.
+    pub exclude_globs: Vec<String>,
+
+    /// If `true`, files and folders ignored by `.gitignore` files within the tree (as well as
+    /// `.git` folders) are left out.
+    pub respect_gitignore: bool,
+
+    /// If `true`, each entry is prefixed with `ls -l` style columns: its permissions, owner (the
+    /// user name if the `ownership` feature is enabled, otherwise the user ID), size, and last
+    /// modification time (in UTC).
+    pub long_format: bool,
+
+    /// If `true`, a `N directories, M files, X total bytes` footer (like the one printed by GNU
+    /// `tree`) is printed after the tree. Only printed entries are counted, and the top-level
+    /// folder is not counted.
+    pub summary: bool,
+}
+
+/// The order in which the entries of each folder are printed by the folder tree printers.
+///
+/// Ties are always broken alphabetically.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum TreeSort {
+    /// Alphabetical order.
+    #[default]
+    Name,
+
+    /// Most recently modified first.
+    ModifiedTime,
+
+    /// Largest first (the size of a folder is the cumulative size of all files below it).
+    Size,
+}
+
+/// Whether the output of the folder tree printers is colored.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum ColorMode {
+    /// Never color the output.
+    #[default]
+    Never,
+
+    /// Always color the output.
+    Always,
+
+    /// Color the output of [`print_folder_tree_with_options`] if standard output is a terminal
+    /// and the `NO_COLOR` environment variable is not set. Output written to any other stream is
+    /// not colored.
+    Auto,
+}
+
+/// Check whether a path is an executable file.
+///
+/// # Arguments
+///
+/// * `path` - The path to check.
+///
+/// # Returns
+///
+/// `true` if `path` is a file with any execute bit set (always `false` on platforms without Unix
+/// permissions).
+fn is_executable(path: &Path) -> bool {
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        std::fs::metadata(path)
+            .is_ok_and(|metadata| metadata.is_file() && metadata.permissions().mode() & 0o111 != 0)
+    }
+    #[cfg(not(unix))]
+    {
+        let _ = path;
+        false
+    }
+}
+
+/// Colors the name of an entry (if enabled).
+///
+/// # Arguments
+///
+/// * `name` - The name to print.
+/// * `path` - The path to the entry.
+/// * `options` - Options controlling how the tree is printed.
+///
+/// # Returns
+///
+/// The (possibly) colored name.
+fn colorize(name: &str, path: &Path, options: &TreeOptions) -> String {
+    if options.color != ColorMode::Always {
+        return name.to_string();
+    }
+    let code = if path.is_symlink() {
+        "1;36"
+    } else if path.is_dir() {
+        "1;34"
+    } else if is_executable(path) {
+        "1;32"
+    } else {
+        return name.to_string();
+    };
+    format!("\x1b[{code}m{name}\x1b[0m")
+}
+
+/// Formats a number of bytes as a human-readable size.
+///
+/// # Arguments
+///
+/// * `bytes` - The number of bytes.
+///
+/// # Returns
+///
+/// The human-readable size using binary units (e.g. `512 B`, `1.5 KiB`, or `3.0 MiB`).
+fn format_size(bytes: u64) -> String {
+    const UNITS: [&str; 6] = ["KiB", "MiB", "GiB", "TiB", "PiB", "EiB"];
+    if bytes < 1024 {
+        return format!("{bytes} B");
+    }
+    let mut size = bytes as f64 / 1024.0;
+    let mut unit = 0;
+    while size >= 1024.0 && unit < UNITS.len() - 1 {
+        size /= 1024.0;
+        unit += 1;
+    }
+    format!("{size:.1} {}", UNITS[unit])
+}
+
+/// Compute the cumulative size of all files in a folder and in each of its subfolders.
+///
+/// # Arguments
+///
+/// * `path` - The path to the folder.
+/// * `sizes` - Map to insert the size of the folder and of each of its subfolders into (in bytes,
+///   keyed by path).
+///
+/// # Note
+///
+/// The sizes are computed bottom-up in a single traversal of the folder (symbolic links are not
+/// followed), so each file is only visited once.
+fn compute_folder_sizes(path: &Path, sizes: &mut HashMap<PathBuf, u64>) {
+    // The folder has a size even if it cannot be read.
+    sizes.entry(path.to_path_buf()).or_default();
+
+    // The contents of each folder are visited before the folder itself, so by the time a folder is
+    // visited, the sizes of all of its contents have been added to it.
+    for entry in WalkDir::new(path)
+        .contents_first(true)
+        .into_iter()
+        .filter_map(Result::ok)
+    {
+        let size = if entry.file_type().is_dir() {
+            *sizes.entry(entry.path().to_path_buf()).or_default()
+        } else if entry.file_type().is_file() {
+            entry.metadata().map_or(0, |metadata| metadata.len())
+        } else {
+            0
+        };
+        if entry.depth() > 0
+            && let Some(parent) = entry.path().parent()
+        {
+            *sizes.entry(parent.to_path_buf()).or_default() += size;
+        }
+    }
+}
+
+/// Get the permissions of an entry in `ls -l` style (e.g. `drwxr-xr-x`).
 ///
 /// # Arguments
 ///
-/// * `path` - The current path to print.
-/// * `prefix` - The prefix string to use for the current level of indentation.
-/// * `is_last` - A boolean indicating if this is the last entry at the current level.
+/// * `metadata` - The metadata of the entry (without following symbolic links).
+///
+/// # Returns
+///
+/// The permissions string. On platforms without Unix permissions, the permission bits are derived
+/// from whether the entry is read-only.
+fn permissions_string(metadata: &std::fs::Metadata) -> String {
+    let file_type = metadata.file_type();
+    let type_char = if file_type.is_symlink() {
+        'l'
+    } else if file_type.is_dir() {
+        'd'
+    } else {
+        '-'
+    };
+    #[cfg(unix)]
+    let mode = {
+        use std::os::unix::fs::PermissionsExt;
+        metadata.permissions().mode()
+    };
+    #[cfg(not(unix))]
+    let mode = if metadata.permissions().readonly() {
+        0o444
+    } else {
+        0o666
+    };
+    let bits: String = (0..9)
+        .map(|i| {
+            if mode & (0o400 >> i) == 0 {
+                '-'
+            } else {
+                ['r', 'w', 'x'][i % 3]
+            }
+        })
+        .collect();
+    format!("{type_char}{bits}")
+}
+
+/// Get the owner of an entry.
+///
+/// # Arguments
+///
+/// * `metadata` - The metadata of the entry (without following symbolic links).
+///
+/// # Returns
+///
+/// The user name (if the `ownership` feature is enabled and the user exists), the user ID (on
+/// other Unix platforms), or `-`.
+fn owner_string(metadata: &std::fs::Metadata) -> String {
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::MetadataExt;
+        let uid = metadata.uid();
+        #[cfg(feature = "ownership")]
+        if let Some(name) = crate::ownership::get_user_name_by_uid(uid) {
+            return name;
+        }
+        uid.to_string()
+    }
+    #[cfg(not(unix))]
+    {
+        let _ = metadata;
+        String::from("-")
+    }
+}
+
+/// Get the `ls -l` style columns to print before the name of an entry.
+///
+/// # Arguments
+///
+/// * `path` - The path to the entry.
+/// * `filter` - Selects the entries of the tree (and holds the options controlling how it is
+///   printed).
+///
+/// # Returns
+///
+/// The columns (empty if `long_format` is not enabled).
+fn long_columns(path: &Path, filter: &TreeFilter) -> String {
+    if !filter.options.long_format {
+        return String::new();
+    }
+    let Ok(metadata) = std::fs::symlink_metadata(path) else {
+        return String::from("[?] ");
+    };
+    let modified = metadata
+        .modified()
+        .map_or_else(|_| String::from("?"), format_datetime);
+    format!(
+        "[{} {:<8} {:>9} {modified}] ",
+        permissions_string(&metadata),
+        owner_string(&metadata),
+        format_size(filter.entry_size(path)),
+    )
+}
+
+/// Get the ` -> target` suffix to print after the name of a symbolic link.
+///
+/// # Arguments
+///
+/// * `path` - The path to the entry.
+///
+/// # Returns
+///
+/// The suffix (empty if `path` is not a symbolic link).
+fn link_target_suffix(path: &Path) -> String {
+    match std::fs::read_link(path) {
+        Ok(target) => format!(" -> {}", target.display()),
+        Err(_) => String::new(),
+    }
+}
+
+/// Get the annotation to print after the name of an entry.
+///
+/// # Arguments
+///
+/// * `path` - The path to the entry.
+/// * `filter` - Selects the entries of the tree (and holds the options controlling how it is
+///   printed).
+///
+/// # Returns
+///
+/// The annotation (empty if no annotations are enabled).
+fn annotation(path: &Path, filter: &TreeFilter) -> String {
+    if filter.options.show_sizes {
+        format!(" ({})", format_size(filter.entry_size(path)))
+    } else {
+        String::new()
+    }
+}
+
+/// Selects and orders the entries of a folder tree according to [`TreeOptions`].
+struct TreeFilter<'a> {
+    /// The top-level folder.
+    root: &'a Path,
+
+    /// Options controlling how the tree is printed.
+    options: &'a TreeOptions,
+
+    /// The compiled `include_globs`.
+    include_globs: Vec<Pattern>,
+
+    /// The compiled `exclude_globs`.
+    exclude_globs: Vec<Pattern>,
+
+    /// The cumulative sizes of the folders computed so far (in bytes, keyed by path).
+    folder_sizes: RefCell<HashMap<PathBuf, u64>>,
+}
+
+impl<'a> TreeFilter<'a> {
+    /// Constructor.
+    ///
+    /// # Arguments
+    ///
+    /// * `root` - The top-level folder.
+    /// * `options` - Options controlling how the tree is printed.
+    ///
+    /// # Returns
+    ///
+    /// The tree filter.
+    ///
+    /// # Panics
+    ///
+    /// If any of the glob patterns is invalid.
+    fn new(root: &'a Path, options: &'a TreeOptions) -> Self {
+        Self {
+            root,
+            options,
+            include_globs: compile_globs(&options.include_globs),
+            exclude_globs: compile_globs(&options.exclude_globs),
+            folder_sizes: RefCell::new(HashMap::new()),
+        }
+    }
+
+    /// Get the size of a file or the cumulative size of all files in a folder.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - The path to the file or folder.
+    ///
+    /// # Returns
+    ///
+    /// The size in bytes (symbolic links are not followed).
+    ///
+    /// # Note
+    ///
+    /// The first time the size of a folder is requested, the sizes of all folders below it are
+    /// computed along with it and cached, so each file in the tree is only visited once.
+    fn entry_size(&self, path: &Path) -> u64 {
+        if path.is_symlink() || !path.is_dir() {
+            return std::fs::symlink_metadata(path).map_or(0, |metadata| metadata.len());
+        }
+        let mut folder_sizes = self.folder_sizes.borrow_mut();
+        if !folder_sizes.contains_key(path) {
+            compute_folder_sizes(path, &mut folder_sizes);
+        }
+        folder_sizes[path]
+    }
+
+    /// Check whether an entry should be left out of the tree.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - The path to the entry.
+    /// * `ignore` - The `.gitignore` rules that apply to the entry.
+    ///
+    /// # Returns
+    ///
+    /// `true` if the entry is filtered out by the options, `false` otherwise.
+    fn is_filtered_out(&self, path: &Path, ignore: &GitIgnore) -> bool {
+        // Globs are matched against both the path relative to the top-level folder and the name.
+        let relative_path = path.strip_prefix(self.root).unwrap_or(path);
+        let name = get_last_path_component(path);
+        let matches = |glob: &Pattern| glob.matches_path(relative_path) || glob.matches(&name);
+
+        let is_folder = path.is_dir();
+        if self.exclude_globs.iter().any(matches) {
+            return true;
+        }
+        if !is_folder && !self.include_globs.is_empty() && !self.include_globs.iter().any(matches) {
+            return true;
+        }
+        self.options.respect_gitignore
+            && ((is_folder && name == ".git") || ignore.is_ignored(path, is_folder))
+    }
+
+    /// Get the entries of a folder to include in the tree.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - The path to the folder.
+    /// * `ignore` - The `.gitignore` rules that apply to the folder.
+    ///
+    /// # Returns
+    ///
+    /// A tuple containing the entries of the folder (filtered and ordered according to the
+    /// options) and the `.gitignore` rules that apply to them.
+    fn children(&self, path: &Path, ignore: &GitIgnore) -> (Vec<PathBuf>, GitIgnore) {
+        let options = self.options;
+
+        // Pick up the folder's own `.gitignore` file.
+        let ignore = if options.respect_gitignore {
+            ignore.child(path)
+        } else {
+            GitIgnore::default()
+        };
+
+        // Read the directory entries into a vector (sorted alphabetically), leaving out filtered
+        // entries, then apply the requested order (stable sorts preserve the alphabetical order
+        // for ties).
+        let mut entries = list_folder_contents(path);
+        entries.retain(|entry| !self.is_filtered_out(entry, &ignore));
+        match options.sort {
+            TreeSort::Name => {}
+            TreeSort::ModifiedTime => entries.sort_by_cached_key(|entry| {
+                std::cmp::Reverse(
+                    std::fs::symlink_metadata(entry)
+                        .and_then(|metadata| metadata.modified())
+                        .ok(),
+                )
+            }),
+            TreeSort::Size => {
+                entries.sort_by_cached_key(|entry| std::cmp::Reverse(self.entry_size(entry)));
+            }
+        }
+        if options.dirs_first {
+            entries.sort_by_key(|entry| !entry.is_dir());
+        }
+
+        (entries, ignore)
+    }
+}
+
+/// State for writing a folder tree.
+struct TreeWriter<'a, W: std::io::Write> {
+    /// Selects and orders the entries to print.
+    filter: TreeFilter<'a>,
+
+    /// The output stream to write the tree structure to.
+    output: &'a mut W,
+
+    /// Canonical paths of the folders currently being printed (used to detect symbolic link
+    /// loops).
+    ancestors: Vec<PathBuf>,
+
+    /// The number of folders printed so far.
+    folder_count: usize,
+
+    /// The number of files (and other non-folder entries) printed so far.
+    file_count: usize,
+
+    /// The total size of the files printed so far (in bytes).
+    total_bytes: u64,
+}
+
+impl<W: std::io::Write> TreeWriter<'_, W> {
+    /// Recursively prints the children of a folder.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - The path to the folder whose children should be printed.
+    /// * `prefix` - The prefix string to use for the current level of indentation.
+    /// * `depth` - The depth of the children (the direct children of the top-level folder have a
+    ///   depth of 1).
+    /// * `ignore` - The `.gitignore` rules that apply to the parent folder.
+    fn write_children(&mut self, path: &Path, prefix: &str, depth: usize, ignore: &GitIgnore) {
+        let options = self.filter.options;
+        let (entries, ignore) = self.filter.children(path, ignore);
+
+        // Only print up to the maximum number of entries.
+        let shown = options
+            .max_entries
+            .map_or(entries.len(), |max| max.min(entries.len()));
+        let hidden = entries.len() - shown;
+
+        // Print each entry.
+        for (i, entry) in entries[..shown].iter().enumerate() {
+            let is_last = i == shown - 1 && hidden == 0;
+            self.write_entry(entry, prefix, is_last, depth, &ignore);
+        }
+
+        // Summarize the entries that were not printed.
+        if hidden > 0 {
+            writeln!(self.output, "{prefix}└── … {hidden} more").unwrap();
+        }
+    }
+
+    /// Recursively prints an entry of the folder tree.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - The current path to print.
+    /// * `prefix` - The prefix string to use for the current level of indentation.
+    /// * `is_last` - A boolean indicating if this is the last entry at the current level.
+    /// * `depth` - The depth of the current path (the direct children of the top-level folder have
+    ///   a depth of 1).
+    /// * `ignore` - The `.gitignore` rules that apply to the current path.
+    fn write_entry(
+        &mut self,
+        path: &Path,
+        prefix: &str,
+        is_last: bool,
+        depth: usize,
+        ignore: &GitIgnore,
+    ) {
+        let options = self.filter.options;
+
+        // Get the name of the file or folder (i.e. the last component of the path).
+        let name = get_last_path_component(path);
+
+        // Check whether this is a folder that is already being printed (i.e. a symbolic link
+        // pointing back into the tree).
+        let canonical_path = std::fs::canonicalize(path).ok();
+        let is_loop = path.is_dir()
+            && canonical_path
+                .as_ref()
+                .is_some_and(|canonical_path| self.ancestors.contains(canonical_path));
+
+        // Print the current file or folder with the appropriate prefix.
+        let connector = if is_last { "└── " } else { "├── " };
+        let name = colorize(&name, path, options);
+        let columns = long_columns(path, &self.filter);
+        let target = link_target_suffix(path);
+        let annotation = annotation(path, &self.filter);
+        let loop_note = if is_loop {
+            "  [recursive, not followed]"
+        } else {
+            ""
+        };
+        writeln!(
+            self.output,
+            "{prefix}{connector}{columns}{name}{target}{annotation}{loop_note}"
+        )
+        .unwrap();
+
+        // Update the counts for the summary.
+        if path.is_dir() {
+            self.folder_count += 1;
+        } else {
+            self.file_count += 1;
+            self.total_bytes +=
+                std::fs::symlink_metadata(path).map_or(0, |metadata| metadata.len());
+        }
+
+        // Special handling for folders (we need to recurse into them and update the prefix),
+        // unless the maximum depth has been reached.
+        if path.is_dir() && !is_loop && options.max_depth.is_none_or(|max| depth < max) {
+            // Create a new prefix for the children. If this is the last entry, we use spaces to
+            // avoid drawing the vertical line.
+            let new_prefix = format!("{}{}", prefix, if is_last { "    " } else { "│   " });
+
+            // Recurse into the children.
+            self.ancestors.extend(canonical_path);
+            self.write_children(path, &new_prefix, depth + 1, ignore);
+            self.ancestors.pop();
+        }
+    }
+}
+
+/// Write the folder tree structure starting from the specified path.
+///
+/// # Arguments
+///
+/// * `path` - The path to the folder to print (can be a `&str`, [`String`], [`Path`], or
+///   [`std::path::PathBuf`]).
 /// * `output` - The output stream to write the tree structure to.
-fn helper<W: std::io::Write>(path: &Path, prefix: String, is_last: bool, output: &mut W) {
-    // Get the name of the file or folder (i.e. the last component of the path).
-    let name = get_last_path_component(path);
+///
+/// # Panics
+///
+/// If the folder cannot be read or the output stream cannot be written to.
+///
+/// # Example
+///
+/// ```
+/// use file_io::write_folder_tree;
+///
+/// // Write the tree to standard error instead of standard output.
+/// write_folder_tree("src", &mut std::io::stderr());
+/// ```
+pub fn write_folder_tree<P: AsRef<Path>, W: std::io::Write>(path: P, output: &mut W) {
+    write_folder_tree_with_options(path, output, &TreeOptions::default());
+}
+
+/// Write the folder tree structure starting from the specified path, with options controlling
+/// how the tree is printed.
+///
+/// # Arguments
+///
+/// * `path` - The path to the folder to print (can be a `&str`, [`String`], [`Path`], or
+///   [`std::path::PathBuf`]).
+/// * `output` - The output stream to write the tree structure to.
+/// * `options` - Options controlling how the tree is printed.
+///
+/// # Panics
+///
+/// If the folder cannot be read or the output stream cannot be written to.
+///
+/// # Example
+///
+/// ```
+/// use file_io::{TreeOptions, write_folder_tree_with_options};
+///
+/// let options = TreeOptions {
+///     max_depth: Some(1),
+///     ..Default::default()
+/// };
+/// write_folder_tree_with_options("src", &mut std::io::stderr(), &options);
+/// ```
+pub fn write_folder_tree_with_options<P: AsRef<Path>, W: std::io::Write>(
+    path: P,
+    output: &mut W,
+    options: &TreeOptions,
+) {
+    // Convert the input path to a Path reference.
+    let path = path.as_ref();
 
-    // Print the current file or folder with the appropriate prefix.
-    let connector = if is_last { "└── " } else { "├── " };
-    writeln!(output, "{prefix}{connector}{name}").unwrap();
+    // Print the full top-level path once.
+    let filter = TreeFilter::new(path, options);
+    let name = colorize(&path.display().to_string(), path, options);
+    writeln!(output, "{name}{}", annotation(path, &filter)).unwrap();
 
-    // Special handling for folders (we need to recurse into them and update the prefix).
-    if path.is_dir() {
-        // Create a new prefix for the children. If this is the last entry, we use spaces to avoid
-        // drawing the vertical line.
-        let new_prefix = format!("{}{}", prefix, if is_last { "    " } else { "│   " });
+    // Recurse only into children (unless nothing below the top-level path should be printed),
+    // counting the printed entries.
+    let counts = if options.max_depth != Some(0) {
+        let mut writer = TreeWriter {
+            filter,
+            output,
+            ancestors: std::fs::canonicalize(path).into_iter().collect(),
+            folder_count: 0,
+            file_count: 0,
+            total_bytes: 0,
+        };
+        writer.write_children(path, "", 1, &GitIgnore::default());
+        (writer.folder_count, writer.file_count, writer.total_bytes)
+    } else {
+        (0, 0, 0)
+    };
 
-        // Read the directory entries into a vector and sort them.
-        let entries = list_folder_contents(path);
+    // Print the summary footer.
+    if options.summary {
+        let (folder_count, file_count, total_bytes) = counts;
+        writeln!(
+            output,
+            "\n{folder_count} {}, {file_count} {}, {total_bytes} total bytes",
+            if folder_count == 1 {
+                "directory"
+            } else {
+                "directories"
+            },
+            if file_count == 1 { "file" } else { "files" },
+        )
+        .unwrap();
+    }
+}
+
+/// Get the folder tree structure starting from the specified path as a string.
+///
+/// # Arguments
+///
+/// * `path` - The path to the folder (can be a `&str`, [`String`], [`Path`], or
+///   [`std::path::PathBuf`]).
+///
+/// # Returns
+///
+/// The folder tree structure (in the same format as [`print_folder_tree`]).
+///
+/// # Panics
+///
+/// If the folder cannot be read.
+///
+/// # Example
+///
+/// ```
+/// use file_io::folder_tree_to_string;
+///
+/// let tree = folder_tree_to_string("src");
+/// assert!(tree.starts_with("src\n"));
+/// assert!(tree.contains("lib.rs"));
+/// ```
+pub fn folder_tree_to_string<P: AsRef<Path>>(path: P) -> String {
+    folder_tree_to_string_with_options(path, &TreeOptions::default())
+}
 
-        // Call the helper function recursively for each entry.
-        for (i, entry) in entries.iter().enumerate() {
-            let is_last = i == entries.len() - 1;
-            helper(entry, new_prefix.clone(), is_last, output);
+/// Get the folder tree structure starting from the specified path as a string, with options
+/// controlling how the tree is printed.
+///
+/// # Arguments
+///
+/// * `path` - The path to the folder (can be a `&str`, [`String`], [`Path`], or
+///   [`std::path::PathBuf`]).
+/// * `options` - Options controlling how the tree is printed.
+///
+/// # Returns
+///
+/// The folder tree structure.
+///
+/// # Panics
+///
+/// If the folder cannot be read.
+///
+/// # Example
+///
+/// ```
+/// use file_io::{TreeOptions, folder_tree_to_string_with_options};
+///
+/// let options = TreeOptions {
+///     max_entries: Some(1),
+///     ..Default::default()
+/// };
+/// let tree = folder_tree_to_string_with_options(".vscode", &options);
+/// assert_eq!(tree, ".vscode\n├── extensions.json\n└── … 1 more\n");
+/// ```
+pub fn folder_tree_to_string_with_options<P: AsRef<Path>>(
+    path: P,
+    options: &TreeOptions,
+) -> String {
+    let mut output: Vec<u8> = Vec::new();
+    write_folder_tree_with_options(path, &mut output, options);
+    String::from_utf8(output).unwrap()
+}
+
+/// Print the folder tree structure starting from the specified path.
+///
+/// # Arguments
+///
+/// * `path` - The path to the folder to print (can be a `&str`, [`String`], [`Path`], or
+///   [`std::path::PathBuf`]).
+///
+/// # Examples
+///
+/// ## Using a string literal
+///
+/// ```
+/// use file_io::print_folder_tree;
+///
+/// print_folder_tree("src");
+/// ```
+///
+/// ## Using a `Path` reference
+///
+/// ```
+/// use file_io::print_folder_tree;
+/// use std::path::Path;
+///
+/// print_folder_tree(Path::new("src"));
+/// ```
+pub fn print_folder_tree<P: AsRef<Path>>(path: P) {
+    write_folder_tree(path, &mut std::io::stdout());
+}
+
+/// Print the folder tree structure starting from the specified path, with options controlling how
+/// the tree is printed.
+///
+/// # Arguments
+///
+/// * `path` - The path to the folder to print (can be a `&str`, [`String`], [`Path`], or
+///   [`std::path::PathBuf`]).
+/// * `options` - Options controlling how the tree is printed.
+///
+/// # Example
+///
+/// ```
+/// use file_io::{TreeOptions, print_folder_tree_with_options};
+///
+/// let options = TreeOptions {
+///     max_depth: Some(1),
+///     max_entries: Some(10),
+///     ..Default::default()
+/// };
+/// print_folder_tree_with_options(".", &options);
+/// ```
+pub fn print_folder_tree_with_options<P: AsRef<Path>>(path: P, options: &TreeOptions) {
+    // Resolve automatic coloring based on standard output.
+    let mut options = options.clone();
+    if options.color == ColorMode::Auto {
+        options.color = if std::io::stdout().is_terminal() && std::env::var_os("NO_COLOR").is_none()
+        {
+            ColorMode::Always
+        } else {
+            ColorMode::Never
+        };
+    }
+    write_folder_tree_with_options(path, &mut std::io::stdout(), &options);
+}
+
+/// A node of a folder tree (see [`get_folder_tree`]).
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TreeNode {
+    /// The name of the file or folder (for the top-level node, the path as given).
+    pub name: String,
+
+    /// The type of the entry.
+    pub entry_type: EntryType,
+
+    /// The size of the file, or the cumulative size of all files below the folder (in bytes).
+    pub size: u64,
+
+    /// The target of a symbolic link (`None` for other entries).
+    pub link_target: Option<PathBuf>,
+
+    /// The children of a folder (empty for files, for folders at the maximum depth, and for
+    /// symbolic links pointing back into the tree).
+    pub children: Vec<TreeNode>,
+}
+
+impl TreeNode {
+    /// Converts the tree to JSON.
+    ///
+    /// # Returns
+    ///
+    /// A compact JSON object of the form `{"name":"...","type":"...","size":...,"children":[...]}`,
+    /// where `type` is one of `file`, `folder`, `symlink`, or `other`, and `children` is only
+    /// present for folders (and symbolic links with children). Symbolic links additionally have a
+    /// `target` (after `type`).
+    pub fn to_json(&self) -> String {
+        let mut json = String::new();
+        self.write_json(&mut json);
+        json
+    }
+
+    /// Appends the JSON representation of the tree to a string.
+    ///
+    /// # Arguments
+    ///
+    /// * `json` - The string to append to.
+    fn write_json(&self, json: &mut String) {
+        let entry_type = match self.entry_type {
+            EntryType::File => "file",
+            EntryType::Folder => "folder",
+            EntryType::Symlink => "symlink",
+            EntryType::Other => "other",
+        };
+        json.push_str("{\"name\":");
+        write_json_string(&self.name, json);
+        json.push_str(&format!(",\"type\":\"{entry_type}\""));
+        if let Some(link_target) = &self.link_target {
+            json.push_str(",\"target\":");
+            write_json_string(&link_target.to_string_lossy(), json);
+        }
+        json.push_str(&format!(",\"size\":{}", self.size));
+        if self.entry_type == EntryType::Folder || !self.children.is_empty() {
+            json.push_str(",\"children\":[");
+            for (i, child) in self.children.iter().enumerate() {
+                if i > 0 {
+                    json.push(',');
+                }
+                child.write_json(json);
+            }
+            json.push(']');
+        }
+        json.push('}');
+    }
+}
+
+/// Appends a string to a JSON document as a JSON string literal.
+///
+/// # Arguments
+///
+/// * `value` - The string to append.
+/// * `json` - The JSON document to append to.
+pub(crate) fn write_json_string(value: &str, json: &mut String) {
+    json.push('"');
+    for c in value.chars() {
+        match c {
+            '"' => json.push_str("\\\""),
+            '\\' => json.push_str("\\\\"),
+            '\n' => json.push_str("\\n"),
+            '\r' => json.push_str("\\r"),
+            '\t' => json.push_str("\\t"),
+            c if c.is_control() => json.push_str(&format!("\\u{:04x}", c as u32)),
+            c => json.push(c),
+        }
+    }
+    json.push('"');
+}
+
+/// Helper function to recursively build a folder tree.
+///
+/// # Arguments
+///
+/// * `filter` - Selects and orders the entries of the tree.
+/// * `path` - The path to the current entry.
+/// * `name` - The name of the current entry.
+/// * `depth` - The depth of the current entry (the top-level folder has a depth of 0).
+/// * `ignore` - The `.gitignore` rules that apply to the current entry.
+/// * `ancestors` - Canonical paths of the folders containing the current entry (used to detect
+///   symbolic link loops).
+///
+/// # Returns
+///
+/// The node for the current entry.
+fn build_node(
+    filter: &TreeFilter,
+    path: &Path,
+    name: String,
+    depth: usize,
+    ignore: &GitIgnore,
+    ancestors: &mut Vec<PathBuf>,
+) -> TreeNode {
+    // Get the size before building the children, so that the sizes of all folders below this one
+    // are computed along with it.
+    let size = filter.entry_size(path);
+
+    let mut children = Vec::new();
+    let canonical_path = std::fs::canonicalize(path).ok();
+    let is_loop = canonical_path
+        .as_ref()
+        .is_some_and(|canonical_path| ancestors.contains(canonical_path));
+    if path.is_dir() && !is_loop && filter.options.max_depth.is_none_or(|max| depth < max) {
+        let (entries, ignore) = filter.children(path, ignore);
+        ancestors.extend(canonical_path);
+        children = entries
+            .iter()
+            .map(|entry| {
+                let name = get_last_path_component(entry);
+                build_node(filter, entry, name, depth + 1, &ignore, ancestors)
+            })
+            .collect();
+        ancestors.pop();
+    }
+    TreeNode {
+        name,
+        entry_type: get_entry_type(path).unwrap_or(EntryType::Other),
+        size,
+        link_target: std::fs::read_link(path).ok(),
+        children,
+    }
+}
+
+/// Get the folder tree structure starting from the specified path as a nested structure.
+///
+/// # Arguments
+///
+/// * `path` - The path to the folder (can be a `&str`, [`String`], [`Path`], or [`PathBuf`]).
+///
+/// # Returns
+///
+/// The top-level node of the tree.
+///
+/// # Panics
+///
+/// If the folder cannot be read.
+///
+/// # Example
+///
+/// ```
+/// use file_io::{EntryType, get_folder_tree};
+///
+/// let tree = get_folder_tree(".vscode");
+/// assert_eq!(tree.entry_type, EntryType::Folder);
+/// assert_eq!(tree.children[0].name, "extensions.json");
+/// ```
+pub fn get_folder_tree<P: AsRef<Path>>(path: P) -> TreeNode {
+    get_folder_tree_with_options(path, &TreeOptions::default())
+}
+
+/// Get the folder tree structure starting from the specified path as a nested structure, with
+/// options controlling which entries are included.
+///
+/// # Arguments
+///
+/// * `path` - The path to the folder (can be a `&str`, [`String`], [`Path`], or [`PathBuf`]).
+/// * `options` - Options controlling which entries are included. Only the options that select and
+///   order entries apply (i.e. `max_depth`, `sort`, `dirs_first`, `include_globs`,
+///   `exclude_globs`, and `respect_gitignore`).
+///
+/// # Returns
+///
+/// The top-level node of the tree.
+///
+/// # Panics
+///
+/// If the folder cannot be read.
+///
+/// # Example
+///
+/// ```
+/// use file_io::{TreeOptions, get_folder_tree_with_options};
+///
+/// let options = TreeOptions {
+///     max_depth: Some(1),
+///     ..Default::default()
+/// };
+/// let tree = get_folder_tree_with_options("src", &options);
+/// assert!(tree.children.iter().all(|child| child.children.is_empty()));
+/// ```
+pub fn get_folder_tree_with_options<P: AsRef<Path>>(path: P, options: &TreeOptions) -> TreeNode {
+    let path = path.as_ref();
+    let filter = TreeFilter::new(path, options);
+    build_node(
+        &filter,
+        path,
+        path.display().to_string(),
+        0,
+        &GitIgnore::default(),
+        &mut Vec::new(),
+    )
+}
+
+/// Get the folder tree structure starting from the specified path as JSON.
+///
+/// # Arguments
+///
+/// * `path` - The path to the folder (can be a `&str`, [`String`], [`Path`], or [`PathBuf`]).
+///
+/// # Returns
+///
+/// The folder tree as JSON (see [`TreeNode::to_json`]).
+///
+/// # Panics
+///
+/// If the folder cannot be read.
+///
+/// # Example
+///
+/// ```
+/// use file_io::folder_tree_to_json;
+///
+/// let json = folder_tree_to_json(".vscode");
+/// assert!(json.starts_with(r#"{"name":".vscode","type":"folder","size":"#));
+/// ```
+pub fn folder_tree_to_json<P: AsRef<Path>>(path: P) -> String {
+    get_folder_tree(path).to_json()
+}
+
+/// Get the folder tree structure starting from the specified path as JSON, with options
+/// controlling which entries are included.
+///
+/// # Arguments
+///
+/// * `path` - The path to the folder (can be a `&str`, [`String`], [`Path`], or [`PathBuf`]).
+/// * `options` - Options controlling which entries are included (see
+///   [`get_folder_tree_with_options`]).
+///
+/// # Returns
+///
+/// The folder tree as JSON (see [`TreeNode::to_json`]).
+///
+/// # Panics
+///
+/// If the folder cannot be read.
+///
+/// # Example
+///
+/// ```
+/// use file_io::{TreeOptions, folder_tree_to_json_with_options};
+///
+/// let options = TreeOptions {
+///     respect_gitignore: true,
+///     ..Default::default()
+/// };
+/// let json = folder_tree_to_json_with_options(".", &options);
+/// assert!(!json.contains(r#""name":"target""#));
+/// ```
+pub fn folder_tree_to_json_with_options<P: AsRef<Path>>(path: P, options: &TreeOptions) -> String {
+    get_folder_tree_with_options(path, options).to_json()
+}
+
+/// Options controlling how a file is printed by [`print_file_with_line_numbers_with_options`] (and
+/// [`write_file_with_line_numbers`]).
+///
+/// The default options print every line as is, which matches the behavior of
+/// [`print_file_with_line_numbers`].
+///
+/// # Example
+///
+/// ```
+/// use file_io::LineNumberOptions;
+///
+/// // Only print lines 10 through 20, expanding tabs to 4 spaces.
+/// let options = LineNumberOptions {
+///     start_line: Some(10),
+///     end_line: Some(20),
+///     tab_width: Some(4),
+/// };
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct LineNumberOptions {
+    /// The first line to print (1-based). If `None`, printing starts at the first line.
+    pub start_line: Option<usize>,
+
+    /// The last line to print (1-based, inclusive). If `None`, printing stops at the last line.
+    pub end_line: Option<usize>,
+
+    /// If set, tabs are expanded to spaces using tab stops of this width (the line number column
+    /// counts towards the position of each tab stop, like in a terminal).
+    pub tab_width: Option<usize>,
+}
+
+/// Expands the tabs in a line to spaces.
+///
+/// # Arguments
+///
+/// * `line` - The line.
+/// * `tab_width` - The distance between tab stops.
+///
+/// # Returns
+///
+/// The line with each tab replaced by enough spaces to reach the next tab stop.
+fn expand_tabs(line: &str, tab_width: usize) -> String {
+    let mut expanded = String::with_capacity(line.len());
+    let mut column = 0;
+    for c in line.chars() {
+        if c == '\t' && tab_width > 0 {
+            let spaces = tab_width - column % tab_width;
+            expanded.extend(std::iter::repeat_n(' ', spaces));
+            column += spaces;
+        } else {
+            expanded.push(c);
+            column += 1;
+        }
+    }
+    expanded
+}
+
+/// Write the contents of a file with line numbers (like `cat -n`).
+///
+/// # Arguments
+///
+/// * `path` - The path to the file (can be a `&str`, [`String`], [`Path`], or [`PathBuf`]).
+/// * `output` - The output stream to write to.
+/// * `options` - Options controlling which lines are printed and how.
+///
+/// # Panics
+///
+/// If the file cannot be read or the output stream cannot be written to.
+///
+/// # Note
+///
+/// The file is read line by line, so it is never loaded into memory as a whole. Invalid UTF-8 is
+/// replaced with `U+FFFD`.
+///
+/// # Example
+///
+/// ```
+/// use file_io::{LineNumberOptions, save_string_to_file, write_file_with_line_numbers};
+///
+/// // Create a file.
+/// let path: &str = "folder/subfolder_24/file_20.txt";
+/// save_string_to_file("first\nsecond\nthird\n", path);
+///
+/// // Write the second and third lines with line numbers.
+/// let options = LineNumberOptions {
+///     start_line: Some(2),
+///     ..Default::default()
+/// };
+/// let mut output: Vec<u8> = Vec::new();
+/// write_file_with_line_numbers(path, &mut output, &options);
+/// assert_eq!(String::from_utf8(output).unwrap(), "     2\tsecond\n     3\tthird\n");
+/// ```
+pub fn write_file_with_line_numbers<P: AsRef<Path>, W: std::io::Write>(
+    path: P,
+    output: &mut W,
+    options: &LineNumberOptions,
+) {
+    let path = path.as_ref();
+    let file =
+        std::fs::File::open(path).unwrap_or_else(|_| panic!("Failed to read file at '{path:?}'."));
+    let mut reader = std::io::BufReader::new(file);
+    let start_line = options.start_line.unwrap_or(1);
+    let mut line = Vec::new();
+    let mut number = 0;
+    loop {
+        // Read the next line (stopping at the end of the file or the requested range).
+        line.clear();
+        let read = reader
+            .read_until(b'\n', &mut line)
+            .unwrap_or_else(|_| panic!("Failed to read file at '{path:?}'."));
+        number += 1;
+        if read == 0 || options.end_line.is_some_and(|end_line| number > end_line) {
+            break;
         }
+        if number < start_line {
+            continue;
+        }
+
+        // Print the line without its line ending.
+        let text = String::from_utf8_lossy(&line);
+        let text = text.trim_end_matches('\n').trim_end_matches('\r');
+        let numbered = format!("{number:>6}\t{text}");
+        let numbered = match options.tab_width {
+            Some(tab_width) => expand_tabs(&numbered, tab_width),
+            None => numbered,
+        };
+        writeln!(output, "{numbered}").unwrap();
     }
 }
 
-/// Write the folder tree structure starting from the specified path.
+/// Print the contents of a file with line numbers (like `cat -n`).
+///
+/// # Arguments
+///
+/// * `path` - The path to the file (can be a `&str`, [`String`], [`Path`], or [`PathBuf`]).
+///
+/// # Panics
+///
+/// If the file cannot be read.
+///
+/// # Example
+///
+/// ```
+/// use file_io::print_file_with_line_numbers;
+///
+/// print_file_with_line_numbers("Cargo.toml");
+/// ```
+pub fn print_file_with_line_numbers<P: AsRef<Path>>(path: P) {
+    print_file_with_line_numbers_with_options(path, &LineNumberOptions::default());
+}
+
+/// Print the contents of a file with line numbers (like `cat -n`), with options controlling which
+/// lines are printed and how.
+///
+/// # Arguments
+///
+/// * `path` - The path to the file (can be a `&str`, [`String`], [`Path`], or [`PathBuf`]).
+/// * `options` - Options controlling which lines are printed and how.
+///
+/// # Panics
+///
+/// If the file cannot be read.
+///
+/// # Example
+///
+/// ```
+/// use file_io::{LineNumberOptions, print_file_with_line_numbers_with_options};
+///
+/// let options = LineNumberOptions {
+///     end_line: Some(5),
+///     tab_width: Some(4),
+///     ..Default::default()
+/// };
+/// print_file_with_line_numbers_with_options("Cargo.toml", &options);
+/// ```
+pub fn print_file_with_line_numbers_with_options<P: AsRef<Path>>(
+    path: P,
+    options: &LineNumberOptions,
+) {
+    write_file_with_line_numbers(path, &mut std::io::stdout(), options);
+}
+
+/// Formats bytes as a hex dump (in the format of `hexdump -C`).
 ///
 /// # Arguments
 ///
-/// * `path` - The path to the folder to print (can be a `&str`, [`String`], [`Path`], or
-///   [`std::path::PathBuf`]).
-/// * `output` - The output stream to write the tree structure to.
-fn write_folder_tree<P: AsRef<Path>, W: std::io::Write>(path: P, output: &mut W) {
-    // Convert the input path to a Path reference.
-    let path = path.as_ref();
-
-    // Print the full top-level path once.
-    writeln!(output, "{}", path.display()).unwrap();
+/// * `bytes` - The bytes to format.
+/// * `offset` - The offset of the first byte (used for the offset column).
+///
+/// # Returns
+///
+/// The hex dump, with one line per 16 bytes consisting of the offset, the bytes in hexadecimal,
+/// and the printable ASCII characters (with other bytes shown as `.`).
+fn format_hex_dump(bytes: &[u8], offset: u64) -> String {
+    let mut dump = String::new();
+    for (i, chunk) in bytes.chunks(16).enumerate() {
+        // Offset column.
+        dump.push_str(&format!("{:08x} ", offset + 16 * i as u64));
 
-    // List and sort children.
-    let entries = list_folder_contents(path);
+        // Hexadecimal columns (with an extra space after the eighth byte).
+        for j in 0..16 {
+            if j == 8 {
+                dump.push(' ');
+            }
+            match chunk.get(j) {
+                Some(byte) => dump.push_str(&format!(" {byte:02x}")),
+                None => dump.push_str("   "),
+            }
+        }
 
-    // Recurse only into children.
-    //  --> The first entry is the top-level path, so we don't need to print it again.
-    for (i, entry) in entries.iter().enumerate() {
-        let is_last = i == entries.len() - 1;
-        helper(entry, "".to_string(), is_last, output);
+        // ASCII column.
+        let ascii: String = chunk
+            .iter()
+            .map(|&byte| {
+                if byte.is_ascii_graphic() || byte == b' ' {
+                    byte as char
+                } else {
+                    '.'
+                }
+            })
+            .collect();
+        dump.push_str(&format!("  |{ascii}|\n"));
     }
+    dump
 }
 
-/// Print the folder tree structure starting from the specified path.
+/// Get a hex dump (in the format of `hexdump -C`) of a portion of a file as a string.
 ///
 /// # Arguments
 ///
-/// * `path` - The path to the folder to print (can be a `&str`, [`String`], [`Path`], or
-///   [`std::path::PathBuf`]).
+/// * `path` - The path to the file (can be a `&str`, [`String`], [`Path`], or [`PathBuf`]).
+/// * `offset` - The offset (in bytes) at which to start.
+/// * `length` - The maximum number of bytes to dump (fewer are dumped if the end of the file is
+///   reached first).
 ///
-/// # Examples
+/// # Returns
 ///
-/// ## Using a string literal
+/// The hex dump, with one line per 16 bytes consisting of the offset, the bytes in hexadecimal,
+/// and the printable ASCII characters (with other bytes shown as `.`).
+///
+/// # Panics
+///
+/// If the file cannot be read.
+///
+/// # Example
 ///
 /// ```
-/// use file_io::print_folder_tree;
+/// use file_io::{hex_dump_to_string, save_string_to_file};
 ///
-/// print_folder_tree("src");
+/// // Create a file.
+/// let path: &str = "folder/subfolder_25/file_21.txt";
+/// save_string_to_file("Hello, world!\n", path);
+///
+/// assert_eq!(
+///     hex_dump_to_string(path, 0, 64),
+///     "00000000  48 65 6c 6c 6f 2c 20 77  6f 72 6c 64 21 0a        |Hello, world!.|\n"
+/// );
 /// ```
+pub fn hex_dump_to_string<P: AsRef<Path>>(path: P, offset: u64, length: usize) -> String {
+    format_hex_dump(&read_file_range(path, offset, length), offset)
+}
+
+/// Print a hex dump (in the format of `hexdump -C`) of a portion of a file.
 ///
-/// ## Using a `Path` reference
+/// # Arguments
+///
+/// * `path` - The path to the file (can be a `&str`, [`String`], [`Path`], or [`PathBuf`]).
+/// * `offset` - The offset (in bytes) at which to start.
+/// * `length` - The maximum number of bytes to dump (fewer are dumped if the end of the file is
+///   reached first).
+///
+/// # Panics
+///
+/// If the file cannot be read.
+///
+/// # Example
 ///
 /// ```
-/// use file_io::print_folder_tree;
-/// use std::path::Path;
+/// use file_io::print_hex_dump;
 ///
-/// print_folder_tree(Path::new("src"));
+/// // Print the first 64 bytes of a file.
+/// print_hex_dump("Cargo.toml", 0, 64);
 /// ```
-pub fn print_folder_tree<P: AsRef<Path>>(path: P) {
-    write_folder_tree(path, &mut std::io::stdout());
+pub fn print_hex_dump<P: AsRef<Path>>(path: P, offset: u64, length: usize) {
+    print!("{}", hex_dump_to_string(path, offset, length));
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::create::create_folder;
     use crate::save_string_to_file;
     use crate::test_utils::get_temp_dir_path;
     use tempfile::tempdir;
@@ -126,4 +1451,465 @@ mod tests {
             )
         );
     }
+
+    #[test]
+    fn test_folder_tree_to_string() {
+        // Create a temporary directory to work in.
+        let temp_dir = tempdir().unwrap();
+
+        // Get the path to the temporary directory.
+        let temp_dir_path = get_temp_dir_path(&temp_dir);
+
+        // Create some test files and folders.
+        save_string_to_file("Content 1", temp_dir_path.join("file1.txt"));
+        save_string_to_file("Content 2", temp_dir_path.join("subfolder/file2.txt"));
+
+        // Check the output.
+        assert_eq!(
+            folder_tree_to_string(&temp_dir_path),
+            format!(
+                "{}\n├── file1.txt\n└── subfolder\n    └── file2.txt\n",
+                temp_dir_path.display()
+            )
+        );
+    }
+
+    #[test]
+    fn test_write_folder_tree_with_limits() {
+        // Create a temporary directory to work in.
+        let temp_dir = tempdir().unwrap();
+
+        // Get the path to the temporary directory.
+        let temp_dir_path = get_temp_dir_path(&temp_dir);
+
+        // Create some test files and folders.
+        save_string_to_file("Content 1", temp_dir_path.join("a/file1.txt"));
+        save_string_to_file("Content 2", temp_dir_path.join("a/file2.txt"));
+        save_string_to_file("Content 3", temp_dir_path.join("a/file3.txt"));
+        save_string_to_file("Content 4", temp_dir_path.join("b/c/file4.txt"));
+        let root = temp_dir_path.display();
+
+        // Limit the depth.
+        let options = TreeOptions {
+            max_depth: Some(1),
+            ..Default::default()
+        };
+        assert_eq!(
+            folder_tree_to_string_with_options(&temp_dir_path, &options),
+            format!("{root}\n├── a\n└── b\n")
+        );
+        let options = TreeOptions {
+            max_depth: Some(0),
+            ..Default::default()
+        };
+        assert_eq!(
+            folder_tree_to_string_with_options(&temp_dir_path, &options),
+            format!("{root}\n")
+        );
+
+        // Limit the number of entries per folder.
+        let options = TreeOptions {
+            max_entries: Some(2),
+            ..Default::default()
+        };
+        assert_eq!(
+            folder_tree_to_string_with_options(&temp_dir_path, &options),
+            format!(
+                "{root}\n├── a\n│   ├── file1.txt\n│   ├── file2.txt\n│   └── … 1 more\n└── b\n    \
+                 └── c\n        └── file4.txt\n"
+            )
+        );
+    }
+
+    #[test]
+    fn test_format_size() {
+        assert_eq!(format_size(0), "0 B");
+        assert_eq!(format_size(1023), "1023 B");
+        assert_eq!(format_size(1536), "1.5 KiB");
+        assert_eq!(format_size(3 * 1024 * 1024), "3.0 MiB");
+    }
+
+    #[test]
+    fn test_write_folder_tree_with_sizes() {
+        // Create a temporary directory to work in.
+        let temp_dir = tempdir().unwrap();
+
+        // Get the path to the temporary directory.
+        let temp_dir_path = get_temp_dir_path(&temp_dir);
+
+        // Create some test files and folders.
+        save_string_to_file(&"a".repeat(100), temp_dir_path.join("file1.txt"));
+        save_string_to_file(&"b".repeat(2000), temp_dir_path.join("sub/file2.txt"));
+        save_string_to_file(&"c".repeat(48), temp_dir_path.join("sub/deeper/file3.txt"));
+
+        // Check the output (folder sizes include entries that are not printed).
+        let options = TreeOptions {
+            max_depth: Some(2),
+            show_sizes: true,
+            ..Default::default()
+        };
+        assert_eq!(
+            folder_tree_to_string_with_options(&temp_dir_path, &options),
+            format!(
+                "{} (2.1 KiB)\n├── file1.txt (100 B)\n└── sub (2.0 KiB)\n    ├── deeper (48 B)\n    \
+                 └── file2.txt (2.0 KiB)\n",
+                temp_dir_path.display()
+            )
+        );
+    }
+
+    #[test]
+    fn test_compute_folder_sizes() {
+        // Create a temporary directory to work in.
+        let temp_dir = tempdir().unwrap();
+        let temp_dir_path = get_temp_dir_path(&temp_dir);
+        save_string_to_file(&"a".repeat(10), temp_dir_path.join("a.txt"));
+        save_string_to_file(&"b".repeat(20), temp_dir_path.join("sub/b.txt"));
+        save_string_to_file(&"c".repeat(30), temp_dir_path.join("sub/deeper/c.txt"));
+        create_folder(temp_dir_path.join("sub/empty"));
+
+        // The sizes of the folder and of all folders below it are computed in a single pass.
+        let mut sizes = HashMap::new();
+        compute_folder_sizes(&temp_dir_path, &mut sizes);
+        assert_eq!(
+            sizes,
+            HashMap::from([
+                (temp_dir_path.clone(), 60),
+                (temp_dir_path.join("sub"), 50),
+                (temp_dir_path.join("sub/deeper"), 30),
+                (temp_dir_path.join("sub/empty"), 0),
+            ])
+        );
+    }
+
+    #[test]
+    fn test_write_folder_tree_with_colors() {
+        // Create a temporary directory to work in.
+        let temp_dir = tempdir().unwrap();
+
+        // Get the path to the temporary directory.
+        let temp_dir_path = get_temp_dir_path(&temp_dir);
+
+        // Create some test files and folders.
+        save_string_to_file("Content", temp_dir_path.join("file.txt"));
+        save_string_to_file("Content", temp_dir_path.join("sub/file.txt"));
+
+        // Check the colored output.
+        let options = TreeOptions {
+            color: ColorMode::Always,
+            ..Default::default()
+        };
+        assert_eq!(
+            folder_tree_to_string_with_options(&temp_dir_path, &options),
+            format!(
+                "\x1b[1;34m{}\x1b[0m\n├── file.txt\n└── \x1b[1;34msub\x1b[0m\n    └── file.txt\n",
+                temp_dir_path.display()
+            )
+        );
+
+        // Automatic coloring does not apply to other output streams.
+        let options = TreeOptions {
+            color: ColorMode::Auto,
+            ..Default::default()
+        };
+        assert!(!folder_tree_to_string_with_options(&temp_dir_path, &options).contains('\x1b'));
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_is_executable() {
+        // Create a temporary directory to work in.
+        let temp_dir = tempdir().unwrap();
+
+        // Path to the script.
+        let script_path = get_temp_dir_path(&temp_dir).join("script.sh");
+
+        // Only files with an execute bit are executable.
+        save_string_to_file("#!/bin/sh\n", &script_path);
+        assert!(!is_executable(&script_path));
+        crate::permissions::make_executable(&script_path);
+        assert!(is_executable(&script_path));
+        assert!(!is_executable(&get_temp_dir_path(&temp_dir)));
+    }
+
+    #[test]
+    fn test_write_folder_tree_sorted() {
+        // Create a temporary directory to work in.
+        let temp_dir = tempdir().unwrap();
+
+        // Get the path to the temporary directory.
+        let temp_dir_path = get_temp_dir_path(&temp_dir);
+        let root = temp_dir_path.display();
+
+        // Create some test files and folders.
+        save_string_to_file(&"a".repeat(10), temp_dir_path.join("a.txt"));
+        save_string_to_file(&"b".repeat(30), temp_dir_path.join("b/file.txt"));
+        save_string_to_file(&"c".repeat(20), temp_dir_path.join("c.txt"));
+        crate::set_modified_time(
+            temp_dir_path.join("a.txt"),
+            std::time::SystemTime::now() + std::time::Duration::from_secs(60),
+        );
+
+        // Sort folders first.
+        let options = TreeOptions {
+            max_depth: Some(1),
+            dirs_first: true,
+            ..Default::default()
+        };
+        assert_eq!(
+            folder_tree_to_string_with_options(&temp_dir_path, &options),
+            format!("{root}\n├── b\n├── a.txt\n└── c.txt\n")
+        );
+
+        // Sort by size.
+        let options = TreeOptions {
+            max_depth: Some(1),
+            sort: TreeSort::Size,
+            ..Default::default()
+        };
+        assert_eq!(
+            folder_tree_to_string_with_options(&temp_dir_path, &options),
+            format!("{root}\n├── b\n├── c.txt\n└── a.txt\n")
+        );
+
+        // Sort by modification time.
+        let options = TreeOptions {
+            max_depth: Some(1),
+            sort: TreeSort::ModifiedTime,
+            ..Default::default()
+        };
+        assert!(
+            folder_tree_to_string_with_options(&temp_dir_path, &options)
+                .starts_with(&format!("{root}\n├── a.txt\n"))
+        );
+    }
+
+    #[test]
+    fn test_write_folder_tree_filtered() {
+        // Create a temporary directory to work in.
+        let temp_dir = tempdir().unwrap();
+
+        // Get the path to the temporary directory.
+        let temp_dir_path = get_temp_dir_path(&temp_dir);
+        let root = temp_dir_path.display();
+
+        // Create some test files and folders.
+        save_string_to_file("target/\n", temp_dir_path.join(".gitignore"));
+        save_string_to_file("[core]", temp_dir_path.join(".git/config"));
+        save_string_to_file("Binary", temp_dir_path.join("target/debug/app"));
+        save_string_to_file("fn main() {}", temp_dir_path.join("src/main.rs"));
+        save_string_to_file("# Notes", temp_dir_path.join("src/notes.md"));
+        save_string_to_file("Lock", temp_dir_path.join("Cargo.lock"));
+
+        // Include and exclude globs.
+        let options = TreeOptions {
+            include_globs: vec![String::from("**/*.rs")],
+            exclude_globs: vec![String::from(".git*"), String::from("target")],
+            ..Default::default()
+        };
+        assert_eq!(
+            folder_tree_to_string_with_options(&temp_dir_path, &options),
+            format!("{root}\n└── src\n    └── main.rs\n")
+        );
+
+        // Respect `.gitignore` files.
+        let options = TreeOptions {
+            respect_gitignore: true,
+            ..Default::default()
+        };
+        assert_eq!(
+            folder_tree_to_string_with_options(&temp_dir_path, &options),
+            format!(
+                "{root}\n├── .gitignore\n├── Cargo.lock\n└── src\n    ├── main.rs\n    └── notes.md\n"
+            )
+        );
+    }
+
+    #[test]
+    fn test_folder_tree_to_json() {
+        // Create a temporary directory to work in.
+        let temp_dir = tempdir().unwrap();
+
+        // Get the path to the temporary directory.
+        let temp_dir_path = get_temp_dir_path(&temp_dir);
+
+        // Create some test files and folders.
+        save_string_to_file("Hello", temp_dir_path.join("a \"quoted\".txt"));
+        save_string_to_file("World!", temp_dir_path.join("sub/b.txt"));
+        create_folder(temp_dir_path.join("sub/empty"));
+
+        // Check the nested structure.
+        let tree = get_folder_tree(&temp_dir_path);
+        assert_eq!(tree.entry_type, EntryType::Folder);
+        assert_eq!(tree.size, 11);
+        assert_eq!(tree.children.len(), 2);
+        assert_eq!(tree.children[1].children[0].name, "b.txt");
+
+        // Check the JSON (of the subfolder, whose name does not depend on the temporary path).
+        assert_eq!(
+            tree.children[0].to_json(),
+            r#"{"name":"a \"quoted\".txt","type":"file","size":5}"#
+        );
+        assert_eq!(
+            tree.children[1].to_json(),
+            r#"{"name":"sub","type":"folder","size":6,"children":[{"name":"b.txt","type":"file","size":6},{"name":"empty","type":"folder","size":0,"children":[]}]}"#
+        );
+        assert_eq!(folder_tree_to_json(&temp_dir_path), tree.to_json());
+    }
+
+    #[test]
+    fn test_write_folder_tree_long_format() {
+        // Create a temporary directory to work in.
+        let temp_dir = tempdir().unwrap();
+
+        // Get the path to the temporary directory.
+        let temp_dir_path = get_temp_dir_path(&temp_dir);
+
+        // Create a test file with a known modification time.
+        let file_path = temp_dir_path.join("file.txt");
+        save_string_to_file("Hello", &file_path);
+        crate::set_modified_time(
+            &file_path,
+            std::time::UNIX_EPOCH + std::time::Duration::from_secs(1_791_970_215),
+        );
+
+        // Check the columns.
+        let options = TreeOptions {
+            long_format: true,
+            ..Default::default()
+        };
+        let output = folder_tree_to_string_with_options(&temp_dir_path, &options);
+        let line = output.lines().nth(1).unwrap();
+        assert!(line.starts_with("└── [-r"));
+        assert!(line.ends_with("      5 B 2026-10-14 09:30] file.txt"));
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_write_folder_tree_symlinks() {
+        // Create a temporary directory to work in.
+        let temp_dir = tempdir().unwrap();
+
+        // Get the path to the temporary directory.
+        let temp_dir_path = get_temp_dir_path(&temp_dir);
+
+        // Create a symbolic link to a file and one pointing back into the tree.
+        save_string_to_file("Content", temp_dir_path.join("sub/file.txt"));
+        std::os::unix::fs::symlink("sub/file.txt", temp_dir_path.join("link.txt")).unwrap();
+        std::os::unix::fs::symlink("..", temp_dir_path.join("sub/parent")).unwrap();
+
+        // Check the output.
+        assert_eq!(
+            folder_tree_to_string(&temp_dir_path),
+            format!(
+                "{}\n├── link.txt -> sub/file.txt\n└── sub\n    ├── file.txt\n    └── parent -> ..  \
+                 [recursive, not followed]\n",
+                temp_dir_path.display()
+            )
+        );
+
+        // Check the nested structure.
+        let tree = get_folder_tree(&temp_dir_path);
+        assert_eq!(
+            tree.children[0].link_target,
+            Some(PathBuf::from("sub/file.txt"))
+        );
+        assert!(tree.children[1].children[1].children.is_empty());
+        assert!(
+            tree.children[0].to_json().starts_with(
+                r#"{"name":"link.txt","type":"symlink","target":"sub/file.txt","size":"#
+            )
+        );
+    }
+
+    #[test]
+    fn test_write_folder_tree_summary() {
+        // Create a temporary directory to work in.
+        let temp_dir = tempdir().unwrap();
+
+        // Get the path to the temporary directory.
+        let temp_dir_path = get_temp_dir_path(&temp_dir);
+
+        // Create some test files and folders.
+        save_string_to_file("Hello", temp_dir_path.join("file1.txt"));
+        save_string_to_file("World!", temp_dir_path.join("sub/file2.txt"));
+
+        // Check the output.
+        let options = TreeOptions {
+            summary: true,
+            ..Default::default()
+        };
+        assert_eq!(
+            folder_tree_to_string_with_options(&temp_dir_path, &options),
+            format!(
+                "{}\n├── file1.txt\n└── sub\n    └── file2.txt\n\n1 directory, 2 files, 11 total bytes\n",
+                temp_dir_path.display()
+            )
+        );
+    }
+
+    #[test]
+    fn test_expand_tabs() {
+        assert_eq!(expand_tabs("a\tb", 4), "a   b");
+        assert_eq!(expand_tabs("abcd\tb", 4), "abcd    b");
+        assert_eq!(expand_tabs("\t\tx", 2), "    x");
+    }
+
+    #[test]
+    fn test_write_file_with_line_numbers() {
+        // Create a temporary directory to work in.
+        let temp_dir = tempdir().unwrap();
+
+        // Path to the file.
+        let file_path = get_temp_dir_path(&temp_dir).join("file.txt");
+        save_string_to_file("one\r\n\ttwo\nthree\nfour", &file_path);
+
+        // Print all lines.
+        let mut output: Vec<u8> = Vec::new();
+        write_file_with_line_numbers(&file_path, &mut output, &LineNumberOptions::default());
+        assert_eq!(
+            String::from_utf8(output).unwrap(),
+            "     1\tone\n     2\t\ttwo\n     3\tthree\n     4\tfour\n"
+        );
+
+        // Print a range of lines with tabs expanded.
+        let options = LineNumberOptions {
+            start_line: Some(2),
+            end_line: Some(3),
+            tab_width: Some(4),
+        };
+        let mut output: Vec<u8> = Vec::new();
+        write_file_with_line_numbers(&file_path, &mut output, &options);
+        assert_eq!(
+            String::from_utf8(output).unwrap(),
+            "     2      two\n     3  three\n"
+        );
+    }
+
+    #[test]
+    fn test_hex_dump_to_string() {
+        // Create a temporary directory to work in.
+        let temp_dir = tempdir().unwrap();
+
+        // Path to the file.
+        let file_path = get_temp_dir_path(&temp_dir).join("file.bin");
+        let bytes: Vec<u8> = (0u8..40).collect();
+        std::fs::write(&file_path, &bytes).unwrap();
+
+        // Dump a portion of the file.
+        assert_eq!(
+            hex_dump_to_string(&file_path, 30, 100),
+            "0000001e  1e 1f 20 21 22 23 24 25  26 27                    |.. !\"#$%&'|\n"
+        );
+
+        // Dump multiple lines.
+        assert_eq!(
+            hex_dump_to_string(&file_path, 0, 20),
+            "00000000  00 01 02 03 04 05 06 07  08 09 0a 0b 0c 0d 0e 0f  |................|\n\
+             00000010  10 11 12 13                                       |....|\n"
+        );
+
+        // Dump past the end of the file.
+        assert_eq!(hex_dump_to_string(&file_path, 100, 10), "");
+    }
 }
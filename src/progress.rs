@@ -0,0 +1,187 @@
+use std::path::Path;
+
+/// Receives progress updates from bulk operations (such as [`crate::copy_folder_with_progress`]),
+/// e.g. to drive a progress bar.
+///
+/// All methods do nothing by default, so implementations only need to override the updates they
+/// care about.
+///
+/// # Note
+///
+/// * [`ProgressSink::on_start`] is called once before any items are processed, and
+///   [`ProgressSink::on_finish`] once after all items have been processed.
+/// * Some operations process items on several threads at once, which is why updates are received
+///   through a shared reference and implementations must be [`Sync`].
+///
+/// # Example
+///
+/// ```
+/// use file_io::{ProgressSink, copy_folder_with_progress};
+/// use std::path::Path;
+/// use std::sync::atomic::{AtomicU64, Ordering};
+///
+/// // Keep track of the number of bytes copied.
+/// #[derive(Default)]
+/// struct ByteCounter(AtomicU64);
+///
+/// impl ProgressSink for ByteCounter {
+///     fn on_bytes(&self, bytes: u64) {
+///         self.0.fetch_add(bytes, Ordering::Relaxed);
+///     }
+/// }
+///
+/// let counter = ByteCounter::default();
+/// copy_folder_with_progress(".vscode", "folder/vscode_copy", &counter);
+/// assert!(counter.0.load(Ordering::Relaxed) > 0);
+/// ```
+pub trait ProgressSink: Sync {
+    /// Called before any items are processed.
+    ///
+    /// # Arguments
+    ///
+    /// * `total_items` - The total number of items (e.g. files) that will be processed.
+    /// * `total_bytes` - The total number of bytes that will be processed.
+    fn on_start(&self, total_items: usize, total_bytes: u64) {
+        let _ = (total_items, total_bytes);
+    }
+
+    /// Called after an item has been processed.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - The path to the item.
+    fn on_item(&self, path: &Path) {
+        let _ = path;
+    }
+
+    /// Called after some bytes have been processed.
+    ///
+    /// # Arguments
+    ///
+    /// * `bytes` - The number of bytes processed since the last call.
+    fn on_bytes(&self, bytes: u64) {
+        let _ = bytes;
+    }
+
+    /// Called after all items have been processed.
+    fn on_finish(&self) {}
+}
+
+/// A [`ProgressSink`] that ignores all progress updates.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct NoProgress;
+
+impl ProgressSink for NoProgress {}
+
+/// Returns the size of a file, for progress reporting purposes.
+///
+/// # Arguments
+///
+/// * `path` - The path to the file.
+///
+/// # Returns
+///
+/// The size of the file (in bytes), or `0` if it cannot be determined.
+pub(crate) fn file_size(path: &Path) -> u64 {
+    std::fs::symlink_metadata(path).map_or(0, |metadata| metadata.len())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::copy::copy_folder_with_progress;
+    use crate::delete::delete_folder_with_progress;
+    use crate::hash::hash_folder_with_progress;
+    use crate::modify::{ModifyOptions, replace_str_in_files_with_progress};
+    use crate::save::save_string_to_file;
+    use crate::test_utils::get_temp_dir_path;
+    use std::sync::Mutex;
+    use tempfile::tempdir;
+
+    /// Progress sink that records the totals and the progress made.
+    #[derive(Default)]
+    struct RecordingSink {
+        /// Totals passed to `on_start`.
+        totals: Mutex<Option<(usize, u64)>>,
+
+        /// Number of items processed.
+        items: Mutex<usize>,
+
+        /// Number of bytes processed.
+        bytes: Mutex<u64>,
+
+        /// Whether `on_finish` was called.
+        finished: Mutex<bool>,
+    }
+
+    impl ProgressSink for RecordingSink {
+        fn on_start(&self, total_items: usize, total_bytes: u64) {
+            *self.totals.lock().unwrap() = Some((total_items, total_bytes));
+        }
+
+        fn on_item(&self, _path: &Path) {
+            *self.items.lock().unwrap() += 1;
+        }
+
+        fn on_bytes(&self, bytes: u64) {
+            *self.bytes.lock().unwrap() += bytes;
+        }
+
+        fn on_finish(&self) {
+            *self.finished.lock().unwrap() = true;
+        }
+    }
+
+    impl RecordingSink {
+        /// Checks that all items were processed.
+        fn assert_complete(&self, total_items: usize, total_bytes: u64) {
+            assert_eq!(
+                *self.totals.lock().unwrap(),
+                Some((total_items, total_bytes))
+            );
+            assert_eq!(*self.items.lock().unwrap(), total_items);
+            assert_eq!(*self.bytes.lock().unwrap(), total_bytes);
+            assert!(*self.finished.lock().unwrap());
+        }
+    }
+
+    #[test]
+    fn test_bulk_operations_progress() {
+        // Create a temporary directory to work in.
+        let temp_dir = tempdir().unwrap();
+        let temp_dir_path = get_temp_dir_path(&temp_dir);
+
+        // Folder with 3 files (10 bytes in total).
+        let folder_path = temp_dir_path.join("source");
+        save_string_to_file("foo", folder_path.join("a.txt"));
+        save_string_to_file("foo", folder_path.join("b.txt"));
+        save_string_to_file("food", folder_path.join("sub/c.txt"));
+
+        // Copy the folder.
+        let sink = RecordingSink::default();
+        copy_folder_with_progress(&folder_path, temp_dir_path.join("copy"), &sink);
+        sink.assert_complete(3, 10);
+
+        // Hash the folder.
+        let sink = RecordingSink::default();
+        hash_folder_with_progress(&folder_path, &sink);
+        sink.assert_complete(3, 10);
+
+        // Replace a string in all files.
+        let sink = RecordingSink::default();
+        replace_str_in_files_with_progress(
+            &folder_path,
+            "foo",
+            "x",
+            &ModifyOptions::default(),
+            &sink,
+        );
+        sink.assert_complete(3, 10);
+
+        // Delete the copy.
+        let sink = RecordingSink::default();
+        delete_folder_with_progress(temp_dir_path.join("copy"), &sink);
+        sink.assert_complete(3, 10);
+        assert!(!temp_dir_path.join("copy").exists());
+    }
+}
@@ -0,0 +1,330 @@
+use crate::load::load_file_as_string;
+use crate::save::save_string_to_file;
+use std::collections::HashMap;
+use std::path::Path;
+
+/// Determines whether a line ends with a line continuation (i.e. an odd number of backslashes).
+///
+/// # Arguments
+///
+/// * `line` - The line.
+///
+/// # Returns
+///
+/// `true` if the line is continued on the next line, `false` otherwise.
+fn is_continued(line: &str) -> bool {
+    line.chars().rev().take_while(|&c| c == '\\').count() % 2 == 1
+}
+
+/// Splits a logical line into its (still escaped) key and value.
+///
+/// # Arguments
+///
+/// * `line` - The logical line (with leading whitespace removed).
+///
+/// # Returns
+///
+/// The escaped key and value.
+fn split_key_value(line: &str) -> (&str, &str) {
+    // The key ends at the first unescaped separator (`=`, `:`, or whitespace).
+    let mut escaped = false;
+    let key_end = line
+        .char_indices()
+        .find(|&(_, c)| {
+            let is_separator = !escaped && (c == '=' || c == ':' || c.is_whitespace());
+            escaped = !escaped && c == '\\';
+            is_separator
+        })
+        .map_or(line.len(), |(i, _)| i);
+    let (key, rest) = line.split_at(key_end);
+
+    // The separator may be surrounded by whitespace.
+    let rest = rest.trim_start();
+    let rest = rest.strip_prefix(['=', ':']).unwrap_or(rest);
+    (key, rest.trim_start())
+}
+
+/// Resolves the escape sequences in a key or value.
+///
+/// # Arguments
+///
+/// * `escaped` - The escaped key or value.
+///
+/// # Returns
+///
+/// The unescaped key or value, or `None` if it contains a malformed `\u` escape sequence.
+fn unescape(escaped: &str) -> Option<String> {
+    let mut units: Vec<u16> = Vec::with_capacity(escaped.len());
+    let mut chars = escaped.chars();
+    while let Some(c) = chars.next() {
+        let c = if c == '\\' {
+            match chars.next() {
+                Some('t') => '\t',
+                Some('n') => '\n',
+                Some('r') => '\r',
+                Some('f') => '\u{c}',
+                Some('u') => {
+                    let hex: String = chars.by_ref().take(4).collect();
+                    if hex.len() != 4 {
+                        return None;
+                    }
+                    units.push(u16::from_str_radix(&hex, 16).ok()?);
+                    continue;
+                }
+                Some(other) => other,
+                None => continue,
+            }
+        } else {
+            c
+        };
+        units.extend(c.encode_utf16(&mut [0; 2]).iter());
+    }
+    // `\u` escape sequences can encode surrogate pairs, so the value is decoded from UTF-16.
+    String::from_utf16(&units).ok()
+}
+
+/// Escapes a key or value for a properties file.
+///
+/// # Arguments
+///
+/// * `text` - The key or value.
+/// * `is_key` - Whether `text` is a key (in which case all spaces are escaped, not only leading
+///   ones).
+///
+/// # Returns
+///
+/// The escaped key or value.
+fn escape(text: &str, is_key: bool) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for (i, c) in text.chars().enumerate() {
+        match c {
+            '\\' => escaped.push_str("\\\\"),
+            '\t' => escaped.push_str("\\t"),
+            '\n' => escaped.push_str("\\n"),
+            '\r' => escaped.push_str("\\r"),
+            '\u{c}' => escaped.push_str("\\f"),
+            ' ' if is_key || i == 0 => escaped.push_str("\\ "),
+            '=' | ':' | '#' | '!' => {
+                escaped.push('\\');
+                escaped.push(c);
+            }
+            ' '..='~' => escaped.push(c),
+            _ => {
+                for unit in c.encode_utf16(&mut [0; 2]) {
+                    escaped.push_str(&format!("\\u{unit:04X}"));
+                }
+            }
+        }
+    }
+    escaped
+}
+
+/// Loads the properties defined in a Java-style `.properties` file.
+///
+/// # Arguments
+///
+/// * `path` - The path to the properties file (can be a `&str`, [`String`], [`Path`], or
+///   [`std::path::PathBuf`]).
+///
+/// # Returns
+///
+/// A map from each key to its value.
+///
+/// # Panics
+///
+/// If the file cannot be read (e.g. because it is not valid UTF-8), or if it contains a malformed
+/// `\u` escape sequence.
+///
+/// # Note
+///
+/// The file is parsed following the format of Java's `java.util.Properties`:
+///
+/// * Each property is defined as `key=value`, `key:value`, or `key value`. Whitespace around the
+///   separator is ignored.
+/// * Lines whose first non-whitespace character is `#` or `!` are comments.
+/// * A line ending in a backslash is continued on the next line (ignoring the leading whitespace
+///   of the next line).
+/// * The escape sequences `\t`, `\n`, `\r`, `\f`, and `\uXXXX` (including surrogate pairs) are
+///   resolved. Any other character preceded by a backslash is taken literally.
+/// * If a key is defined more than once, the last definition is used.
+///
+/// # Example
+///
+/// ```
+/// use file_io::{load_properties_file, save_string_to_file};
+///
+/// let path: &str = "folder/subfolder_54/application.properties";
+/// save_string_to_file(
+///     "# Server settings\nserver.port = 8080\ngreeting: Gr\\u00FC\\u00DF dich\n",
+///     path,
+/// );
+///
+/// let properties = load_properties_file(path);
+/// assert_eq!(properties["server.port"], "8080");
+/// assert_eq!(properties["greeting"], "Grüß dich");
+/// ```
+pub fn load_properties_file<P: AsRef<Path>>(path: P) -> HashMap<String, String> {
+    let path = path.as_ref();
+    let content = load_file_as_string(path);
+    let mut properties = HashMap::new();
+    let mut lines = content.lines();
+    while let Some(line) = lines.next() {
+        // Skip blank lines and comments.
+        let line = line.trim_start();
+        if line.is_empty() || line.starts_with(['#', '!']) {
+            continue;
+        }
+
+        // Join continued lines into a single logical line.
+        let mut logical_line = line.to_string();
+        while is_continued(&logical_line) {
+            logical_line.pop();
+            match lines.next() {
+                Some(next_line) => logical_line.push_str(next_line.trim_start()),
+                None => break,
+            }
+        }
+
+        // Split the logical line into the key and value, and resolve their escape sequences.
+        let (key, value) = split_key_value(&logical_line);
+        let unescape = |escaped: &str| {
+            unescape(escaped).unwrap_or_else(|| {
+                panic!("Malformed escape sequence in '{logical_line}' in '{path:?}'.")
+            })
+        };
+        properties.insert(unescape(key), unescape(value));
+    }
+    properties
+}
+
+/// Saves properties to a Java-style `.properties` file.
+///
+/// # Arguments
+///
+/// * `properties` - A map from each key to its value.
+/// * `path` - The path to the properties file (can be a `&str`, [`String`], [`Path`], or
+///   [`std::path::PathBuf`]).
+///
+/// # Panics
+///
+/// If the file cannot be written.
+///
+/// # Note
+///
+/// * The properties are written in alphabetical order of their keys, one `key=value` definition
+///   per line.
+/// * Keys and values are escaped following Java's `java.util.Properties::store`, with all
+///   non-ASCII characters written as `\uXXXX` escape sequences. The file can therefore be loaded
+///   by Java regardless of whether it is read as ISO 8859-1 or UTF-8.
+/// * This function will create the parent folder for the file if it does not already exist.
+///
+/// # Example
+///
+/// ```
+/// use file_io::{load_file_as_string, save_properties_file};
+/// use std::collections::HashMap;
+///
+/// let path: &str = "folder/subfolder_54/file_56.properties";
+/// let properties = HashMap::from([
+///     (String::from("server.port"), String::from("8080")),
+///     (String::from("greeting"), String::from("Grüß dich")),
+/// ]);
+/// save_properties_file(&properties, path);
+///
+/// assert_eq!(
+///     load_file_as_string(path),
+///     "greeting=Gr\\u00FC\\u00DF dich\nserver.port=8080\n"
+/// );
+/// ```
+pub fn save_properties_file<P: AsRef<Path>>(properties: &HashMap<String, String>, path: P) {
+    let mut keys: Vec<&String> = properties.keys().collect();
+    keys.sort();
+    let mut content = String::new();
+    for key in keys {
+        content.push_str(&escape(key, true));
+        content.push('=');
+        content.push_str(&escape(&properties[key], false));
+        content.push('\n');
+    }
+    save_string_to_file(&content, path);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::get_temp_dir_path;
+    use tempfile::tempdir;
+
+    #[test]
+    fn test_load_properties_file() {
+        // Create a temporary directory to work in.
+        let temp_dir = tempdir().unwrap();
+        let file_path = get_temp_dir_path(&temp_dir).join("test.properties");
+        save_string_to_file(
+            "# Comment\n\
+             ! Another comment\n\
+             \n\
+             equals=value\n\
+             colon : value with spaces  \n\
+             space value\n\
+             empty\n\
+             \x20   indented = value\n\
+             escaped\\ key\\=with\\:separators = a\\tb\\nc\\\\d\n\
+             unicode = \\u00E9\\uD83D\\uDE00\n\
+             multiline = first, \\\n\
+             \x20   second\n\
+             backslashes = ends with one\\\\\n\
+             equals = overridden\n",
+            &file_path,
+        );
+
+        let properties = load_properties_file(&file_path);
+        let expected = HashMap::from(
+            [
+                ("equals", "overridden"),
+                ("colon", "value with spaces  "),
+                ("space", "value"),
+                ("empty", ""),
+                ("indented", "value"),
+                ("escaped key=with:separators", "a\tb\nc\\d"),
+                ("unicode", "é😀"),
+                ("multiline", "first, second"),
+                ("backslashes", "ends with one\\"),
+            ]
+            .map(|(key, value)| (key.to_string(), value.to_string())),
+        );
+        assert_eq!(properties, expected);
+    }
+
+    #[test]
+    #[should_panic(expected = "Malformed escape sequence")]
+    fn test_load_properties_file_malformed_unicode_escape() {
+        // Create a temporary directory to work in.
+        let temp_dir = tempdir().unwrap();
+        let file_path = get_temp_dir_path(&temp_dir).join("test.properties");
+        save_string_to_file("key = \\u00G9\n", &file_path);
+        load_properties_file(&file_path);
+    }
+
+    #[test]
+    fn test_save_properties_file_round_trip() {
+        // Create a temporary directory to work in.
+        let temp_dir = tempdir().unwrap();
+        let file_path = get_temp_dir_path(&temp_dir).join("config/test.properties");
+        let properties = HashMap::from(
+            [
+                ("key with spaces", " leading and trailing spaces "),
+                ("separators=:", "#!=: \\"),
+                ("control", "\t\n\r\u{c}"),
+                ("unicode", "héllo 😀"),
+                ("", "empty key"),
+            ]
+            .map(|(key, value)| (key.to_string(), value.to_string())),
+        );
+
+        // Save the properties and load them back.
+        save_properties_file(&properties, &file_path);
+        assert!(load_file_as_string(&file_path).is_ascii());
+        assert_eq!(load_properties_file(&file_path), properties);
+    }
+}
@@ -0,0 +1,1089 @@
+use crate::instrument::Mutation;
+use crate::modify::compile_globs;
+use crate::path::{add_prefix_to_stem, add_suffix_to_stem, extension_matches, unique_path};
+use glob::MatchOptions;
+use same_file::is_same_file;
+use std::collections::{BTreeSet, HashSet};
+use std::path::{Path, PathBuf};
+use unicode_normalization::UnicodeNormalization;
+use unicode_normalization::char::is_combining_mark;
+use walkdir::WalkDir;
+
+/// Rule used by [`rename_files`] to determine the new name of each file.
+#[derive(Clone, Debug, PartialEq)]
+pub enum RenameRule {
+    /// Adds a prefix to the file stem, keeping the extension (e.g. `report.csv` is renamed to
+    /// `2026_report.csv` with the prefix `2026_`).
+    Prefix(String),
+
+    /// Adds a suffix to the file stem, keeping the extension (e.g. `report.csv` is renamed to
+    /// `report_old.csv` with the suffix `_old`).
+    Suffix(String),
+
+    /// Replaces all occurrences of a string in the file name.
+    Replace {
+        /// The string to find.
+        find: String,
+
+        /// The replacement string.
+        replace: String,
+    },
+
+    /// Replaces all matches of a regular expression in the file name. The replacement can refer
+    /// to capture groups (e.g. `$1` or `${name}`).
+    ///
+    /// Requires the `regex` feature.
+    #[cfg(feature = "regex")]
+    Regex {
+        /// The regular expression to find.
+        pattern: String,
+
+        /// The replacement (which can refer to capture groups).
+        replacement: String,
+    },
+
+    /// Numbers the files sequentially (in alphabetical order of their paths), keeping their
+    /// extensions. The file stem is `template` with `{n}` replaced by the number (e.g.
+    /// `IMG_4821.jpg` is renamed to `photo_001.jpg` with the template `photo_{n}` and a width
+    /// of 3).
+    Numbered {
+        /// The template for the file stem.
+        template: String,
+
+        /// The number of the first file.
+        start: usize,
+
+        /// The minimum number of digits (numbers are padded with leading zeros).
+        width: usize,
+    },
+}
+
+/// Options controlling how files are renamed by [`rename_files_with_options`].
+///
+/// The default options match the behavior of [`rename_files`].
+///
+/// # Example
+///
+/// ```
+/// use file_io::RenameOptions;
+///
+/// // Only preview the renames.
+/// let options = RenameOptions { dry_run: true };
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct RenameOptions {
+    /// If `true`, no files are renamed. Instead, the returned list contains the renames that
+    /// would be performed.
+    pub dry_run: bool,
+}
+
+/// A file renamed by [`rename_files`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct FileRename {
+    /// The original path of the file.
+    pub from: PathBuf,
+
+    /// The new path of the file.
+    pub to: PathBuf,
+}
+
+/// Determines the new name of each file according to a rename rule.
+///
+/// # Arguments
+///
+/// * `files` - The paths of the files to rename (in alphabetical order).
+/// * `rule` - The rename rule.
+///
+/// # Returns
+///
+/// The new path of each file.
+///
+/// # Panics
+///
+/// If the rule contains an invalid regular expression.
+fn apply_rule(files: &[PathBuf], rule: &RenameRule) -> Vec<PathBuf> {
+    let rename_file_name = |file: &PathBuf, rename: &dyn Fn(&str) -> String| {
+        let file_name = file.file_name().unwrap_or_default().to_string_lossy();
+        file.with_file_name(rename(&file_name))
+    };
+    match rule {
+        RenameRule::Prefix(prefix) => files
+            .iter()
+            .map(|file| add_prefix_to_stem(file, prefix))
+            .collect(),
+        RenameRule::Suffix(suffix) => files
+            .iter()
+            .map(|file| add_suffix_to_stem(file, suffix))
+            .collect(),
+        RenameRule::Replace { find, replace } => files
+            .iter()
+            .map(|file| rename_file_name(file, &|name| name.replace(find, replace)))
+            .collect(),
+        #[cfg(feature = "regex")]
+        RenameRule::Regex {
+            pattern,
+            replacement,
+        } => {
+            let regex = regex::Regex::new(pattern)
+                .unwrap_or_else(|_| panic!("Invalid regular expression '{pattern}'."));
+            files
+                .iter()
+                .map(|file| {
+                    rename_file_name(file, &|name| {
+                        regex.replace_all(name, replacement.as_str()).into_owned()
+                    })
+                })
+                .collect()
+        }
+        RenameRule::Numbered {
+            template,
+            start,
+            width,
+        } => files
+            .iter()
+            .enumerate()
+            .map(|(index, file)| {
+                let number = format!("{:0width$}", start + index);
+                let stem = template.replace("{n}", &number);
+                match file.extension() {
+                    Some(extension) => {
+                        file.with_file_name(format!("{stem}.{}", extension.to_string_lossy()))
+                    }
+                    None => file.with_file_name(stem),
+                }
+            })
+            .collect(),
+    }
+}
+
+/// Renames a file (or records the rename during a dry run).
+///
+/// # Arguments
+///
+/// * `from` - The current path of the file.
+/// * `to` - The new path of the file.
+///
+/// # Panics
+///
+/// If the file cannot be renamed.
+fn rename_file(from: &Path, to: &Path) {
+    let mut mutation = Mutation::start("rename_file", from).to(to);
+    if mutation.is_dry_run() {
+        return;
+    }
+    std::fs::rename(from, to)
+        .unwrap_or_else(|_| panic!("Failed to rename file '{from:?}' to '{to:?}'."));
+    mutation.succeeded();
+}
+
+/// Renames all files within a folder that match a glob pattern, according to a rename rule.
+///
+/// # Arguments
+///
+/// * `path` - The path to the folder (can be a `&str`, [`String`], [`Path`], or
+///   [`std::path::PathBuf`]).
+/// * `glob` - The glob pattern selecting the files to rename, matched against their paths
+///   relative to the folder (e.g. `*.jpg` selects the JPEG files directly within the folder,
+///   while `**/*.jpg` also selects those in its subfolders).
+/// * `rule` - The rule determining the new name of each file.
+///
+/// # Returns
+///
+/// The files that were renamed, in alphabetical order of their original paths. Files whose name
+/// would not change are not included.
+///
+/// # Panics
+///
+/// * If the glob pattern (or a regular expression in `rule`) is invalid.
+/// * If two files would be renamed to the same path, or a file would be renamed to the path of an
+///   existing file that is not itself being renamed. In this case, no files are renamed.
+/// * If some error is encountered while renaming a file.
+///
+/// # Note
+///
+/// * Files are only ever renamed within their own folder.
+/// * Renames may overlap (e.g. swapping the names of two files, or shifting a numbered sequence
+///   by one).
+///
+/// # Example
+///
+/// ```
+/// use file_io::{RenameRule, rename_files, save_string_to_file};
+/// use std::path::Path;
+///
+/// save_string_to_file("", "folder/subfolder_42/IMG_4821.jpg");
+/// save_string_to_file("", "folder/subfolder_42/IMG_4822.jpg");
+///
+/// let rule = RenameRule::Numbered {
+///     template: String::from("photo_{n}"),
+///     start: 1,
+///     width: 3,
+/// };
+/// rename_files("folder/subfolder_42", "*.jpg", &rule);
+///
+/// assert!(Path::new("folder/subfolder_42/photo_001.jpg").exists());
+/// assert!(Path::new("folder/subfolder_42/photo_002.jpg").exists());
+/// ```
+pub fn rename_files<P: AsRef<Path>>(path: P, glob: &str, rule: &RenameRule) -> Vec<FileRename> {
+    rename_files_with_options(path, glob, rule, &RenameOptions::default())
+}
+
+/// Renames all files within a folder that match a glob pattern, according to a rename rule, with
+/// options controlling how the files are renamed.
+///
+/// # Arguments
+///
+/// * `path` - The path to the folder (can be a `&str`, [`String`], [`Path`], or
+///   [`std::path::PathBuf`]).
+/// * `glob` - The glob pattern selecting the files to rename (see [`rename_files`]).
+/// * `rule` - The rule determining the new name of each file.
+/// * `options` - Options controlling how the files are renamed.
+///
+/// # Returns
+///
+/// The files that were (or, for a dry run, would be) renamed, in alphabetical order of their
+/// original paths.
+///
+/// # Panics
+///
+/// See [`rename_files`]. Collisions are also detected during a dry run.
+///
+/// # Example
+///
+/// ```
+/// use file_io::{RenameOptions, RenameRule, rename_files_with_options, save_string_to_file};
+/// use std::path::PathBuf;
+///
+/// save_string_to_file("", "folder/subfolder_43/report.csv");
+///
+/// // Preview the renames.
+/// let options = RenameOptions { dry_run: true };
+/// let rule = RenameRule::Prefix(String::from("2026_"));
+/// let renames = rename_files_with_options("folder/subfolder_43", "*.csv", &rule, &options);
+///
+/// assert_eq!(renames[0].to, PathBuf::from("folder/subfolder_43/2026_report.csv"));
+/// assert!(!renames[0].to.exists());
+/// ```
+pub fn rename_files_with_options<P: AsRef<Path>>(
+    path: P,
+    glob: &str,
+    rule: &RenameRule,
+    options: &RenameOptions,
+) -> Vec<FileRename> {
+    let path = path.as_ref();
+
+    // Select the files to rename.
+    let pattern = compile_globs(&[glob.to_string()]).remove(0);
+    let match_options = MatchOptions {
+        require_literal_separator: true,
+        ..MatchOptions::new()
+    };
+    let mut files: Vec<PathBuf> = WalkDir::new(path)
+        .min_depth(1)
+        .into_iter()
+        .filter_map(Result::ok)
+        .filter(|entry| entry.file_type().is_file())
+        .map(|entry| entry.into_path())
+        .filter(|file| {
+            let relative_path = file.strip_prefix(path).unwrap();
+            pattern.matches_path_with(relative_path, match_options)
+        })
+        .collect();
+    files.sort();
+
+    // Determine the new name of each file, skipping those whose name does not change.
+    let renames: Vec<FileRename> = apply_rule(&files, rule)
+        .into_iter()
+        .zip(files)
+        .filter(|(to, from)| to != from)
+        .map(|(to, from)| FileRename { from, to })
+        .collect();
+
+    // Check for collisions before renaming anything.
+    let sources: HashSet<&Path> = renames.iter().map(|rename| rename.from.as_path()).collect();
+    let mut targets = BTreeSet::new();
+    for FileRename { from, to } in &renames {
+        if !targets.insert(to.as_path()) {
+            panic!("Cannot rename '{from:?}' to '{to:?}': another file is renamed to it too.");
+        }
+        if (to.exists() || to.is_symlink()) && !sources.contains(to.as_path()) {
+            panic!("Cannot rename '{from:?}' to '{to:?}': a file already exists there.");
+        }
+    }
+    if options.dry_run {
+        return renames;
+    }
+
+    // If a file is renamed to the original path of another one, first move all files out of the
+    // way (to temporary names) so that no file is overwritten.
+    if renames
+        .iter()
+        .any(|rename| sources.contains(rename.to.as_path()))
+    {
+        let temporary_paths: Vec<PathBuf> = renames
+            .iter()
+            .map(|rename| {
+                let temporary_path = unique_path(add_suffix_to_stem(&rename.from, ".renaming"));
+                rename_file(&rename.from, &temporary_path);
+                temporary_path
+            })
+            .collect();
+        for (temporary_path, rename) in temporary_paths.iter().zip(&renames) {
+            rename_file(temporary_path, &rename.to);
+        }
+    } else {
+        for rename in &renames {
+            rename_file(&rename.from, &rename.to);
+        }
+    }
+    renames
+}
+
+/// Report summarizing the result of [`change_extension_in_folder`] or [`rename_to_case`].
+#[derive(Debug, Default, PartialEq)]
+pub struct RenameReport {
+    /// Files (or folders) that were renamed, in the order they were renamed.
+    pub renamed: Vec<FileRename>,
+
+    /// Files (or folders) that were not renamed because something else already exists at their
+    /// new path, in the order they were encountered.
+    pub conflicts: Vec<FileRename>,
+}
+
+/// Changes the extension of all files within a folder that have a given extension.
+///
+/// # Arguments
+///
+/// * `path` - The path to the folder (can be a `&str`, [`String`], [`Path`], or
+///   [`std::path::PathBuf`]).
+/// * `from` - The extension of the files to rename (with or without a leading `.`). Extensions are
+///   compared ignoring ASCII case (e.g. `jpeg` also matches `photo.JPEG`).
+/// * `to` - The new extension (with or without a leading `.`).
+/// * `recursive` - Whether to also rename the files within subfolders.
+///
+/// # Returns
+///
+/// An [`RenameReport`] listing the files that were renamed and the files that were not
+/// renamed because of a conflict.
+///
+/// # Panics
+///
+/// If some error is encountered while reading the folder or renaming a file.
+///
+/// # Note
+///
+/// A file is not renamed (and is instead reported as a conflict) if a file already exists at its
+/// new path, or if another file was already renamed to that path (e.g. `photo.jpeg` and
+/// `photo.JPEG` both being renamed to `photo.jpg`).
+///
+/// # Example
+///
+/// ```
+/// use file_io::{change_extension_in_folder, delete_folder, save_string_to_file};
+/// use std::path::Path;
+///
+/// // Start from an empty folder.
+/// delete_folder("folder/subfolder_44");
+///
+/// save_string_to_file("", "folder/subfolder_44/photo.jpeg");
+/// save_string_to_file("", "folder/subfolder_44/album/cover.JPEG");
+///
+/// let report = change_extension_in_folder("folder/subfolder_44", "jpeg", "jpg", true);
+///
+/// assert_eq!(report.renamed.len(), 2);
+/// assert!(Path::new("folder/subfolder_44/photo.jpg").exists());
+/// assert!(Path::new("folder/subfolder_44/album/cover.jpg").exists());
+/// ```
+pub fn change_extension_in_folder<P: AsRef<Path>>(
+    path: P,
+    from: &str,
+    to: &str,
+    recursive: bool,
+) -> RenameReport {
+    let path = path.as_ref();
+    let to = to.strip_prefix('.').unwrap_or(to);
+
+    // Select the files to rename.
+    let mut walk_dir = WalkDir::new(path).min_depth(1);
+    if !recursive {
+        walk_dir = walk_dir.max_depth(1);
+    }
+    let mut files: Vec<PathBuf> = walk_dir
+        .into_iter()
+        .map(|entry| entry.unwrap_or_else(|_| panic!("Failed to traverse folder '{path:?}'.")))
+        .filter(|entry| entry.file_type().is_file())
+        .map(|entry| entry.into_path())
+        .filter(|file| extension_matches(file, &[from], true))
+        .collect();
+    files.sort();
+
+    // Rename the files that do not conflict with an existing file.
+    let mut report = RenameReport::default();
+    for file in files {
+        let rename = FileRename {
+            to: file.with_extension(to),
+            from: file,
+        };
+        if rename.to == rename.from {
+            continue;
+        }
+
+        // A file that only differs by case may refer to the file itself (on case-insensitive
+        // filesystems), in which case it is not a conflict.
+        let exists = rename.to.exists() || rename.to.is_symlink();
+        if exists && !is_same_file(&rename.from, &rename.to).unwrap_or(false) {
+            report.conflicts.push(rename);
+            continue;
+        }
+        rename_file(&rename.from, &rename.to);
+        report.renamed.push(rename);
+    }
+    report
+}
+
+/// Letter case that [`rename_to_case`] converts names to.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum CaseStyle {
+    /// All letters are lowercase (e.g. `My Photo.JPG` is renamed to `my photo.jpg`).
+    #[default]
+    Lower,
+
+    /// All letters are uppercase (e.g. `My Photo.jpg` is renamed to `MY PHOTO.JPG`).
+    Upper,
+
+    /// The first letter of each word in the file stem is uppercase and the other letters are
+    /// lowercase, with the extension left unchanged (e.g. `my_PHOTO-one.jpg` is renamed to
+    /// `My_Photo-One.jpg`). Words are separated by any character that is not a letter or digit.
+    Title,
+}
+
+/// Converts a file name to a letter case.
+///
+/// # Arguments
+///
+/// * `file_name` - The file name.
+/// * `style` - The letter case to convert to.
+///
+/// # Returns
+///
+/// The converted file name.
+fn convert_case(file_name: &str, style: CaseStyle) -> String {
+    match style {
+        CaseStyle::Lower => file_name.to_lowercase(),
+        CaseStyle::Upper => file_name.to_uppercase(),
+        CaseStyle::Title => {
+            let (stem, extension) = match file_name.rfind('.') {
+                Some(index) if index > 0 => file_name.split_at(index),
+                _ => (file_name, ""),
+            };
+            let mut title = String::with_capacity(file_name.len());
+            let mut word_start = true;
+            for c in stem.chars() {
+                if word_start {
+                    title.extend(c.to_uppercase());
+                } else {
+                    title.extend(c.to_lowercase());
+                }
+                word_start = !c.is_alphanumeric();
+            }
+            title + extension
+        }
+    }
+}
+
+/// Renames all files and folders within a folder so that their names are in a given letter case.
+///
+/// # Arguments
+///
+/// * `path` - The path to the folder (can be a `&str`, [`String`], [`Path`], or
+///   [`std::path::PathBuf`]). The folder itself is not renamed.
+/// * `style` - The letter case to convert the names to.
+/// * `recursive` - Whether to also rename the contents of subfolders.
+///
+/// # Returns
+///
+/// A [`RenameReport`] listing the files and folders that were renamed and those that were not
+/// renamed because of a conflict.
+///
+/// # Panics
+///
+/// If some error is encountered while reading the folder or renaming a file or folder.
+///
+/// # Note
+///
+/// * Each entry is renamed through a temporary name, so that renames that only change the case of
+///   a name also work on case-insensitive filesystems (e.g. on Windows and macOS).
+/// * An entry is not renamed (and is instead reported as a conflict) if a different file or
+///   folder already exists at its new path (e.g. `README.md` and `readme.md` on a case-sensitive
+///   filesystem).
+/// * The contents of a folder are renamed before the folder itself.
+///
+/// # Example
+///
+/// ```
+/// use file_io::{
+///     CaseStyle, delete_folder, get_file_name, list_folder_contents, rename_to_case,
+///     save_string_to_file,
+/// };
+///
+/// // Start from an empty folder.
+/// delete_folder("folder/subfolder_45");
+///
+/// save_string_to_file("", "folder/subfolder_45/IMG_0001.JPG");
+///
+/// rename_to_case("folder/subfolder_45", CaseStyle::Lower, false);
+///
+/// let names: Vec<String> = list_folder_contents("folder/subfolder_45")
+///     .iter()
+///     .map(get_file_name)
+///     .collect();
+/// assert_eq!(names, ["img_0001.jpg"]);
+/// ```
+pub fn rename_to_case<P: AsRef<Path>>(path: P, style: CaseStyle, recursive: bool) -> RenameReport {
+    let path = path.as_ref();
+
+    // Collect the entries to rename, with the contents of each folder before the folder itself
+    // (so that renaming a folder does not invalidate the paths of its contents).
+    let mut walk_dir = WalkDir::new(path).min_depth(1).contents_first(true);
+    if !recursive {
+        walk_dir = walk_dir.max_depth(1);
+    }
+    let entries: Vec<PathBuf> = walk_dir
+        .into_iter()
+        .map(|entry| entry.unwrap_or_else(|_| panic!("Failed to traverse folder '{path:?}'.")))
+        .map(|entry| entry.into_path())
+        .collect();
+
+    let mut report = RenameReport::default();
+    for entry in entries {
+        let file_name = entry.file_name().unwrap_or_default().to_string_lossy();
+        let new_file_name = convert_case(&file_name, style);
+        if new_file_name == file_name {
+            continue;
+        }
+        let rename = FileRename {
+            to: entry.with_file_name(new_file_name),
+            from: entry,
+        };
+
+        // On case-insensitive filesystems, the new path refers to the entry itself.
+        let exists = rename.to.exists() || rename.to.is_symlink();
+        if exists && !is_same_file(&rename.from, &rename.to).unwrap_or(false) {
+            report.conflicts.push(rename);
+            continue;
+        }
+
+        // Rename through a temporary name (renaming directly may do nothing on case-insensitive
+        // filesystems).
+        let temporary_path = unique_path(add_suffix_to_stem(&rename.from, ".renaming"));
+        rename_file(&rename.from, &temporary_path);
+        rename_file(&temporary_path, &rename.to);
+        report.renamed.push(rename);
+    }
+    report
+}
+
+/// Renames all files and folders within a folder so that their names are lowercase.
+///
+/// # Arguments
+///
+/// * `path` - The path to the folder (can be a `&str`, [`String`], [`Path`], or
+///   [`std::path::PathBuf`]). The folder itself is not renamed.
+/// * `recursive` - Whether to also rename the contents of subfolders.
+///
+/// # Returns
+///
+/// A [`RenameReport`] listing the files and folders that were renamed and those that were not
+/// renamed because of a conflict.
+///
+/// # Panics
+///
+/// If some error is encountered while reading the folder or renaming a file or folder.
+///
+/// # Note
+///
+/// This is equivalent to [`rename_to_case`] with [`CaseStyle::Lower`].
+///
+/// # Example
+///
+/// ```
+/// use file_io::{delete_folder, rename_to_lowercase, save_string_to_file};
+///
+/// // Start from an empty folder.
+/// delete_folder("folder/subfolder_46");
+///
+/// save_string_to_file("", "folder/subfolder_46/Assets/Logo.PNG");
+///
+/// let report = rename_to_lowercase("folder/subfolder_46", true);
+/// assert_eq!(report.renamed.len(), 2);
+/// ```
+pub fn rename_to_lowercase<P: AsRef<Path>>(path: P, recursive: bool) -> RenameReport {
+    rename_to_case(path, CaseStyle::Lower, recursive)
+}
+
+/// Converts a string to a slug made up of lowercase ASCII letters, digits, `-`, and `_`.
+///
+/// # Arguments
+///
+/// * `text` - The string to convert.
+///
+/// # Returns
+///
+/// The slug. Diacritics are removed (e.g. `é` becomes `e`), a few letters are transliterated (e.g.
+/// `ß` becomes `ss`), and each run of other characters is replaced by a single `-` (with any
+/// leading or trailing `-` removed).
+fn slugify(text: &str) -> String {
+    let mut slug = String::with_capacity(text.len());
+    for c in text.nfd().filter(|c| !is_combining_mark(*c)) {
+        let transliterated = match c {
+            'ß' => "ss",
+            'æ' | 'Æ' => "ae",
+            'œ' | 'Œ' => "oe",
+            'ø' | 'Ø' => "o",
+            'đ' | 'Đ' | 'ð' | 'Ð' => "d",
+            'ł' | 'Ł' => "l",
+            'þ' | 'Þ' => "th",
+            c if c.is_ascii_alphanumeric() || c == '_' => {
+                slug.push(c.to_ascii_lowercase());
+                continue;
+            }
+            _ => "-",
+        };
+        if transliterated != "-" || !slug.ends_with('-') {
+            slug.push_str(transliterated);
+        }
+    }
+    slug.trim_matches('-').to_string()
+}
+
+/// Converts a file name to a URL- and shell-safe file name.
+///
+/// # Arguments
+///
+/// * `file_name` - The file name.
+///
+/// # Returns
+///
+/// The safe file name, with the file stem and extension slugified separately (see [`slugify`]). A
+/// leading `.` (marking a hidden file) is kept, and an empty file stem is replaced by `file`.
+fn slugify_file_name(file_name: &str) -> String {
+    let (dot, name) = match file_name.strip_prefix('.') {
+        Some(name) => (".", name),
+        None => ("", file_name),
+    };
+    let (stem, extension) = match name.rfind('.') {
+        Some(index) => (&name[..index], slugify(&name[index + 1..])),
+        None => (name, String::new()),
+    };
+    let mut stem = slugify(stem);
+    if stem.is_empty() {
+        stem = String::from("file");
+    }
+    if extension.is_empty() {
+        format!("{dot}{stem}")
+    } else {
+        format!("{dot}{stem}.{extension}")
+    }
+}
+
+/// Slugifies the names of the entries within a folder (recursively).
+///
+/// # Arguments
+///
+/// * `original` - The original path of the folder.
+/// * `renamed` - The path of the folder after renaming.
+/// * `actual` - The path of the folder on the filesystem (which differs from `renamed` during a dry
+///   run, since folders are not actually renamed).
+/// * `renames` - The renames performed so far (appended to).
+///
+/// # Panics
+///
+/// If some error is encountered while reading the folder or renaming an entry.
+fn slugify_folder(original: &Path, renamed: &Path, actual: &Path, renames: &mut Vec<FileRename>) {
+    let mut entries: Vec<PathBuf> = std::fs::read_dir(actual)
+        .unwrap_or_else(|_| panic!("Failed to read folder '{actual:?}'."))
+        .map(|entry| {
+            entry
+                .unwrap_or_else(|_| panic!("Failed to read folder '{actual:?}'."))
+                .path()
+        })
+        .collect();
+    entries.sort();
+
+    for entry in entries {
+        let file_name = entry.file_name().unwrap_or_default().to_string_lossy();
+        let original_path = original.join(&*file_name);
+        let mut new_path = renamed.join(&*file_name);
+        let mut actual_path = entry.clone();
+
+        let slug = slugify_file_name(&file_name);
+        if slug != file_name {
+            // Number the new name if something else already has it (e.g. because two names
+            // differ only by special characters).
+            new_path = renamed.join(&slug);
+            let mut number = 1;
+            while (new_path.exists() || new_path.is_symlink())
+                && !is_same_file(&entry, &new_path).unwrap_or(false)
+            {
+                new_path = renamed.join(add_suffix_to_stem(&slug, &format!("-{number}")));
+                number += 1;
+            }
+
+            // Rename through a temporary name (renaming directly may do nothing on
+            // case-insensitive filesystems).
+            let temporary_path = unique_path(add_suffix_to_stem(&entry, ".renaming"));
+            rename_file(&entry, &temporary_path);
+            rename_file(&temporary_path, &new_path);
+            if new_path.exists() {
+                actual_path = new_path.clone();
+            }
+            renames.push(FileRename {
+                from: original_path.clone(),
+                to: new_path.clone(),
+            });
+        }
+
+        if actual_path.is_dir() && !actual_path.is_symlink() {
+            slugify_folder(&original_path, &new_path, &actual_path, renames);
+        }
+    }
+}
+
+/// Renames all files and folders within a folder (recursively) so that their names are URL- and
+/// shell-safe.
+///
+/// # Arguments
+///
+/// * `path` - The path to the folder (can be a `&str`, [`String`], [`Path`], or
+///   [`std::path::PathBuf`]). The folder itself is not renamed.
+///
+/// # Returns
+///
+/// The files and folders that were renamed, mapping their original paths to their new paths (in
+/// the order they were renamed, with each folder before its contents).
+///
+/// # Panics
+///
+/// If some error is encountered while reading the folder or renaming a file or folder.
+///
+/// # Note
+///
+/// * New names are made up of lowercase ASCII letters, digits, `-`, and `_` (along with the `.`
+///   before the extension, and a leading `.` for hidden files). Diacritics are removed (e.g.
+///   `Café.txt` becomes `cafe.txt`), and each run of spaces and other special characters is
+///   replaced by a single `-` (e.g. `My Résumé (final).PDF` becomes `my-resume-final.pdf`).
+/// * If the new name of an entry is already taken, a number is appended to it (e.g.
+///   `my-file-1.txt`).
+/// * Files within a renamed folder that keep their own names are not listed, even though their
+///   paths change along with the folder.
+///
+/// # Example
+///
+/// ```
+/// use file_io::{delete_folder, save_string_to_file, slugify_filenames};
+/// use std::path::PathBuf;
+///
+/// // Start from an empty folder.
+/// delete_folder("folder/subfolder_47");
+///
+/// save_string_to_file("", "folder/subfolder_47/My Résumé (final).PDF");
+///
+/// let renames = slugify_filenames("folder/subfolder_47");
+/// assert_eq!(renames[0].to, PathBuf::from("folder/subfolder_47/my-resume-final.pdf"));
+/// ```
+pub fn slugify_filenames<P: AsRef<Path>>(path: P) -> Vec<FileRename> {
+    let path = path.as_ref();
+    let mut renames = Vec::new();
+    slugify_folder(path, path, path, &mut renames);
+    renames
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::load::load_file_as_string;
+    use crate::save::save_string_to_file;
+    use crate::test_utils::get_temp_dir_path;
+    use tempfile::tempdir;
+
+    #[test]
+    fn test_rename_files_rules() {
+        // Create a temporary directory to work in.
+        let temp_dir = tempdir().unwrap();
+        let temp_dir_path = get_temp_dir_path(&temp_dir);
+        save_string_to_file("a", temp_dir_path.join("a.txt"));
+        save_string_to_file("b", temp_dir_path.join("b.txt"));
+        save_string_to_file("c", temp_dir_path.join("c.md"));
+        save_string_to_file("d", temp_dir_path.join("sub/d.txt"));
+
+        // Add a prefix to the selected files only.
+        let renames = rename_files(&temp_dir_path, "*.txt", &RenameRule::Prefix("x_".into()));
+        assert_eq!(
+            renames,
+            [
+                FileRename {
+                    from: temp_dir_path.join("a.txt"),
+                    to: temp_dir_path.join("x_a.txt"),
+                },
+                FileRename {
+                    from: temp_dir_path.join("b.txt"),
+                    to: temp_dir_path.join("x_b.txt"),
+                },
+            ]
+        );
+        assert_eq!(load_file_as_string(temp_dir_path.join("x_a.txt")), "a");
+        assert!(temp_dir_path.join("sub/d.txt").exists());
+
+        // Add a suffix to the files in the subfolders too.
+        rename_files(&temp_dir_path, "**/*.txt", &RenameRule::Suffix("_1".into()));
+        assert!(temp_dir_path.join("x_a_1.txt").exists());
+        assert!(temp_dir_path.join("sub/d_1.txt").exists());
+
+        // Find and replace within the file names.
+        let rule = RenameRule::Replace {
+            find: "_1".into(),
+            replace: String::new(),
+        };
+        rename_files(&temp_dir_path, "**/*", &rule);
+        assert!(temp_dir_path.join("x_b.txt").exists());
+        assert!(temp_dir_path.join("sub/d.txt").exists());
+        assert!(temp_dir_path.join("c.md").exists());
+    }
+
+    #[cfg(feature = "regex")]
+    #[test]
+    fn test_rename_files_regex() {
+        // Create a temporary directory to work in.
+        let temp_dir = tempdir().unwrap();
+        let temp_dir_path = get_temp_dir_path(&temp_dir);
+        save_string_to_file("", temp_dir_path.join("2026-10-16 notes.txt"));
+
+        // Reorder the parts of the file name using capture groups.
+        let rule = RenameRule::Regex {
+            pattern: r"^(\d{4})-(\d{2})-(\d{2}) (.*)$".into(),
+            replacement: "${4}_$3.$2.$1".into(),
+        };
+        rename_files(&temp_dir_path, "*", &rule);
+        assert!(temp_dir_path.join("notes.txt_16.10.2026").exists());
+    }
+
+    #[test]
+    fn test_rename_files_overlapping() {
+        // Create a temporary directory to work in.
+        let temp_dir = tempdir().unwrap();
+        let temp_dir_path = get_temp_dir_path(&temp_dir);
+        for n in 1..=3 {
+            save_string_to_file(&n.to_string(), temp_dir_path.join(format!("{n}.txt")));
+        }
+
+        // Shift the numbering by one (each file takes the name of the next one).
+        let rule = RenameRule::Numbered {
+            template: String::from("{n}"),
+            start: 2,
+            width: 1,
+        };
+        rename_files(&temp_dir_path, "*.txt", &rule);
+        for n in 1..=3 {
+            assert_eq!(
+                load_file_as_string(temp_dir_path.join(format!("{}.txt", n + 1))),
+                n.to_string()
+            );
+        }
+        assert!(!temp_dir_path.join("1.txt").exists());
+    }
+
+    #[test]
+    fn test_rename_files_collision() {
+        // Create a temporary directory to work in.
+        let temp_dir = tempdir().unwrap();
+        let temp_dir_path = get_temp_dir_path(&temp_dir);
+        save_string_to_file("a", temp_dir_path.join("a1.txt"));
+        save_string_to_file("b", temp_dir_path.join("a2.txt"));
+        save_string_to_file("c", temp_dir_path.join("b1.txt"));
+
+        // Renaming `a1.txt` to the existing `b1.txt` fails without renaming anything.
+        let rule = RenameRule::Replace {
+            find: "a".into(),
+            replace: "b".into(),
+        };
+        let result = std::panic::catch_unwind(|| rename_files(&temp_dir_path, "a*", &rule));
+        assert!(result.is_err());
+        assert!(temp_dir_path.join("a2.txt").exists());
+
+        // Renaming both `a*` files to the same name fails too.
+        let rule = RenameRule::Numbered {
+            template: String::from("same"),
+            start: 1,
+            width: 1,
+        };
+        let result = std::panic::catch_unwind(|| rename_files(&temp_dir_path, "a*", &rule));
+        assert!(result.is_err());
+        assert_eq!(load_file_as_string(temp_dir_path.join("a1.txt")), "a");
+    }
+
+    #[test]
+    fn test_change_extension_in_folder() {
+        // Create a temporary directory to work in.
+        let temp_dir = tempdir().unwrap();
+        let temp_dir_path = get_temp_dir_path(&temp_dir);
+        save_string_to_file("a", temp_dir_path.join("a.jpeg"));
+        save_string_to_file("b", temp_dir_path.join("b.jpeg"));
+        save_string_to_file("b existing", temp_dir_path.join("b.jpg"));
+        save_string_to_file("c", temp_dir_path.join("sub/c.jpeg"));
+        save_string_to_file("d", temp_dir_path.join("d.png"));
+
+        // Change the extension of the files directly within the folder.
+        let report = change_extension_in_folder(&temp_dir_path, ".jpeg", "jpg", false);
+        assert_eq!(
+            report,
+            RenameReport {
+                renamed: vec![FileRename {
+                    from: temp_dir_path.join("a.jpeg"),
+                    to: temp_dir_path.join("a.jpg"),
+                }],
+                conflicts: vec![FileRename {
+                    from: temp_dir_path.join("b.jpeg"),
+                    to: temp_dir_path.join("b.jpg"),
+                }],
+            }
+        );
+        assert_eq!(
+            load_file_as_string(temp_dir_path.join("b.jpg")),
+            "b existing"
+        );
+        assert!(temp_dir_path.join("sub/c.jpeg").exists());
+
+        // Change the extension of the files within subfolders too.
+        let report = change_extension_in_folder(&temp_dir_path, "jpeg", "jpg", true);
+        assert_eq!(report.renamed.len(), 1);
+        assert!(temp_dir_path.join("sub/c.jpg").exists());
+        assert!(temp_dir_path.join("d.png").exists());
+    }
+
+    #[test]
+    fn test_convert_case() {
+        assert_eq!(
+            convert_case("My Photo.JPG", CaseStyle::Lower),
+            "my photo.jpg"
+        );
+        assert_eq!(
+            convert_case("My Photo.jpg", CaseStyle::Upper),
+            "MY PHOTO.JPG"
+        );
+        assert_eq!(
+            convert_case("my_PHOTO-one.jpg", CaseStyle::Title),
+            "My_Photo-One.jpg"
+        );
+        assert_eq!(convert_case(".bashrc", CaseStyle::Title), ".Bashrc");
+    }
+
+    #[test]
+    fn test_rename_to_case() {
+        // Create a temporary directory to work in.
+        let temp_dir = tempdir().unwrap();
+        let temp_dir_path = get_temp_dir_path(&temp_dir);
+        save_string_to_file("a", temp_dir_path.join("Docs/README.md"));
+        save_string_to_file("b", temp_dir_path.join("Docs/Notes.TXT"));
+        save_string_to_file("c", temp_dir_path.join("Top.txt"));
+
+        // Only the entries directly within the folder are renamed.
+        let report = rename_to_lowercase(&temp_dir_path, false);
+        assert_eq!(report.renamed.len(), 2);
+        assert_eq!(load_file_as_string(temp_dir_path.join("top.txt")), "c");
+        assert_eq!(
+            load_file_as_string(temp_dir_path.join("docs/README.md")),
+            "a"
+        );
+
+        // The contents of subfolders are renamed too.
+        rename_to_case(&temp_dir_path, CaseStyle::Upper, true);
+        assert_eq!(
+            load_file_as_string(temp_dir_path.join("DOCS/README.MD")),
+            "a"
+        );
+        assert_eq!(
+            load_file_as_string(temp_dir_path.join("DOCS/NOTES.TXT")),
+            "b"
+        );
+        assert_eq!(load_file_as_string(temp_dir_path.join("TOP.TXT")), "c");
+    }
+
+    #[test]
+    fn test_rename_to_case_conflict() {
+        // Create a temporary directory to work in.
+        let temp_dir = tempdir().unwrap();
+        let temp_dir_path = get_temp_dir_path(&temp_dir);
+        save_string_to_file("upper", temp_dir_path.join("README"));
+
+        // Only check for conflicts on case-sensitive filesystems.
+        if temp_dir_path.join("readme").exists() {
+            return;
+        }
+        save_string_to_file("lower", temp_dir_path.join("readme"));
+
+        // The existing file is not overwritten.
+        let report = rename_to_lowercase(&temp_dir_path, false);
+        assert!(report.renamed.is_empty());
+        assert_eq!(
+            report.conflicts,
+            [FileRename {
+                from: temp_dir_path.join("README"),
+                to: temp_dir_path.join("readme"),
+            }]
+        );
+        assert_eq!(load_file_as_string(temp_dir_path.join("README")), "upper");
+        assert_eq!(load_file_as_string(temp_dir_path.join("readme")), "lower");
+    }
+
+    #[test]
+    fn test_slugify_file_name() {
+        assert_eq!(
+            slugify_file_name("My Résumé (final).PDF"),
+            "my-resume-final.pdf"
+        );
+        assert_eq!(slugify_file_name("Straße & Ærø.txt"), "strasse-aero.txt");
+        assert_eq!(slugify_file_name("snake_case-name"), "snake_case-name");
+        assert_eq!(slugify_file_name(".Hidden File"), ".hidden-file");
+        assert_eq!(slugify_file_name("???.txt"), "file.txt");
+        assert_eq!(slugify_file_name("archive.tar.GZ"), "archive-tar.gz");
+    }
+
+    #[test]
+    fn test_slugify_filenames() {
+        // Create a temporary directory to work in.
+        let temp_dir = tempdir().unwrap();
+        let temp_dir_path = get_temp_dir_path(&temp_dir);
+        save_string_to_file("a", temp_dir_path.join("Café Photos/Été 2026.JPG"));
+        save_string_to_file("b", temp_dir_path.join("Café Photos/été-2026.jpg"));
+        save_string_to_file("c", temp_dir_path.join("ok.txt"));
+
+        // Rename the folder and the files within it.
+        let renames = slugify_filenames(&temp_dir_path);
+        assert_eq!(
+            renames,
+            [
+                FileRename {
+                    from: temp_dir_path.join("Café Photos"),
+                    to: temp_dir_path.join("cafe-photos"),
+                },
+                FileRename {
+                    from: temp_dir_path.join("Café Photos/Été 2026.JPG"),
+                    to: temp_dir_path.join("cafe-photos/ete-2026.jpg"),
+                },
+                FileRename {
+                    from: temp_dir_path.join("Café Photos/été-2026.jpg"),
+                    to: temp_dir_path.join("cafe-photos/ete-2026-1.jpg"),
+                },
+            ]
+        );
+        assert_eq!(
+            load_file_as_string(temp_dir_path.join("cafe-photos/ete-2026.jpg")),
+            "a"
+        );
+        assert_eq!(
+            load_file_as_string(temp_dir_path.join("cafe-photos/ete-2026-1.jpg")),
+            "b"
+        );
+        assert_eq!(load_file_as_string(temp_dir_path.join("ok.txt")), "c");
+    }
+}
@@ -0,0 +1,432 @@
+use crate::copy::{copy_file, copy_folder};
+use crate::create::{create_folder, touch_file};
+use crate::delete::{delete_file, delete_folder};
+use crate::filesystem::FileSystem;
+use crate::list::list_folder_contents;
+use crate::load::load_file_as_string;
+use crate::path::{is_inside, safe_join, safe_join_clamped};
+use crate::save::save_string_to_file;
+use std::path::{Path, PathBuf};
+
+/// How a [`Sandbox`] handles paths that would lead outside of its root folder.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum SandboxMode {
+    /// Reject (i.e. panic on) absolute paths and paths that escape the root folder (see
+    /// [`crate::safe_join`]).
+    #[default]
+    Reject,
+
+    /// Re-root absolute paths and drop `..` components that would escape the root folder (see
+    /// [`crate::safe_join_clamped`]).
+    Clamp,
+}
+
+/// A handle for performing file i/o that is confined to a root folder.
+///
+/// The methods mirror the crate's functions of the same name, but interpret every path relative to
+/// the sandbox's root folder, so that tools operating on user-provided paths cannot escape their
+/// workspace.
+///
+/// # Note
+///
+/// * Paths are resolved lexically first (according to the [`SandboxMode`]).
+/// * Paths that are lexically inside the root folder but resolve to a location outside of it via a
+///   symbolic link are always rejected, regardless of the [`SandboxMode`].
+/// * [`Sandbox`] also implements [`FileSystem`], so it can be passed to code written against that
+///   trait.
+///
+/// # Example
+///
+/// ```
+/// use file_io::{Sandbox, SandboxMode};
+///
+/// let sandbox = Sandbox::new("folder/sandbox_1");
+/// sandbox.save_string_to_file("Hello, world!", "notes/hello.txt");
+/// assert_eq!(sandbox.load_file_as_string("notes/../notes/hello.txt"), "Hello, world!");
+/// assert_eq!(sandbox.try_resolve("../escape.txt"), None);
+///
+/// // Clamp escaping paths instead of rejecting them.
+/// let sandbox = Sandbox::with_mode("folder/sandbox_1", SandboxMode::Clamp);
+/// assert_eq!(sandbox.load_file_as_string("/notes/hello.txt"), "Hello, world!");
+/// ```
+#[derive(Clone, Debug)]
+pub struct Sandbox {
+    /// The root folder.
+    root: PathBuf,
+
+    /// How paths that would escape the root folder are handled.
+    mode: SandboxMode,
+}
+
+impl Sandbox {
+    /// Constructor (rejecting paths that escape the root folder).
+    ///
+    /// # Arguments
+    ///
+    /// * `root` - The root folder (can be a `&str`, [`String`], [`Path`], or [`PathBuf`]).
+    ///
+    /// # Returns
+    ///
+    /// A sandbox confined to `root`.
+    ///
+    /// # Note
+    ///
+    /// The root folder does not need to exist yet.
+    pub fn new<P: AsRef<Path>>(root: P) -> Self {
+        Self::with_mode(root, SandboxMode::Reject)
+    }
+
+    /// Constructor.
+    ///
+    /// # Arguments
+    ///
+    /// * `root` - The root folder (can be a `&str`, [`String`], [`Path`], or [`PathBuf`]).
+    /// * `mode` - How paths that would escape the root folder are handled.
+    ///
+    /// # Returns
+    ///
+    /// A sandbox confined to `root`.
+    pub fn with_mode<P: AsRef<Path>>(root: P, mode: SandboxMode) -> Self {
+        Self {
+            root: root.as_ref().to_path_buf(),
+            mode,
+        }
+    }
+
+    /// Get the root folder.
+    ///
+    /// # Returns
+    ///
+    /// The root folder.
+    pub fn root(&self) -> &Path {
+        &self.root
+    }
+
+    /// Get the mode.
+    ///
+    /// # Returns
+    ///
+    /// How paths that would escape the root folder are handled.
+    pub fn mode(&self) -> SandboxMode {
+        self.mode
+    }
+
+    /// Resolves a path relative to the root folder.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - The path, relative to the root folder (can be a `&str`, [`String`], [`Path`], or
+    ///   [`PathBuf`]).
+    ///
+    /// # Returns
+    ///
+    /// The resolved path, or `None` if it would lead outside of the root folder.
+    pub fn try_resolve<P: AsRef<Path>>(&self, path: P) -> Option<PathBuf> {
+        let resolved = match self.mode {
+            SandboxMode::Reject => safe_join(&self.root, path)?,
+            SandboxMode::Clamp => safe_join_clamped(&self.root, path),
+        };
+        is_inside(&resolved, &self.root).then_some(resolved)
+    }
+
+    /// Resolves a path relative to the root folder.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - The path, relative to the root folder (can be a `&str`, [`String`], [`Path`], or
+    ///   [`PathBuf`]).
+    ///
+    /// # Returns
+    ///
+    /// The resolved path.
+    ///
+    /// # Panics
+    ///
+    /// If the path would lead outside of the root folder.
+    pub fn resolve<P: AsRef<Path>>(&self, path: P) -> PathBuf {
+        let path = path.as_ref();
+        self.try_resolve(path).unwrap_or_else(|| {
+            panic!(
+                "Path '{path:?}' is outside of the sandbox '{:?}'.",
+                self.root
+            )
+        })
+    }
+
+    /// Loads the contents of a file in the sandbox (see [`crate::load_file_as_string`]).
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - The path to the file, relative to the root folder (can be a `&str`, [`String`],
+    ///   [`Path`], or [`PathBuf`]).
+    ///
+    /// # Returns
+    ///
+    /// The contents of the file.
+    ///
+    /// # Panics
+    ///
+    /// If the path would lead outside of the root folder, or the file cannot be read.
+    pub fn load_file_as_string<P: AsRef<Path>>(&self, path: P) -> String {
+        load_file_as_string(self.resolve(path))
+    }
+
+    /// Saves a string to a file in the sandbox (see [`crate::save_string_to_file`]).
+    ///
+    /// # Arguments
+    ///
+    /// * `content` - The string content to save to the file.
+    /// * `path` - The path to the file, relative to the root folder (can be a `&str`, [`String`],
+    ///   [`Path`], or [`PathBuf`]).
+    ///
+    /// # Panics
+    ///
+    /// If the path would lead outside of the root folder, or the file cannot be written.
+    pub fn save_string_to_file<P: AsRef<Path>>(&self, content: &str, path: P) {
+        save_string_to_file(content, self.resolve(path));
+    }
+
+    /// Creates a folder in the sandbox (see [`crate::create_folder`]).
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - The path to the folder, relative to the root folder (can be a `&str`,
+    ///   [`String`], [`Path`], or [`PathBuf`]).
+    ///
+    /// # Panics
+    ///
+    /// If the path would lead outside of the root folder, or the folder cannot be created.
+    pub fn create_folder<P: AsRef<Path>>(&self, path: P) {
+        create_folder(self.resolve(path));
+    }
+
+    /// Creates a file in the sandbox or updates its modification time (see
+    /// [`crate::touch_file`]).
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - The path to the file, relative to the root folder (can be a `&str`, [`String`],
+    ///   [`Path`], or [`PathBuf`]).
+    ///
+    /// # Returns
+    ///
+    /// `true` if the file was created, `false` if it already existed.
+    ///
+    /// # Panics
+    ///
+    /// If the path would lead outside of the root folder, or the file cannot be created.
+    pub fn touch_file<P: AsRef<Path>>(&self, path: P) -> bool {
+        touch_file(self.resolve(path))
+    }
+
+    /// Deletes a file in the sandbox (see [`crate::delete_file`]).
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - The path to the file, relative to the root folder (can be a `&str`, [`String`],
+    ///   [`Path`], or [`PathBuf`]).
+    ///
+    /// # Panics
+    ///
+    /// If the path would lead outside of the root folder, or the file cannot be deleted.
+    pub fn delete_file<P: AsRef<Path>>(&self, path: P) {
+        delete_file(self.resolve(path));
+    }
+
+    /// Deletes a folder in the sandbox (see [`crate::delete_folder`]).
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - The path to the folder, relative to the root folder (can be a `&str`,
+    ///   [`String`], [`Path`], or [`PathBuf`]).
+    ///
+    /// # Panics
+    ///
+    /// If the path would lead outside of the root folder, or the folder cannot be deleted.
+    pub fn delete_folder<P: AsRef<Path>>(&self, path: P) {
+        delete_folder(self.resolve(path));
+    }
+
+    /// Copies a file within the sandbox (see [`crate::copy_file`]).
+    ///
+    /// # Arguments
+    ///
+    /// * `from` - The source file path, relative to the root folder (can be a `&str`, [`String`],
+    ///   [`Path`], or [`PathBuf`]).
+    /// * `to` - The destination file path, relative to the root folder (can be a `&str`,
+    ///   [`String`], [`Path`], or [`PathBuf`]).
+    ///
+    /// # Panics
+    ///
+    /// If either path would lead outside of the root folder, or the file cannot be copied.
+    pub fn copy_file<P: AsRef<Path>, Q: AsRef<Path>>(&self, from: P, to: Q) {
+        copy_file(self.resolve(from), self.resolve(to));
+    }
+
+    /// Copies a folder within the sandbox (see [`crate::copy_folder`]).
+    ///
+    /// # Arguments
+    ///
+    /// * `from` - The source folder path, relative to the root folder (can be a `&str`,
+    ///   [`String`], [`Path`], or [`PathBuf`]).
+    /// * `to` - The destination folder path, relative to the root folder (can be a `&str`,
+    ///   [`String`], [`Path`], or [`PathBuf`]).
+    ///
+    /// # Panics
+    ///
+    /// If either path would lead outside of the root folder, or the folder cannot be copied.
+    pub fn copy_folder<P: AsRef<Path>, Q: AsRef<Path>>(&self, from: P, to: Q) {
+        copy_folder(self.resolve(from), self.resolve(to));
+    }
+
+    /// Lists the contents of a folder in the sandbox (see [`crate::list_folder_contents`]).
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - The path to the folder, relative to the root folder (can be a `&str`,
+    ///   [`String`], [`Path`], or [`PathBuf`]).
+    ///
+    /// # Returns
+    ///
+    /// Paths of the files and folders in the folder (in alphabetical order, including the root
+    /// folder).
+    ///
+    /// # Panics
+    ///
+    /// If the path would lead outside of the root folder, or it is not a folder.
+    pub fn list_folder_contents<P: AsRef<Path>>(&self, path: P) -> Vec<PathBuf> {
+        list_folder_contents(self.resolve(path))
+    }
+
+    /// Check whether a file or folder exists in the sandbox.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - The path to check, relative to the root folder (can be a `&str`, [`String`],
+    ///   [`Path`], or [`PathBuf`]).
+    ///
+    /// # Returns
+    ///
+    /// `true` if a file or folder exists at `path`, `false` otherwise (including if the path would
+    /// lead outside of the root folder).
+    pub fn exists<P: AsRef<Path>>(&self, path: P) -> bool {
+        self.try_resolve(path).is_some_and(|path| path.exists())
+    }
+}
+
+impl FileSystem for Sandbox {
+    fn load_file_as_string(&self, path: &Path) -> String {
+        Sandbox::load_file_as_string(self, path)
+    }
+
+    fn save_string_to_file(&self, content: &str, path: &Path) {
+        Sandbox::save_string_to_file(self, content, path);
+    }
+
+    fn create_folder(&self, path: &Path) {
+        Sandbox::create_folder(self, path);
+    }
+
+    fn delete_file(&self, path: &Path) {
+        Sandbox::delete_file(self, path);
+    }
+
+    fn delete_folder(&self, path: &Path) {
+        Sandbox::delete_folder(self, path);
+    }
+
+    fn copy_file(&self, from: &Path, to: &Path) {
+        Sandbox::copy_file(self, from, to);
+    }
+
+    fn list_folder_contents(&self, path: &Path) -> Vec<PathBuf> {
+        Sandbox::list_folder_contents(self, path)
+    }
+
+    fn exists(&self, path: &Path) -> bool {
+        Sandbox::exists(self, path)
+    }
+
+    fn is_file(&self, path: &Path) -> bool {
+        self.try_resolve(path).is_some_and(|path| path.is_file())
+    }
+
+    fn is_folder(&self, path: &Path) -> bool {
+        self.try_resolve(path).is_some_and(|path| path.is_dir())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::get_temp_dir_path;
+    use tempfile::tempdir;
+
+    #[test]
+    fn test_sandbox_reject() {
+        // Create a temporary directory to work in.
+        let temp_dir = tempdir().unwrap();
+
+        // Get the path to the temporary directory.
+        let temp_dir_path = get_temp_dir_path(&temp_dir);
+
+        // Operations within the sandbox.
+        let sandbox = Sandbox::new(temp_dir_path.join("root"));
+        sandbox.save_string_to_file("Hello", "a/b.txt");
+        sandbox.copy_file("a/b.txt", "a/../c.txt");
+        assert_eq!(
+            load_file_as_string(temp_dir_path.join("root/c.txt")),
+            "Hello"
+        );
+        assert!(sandbox.exists("a/b.txt"));
+
+        // Escaping paths are rejected.
+        assert_eq!(sandbox.try_resolve("../outside.txt"), None);
+        assert_eq!(sandbox.try_resolve("/outside.txt"), None);
+        assert!(!sandbox.exists("../root/a/b.txt"));
+        let result = std::panic::catch_unwind(|| sandbox.save_string_to_file("x", "../x.txt"));
+        assert!(result.is_err());
+        assert!(!temp_dir_path.join("x.txt").exists());
+    }
+
+    #[test]
+    fn test_sandbox_clamp() {
+        // Create a temporary directory to work in.
+        let temp_dir = tempdir().unwrap();
+
+        // Get the path to the temporary directory.
+        let temp_dir_path = get_temp_dir_path(&temp_dir);
+
+        // Escaping paths are re-rooted.
+        let sandbox = Sandbox::with_mode(temp_dir_path.join("root"), SandboxMode::Clamp);
+        sandbox.save_string_to_file("Hello", "../../a.txt");
+        sandbox.save_string_to_file("World", "/b.txt");
+        assert!(temp_dir_path.join("root/a.txt").is_file());
+        assert!(temp_dir_path.join("root/b.txt").is_file());
+        assert!(!temp_dir_path.join("a.txt").exists());
+    }
+
+    #[cfg(unix)]
+    #[test]
+    #[should_panic(expected = "is outside of the sandbox")]
+    fn test_sandbox_symlink_escape() {
+        // Create a temporary directory to work in.
+        let temp_dir = tempdir().unwrap();
+
+        // Get the path to the temporary directory.
+        let temp_dir_path = get_temp_dir_path(&temp_dir);
+
+        // A symbolic link inside the sandbox that points outside of it.
+        save_string_to_file("Secret", temp_dir_path.join("outside/secret.txt"));
+        create_folder(temp_dir_path.join("root"));
+        std::os::unix::fs::symlink(
+            temp_dir_path.join("outside"),
+            temp_dir_path.join("root/link"),
+        )
+        .unwrap();
+
+        // Reading through the symbolic link is rejected.
+        let sandbox = Sandbox::with_mode(temp_dir_path.join("root"), SandboxMode::Clamp);
+        sandbox.load_file_as_string("link/secret.txt");
+    }
+}
@@ -1,5 +1,11 @@
-use crate::create::create_folder_for_file;
-use std::path::Path;
+use crate::create::{create_folder_for_file, create_unique_file};
+use crate::instrument::Mutation;
+use crate::path::unique_path;
+use base64::Engine;
+use base64::engine::general_purpose::STANDARD;
+use std::fs::File;
+use std::io::{BufWriter, Read, Seek, SeekFrom, Write};
+use std::path::{Path, PathBuf};
 
 /// Saves a string to a file at the specified path.
 ///
@@ -43,6 +49,657 @@ use std::path::Path;
 /// ```
 pub fn save_string_to_file<P: AsRef<Path>>(content: &str, path: P) {
     let path = path.as_ref();
+    let mut mutation = Mutation::start("save_string_to_file", path);
+    mutation.bytes(content.len() as u64);
+    if mutation.is_dry_run() {
+        return;
+    }
     create_folder_for_file(path);
     std::fs::write(path, content).unwrap_or_else(|_| panic!("Failed to write to file '{path:?}'."));
+    mutation.succeeded();
+}
+
+/// Saves a string to a file at the specified path, writing to standard output if the path is `-`.
+///
+/// # Arguments
+///
+/// * `content` - The string content to save to the file (or write to standard output).
+/// * `path` - The path where the file should be saved (can be a `&str`, [`String`], [`Path`], or
+///   [`std::path::PathBuf`]), or `-` to write to standard output.
+///
+/// # Panics
+///
+/// If some error is encountered while creating the file or writing to it (or to standard output).
+///
+/// # Note
+///
+/// * This follows the common convention of command-line tools, so that they can be composed with
+///   pipes. The content is written to standard output as is (without appending a newline).
+/// * This function will create the parent folder for the file if it does not already exist.
+///
+/// # Example
+///
+/// ```
+/// use file_io::{load_file_as_string, save_string_to_file_or_stdout};
+///
+/// let path: &str = "folder/subfolder_56/file_59.txt";
+/// save_string_to_file_or_stdout("Hello, world!", path);
+///
+/// assert_eq!(load_file_as_string(path), "Hello, world!");
+/// ```
+pub fn save_string_to_file_or_stdout<P: AsRef<Path>>(content: &str, path: P) {
+    let path = path.as_ref();
+    if path != Path::new("-") {
+        save_string_to_file(content, path);
+        return;
+    }
+    let mut stdout = std::io::stdout().lock();
+    stdout
+        .write_all(content.as_bytes())
+        .and_then(|_| stdout.flush())
+        .expect("Failed to write to standard output.");
+}
+
+/// Saves the content read from a reader to a file at the specified path.
+///
+/// # Arguments
+///
+/// * `reader` - The reader to read the content from (e.g. an HTTP response body or a decompression
+///   stream).
+/// * `path` - The path where the file should be saved (can be a `&str`, [`String`], [`Path`], or
+///   [`std::path::PathBuf`]).
+///
+/// # Returns
+///
+/// The number of bytes written to the file.
+///
+/// # Panics
+///
+/// If some error is encountered while reading from the reader, or while creating the file or
+/// writing to it.
+///
+/// # Note
+///
+/// * The content is streamed to the file through a buffer (the reader is read until its end), so
+///   it never has to be held in memory in its entirety.
+/// * This function will create the parent folder for the file if it does not already exist.
+///
+/// # Example
+///
+/// ```
+/// use file_io::{load_file_as_string, save_reader_to_file};
+///
+/// let path: &str = "folder/subfolder_57/file_60.txt";
+/// let bytes_written = save_reader_to_file("Hello, world!".as_bytes(), path);
+///
+/// assert_eq!(bytes_written, 13);
+/// assert_eq!(load_file_as_string(path), "Hello, world!");
+/// ```
+pub fn save_reader_to_file<R: Read, P: AsRef<Path>>(mut reader: R, path: P) -> u64 {
+    let path = path.as_ref();
+    let mut mutation = Mutation::start("save_reader_to_file", path);
+    if mutation.is_dry_run() {
+        return 0;
+    }
+    create_folder_for_file(path);
+    let bytes_written = File::create(path)
+        .map(BufWriter::new)
+        .and_then(|mut writer| {
+            let bytes_written = std::io::copy(&mut reader, &mut writer)?;
+            writer.flush()?;
+            Ok(bytes_written)
+        })
+        .unwrap_or_else(|_| panic!("Failed to write to file '{path:?}'."));
+    mutation.bytes(bytes_written);
+    mutation.succeeded();
+    bytes_written
+}
+
+/// Saves lines to a file at the specified path, writing them one at a time.
+///
+/// # Arguments
+///
+/// * `lines` - The lines to save (e.g. an iterator producing the lines on demand). Each line can
+///   be a `&str` or [`String`] (or anything else implementing [`AsRef<str>`]).
+/// * `path` - The path where the file should be saved (can be a `&str`, [`String`], [`Path`], or
+///   [`std::path::PathBuf`]).
+///
+/// # Panics
+///
+/// If some error is encountered while creating the file or writing to it.
+///
+/// # Note
+///
+/// * Each line is followed by a newline (`\n`), including the last one.
+/// * The lines are written through a buffer as they are produced, so the content of the file never
+///   has to be held in memory in its entirety.
+/// * This function will create the parent folder for the file if it does not already exist.
+///
+/// # Example
+///
+/// ```
+/// use file_io::{load_file_as_string, save_lines_streaming};
+///
+/// let path: &str = "folder/subfolder_59/file_63.csv";
+/// save_lines_streaming((1..=3).map(|i| format!("{i},{}", i * i)), path);
+///
+/// assert_eq!(load_file_as_string(path), "1,1\n2,4\n3,9\n");
+/// ```
+pub fn save_lines_streaming<I, S, P>(lines: I, path: P)
+where
+    I: IntoIterator<Item = S>,
+    S: AsRef<str>,
+    P: AsRef<Path>,
+{
+    let path = path.as_ref();
+    let mut mutation = Mutation::start("save_lines_streaming", path);
+    if mutation.is_dry_run() {
+        return;
+    }
+    create_folder_for_file(path);
+    let bytes_written = File::create(path)
+        .map(BufWriter::new)
+        .and_then(|mut writer| {
+            let mut bytes_written: u64 = 0;
+            for line in lines {
+                let line = line.as_ref();
+                writer.write_all(line.as_bytes())?;
+                writer.write_all(b"\n")?;
+                bytes_written += line.len() as u64 + 1;
+            }
+            writer.flush()?;
+            Ok(bytes_written)
+        })
+        .unwrap_or_else(|_| panic!("Failed to write to file '{path:?}'."));
+    mutation.bytes(bytes_written);
+    mutation.succeeded();
+}
+
+/// Options controlling how a file is saved by [`save_string_to_file_with_options`].
+///
+/// The default options match the behavior of [`save_string_to_file`].
+///
+/// # Example
+///
+/// ```
+/// use file_io::SaveOptions;
+///
+/// // Keep the previous 3 versions of the file.
+/// let options = SaveOptions { backups: 3 };
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct SaveOptions {
+    /// The number of previous versions of the file to keep. Before the file is overwritten, its
+    /// current version is moved to `<path>.1`, the version at `<path>.1` to `<path>.2`, and so on.
+    /// Versions beyond this number are deleted. If `0`, no backups are kept.
+    pub backups: usize,
+}
+
+/// Returns the path of a backup of a file.
+///
+/// # Arguments
+///
+/// * `path` - The path to the file.
+/// * `index` - The index of the backup (`1` being the most recent).
+///
+/// # Returns
+///
+/// The path of the backup (the path of the file with `.<index>` appended).
+fn backup_path(path: &Path, index: usize) -> PathBuf {
+    let mut backup = path.as_os_str().to_owned();
+    backup.push(format!(".{index}"));
+    PathBuf::from(backup)
+}
+
+/// Copies the current version of a file to its first backup, shifting the existing backups along
+/// and deleting those beyond the number to keep.
+///
+/// # Arguments
+///
+/// * `path` - The path to the file.
+/// * `backups` - The number of backups to keep.
+///
+/// # Panics
+///
+/// If some error is encountered while moving, copying, or deleting a backup.
+fn rotate_backups(path: &Path, backups: usize) {
+    if backups == 0 || !path.is_file() {
+        return;
+    }
+    let mut mutation = Mutation::start("rotate_backups", path);
+    if mutation.is_dry_run() {
+        return;
+    }
+
+    // Delete the oldest backup that is kept (it is about to be shifted out) and any beyond it.
+    let mut index = backups;
+    while backup_path(path, index).is_file() {
+        let backup = backup_path(path, index);
+        std::fs::remove_file(&backup)
+            .unwrap_or_else(|_| panic!("Failed to delete file '{backup:?}'."));
+        index += 1;
+    }
+
+    // Shift the remaining backups along, from the oldest to the most recent.
+    for index in (1..backups).rev() {
+        let from = backup_path(path, index);
+        if from.is_file() {
+            let to = backup_path(path, index + 1);
+            std::fs::rename(&from, &to)
+                .unwrap_or_else(|_| panic!("Failed to move file '{from:?}' to '{to:?}'."));
+        }
+    }
+
+    // Copy (rather than move) the current version so the file never goes missing.
+    let backup = backup_path(path, 1);
+    std::fs::copy(path, &backup)
+        .unwrap_or_else(|_| panic!("Failed to copy file '{path:?}' to '{backup:?}'."));
+    mutation.succeeded();
+}
+
+/// Saves a string to a file at the specified path, with options controlling how it is saved.
+///
+/// # Arguments
+///
+/// * `content` - The string content to save to the file.
+/// * `path` - The path where the file should be saved (can be a `&str`, [`String`], [`Path`], or
+///   [`std::path::PathBuf`]).
+/// * `options` - Options controlling how the file is saved.
+///
+/// # Panics
+///
+/// If some error is encountered while creating the file, writing to it, or managing its backups.
+///
+/// # Note
+///
+/// This function will create the parent folder for the file if it does not already exist.
+///
+/// # Example
+///
+/// ```
+/// use file_io::{load_file_as_string, save_string_to_file_with_options, SaveOptions};
+///
+/// let path: &str = "folder/subfolder_34/config.toml";
+/// let options = SaveOptions { backups: 2 };
+///
+/// save_string_to_file_with_options("version = 1", path, &options);
+/// save_string_to_file_with_options("version = 2", path, &options);
+/// save_string_to_file_with_options("version = 3", path, &options);
+///
+/// // The previous versions are kept next to the file.
+/// assert_eq!(load_file_as_string(path), "version = 3");
+/// assert_eq!(load_file_as_string("folder/subfolder_34/config.toml.1"), "version = 2");
+/// assert_eq!(load_file_as_string("folder/subfolder_34/config.toml.2"), "version = 1");
+/// ```
+pub fn save_string_to_file_with_options<P: AsRef<Path>>(
+    content: &str,
+    path: P,
+    options: &SaveOptions,
+) {
+    let path = path.as_ref();
+    rotate_backups(path, options.backups);
+    save_string_to_file(content, path);
+}
+
+/// Saves a string to a new file, never overwriting an existing file.
+///
+/// # Arguments
+///
+/// * `content` - The string content to save to the file.
+/// * `path` - The desired path of the file (can be a `&str`, [`String`], [`Path`], or
+///   [`std::path::PathBuf`]).
+///
+/// # Returns
+///
+/// The path the file was actually saved to. This is `path` itself if nothing exists there yet, or
+/// otherwise a numbered variant of it (see [`crate::unique_path`]).
+///
+/// # Panics
+///
+/// If some error is encountered while creating the file or writing to it.
+///
+/// # Note
+///
+/// This function will create the parent folder for the file if it does not already exist.
+///
+/// # Example
+///
+/// ```
+/// use file_io::save_string_to_unique_file;
+///
+/// let path: &str = "folder/subfolder_21/file_17.txt";
+///
+/// // Saving twice to the same path creates two separate files.
+/// let first = save_string_to_unique_file("Hello, world!", path);
+/// let second = save_string_to_unique_file("Hello, world!", path);
+/// assert_ne!(first, second);
+/// ```
+pub fn save_string_to_unique_file<P: AsRef<Path>>(content: &str, path: P) -> PathBuf {
+    let path = path.as_ref();
+    let mut mutation = Mutation::start("save_string_to_unique_file", path);
+    mutation.bytes(content.len() as u64);
+    if mutation.is_dry_run() {
+        return unique_path(path);
+    }
+    let (mut file, path) = create_unique_file(path);
+    file.write_all(content.as_bytes())
+        .unwrap_or_else(|_| panic!("Failed to write to file '{path:?}'."));
+    mutation.succeeded();
+    path
+}
+
+/// Writes bytes to a file at the specified offset, overwriting the bytes that are already there.
+///
+/// # Arguments
+///
+/// * `path` - The path to the file (can be a `&str`, [`String`], [`Path`], or
+///   [`std::path::PathBuf`]).
+/// * `offset` - The offset (in bytes) at which to write.
+/// * `bytes` - The bytes to write.
+///
+/// # Panics
+///
+/// If some error is encountered while creating the file or writing to it.
+///
+/// # Note
+///
+/// * This function will create the file (along with its parent folder) if it does not already
+///   exist.
+/// * The rest of the file is left untouched. If `offset` is past the end of the file, the gap is
+///   filled with zeros.
+///
+/// # Example
+///
+/// ```
+/// use file_io::{load_file_as_string, save_string_to_file, write_bytes_at};
+///
+/// // Create a file.
+/// let path: &str = "folder/subfolder_29/file_28.txt";
+/// save_string_to_file("Hello, world!", path);
+///
+/// // Patch part of it.
+/// write_bytes_at(path, 7, b"there");
+/// assert_eq!(load_file_as_string(path), "Hello, there!");
+/// ```
+pub fn write_bytes_at<P: AsRef<Path>>(path: P, offset: u64, bytes: &[u8]) {
+    let path = path.as_ref();
+    let mut mutation = Mutation::start("write_bytes_at", path);
+    mutation.bytes(bytes.len() as u64);
+    if mutation.is_dry_run() {
+        return;
+    }
+    create_folder_for_file(path);
+    std::fs::OpenOptions::new()
+        .write(true)
+        .create(true)
+        .truncate(false)
+        .open(path)
+        .and_then(|mut file| {
+            file.seek(SeekFrom::Start(offset))?;
+            file.write_all(bytes)
+        })
+        .unwrap_or_else(|_| panic!("Failed to write to file '{path:?}'."));
+    mutation.succeeded();
+}
+
+/// Truncates (or extends) a file to the specified length.
+///
+/// # Arguments
+///
+/// * `path` - The path to the file (can be a `&str`, [`String`], [`Path`], or
+///   [`std::path::PathBuf`]).
+/// * `len` - The new length of the file (in bytes).
+///
+/// # Panics
+///
+/// If some error is encountered while creating the file or changing its length.
+///
+/// # Note
+///
+/// * This function will create the file (along with its parent folder) if it does not already
+///   exist.
+/// * If `len` is greater than the current length of the file, the file is extended with zeros.
+///
+/// # Example
+///
+/// ```
+/// use file_io::{load_file_as_string, save_string_to_file, truncate_file};
+///
+/// // Create a file.
+/// let path: &str = "folder/subfolder_29/file_29.txt";
+/// save_string_to_file("Hello, world!", path);
+///
+/// // Keep only the first 5 bytes.
+/// truncate_file(path, 5);
+/// assert_eq!(load_file_as_string(path), "Hello");
+/// ```
+pub fn truncate_file<P: AsRef<Path>>(path: P, len: u64) {
+    let path = path.as_ref();
+    let mut mutation = Mutation::start("truncate_file", path);
+    if mutation.is_dry_run() {
+        return;
+    }
+    create_folder_for_file(path);
+    std::fs::OpenOptions::new()
+        .write(true)
+        .create(true)
+        .truncate(false)
+        .open(path)
+        .and_then(|file| file.set_len(len))
+        .unwrap_or_else(|_| panic!("Failed to truncate file '{path:?}'."));
+    mutation.succeeded();
+}
+
+/// Decodes a base64 string and saves the decoded bytes to a file at the specified path.
+///
+/// # Arguments
+///
+/// * `encoded` - The base64 string, using the standard base64 alphabet with padding (RFC 4648).
+///   Leading and trailing whitespace is ignored.
+/// * `path` - The path where the file should be saved (can be a `&str`, [`String`], [`Path`], or
+///   [`std::path::PathBuf`]).
+///
+/// # Panics
+///
+/// If `encoded` is not valid base64, or if some error is encountered while creating the file or
+/// writing to it.
+///
+/// # Note
+///
+/// This function will create the parent folder for the file if it does not already exist.
+///
+/// # Example
+///
+/// ```
+/// use file_io::{load_file_as_string, save_base64_to_file};
+///
+/// let path: &str = "folder/subfolder_32/file_35.txt";
+/// save_base64_to_file("SGVsbG8sIHdvcmxkIQ==", path);
+///
+/// assert_eq!(load_file_as_string(path), "Hello, world!");
+/// ```
+pub fn save_base64_to_file<P: AsRef<Path>>(encoded: &str, path: P) {
+    let path = path.as_ref();
+    let bytes = STANDARD
+        .decode(encoded.trim())
+        .unwrap_or_else(|error| panic!("Invalid base64 content for file '{path:?}': {error}."));
+    let mut mutation = Mutation::start("save_base64_to_file", path);
+    mutation.bytes(bytes.len() as u64);
+    if mutation.is_dry_run() {
+        return;
+    }
+    create_folder_for_file(path);
+    std::fs::write(path, bytes).unwrap_or_else(|_| panic!("Failed to write to file '{path:?}'."));
+    mutation.succeeded();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::load::load_file_as_string;
+    use crate::test_utils::get_temp_dir_path;
+    use tempfile::tempdir;
+
+    #[test]
+    fn test_save_string_to_unique_file() {
+        // Create a temporary directory to work in.
+        let temp_dir = tempdir().unwrap();
+        let file_path = get_temp_dir_path(&temp_dir).join("file.txt");
+
+        // Save an existing file.
+        save_string_to_file("original", &file_path);
+
+        // Saving to the same path must not overwrite the existing file.
+        let new_path = save_string_to_unique_file("new", &file_path);
+        assert_eq!(new_path, file_path.with_file_name("file (1).txt"));
+        assert_eq!(load_file_as_string(&file_path), "original");
+        assert_eq!(load_file_as_string(&new_path), "new");
+    }
+
+    #[test]
+    fn test_write_bytes_at() {
+        // Create a temporary directory to work in.
+        let temp_dir = tempdir().unwrap();
+
+        // Path to the file (in a folder that does not exist yet).
+        let file_path = get_temp_dir_path(&temp_dir).join("folder/file.bin");
+
+        // Write to a new file, past its end.
+        write_bytes_at(&file_path, 2, b"ab");
+        assert_eq!(std::fs::read(&file_path).unwrap(), b"\0\0ab");
+
+        // Overwrite part of the file, extending it.
+        write_bytes_at(&file_path, 3, b"cd");
+        assert_eq!(std::fs::read(&file_path).unwrap(), b"\0\0acd");
+    }
+
+    #[test]
+    fn test_truncate_file() {
+        // Create a temporary directory to work in.
+        let temp_dir = tempdir().unwrap();
+
+        // Path to the file.
+        let file_path = get_temp_dir_path(&temp_dir).join("file.txt");
+        save_string_to_file("Hello, world!", &file_path);
+
+        // Shorten and extend the file.
+        truncate_file(&file_path, 5);
+        assert_eq!(std::fs::read(&file_path).unwrap(), b"Hello");
+        truncate_file(&file_path, 7);
+        assert_eq!(std::fs::read(&file_path).unwrap(), b"Hello\0\0");
+
+        // Create a new file.
+        truncate_file(get_temp_dir_path(&temp_dir).join("new/file.txt"), 3);
+        assert_eq!(
+            std::fs::read(get_temp_dir_path(&temp_dir).join("new/file.txt")).unwrap(),
+            b"\0\0\0"
+        );
+    }
+
+    #[test]
+    fn test_save_base64_to_file() {
+        // Create a temporary directory to work in.
+        let temp_dir = tempdir().unwrap();
+
+        // Path to the file (in a folder that does not exist yet).
+        let file_path = get_temp_dir_path(&temp_dir).join("folder/file.bin");
+
+        // Decode binary content.
+        save_base64_to_file("AAEC/f7/\n", &file_path);
+        assert_eq!(
+            std::fs::read(&file_path).unwrap(),
+            [0u8, 1, 2, 253, 254, 255]
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "Invalid base64 content")]
+    fn test_save_base64_to_file_invalid() {
+        // Create a temporary directory to work in.
+        let temp_dir = tempdir().unwrap();
+
+        // Try to decode invalid content.
+        save_base64_to_file("not base64!", get_temp_dir_path(&temp_dir).join("file.bin"));
+    }
+
+    #[test]
+    fn test_save_string_to_file_with_backups() {
+        // Create a temporary directory to work in.
+        let temp_dir = tempdir().unwrap();
+
+        // Path to the file.
+        let file_path = get_temp_dir_path(&temp_dir).join("config.toml");
+        let backup = |index: usize| backup_path(&file_path, index);
+
+        // Save several versions of the file, keeping 2 backups.
+        let options = SaveOptions { backups: 2 };
+        for version in 1..=4 {
+            save_string_to_file_with_options(&format!("v{version}"), &file_path, &options);
+        }
+        assert_eq!(load_file_as_string(&file_path), "v4");
+        assert_eq!(load_file_as_string(backup(1)), "v3");
+        assert_eq!(load_file_as_string(backup(2)), "v2");
+        assert!(!backup(3).exists());
+
+        // Reducing the number of backups prunes the older ones.
+        save_string_to_file_with_options("v5", &file_path, &SaveOptions { backups: 1 });
+        assert_eq!(load_file_as_string(&file_path), "v5");
+        assert_eq!(load_file_as_string(backup(1)), "v4");
+        assert!(!backup(2).exists());
+
+        // Without backups, the existing ones are left alone.
+        save_string_to_file_with_options("v6", &file_path, &SaveOptions::default());
+        assert_eq!(load_file_as_string(&file_path), "v6");
+        assert_eq!(load_file_as_string(backup(1)), "v4");
+    }
+
+    #[test]
+    fn test_save_string_to_file_or_stdout() {
+        // Create a temporary directory to work in.
+        let temp_dir = tempdir().unwrap();
+        let file_path = get_temp_dir_path(&temp_dir).join("a/-");
+
+        // Paths other than `-` (even if their file name is `-`) are saved as files.
+        save_string_to_file_or_stdout("content", &file_path);
+        assert_eq!(load_file_as_string(&file_path), "content");
+    }
+
+    #[test]
+    fn test_save_reader_to_file() {
+        // Create a temporary directory to work in.
+        let temp_dir = tempdir().unwrap();
+        let file_path = get_temp_dir_path(&temp_dir).join("a/b/file.bin");
+
+        // Stream content larger than the internal buffers from a reader.
+        let content: Vec<u8> = (0..200_000).map(|i| (i % 251) as u8).collect();
+        let bytes_written = save_reader_to_file(std::io::Cursor::new(&content), &file_path);
+        assert_eq!(bytes_written, content.len() as u64);
+        assert_eq!(std::fs::read(&file_path).unwrap(), content);
+
+        // An existing file is overwritten.
+        assert_eq!(save_reader_to_file(&b"short"[..], &file_path), 5);
+        assert_eq!(load_file_as_string(&file_path), "short");
+    }
+
+    #[test]
+    fn test_save_lines_streaming() {
+        // Create a temporary directory to work in.
+        let temp_dir = tempdir().unwrap();
+        let file_path = get_temp_dir_path(&temp_dir).join("a/lines.txt");
+
+        // Save lines produced by an iterator of `String`s.
+        save_lines_streaming((0..10_000).map(|i| i.to_string()), &file_path);
+        let content = load_file_as_string(&file_path);
+        assert_eq!(content.lines().count(), 10_000);
+        assert!(content.starts_with("0\n1\n2\n"));
+        assert!(content.ends_with("9998\n9999\n"));
+
+        // Save a collection of `&str`s (including empty lines), overwriting the file.
+        save_lines_streaming(["first", "", "third"], &file_path);
+        assert_eq!(load_file_as_string(&file_path), "first\n\nthird\n");
+
+        // Saving no lines creates an empty file.
+        save_lines_streaming(Vec::<String>::new(), &file_path);
+        assert_eq!(load_file_as_string(&file_path), "");
+    }
 }
@@ -0,0 +1,309 @@
+use crate::hash::hash_file;
+use crate::load::load_file_as_string;
+use crate::save::save_string_to_file;
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+use walkdir::WalkDir;
+
+/// Header line of a serialized [`FolderSnapshot`].
+const SNAPSHOT_HEADER: &str = "# file-io folder snapshot v1";
+
+/// A single entry of a [`FolderSnapshot`].
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum SnapshotEntry {
+    /// A folder.
+    Folder,
+
+    /// A file, with the SHA-256 hash of its contents (see [`crate::hash_file`]).
+    File(String),
+
+    /// A symbolic link, with its target.
+    Symlink(PathBuf),
+}
+
+/// A snapshot of the structure and file contents of a folder (see [`snapshot_folder`]).
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct FolderSnapshot {
+    /// Entries of the folder, keyed by their path relative to the folder.
+    entries: BTreeMap<PathBuf, SnapshotEntry>,
+}
+
+/// Differences between two [`FolderSnapshot`]s (see [`FolderSnapshot::diff`]).
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct SnapshotDiff {
+    /// Paths of entries that only exist in the other snapshot.
+    pub added: Vec<PathBuf>,
+
+    /// Paths of entries that only exist in this snapshot.
+    pub removed: Vec<PathBuf>,
+
+    /// Paths of entries that exist in both snapshots but differ (i.e. files with different
+    /// contents, symbolic links with different targets, or entries whose type changed).
+    pub modified: Vec<PathBuf>,
+}
+
+impl SnapshotDiff {
+    /// Check whether the snapshots are identical.
+    ///
+    /// # Returns
+    ///
+    /// `true` if no entries were added, removed, or modified, `false` otherwise.
+    pub fn is_empty(&self) -> bool {
+        self.added.is_empty() && self.removed.is_empty() && self.modified.is_empty()
+    }
+}
+
+/// Converts a relative path to a string with `/` separators (so that serialized snapshots are
+/// portable across platforms).
+///
+/// # Arguments
+///
+/// * `path` - The relative path.
+///
+/// # Returns
+///
+/// The path as a string.
+///
+/// # Panics
+///
+/// If the path is not valid UTF-8 or contains a tab or line break.
+fn path_to_portable_string(path: &Path) -> String {
+    let components: Vec<&str> = path
+        .components()
+        .map(|comp| {
+            comp.as_os_str()
+                .to_str()
+                .filter(|name| !name.contains(['\t', '\n', '\r']))
+                .unwrap_or_else(|| panic!("Path '{path:?}' cannot be serialized."))
+        })
+        .collect();
+    components.join("/")
+}
+
+impl FolderSnapshot {
+    /// Get the entries of the snapshot.
+    ///
+    /// # Returns
+    ///
+    /// The entries of the snapshot, keyed by their path relative to the snapshotted folder.
+    pub fn entries(&self) -> &BTreeMap<PathBuf, SnapshotEntry> {
+        &self.entries
+    }
+
+    /// Compares this snapshot to another (e.g. newer) snapshot.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - The snapshot to compare to.
+    ///
+    /// # Returns
+    ///
+    /// The differences between the snapshots (each list is sorted by path).
+    pub fn diff(&self, other: &FolderSnapshot) -> SnapshotDiff {
+        let mut diff = SnapshotDiff::default();
+        for (path, entry) in &self.entries {
+            match other.entries.get(path) {
+                None => diff.removed.push(path.clone()),
+                Some(other_entry) if other_entry != entry => diff.modified.push(path.clone()),
+                Some(_) => {}
+            }
+        }
+        diff.added = other
+            .entries
+            .keys()
+            .filter(|path| !self.entries.contains_key(*path))
+            .cloned()
+            .collect();
+        diff
+    }
+
+    /// Saves the snapshot to a file.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - The path to the file (can be a `&str`, [`String`], [`Path`], or [`PathBuf`]).
+    ///
+    /// # Panics
+    ///
+    /// If the file cannot be written, or if the snapshot contains a path (or symbolic link
+    /// target) that is not valid UTF-8 or contains a tab or line break.
+    ///
+    /// # Note
+    ///
+    /// The file is a plain text file with one line per entry, so it can be committed to version
+    /// control and reviewed like any other text file.
+    pub fn save<P: AsRef<Path>>(&self, path: P) {
+        let mut content = format!("{SNAPSHOT_HEADER}\n");
+        for (entry_path, entry) in &self.entries {
+            let entry_path = path_to_portable_string(entry_path);
+            let line = match entry {
+                SnapshotEntry::Folder => format!("folder\t-\t{entry_path}"),
+                SnapshotEntry::File(hash) => format!("file\t{hash}\t{entry_path}"),
+                SnapshotEntry::Symlink(target) => {
+                    format!("symlink\t{}\t{entry_path}", path_to_portable_string(target))
+                }
+            };
+            content.push_str(&line);
+            content.push('\n');
+        }
+        save_string_to_file(&content, path);
+    }
+
+    /// Loads a snapshot from a file (see [`FolderSnapshot::save`]).
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - The path to the file (can be a `&str`, [`String`], [`Path`], or [`PathBuf`]).
+    ///
+    /// # Returns
+    ///
+    /// The loaded snapshot.
+    ///
+    /// # Panics
+    ///
+    /// If the file cannot be read or is not a valid snapshot file.
+    pub fn load<P: AsRef<Path>>(path: P) -> Self {
+        let path = path.as_ref();
+        let content = load_file_as_string(path);
+        let mut lines = content.lines();
+        if lines.next() != Some(SNAPSHOT_HEADER) {
+            panic!("File '{path:?}' is not a folder snapshot.");
+        }
+        let entries = lines
+            .map(|line| {
+                let invalid = || panic!("Invalid line in folder snapshot '{path:?}': {line:?}");
+                let mut fields = line.splitn(3, '\t');
+                let (Some(kind), Some(value), Some(entry_path)) =
+                    (fields.next(), fields.next(), fields.next())
+                else {
+                    invalid()
+                };
+                let entry = match kind {
+                    "folder" => SnapshotEntry::Folder,
+                    "file" => SnapshotEntry::File(value.to_string()),
+                    "symlink" => SnapshotEntry::Symlink(PathBuf::from(value)),
+                    _ => invalid(),
+                };
+                (PathBuf::from(entry_path), entry)
+            })
+            .collect();
+        Self { entries }
+    }
+}
+
+/// Take a snapshot of the structure and file contents of a folder.
+///
+/// # Arguments
+///
+/// * `path` - The path to the folder (can be a `&str`, [`String`], [`Path`], or [`PathBuf`]).
+///
+/// # Returns
+///
+/// A snapshot of all entries within the folder (recursively), with the SHA-256 hash of each file's
+/// contents. Snapshots of the same folder taken at different times can be compared using
+/// [`FolderSnapshot::diff`].
+///
+/// # Panics
+///
+/// If the folder cannot be traversed or any file cannot be hashed.
+///
+/// # Note
+///
+/// Symbolic links are not followed (their targets are recorded instead). Timestamps and
+/// permissions are not part of the snapshot.
+///
+/// # Example
+///
+/// ```
+/// use file_io::{save_string_to_file, snapshot_folder};
+/// use std::path::PathBuf;
+///
+/// // Take a snapshot before and after an operation.
+/// save_string_to_file("Hello, world!", "folder/snapshot/file.txt");
+/// let before = snapshot_folder("folder/snapshot");
+/// save_string_to_file("Goodbye, world!", "folder/snapshot/file.txt");
+/// let after = snapshot_folder("folder/snapshot");
+///
+/// // Check what changed.
+/// let diff = before.diff(&after);
+/// assert_eq!(diff.modified, vec![PathBuf::from("file.txt")]);
+/// assert!(diff.added.is_empty() && diff.removed.is_empty());
+/// ```
+pub fn snapshot_folder<P: AsRef<Path>>(path: P) -> FolderSnapshot {
+    let path = path.as_ref();
+    let entries = WalkDir::new(path)
+        .min_depth(1)
+        .into_iter()
+        .map(|entry| entry.unwrap_or_else(|_| panic!("Failed to traverse '{path:?}'.")))
+        .map(|entry| {
+            let relative_path = entry.path().strip_prefix(path).unwrap().to_path_buf();
+            let file_type = entry.file_type();
+            let snapshot_entry = if file_type.is_symlink() {
+                SnapshotEntry::Symlink(std::fs::read_link(entry.path()).unwrap_or_else(|_| {
+                    panic!("Failed to read symbolic link '{:?}'.", entry.path())
+                }))
+            } else if file_type.is_dir() {
+                SnapshotEntry::Folder
+            } else {
+                SnapshotEntry::File(hash_file(entry.path()))
+            };
+            (relative_path, snapshot_entry)
+        })
+        .collect();
+    FolderSnapshot { entries }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::delete::delete_file;
+    use crate::test_utils::get_temp_dir_path;
+    use tempfile::tempdir;
+
+    #[test]
+    fn test_snapshot_folder_diff() {
+        // Create a temporary directory to work in.
+        let temp_dir = tempdir().unwrap();
+        let root = get_temp_dir_path(&temp_dir).join("root");
+
+        // Create the initial structure and take a snapshot.
+        save_string_to_file("a", root.join("a.txt"));
+        save_string_to_file("b", root.join("sub/b.txt"));
+        save_string_to_file("c", root.join("c.txt"));
+        let before = snapshot_folder(&root);
+        assert_eq!(before.entries().len(), 4);
+        assert_eq!(before.entries()[Path::new("sub")], SnapshotEntry::Folder);
+
+        // An unchanged folder produces an empty diff.
+        assert!(before.diff(&snapshot_folder(&root)).is_empty());
+
+        // Modify, add, and remove files.
+        save_string_to_file("b2", root.join("sub/b.txt"));
+        save_string_to_file("d", root.join("d.txt"));
+        delete_file(root.join("c.txt"));
+        let diff = before.diff(&snapshot_folder(&root));
+        assert_eq!(diff.added, vec![PathBuf::from("d.txt")]);
+        assert_eq!(diff.removed, vec![PathBuf::from("c.txt")]);
+        assert_eq!(diff.modified, vec![PathBuf::from("sub/b.txt")]);
+    }
+
+    #[test]
+    fn test_snapshot_save_load() {
+        // Create a temporary directory to work in.
+        let temp_dir = tempdir().unwrap();
+        let temp_dir_path = get_temp_dir_path(&temp_dir);
+        let root = temp_dir_path.join("root");
+
+        // Create a structure (including a symbolic link on Unix).
+        save_string_to_file("a", root.join("a b.txt"));
+        save_string_to_file("b", root.join("sub/deeper/b.txt"));
+        #[cfg(unix)]
+        std::os::unix::fs::symlink("sub/deeper", root.join("link")).unwrap();
+
+        // Save and reload the snapshot.
+        let snapshot = snapshot_folder(&root);
+        let snapshot_path = temp_dir_path.join("snapshot.txt");
+        snapshot.save(&snapshot_path);
+        assert_eq!(FolderSnapshot::load(&snapshot_path), snapshot);
+    }
+}
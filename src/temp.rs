@@ -0,0 +1,337 @@
+use std::path::{Path, PathBuf};
+use tempfile::{Builder, TempDir, TempPath};
+
+/// A temporary file that is deleted when it goes out of scope (i.e. when it is dropped).
+///
+/// # Example
+///
+/// ```
+/// use file_io::{TempFileGuard, load_file_as_string, save_string_to_file};
+///
+/// // Create a temporary file and write to it.
+/// let temp_file = TempFileGuard::new("data_", ".txt");
+/// save_string_to_file("Hello, world!", temp_file.path());
+/// assert_eq!(load_file_as_string(temp_file.path()), "Hello, world!");
+///
+/// // The file is deleted when the guard is dropped.
+/// let path = temp_file.path().to_path_buf();
+/// drop(temp_file);
+/// assert!(!path.exists());
+/// ```
+#[derive(Debug)]
+#[must_use]
+pub struct TempFileGuard {
+    /// Path to the temporary file (deletes the file when dropped).
+    path: TempPath,
+}
+
+impl TempFileGuard {
+    /// Constructor.
+    ///
+    /// # Arguments
+    ///
+    /// * `prefix` - Prefix of the file name.
+    /// * `suffix` - Suffix of the file name (e.g. an extension such as `".txt"`).
+    ///
+    /// # Returns
+    ///
+    /// A guard for a new, empty file in the system's temporary folder, with a file name made up of
+    /// `prefix`, a random string, and `suffix`.
+    ///
+    /// # Panics
+    ///
+    /// If the file cannot be created.
+    pub fn new(prefix: &str, suffix: &str) -> Self {
+        Self::new_in(std::env::temp_dir(), prefix, suffix)
+    }
+
+    /// Constructor (in a given folder).
+    ///
+    /// # Arguments
+    ///
+    /// * `dir` - The folder to create the file in (can be a `&str`, [`String`], [`Path`], or
+    ///   [`PathBuf`]).
+    /// * `prefix` - Prefix of the file name.
+    /// * `suffix` - Suffix of the file name (e.g. an extension such as `".txt"`).
+    ///
+    /// # Returns
+    ///
+    /// A guard for a new, empty file in `dir`, with a file name made up of `prefix`, a random
+    /// string, and `suffix`.
+    ///
+    /// # Panics
+    ///
+    /// If the file cannot be created (e.g. because `dir` does not exist).
+    pub fn new_in<P: AsRef<Path>>(dir: P, prefix: &str, suffix: &str) -> Self {
+        let dir = dir.as_ref();
+        let file = Builder::new()
+            .prefix(prefix)
+            .suffix(suffix)
+            .tempfile_in(dir)
+            .unwrap_or_else(|_| panic!("Failed to create a temporary file in '{dir:?}'."));
+        Self {
+            path: file.into_temp_path(),
+        }
+    }
+
+    /// Get the path to the temporary file.
+    ///
+    /// # Returns
+    ///
+    /// Path to the temporary file.
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Keeps the temporary file (i.e. it is not deleted when the guard is dropped).
+    ///
+    /// # Returns
+    ///
+    /// Path to the file.
+    ///
+    /// # Panics
+    ///
+    /// If the file cannot be kept.
+    pub fn keep(self) -> PathBuf {
+        self.path
+            .keep()
+            .expect("Failed to keep the temporary file.")
+    }
+}
+
+/// A temporary folder that is deleted (along with all of its contents) when it goes out of scope
+/// (i.e. when it is dropped).
+///
+/// # Example
+///
+/// ```
+/// use file_io::{TempFolderGuard, save_string_to_file};
+///
+/// // Create a temporary folder and a file inside it.
+/// let temp_folder = TempFolderGuard::new();
+/// save_string_to_file("Hello, world!", temp_folder.path().join("file.txt"));
+///
+/// // The folder and its contents are deleted when the guard is dropped.
+/// let path = temp_folder.path().to_path_buf();
+/// drop(temp_folder);
+/// assert!(!path.exists());
+/// ```
+#[derive(Debug)]
+#[must_use]
+pub struct TempFolderGuard {
+    /// The temporary folder (deletes the folder when dropped).
+    dir: TempDir,
+}
+
+impl TempFolderGuard {
+    /// Constructor.
+    ///
+    /// # Returns
+    ///
+    /// A guard for a new, empty folder in the system's temporary folder.
+    ///
+    /// # Panics
+    ///
+    /// If the folder cannot be created.
+    pub fn new() -> Self {
+        Self::new_in(std::env::temp_dir())
+    }
+
+    /// Constructor (in a given folder).
+    ///
+    /// # Arguments
+    ///
+    /// * `dir` - The folder to create the temporary folder in (can be a `&str`, [`String`],
+    ///   [`Path`], or [`PathBuf`]).
+    ///
+    /// # Returns
+    ///
+    /// A guard for a new, empty folder in `dir`.
+    ///
+    /// # Panics
+    ///
+    /// If the folder cannot be created (e.g. because `dir` does not exist).
+    pub fn new_in<P: AsRef<Path>>(dir: P) -> Self {
+        let dir = dir.as_ref();
+        Self {
+            dir: TempDir::new_in(dir)
+                .unwrap_or_else(|_| panic!("Failed to create a temporary folder in '{dir:?}'.")),
+        }
+    }
+
+    /// Get the path to the temporary folder.
+    ///
+    /// # Returns
+    ///
+    /// Path to the temporary folder.
+    pub fn path(&self) -> &Path {
+        self.dir.path()
+    }
+
+    /// Keeps the temporary folder (i.e. it is not deleted when the guard is dropped).
+    ///
+    /// # Returns
+    ///
+    /// Path to the folder.
+    pub fn keep(self) -> PathBuf {
+        self.dir.keep()
+    }
+}
+
+impl Default for TempFolderGuard {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// An isolated scratch directory for a test (or any other task), deleted (along with all of its
+/// contents) when it goes out of scope (i.e. when it is dropped).
+///
+/// # Example
+///
+/// ```
+/// use file_io::{ScratchDir, load_file_as_string, save_string_to_file};
+///
+/// let scratch = ScratchDir::for_test("my_test");
+/// save_string_to_file("Hello, world!", scratch.join("input.txt"));
+/// assert_eq!(load_file_as_string(scratch.join("input.txt")), "Hello, world!");
+/// ```
+#[derive(Debug)]
+#[must_use]
+pub struct ScratchDir {
+    /// The scratch directory (deletes the directory when dropped).
+    dir: TempDir,
+}
+
+impl ScratchDir {
+    /// Constructor.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - Name of the test (used as the prefix of the directory name, which makes it easy
+    ///   to find the directory of a specific test after calling [`ScratchDir::keep`]).
+    ///
+    /// # Returns
+    ///
+    /// A new, empty directory in the system's temporary folder. Every call creates a different
+    /// directory, even for the same `name`.
+    ///
+    /// # Panics
+    ///
+    /// If the directory cannot be created.
+    pub fn for_test(name: &str) -> Self {
+        let dir = Builder::new()
+            .prefix(&format!("{name}-"))
+            .tempdir()
+            .unwrap_or_else(|_| panic!("Failed to create a scratch directory for '{name}'."));
+        Self { dir }
+    }
+
+    /// Get the path to the scratch directory.
+    ///
+    /// # Returns
+    ///
+    /// Path to the scratch directory.
+    pub fn path(&self) -> &Path {
+        self.dir.path()
+    }
+
+    /// Get the path to an entry inside the scratch directory.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - The path relative to the scratch directory (can be a `&str`, [`String`],
+    ///   [`Path`], or [`PathBuf`]).
+    ///
+    /// # Returns
+    ///
+    /// The path joined onto the path to the scratch directory.
+    pub fn join<P: AsRef<Path>>(&self, path: P) -> PathBuf {
+        self.dir.path().join(path)
+    }
+
+    /// Keeps the scratch directory (i.e. it is not deleted when dropped), e.g. to inspect the
+    /// files left behind by a failed test.
+    ///
+    /// # Returns
+    ///
+    /// Path to the scratch directory.
+    pub fn keep(self) -> PathBuf {
+        self.dir.keep()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::save::save_string_to_file;
+    use crate::test_utils::get_temp_dir_path;
+    use tempfile::tempdir;
+
+    #[test]
+    fn test_temp_file_guard() {
+        // Create a temporary directory to work in.
+        let temp_dir = tempdir().unwrap();
+        let temp_dir_path = get_temp_dir_path(&temp_dir);
+
+        // Create a temporary file in the given folder.
+        let temp_file = TempFileGuard::new_in(&temp_dir_path, "prefix_", ".txt");
+        let path = temp_file.path().to_path_buf();
+        assert!(path.is_file());
+        assert_eq!(path.parent().unwrap(), temp_dir_path);
+        let file_name = path.file_name().unwrap().to_str().unwrap();
+        assert!(file_name.starts_with("prefix_") && file_name.ends_with(".txt"));
+
+        // The file is deleted on drop.
+        drop(temp_file);
+        assert!(!path.exists());
+
+        // A kept file is not deleted.
+        let kept = TempFileGuard::new_in(&temp_dir_path, "", "").keep();
+        assert!(kept.is_file());
+    }
+
+    #[test]
+    fn test_temp_folder_guard() {
+        // Create a temporary directory to work in.
+        let temp_dir = tempdir().unwrap();
+        let temp_dir_path = get_temp_dir_path(&temp_dir);
+
+        // Create a temporary folder with some contents.
+        let temp_folder = TempFolderGuard::new_in(&temp_dir_path);
+        let path = temp_folder.path().to_path_buf();
+        save_string_to_file("Hello, world!", path.join("sub/file.txt"));
+
+        // The folder and its contents are deleted on drop.
+        drop(temp_folder);
+        assert!(!path.exists());
+
+        // A kept folder is not deleted.
+        let kept = TempFolderGuard::new_in(&temp_dir_path).keep();
+        assert!(kept.is_dir());
+    }
+
+    #[test]
+    fn test_scratch_dir() {
+        // Each invocation creates a separate directory.
+        let scratch_1 = ScratchDir::for_test("test_scratch_dir");
+        let scratch_2 = ScratchDir::for_test("test_scratch_dir");
+        assert_ne!(scratch_1.path(), scratch_2.path());
+        let dir_name = scratch_1.path().file_name().unwrap().to_str().unwrap();
+        assert!(dir_name.starts_with("test_scratch_dir-"));
+
+        // Create some contents.
+        save_string_to_file("Hello, world!", scratch_1.join("sub/file.txt"));
+        assert!(scratch_1.path().join("sub/file.txt").is_file());
+
+        // The directory is deleted on drop.
+        let path_1 = scratch_1.path().to_path_buf();
+        drop(scratch_1);
+        assert!(!path_1.exists());
+
+        // A kept directory is not deleted.
+        let path_2 = scratch_2.keep();
+        assert!(path_2.is_dir());
+        std::fs::remove_dir_all(path_2).unwrap();
+    }
+}
@@ -1,3 +1,4 @@
+use crate::path::canonicalize_path;
 use std::path::{Path, PathBuf};
 use tempfile::TempDir;
 
@@ -20,8 +21,7 @@ use tempfile::TempDir;
 /// This function is useful for obtaining a stable path to a temporary directory that can be used in
 /// unit tests (since temporary directories can have paths containing symlinks).
 pub(crate) fn get_temp_dir_path(temp_dir: &TempDir) -> PathBuf {
-    std::fs::canonicalize(temp_dir.path())
-        .expect("Failed to get the canonical path of the temporary directory.")
+    canonicalize_path(temp_dir.path())
 }
 
 /// Assert that a folder exists at the specified path.
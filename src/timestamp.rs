@@ -0,0 +1,177 @@
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// Converts a number of days since the Unix epoch (1970-01-01) to a civil (proleptic Gregorian)
+/// date.
+///
+/// # Arguments
+///
+/// * `days` - Number of days since the Unix epoch.
+///
+/// # Returns
+///
+/// The year, month (1-12), and day (1-31).
+///
+/// # Note
+///
+/// This uses Howard Hinnant's `civil_from_days` algorithm.
+fn civil_from_days(days: i64) -> (i64, u32, u32) {
+    let z = days + 719468;
+    let era = z.div_euclid(146097);
+    let doe = z.rem_euclid(146097);
+    let yoe = (doe - doe / 1460 + doe / 36524 - doe / 146096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = (doy - (153 * mp + 2) / 5 + 1) as u32;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 } as u32;
+    let year = yoe + era * 400 + i64::from(month <= 2);
+    (year, month, day)
+}
+
+/// Formats a time as a compact UTC timestamp that is safe to use in file names and sorts
+/// chronologically (e.g. `20261016T093015.123456Z`).
+///
+/// # Arguments
+///
+/// * `time` - The time to format.
+///
+/// # Returns
+///
+/// The formatted timestamp.
+///
+/// # Panics
+///
+/// If `time` is before the Unix epoch.
+pub(crate) fn format_timestamp(time: SystemTime) -> String {
+    let duration = time
+        .duration_since(UNIX_EPOCH)
+        .expect("Time is before the Unix epoch.");
+    let seconds = duration.as_secs() as i64;
+    let (year, month, day) = civil_from_days(seconds.div_euclid(86400));
+    let seconds_of_day = seconds.rem_euclid(86400);
+    format!(
+        "{year:04}{month:02}{day:02}T{:02}{:02}{:02}.{:06}Z",
+        seconds_of_day / 3600,
+        (seconds_of_day % 3600) / 60,
+        seconds_of_day % 60,
+        duration.subsec_micros()
+    )
+}
+
+/// Formats a time as a human-readable UTC date and time (e.g. `2026-10-16 09:30`).
+///
+/// # Arguments
+///
+/// * `time` - The time to format.
+///
+/// # Returns
+///
+/// The formatted date and time.
+pub(crate) fn format_datetime(time: SystemTime) -> String {
+    let seconds = match time.duration_since(UNIX_EPOCH) {
+        Ok(duration) => duration.as_secs() as i64,
+        Err(error) => -(error.duration().as_secs_f64().ceil() as i64),
+    };
+    let (year, month, day) = civil_from_days(seconds.div_euclid(86400));
+    let seconds_of_day = seconds.rem_euclid(86400);
+    format!(
+        "{year:04}-{month:02}-{day:02} {:02}:{:02}",
+        seconds_of_day / 3600,
+        (seconds_of_day % 3600) / 60
+    )
+}
+
+/// Formats a time as a UTC date and time according to a pattern.
+///
+/// # Arguments
+///
+/// * `time` - The time to format.
+/// * `pattern` - The pattern, in which `%Y` (year), `%m` (month), `%d` (day), `%H` (hour), `%M`
+///   (minute), and `%S` (second) are replaced by the corresponding (zero-padded) parts of the time,
+///   and `%%` by a literal `%`. Any other characters are kept as they are.
+///
+/// # Returns
+///
+/// The formatted date and time.
+pub(crate) fn format_time_pattern(time: SystemTime, pattern: &str) -> String {
+    let seconds = match time.duration_since(UNIX_EPOCH) {
+        Ok(duration) => duration.as_secs() as i64,
+        Err(error) => -(error.duration().as_secs_f64().ceil() as i64),
+    };
+    let (year, month, day) = civil_from_days(seconds.div_euclid(86400));
+    let seconds_of_day = seconds.rem_euclid(86400);
+    let mut formatted = String::with_capacity(pattern.len());
+    let mut chars = pattern.chars();
+    while let Some(c) = chars.next() {
+        if c != '%' {
+            formatted.push(c);
+            continue;
+        }
+        match chars.next() {
+            Some('Y') => formatted.push_str(&format!("{year:04}")),
+            Some('m') => formatted.push_str(&format!("{month:02}")),
+            Some('d') => formatted.push_str(&format!("{day:02}")),
+            Some('H') => formatted.push_str(&format!("{:02}", seconds_of_day / 3600)),
+            Some('M') => formatted.push_str(&format!("{:02}", (seconds_of_day % 3600) / 60)),
+            Some('S') => formatted.push_str(&format!("{:02}", seconds_of_day % 60)),
+            Some('%') => formatted.push('%'),
+            Some(other) => {
+                formatted.push('%');
+                formatted.push(other);
+            }
+            None => formatted.push('%'),
+        }
+    }
+    formatted
+}
+
+/// Formats the current time as a compact UTC timestamp (see [`format_timestamp`]).
+///
+/// # Returns
+///
+/// The formatted timestamp.
+pub(crate) fn current_timestamp() -> String {
+    format_timestamp(SystemTime::now())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+
+    #[test]
+    fn test_format_timestamp() {
+        assert_eq!(format_timestamp(UNIX_EPOCH), "19700101T000000.000000Z");
+        assert_eq!(
+            format_timestamp(UNIX_EPOCH + Duration::from_micros(951_782_400_000_001)),
+            "20000229T000000.000001Z"
+        );
+        assert_eq!(
+            format_timestamp(UNIX_EPOCH + Duration::from_secs(1_792_143_015)),
+            "20261016T093015.000000Z"
+        );
+    }
+
+    #[test]
+    fn test_format_datetime() {
+        assert_eq!(format_datetime(UNIX_EPOCH), "1970-01-01 00:00");
+        assert_eq!(
+            format_datetime(UNIX_EPOCH + Duration::from_secs(1_791_970_215)),
+            "2026-10-14 09:30"
+        );
+        assert_eq!(
+            format_datetime(UNIX_EPOCH - Duration::from_secs(60)),
+            "1969-12-31 23:59"
+        );
+    }
+
+    #[test]
+    fn test_format_time_pattern() {
+        let time = UNIX_EPOCH + Duration::from_secs(1_791_970_215);
+        assert_eq!(format_time_pattern(time, "%Y/%m"), "2026/10");
+        assert_eq!(
+            format_time_pattern(time, "%Y-%m-%d %H:%M:%S"),
+            "2026-10-14 09:30:15"
+        );
+        assert_eq!(format_time_pattern(time, "100%% %q %"), "100% %q %");
+    }
+}
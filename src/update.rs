@@ -0,0 +1,206 @@
+use crate::create::create_folder_for_file;
+use crate::instrument::Mutation;
+use std::ffi::OsString;
+use std::fs::File;
+use std::io::{ErrorKind, Write};
+use std::path::{Path, PathBuf};
+use tempfile::NamedTempFile;
+
+/// Determines the path of the lock file guarding updates to a file.
+///
+/// # Arguments
+///
+/// * `path` - The path to the file.
+///
+/// # Returns
+///
+/// The path of the lock file (the path of the file with `.lock` appended).
+fn lock_path(path: &Path) -> PathBuf {
+    let mut lock_path = OsString::from(path.as_os_str());
+    lock_path.push(".lock");
+    PathBuf::from(lock_path)
+}
+
+/// Atomically replaces the content of a file.
+///
+/// # Arguments
+///
+/// * `path` - The path to the file.
+/// * `content` - The new content of the file.
+///
+/// # Errors
+///
+/// If the temporary file cannot be created, written, or renamed over the file.
+///
+/// # Note
+///
+/// The content is written to a temporary file in the same folder, which is flushed to disk and
+/// then renamed over the file. If the file already exists, its permissions are preserved.
+fn replace_atomically(path: &Path, content: &str) -> std::io::Result<()> {
+    let folder = match path.parent() {
+        Some(parent) if !parent.as_os_str().is_empty() => parent,
+        _ => Path::new("."),
+    };
+    let mut temp_file = NamedTempFile::new_in(folder)?;
+    temp_file.write_all(content.as_bytes())?;
+    match std::fs::metadata(path) {
+        Ok(metadata) => temp_file
+            .as_file()
+            .set_permissions(metadata.permissions())?,
+        Err(error) if error.kind() == ErrorKind::NotFound => {}
+        Err(error) => return Err(error),
+    }
+    temp_file.as_file().sync_all()?;
+    temp_file.persist(path).map_err(|error| error.error)?;
+    Ok(())
+}
+
+/// Updates the content of a file while holding an exclusive lock on it.
+///
+/// # Arguments
+///
+/// * `path` - The path to the file (can be a `&str`, [`String`], [`Path`], or
+///   [`std::path::PathBuf`]).
+/// * `update` - Function computing the new content of the file from its current content (which
+///   is empty if the file does not exist yet).
+///
+/// # Returns
+///
+/// The new content of the file.
+///
+/// # Panics
+///
+/// If the lock cannot be acquired, or if the file cannot be read or written.
+///
+/// # Note
+///
+/// * The lock is held on a separate lock file next to the file (named after the file with `.lock`
+///   appended, e.g. `counter.txt.lock`), which is created if necessary and left in place
+///   afterwards. All processes (and threads) updating the file with this function are therefore
+///   serialized, so no update is lost. The lock does not prevent other code from writing to the
+///   file directly.
+/// * The new content is written to a temporary file that is then renamed over the file, so the
+///   file never contains partially written content (even if the process is interrupted).
+/// * This function will create the parent folder for the file if it does not already exist.
+///
+/// # Example
+///
+/// ```
+/// use file_io::update_file_locked;
+///
+/// let path: &str = "folder/subfolder_64/file_67.txt";
+///
+/// // Increment a counter stored in the file (starting from zero if the file does not exist yet).
+/// let increment = |content: &str| (content.trim().parse::<u64>().unwrap_or(0) + 1).to_string();
+/// let first: u64 = update_file_locked(path, increment).parse().unwrap();
+/// let second: u64 = update_file_locked(path, increment).parse().unwrap();
+/// assert_eq!(second, first + 1);
+/// ```
+pub fn update_file_locked<P: AsRef<Path>, F: FnOnce(&str) -> String>(path: P, update: F) -> String {
+    let path = path.as_ref();
+    let mut mutation = Mutation::start("update_file_locked", path);
+
+    // Acquire the lock (it is released when the lock file is closed).
+    let lock_file = if mutation.is_dry_run() {
+        None
+    } else {
+        create_folder_for_file(path);
+        let lock_path = lock_path(path);
+        let lock_file = File::options()
+            .create(true)
+            .truncate(false)
+            .write(true)
+            .open(&lock_path)
+            .unwrap_or_else(|_| panic!("Failed to open lock file '{lock_path:?}'."));
+        lock_file
+            .lock()
+            .unwrap_or_else(|_| panic!("Failed to lock '{lock_path:?}'."));
+        Some(lock_file)
+    };
+
+    // Read the current content and compute the new content.
+    let content = match std::fs::read_to_string(path) {
+        Ok(content) => content,
+        Err(error) if error.kind() == ErrorKind::NotFound => String::new(),
+        Err(_) => panic!("Failed to read file at '{path:?}'."),
+    };
+    let new_content = update(&content);
+    mutation.bytes(new_content.len() as u64);
+
+    // Write the new content.
+    if lock_file.is_some() {
+        replace_atomically(path, &new_content)
+            .unwrap_or_else(|_| panic!("Failed to write to file '{path:?}'."));
+        mutation.succeeded();
+    }
+    drop(lock_file);
+    new_content
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::load::load_file_as_string;
+    use crate::save::save_string_to_file;
+    use crate::test_utils::get_temp_dir_path;
+    use tempfile::tempdir;
+
+    #[test]
+    fn test_update_file_locked() {
+        // Create a temporary directory to work in.
+        let temp_dir = tempdir().unwrap();
+        let file_path = get_temp_dir_path(&temp_dir).join("state/list.txt");
+
+        // A file that does not exist yet is updated from empty content.
+        let new_content = update_file_locked(&file_path, |content| format!("{content}a\n"));
+        assert_eq!(new_content, "a\n");
+        assert_eq!(load_file_as_string(&file_path), "a\n");
+        assert!(file_path.with_file_name("list.txt.lock").is_file());
+
+        // An existing file is updated from its current content.
+        save_string_to_file("x\n", &file_path);
+        update_file_locked(&file_path, |content| format!("{content}b\n"));
+        assert_eq!(load_file_as_string(&file_path), "x\nb\n");
+    }
+
+    #[test]
+    fn test_update_file_locked_concurrently() {
+        // Create a temporary directory to work in.
+        let temp_dir = tempdir().unwrap();
+        let file_path = get_temp_dir_path(&temp_dir).join("counter.txt");
+
+        // Increment a counter from several threads at once (each thread opens the lock file
+        // separately, just like separate processes would).
+        std::thread::scope(|scope| {
+            for _ in 0..8 {
+                scope.spawn(|| {
+                    for _ in 0..25 {
+                        update_file_locked(&file_path, |content| {
+                            (content.parse::<u64>().unwrap_or(0) + 1).to_string()
+                        });
+                    }
+                });
+            }
+        });
+
+        // No increment was lost.
+        assert_eq!(load_file_as_string(&file_path), "200");
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_update_file_locked_preserves_permissions() {
+        use std::os::unix::fs::PermissionsExt;
+
+        // Create a temporary directory to work in.
+        let temp_dir = tempdir().unwrap();
+        let file_path = get_temp_dir_path(&temp_dir).join("script.sh");
+        save_string_to_file("#!/bin/sh\n", &file_path);
+        std::fs::set_permissions(&file_path, std::fs::Permissions::from_mode(0o750)).unwrap();
+
+        // The permissions of the file are preserved.
+        update_file_locked(&file_path, |content| format!("{content}echo hi\n"));
+        let mode = std::fs::metadata(&file_path).unwrap().permissions().mode();
+        assert_eq!(mode & 0o777, 0o750);
+    }
+}
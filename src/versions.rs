@@ -0,0 +1,267 @@
+use crate::copy::copy_file;
+use crate::save::save_string_to_file;
+use crate::timestamp::current_timestamp;
+use std::path::{Path, PathBuf};
+
+/// Returns the name of a file.
+///
+/// # Arguments
+///
+/// * `path` - The path to the file.
+///
+/// # Returns
+///
+/// The name of the file.
+///
+/// # Panics
+///
+/// If the path does not have a file name.
+fn file_name(path: &Path) -> &std::ffi::OsStr {
+    path.file_name()
+        .unwrap_or_else(|| panic!("Path '{path:?}' does not have a file name."))
+}
+
+/// Archives the current version of a file into a new timestamped folder within a history folder.
+///
+/// # Arguments
+///
+/// * `path` - The path to the file.
+/// * `history_dir` - The path to the history folder.
+///
+/// # Panics
+///
+/// If some error is encountered while copying the file into the history folder.
+fn archive_version(path: &Path, history_dir: &Path) {
+    if !path.is_file() {
+        return;
+    }
+
+    // Take a new timestamp if a version of the file was already archived at the same time.
+    let mut version = history_dir.join(current_timestamp()).join(file_name(path));
+    while version.exists() {
+        version = history_dir.join(current_timestamp()).join(file_name(path));
+    }
+    copy_file(path, version);
+}
+
+/// Saves a string to a file at the specified path, archiving the previous version of the file into
+/// a history folder.
+///
+/// # Arguments
+///
+/// * `content` - The string content to save to the file.
+/// * `path` - The path where the file should be saved (can be a `&str`, [`String`], [`Path`], or
+///   [`std::path::PathBuf`]).
+/// * `history_dir` - The path to the history folder (can be a `&str`, [`String`], [`Path`], or
+///   [`std::path::PathBuf`]).
+///
+/// # Panics
+///
+/// If some error is encountered while archiving the previous version, creating the file, or
+/// writing to it.
+///
+/// # Note
+///
+/// * If the file already exists, it is copied to `<history_dir>/<timestamp>/<file name>` (where
+///   `<timestamp>` is the current UTC time, e.g. `20261016T093015.123456Z`) before being
+///   overwritten.
+/// * The history folder can be shared by several files, as long as they have different file names
+///   (versions are identified by file name only, so files with the same name in different folders
+///   would share their versions). The archived versions of a file can be listed with
+///   [`list_versions`] and restored with [`restore_version`].
+/// * This function will create the parent folder for the file and the history folder if they do
+///   not already exist.
+///
+/// # Example
+///
+/// ```
+/// use file_io::{list_versions, load_file_as_string, save_string_to_file_versioned};
+///
+/// let path: &str = "folder/subfolder_35/file_38.txt";
+/// let history_dir: &str = "folder/subfolder_35/history";
+///
+/// save_string_to_file_versioned("Hello, world!", path, history_dir);
+/// save_string_to_file_versioned("Goodbye, world!", path, history_dir);
+///
+/// // The previous version is kept in the history folder.
+/// let versions = list_versions(path, history_dir);
+/// assert_eq!(load_file_as_string(versions.last().unwrap()), "Hello, world!");
+/// ```
+pub fn save_string_to_file_versioned<P: AsRef<Path>, Q: AsRef<Path>>(
+    content: &str,
+    path: P,
+    history_dir: Q,
+) {
+    let path = path.as_ref();
+    archive_version(path, history_dir.as_ref());
+    save_string_to_file(content, path);
+}
+
+/// Lists the archived versions of a file within a history folder.
+///
+/// # Arguments
+///
+/// * `path` - The path to the file (can be a `&str`, [`String`], [`Path`], or
+///   [`std::path::PathBuf`]).
+/// * `history_dir` - The path to the history folder (can be a `&str`, [`String`], [`Path`], or
+///   [`std::path::PathBuf`]).
+///
+/// # Returns
+///
+/// The paths of the archived versions of the file, from the oldest to the most recent. This is
+/// empty if the history folder does not exist.
+///
+/// # Panics
+///
+/// If some error is encountered while reading the history folder.
+///
+/// # Note
+///
+/// Versions are identified by file name only, so the versions of any file with the same name that
+/// shares the history folder are listed as well.
+///
+/// # Example
+///
+/// ```
+/// use file_io::{delete_folder, list_versions, save_string_to_file_versioned};
+///
+/// let path: &str = "folder/subfolder_67/file_39.txt";
+/// let history_dir: &str = "folder/subfolder_67/history";
+///
+/// // Start without any history.
+/// delete_folder("folder/subfolder_67");
+///
+/// save_string_to_file_versioned("1", path, history_dir);
+/// save_string_to_file_versioned("2", path, history_dir);
+/// save_string_to_file_versioned("3", path, history_dir);
+///
+/// assert_eq!(list_versions(path, history_dir).len(), 2);
+/// ```
+pub fn list_versions<P: AsRef<Path>, Q: AsRef<Path>>(path: P, history_dir: Q) -> Vec<PathBuf> {
+    let name = file_name(path.as_ref());
+    let history_dir = history_dir.as_ref();
+    if !history_dir.is_dir() {
+        return Vec::new();
+    }
+
+    // Each timestamped folder holding a version of the file is one version. Since the timestamps
+    // sort chronologically, so do the folders.
+    let mut versions: Vec<PathBuf> = std::fs::read_dir(history_dir)
+        .unwrap_or_else(|_| panic!("Failed to read folder '{history_dir:?}'."))
+        .filter_map(|entry| entry.ok())
+        .map(|entry| entry.path().join(name))
+        .filter(|version| version.is_file())
+        .collect();
+    versions.sort();
+    versions
+}
+
+/// Restores an archived version of a file, archiving the current version first.
+///
+/// # Arguments
+///
+/// * `path` - The path to the file (can be a `&str`, [`String`], [`Path`], or
+///   [`std::path::PathBuf`]).
+/// * `history_dir` - The path to the history folder (can be a `&str`, [`String`], [`Path`], or
+///   [`std::path::PathBuf`]).
+/// * `version` - The path to the version to restore, as returned by [`list_versions`] (can be a
+///   `&str`, [`String`], [`Path`], or [`std::path::PathBuf`]).
+///
+/// # Panics
+///
+/// If some error is encountered while archiving the current version or restoring the requested
+/// one.
+///
+/// # Note
+///
+/// Since the current version is archived before being overwritten, restoring a version can itself
+/// be rolled back.
+///
+/// # Example
+///
+/// ```
+/// use file_io::{
+///     list_versions, load_file_as_string, restore_version, save_string_to_file_versioned,
+/// };
+///
+/// let path: &str = "folder/subfolder_35/file_40.txt";
+/// let history_dir: &str = "folder/subfolder_35/history";
+///
+/// save_string_to_file_versioned("good", path, history_dir);
+/// save_string_to_file_versioned("bad", path, history_dir);
+///
+/// // Roll back to the previous version.
+/// let versions = list_versions(path, history_dir);
+/// restore_version(path, history_dir, versions.last().unwrap());
+/// assert_eq!(load_file_as_string(path), "good");
+/// ```
+pub fn restore_version<P: AsRef<Path>, Q: AsRef<Path>, R: AsRef<Path>>(
+    path: P,
+    history_dir: Q,
+    version: R,
+) {
+    let path = path.as_ref();
+    let version = version.as_ref();
+    if !version.is_file() {
+        panic!("Version '{version:?}' does not exist.");
+    }
+    archive_version(path, history_dir.as_ref());
+    copy_file(version, path);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::load::load_file_as_string;
+    use crate::test_utils::get_temp_dir_path;
+    use tempfile::tempdir;
+
+    #[test]
+    fn test_save_list_restore_versions() {
+        // Create a temporary directory to work in.
+        let temp_dir = tempdir().unwrap();
+
+        // Paths to the files and the history folder.
+        let file_path = get_temp_dir_path(&temp_dir).join("config.toml");
+        let other_path = get_temp_dir_path(&temp_dir).join("other.toml");
+        let history_dir = get_temp_dir_path(&temp_dir).join("history");
+
+        // No versions exist before the history folder is created.
+        assert!(list_versions(&file_path, &history_dir).is_empty());
+
+        // Save several versions of two files sharing the history folder.
+        for version in 1..=3 {
+            save_string_to_file_versioned(&format!("v{version}"), &file_path, &history_dir);
+        }
+        save_string_to_file_versioned("other v1", &other_path, &history_dir);
+        save_string_to_file_versioned("other v2", &other_path, &history_dir);
+
+        // Only the previous versions of each file are listed, from the oldest.
+        let versions = list_versions(&file_path, &history_dir);
+        let contents: Vec<String> = versions.iter().map(load_file_as_string).collect();
+        assert_eq!(contents, ["v1", "v2"]);
+        assert_eq!(list_versions(&other_path, &history_dir).len(), 1);
+
+        // Restore the oldest version, archiving the current one.
+        restore_version(&file_path, &history_dir, &versions[0]);
+        assert_eq!(load_file_as_string(&file_path), "v1");
+        let versions = list_versions(&file_path, &history_dir);
+        let contents: Vec<String> = versions.iter().map(load_file_as_string).collect();
+        assert_eq!(contents, ["v1", "v2", "v3"]);
+    }
+
+    #[test]
+    #[should_panic(expected = "does not exist")]
+    fn test_restore_version_missing() {
+        // Create a temporary directory to work in.
+        let temp_dir = tempdir().unwrap();
+        let temp_dir_path = get_temp_dir_path(&temp_dir);
+
+        // Try to restore a version that does not exist.
+        restore_version(
+            temp_dir_path.join("file.txt"),
+            temp_dir_path.join("history"),
+            temp_dir_path.join("history/missing/file.txt"),
+        );
+    }
+}
@@ -0,0 +1,170 @@
+use crate::load::load_file_as_string;
+use crate::save::save_string_to_file;
+use serde::Serialize;
+use serde::de::DeserializeOwned;
+use std::path::Path;
+
+/// XML declaration written at the start of every file saved by [`save_xml`].
+const XML_DECLARATION: &str = "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n";
+
+/// Loads an XML file and deserializes it into a value.
+///
+/// # Arguments
+///
+/// * `path` - The path to the XML file (can be a `&str`, [`String`], [`Path`], or
+///   [`std::path::PathBuf`]).
+///
+/// # Returns
+///
+/// The deserialized value.
+///
+/// # Panics
+///
+/// If the file cannot be read, or if its content cannot be deserialized into a `T`.
+///
+/// # Note
+///
+/// Deserialization is performed by `quick-xml`, so the mapping between XML and Rust types follows
+/// its conventions (e.g. fields named `@name` map to attributes and `$text` to text content).
+///
+/// # Example
+///
+/// ```
+/// use file_io::{load_xml, save_string_to_file};
+/// use serde::Deserialize;
+///
+/// #[derive(Debug, Deserialize, PartialEq)]
+/// struct Server {
+///     #[serde(rename = "@name")]
+///     name: String,
+///     port: u16,
+/// }
+///
+/// let path: &str = "folder/subfolder_55/server.xml";
+/// save_string_to_file("<Server name=\"api\"><port>8080</port></Server>", path);
+///
+/// let server: Server = load_xml(path);
+/// assert_eq!(
+///     server,
+///     Server {
+///         name: String::from("api"),
+///         port: 8080
+///     }
+/// );
+/// ```
+pub fn load_xml<T: DeserializeOwned, P: AsRef<Path>>(path: P) -> T {
+    let path = path.as_ref();
+    quick_xml::de::from_str(&load_file_as_string(path))
+        .unwrap_or_else(|error| panic!("Failed to deserialize XML file '{path:?}': {error}."))
+}
+
+/// Serializes a value to XML and saves it to a file at the specified path.
+///
+/// # Arguments
+///
+/// * `value` - The value to serialize.
+/// * `path` - The path where the file should be saved (can be a `&str`, [`String`], [`Path`], or
+///   [`std::path::PathBuf`]).
+///
+/// # Panics
+///
+/// If the value cannot be serialized to XML, or if some error is encountered while creating the
+/// file or writing to it.
+///
+/// # Note
+///
+/// * Serialization is performed by `quick-xml`, so the mapping between Rust types and XML follows
+///   its conventions (e.g. the root element is named after the type, fields named `@name` map to
+///   attributes, and `$text` to text content).
+/// * The file starts with an XML declaration, and nested elements are indented by two spaces.
+/// * This function will create the parent folder for the file if it does not already exist.
+///
+/// # Example
+///
+/// ```
+/// use file_io::{load_file_as_string, save_xml};
+/// use serde::Serialize;
+///
+/// #[derive(Serialize)]
+/// struct Server {
+///     #[serde(rename = "@name")]
+///     name: String,
+///     port: u16,
+/// }
+///
+/// let path: &str = "folder/subfolder_55/file_57.xml";
+/// let server = Server {
+///     name: String::from("api"),
+///     port: 8080,
+/// };
+/// save_xml(&server, path);
+///
+/// assert_eq!(
+///     load_file_as_string(path),
+///     "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n\
+///      <Server name=\"api\">\n  <port>8080</port>\n</Server>\n"
+/// );
+/// ```
+pub fn save_xml<T: Serialize + ?Sized, P: AsRef<Path>>(value: &T, path: P) {
+    let path = path.as_ref();
+    let mut content = String::from(XML_DECLARATION);
+    let mut serializer = quick_xml::se::Serializer::new(&mut content);
+    serializer.indent(' ', 2);
+    value
+        .serialize(serializer)
+        .unwrap_or_else(|error| panic!("Failed to serialize XML for '{path:?}': {error}."));
+    content.push('\n');
+    save_string_to_file(&content, path);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::get_temp_dir_path;
+    use serde::Deserialize;
+    use tempfile::tempdir;
+
+    #[derive(Debug, Deserialize, PartialEq, Serialize)]
+    struct Service {
+        #[serde(rename = "@id")]
+        id: u32,
+        name: String,
+        #[serde(default, rename = "endpoint")]
+        endpoints: Vec<String>,
+    }
+
+    #[test]
+    fn test_save_load_xml() {
+        // Create a temporary directory to work in.
+        let temp_dir = tempdir().unwrap();
+        let file_path = get_temp_dir_path(&temp_dir).join("config/service.xml");
+        let service = Service {
+            id: 7,
+            name: String::from("billing & <invoicing>"),
+            endpoints: vec![String::from("/a"), String::from("/b")],
+        };
+
+        // Save the value and load it back.
+        save_xml(&service, &file_path);
+        assert_eq!(
+            load_file_as_string(&file_path),
+            "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n\
+             <Service id=\"7\">\n  \
+             <name>billing &amp; &lt;invoicing&gt;</name>\n  \
+             <endpoint>/a</endpoint>\n  \
+             <endpoint>/b</endpoint>\n\
+             </Service>\n"
+        );
+        assert_eq!(load_xml::<Service, _>(&file_path), service);
+    }
+
+    #[test]
+    #[should_panic(expected = "Failed to deserialize XML file")]
+    fn test_load_xml_invalid() {
+        // Create a temporary directory to work in.
+        let temp_dir = tempdir().unwrap();
+        let file_path = get_temp_dir_path(&temp_dir).join("service.xml");
+        save_string_to_file("<Service id=\"seven\"><name>x</name></Service>", &file_path);
+        load_xml::<Service, _>(&file_path);
+    }
+}